@@ -1,6 +1,11 @@
 #![allow(missing_docs)]
 //! Defines fluent builder for styles.
 
+use crate::builder_responsive::MediaQueryResults;
+use crate::color_tokens::{ColorField, ColorToken, ColorTokens, TokenColors};
+use crate::relative_lengths::{
+    inherited_font_size, LengthField, RelativeLengths, RootFontSize, DEFAULT_ROOT_FONT_SIZE,
+};
 use bevy::{
     asset::AssetPath,
     color::{LinearRgba, Srgba},
@@ -15,6 +20,7 @@ pub struct StyleBuilder<'a, 'w> {
     pub target: &'a mut EntityWorldMut<'w>,
     pub(crate) node: ui::Node,
     pub(crate) node_changed: bool,
+    pub(crate) media_queries: MediaQueryResults,
 }
 
 impl<'a, 'w> StyleBuilder<'a, 'w> {
@@ -24,6 +30,7 @@ impl<'a, 'w> StyleBuilder<'a, 'w> {
             target,
             node,
             node_changed: false,
+            media_queries: MediaQueryResults::default(),
         }
     }
 
@@ -35,11 +42,126 @@ impl<'a, 'w> StyleBuilder<'a, 'w> {
         })
     }
 
-    /// Consumes the [`StyleBuilder`] and applies the style to the target entity.
-    pub fn finish(self) {
+    /// Resolve a length for the given node field. If the length is font-relative, it is
+    /// recorded on the entity so that it can be re-resolved when the font size changes;
+    /// otherwise any previously recorded relative length for that field is discarded.
+    pub(crate) fn resolve_length(&mut self, field: LengthField, length: Length) -> ui::Val {
+        if !length.is_relative() {
+            if self
+                .target
+                .get::<RelativeLengths>()
+                .is_some_and(|rel| rel.contains(field))
+            {
+                self.target
+                    .get_mut::<RelativeLengths>()
+                    .unwrap()
+                    .remove(field);
+            }
+            return length.resolve(0., 0.);
+        }
+
+        let (em, rem) = self.font_sizes();
+        match self.target.get_mut::<RelativeLengths>() {
+            Some(mut rel) => {
+                if rel.get(field) != Some(length) {
+                    rel.set(field, length);
+                }
+            }
+            None => {
+                let mut rel = RelativeLengths::default();
+                rel.set(field, length);
+                self.target.insert(rel);
+            }
+        }
+        length.resolve(em, rem)
+    }
+
+    /// Resolve a length against the current font sizes of the target entity, without recording
+    /// it. Use this for properties which are not re-resolved when the font size changes.
+    pub(crate) fn resolve_length_untracked(&self, length: Length) -> ui::Val {
+        if !length.is_relative() {
+            return length.resolve(0., 0.);
+        }
+        let (em, rem) = self.font_sizes();
+        length.resolve(em, rem)
+    }
+
+    /// The font size inherited by the target entity and the root font size, in that order.
+    fn font_sizes(&self) -> (f32, f32) {
+        let world = self.target.world();
+        let rem = world
+            .get_resource::<RootFontSize>()
+            .map_or(DEFAULT_ROOT_FONT_SIZE, |r| r.0);
+        let em = inherited_font_size(world, self.target.id()).unwrap_or(rem);
+        (em, rem)
+    }
+
+    /// Resolve all four edges of a rect, see [`StyleBuilder::resolve_length`].
+    pub(crate) fn resolve_rect(
+        &mut self,
+        fields: [LengthField; 4],
+        rect: impl UiRectParam,
+    ) -> ui::UiRect {
+        let [left, right, top, bottom] = rect.to_lengths();
+        ui::UiRect {
+            left: self.resolve_length(fields[0], left),
+            right: self.resolve_length(fields[1], right),
+            top: self.resolve_length(fields[2], top),
+            bottom: self.resolve_length(fields[3], bottom),
+        }
+    }
+
+    /// Resolve a color for the given style property. If a token is given, it is looked up in
+    /// the [`ColorTokens`] resource and recorded on the entity so that it can be re-resolved
+    /// when the tokens change; otherwise any previously recorded token for that property is
+    /// discarded and `color` is returned unchanged.
+    pub(crate) fn resolve_color(
+        &mut self,
+        field: ColorField,
+        token: Option<ColorToken>,
+        color: Option<Color>,
+    ) -> Option<Color> {
+        let Some(token) = token else {
+            if self
+                .target
+                .get::<TokenColors>()
+                .is_some_and(|tc| tc.contains(field))
+            {
+                self.target.get_mut::<TokenColors>().unwrap().remove(field);
+            }
+            return color;
+        };
+
+        let resolved = self
+            .target
+            .world()
+            .get_resource::<ColorTokens>()
+            .and_then(|tokens| token.resolve(tokens));
+        match self.target.get_mut::<TokenColors>() {
+            Some(mut tc) => {
+                if tc.get(field) != Some(token) {
+                    tc.set(field, token);
+                }
+            }
+            None => {
+                let mut tc = TokenColors::default();
+                tc.set(field, token);
+                self.target.insert(tc);
+            }
+        }
+        // An unknown token resolves to transparent rather than removing the component, so
+        // that it can be filled in later when the token is defined.
+        Some(resolved.unwrap_or(Color::NONE))
+    }
+
+    /// Consumes the [`StyleBuilder`] and applies the style to the target entity. Returns the
+    /// media queries that were evaluated, so that the caller can re-apply the style when the
+    /// viewport changes.
+    pub fn finish(self) -> MediaQueryResults {
         if self.node_changed {
             self.target.insert(self.node);
         }
+        self.media_queries
     }
 }
 
@@ -48,6 +170,23 @@ impl<'a, 'w> StyleBuilder<'a, 'w> {
 /// Trait that represents a CSS color
 pub trait ColorParam {
     fn to_val(self) -> Option<Color>;
+
+    /// If this color is a reference to a named color, return the token.
+    fn token(&self) -> Option<ColorToken> {
+        None
+    }
+}
+
+/// Tokens have no value outside of a style context; the style builder resolves them against
+/// the [`ColorTokens`] resource instead of calling `to_val`.
+impl ColorParam for ColorToken {
+    fn to_val(self) -> Option<Color> {
+        None
+    }
+
+    fn token(&self) -> Option<ColorToken> {
+        Some(*self)
+    }
 }
 
 impl ColorParam for Option<Color> {
@@ -93,26 +232,75 @@ impl ColorParam for &str {
     }
 }
 
+/// A length which may be relative to a font size. Relative lengths are resolved to pixels
+/// when the style is applied, and re-resolved whenever the font size they depend on changes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Length {
+    /// An absolute or layout-relative length.
+    Val(ui::Val),
+    /// Multiple of the font size inherited by the element (CSS `em`).
+    Em(f32),
+    /// Multiple of the root font size, see [`RootFontSize`] (CSS `rem`).
+    Rem(f32),
+    /// Percentage of the font size inherited by the element, so `FontPercent(50.)` is the same
+    /// as `Em(0.5)`.
+    FontPercent(f32),
+}
+
+impl Length {
+    /// True if this length depends on a font size.
+    pub fn is_relative(&self) -> bool {
+        !matches!(self, Length::Val(_))
+    }
+
+    /// Resolve this length, given the inherited font size and the root font size.
+    pub fn resolve(self, em: f32, rem: f32) -> ui::Val {
+        match self {
+            Length::Val(val) => val,
+            Length::Em(n) => ui::Val::Px(n * em),
+            Length::Rem(n) => ui::Val::Px(n * rem),
+            Length::FontPercent(n) => ui::Val::Px(n * em * 0.01),
+        }
+    }
+}
+
 /// Trait that represents a CSS "length"
 pub trait LengthParam {
-    fn to_val(self) -> ui::Val;
+    fn to_length(self) -> Length;
+
+    /// Convert to a `ui::Val` outside of any element, for example when building a value ahead of
+    /// time. Font-relative lengths are resolved against [`DEFAULT_ROOT_FONT_SIZE`]; style
+    /// builder methods resolve them against the element's font sizes instead.
+    fn to_val(self) -> ui::Val
+    where
+        Self: Sized,
+    {
+        self.to_length()
+            .resolve(DEFAULT_ROOT_FONT_SIZE, DEFAULT_ROOT_FONT_SIZE)
+    }
 }
 
-impl LengthParam for ui::Val {
-    fn to_val(self) -> ui::Val {
+impl LengthParam for Length {
+    fn to_length(self) -> Length {
         self
     }
 }
 
+impl LengthParam for ui::Val {
+    fn to_length(self) -> Length {
+        Length::Val(self)
+    }
+}
+
 impl LengthParam for f32 {
-    fn to_val(self) -> ui::Val {
-        ui::Val::Px(self)
+    fn to_length(self) -> Length {
+        Length::Val(ui::Val::Px(self))
     }
 }
 
 impl LengthParam for i32 {
-    fn to_val(self) -> ui::Val {
-        ui::Val::Px(self as f32)
+    fn to_length(self) -> Length {
+        Length::Val(ui::Val::Px(self as f32))
     }
 }
 
@@ -136,6 +324,20 @@ impl ZIndexParam for i32 {
 /// Trait that represents CSS edge widths (margin, padding, etc.)
 pub trait UiRectParam {
     fn to_uirect(self) -> ui::UiRect;
+
+    /// Edge lengths in the order left, right, top, bottom.
+    fn to_lengths(self) -> [Length; 4]
+    where
+        Self: Sized,
+    {
+        let rect = self.to_uirect();
+        [
+            Length::Val(rect.left),
+            Length::Val(rect.right),
+            Length::Val(rect.top),
+            Length::Val(rect.bottom),
+        ]
+    }
 }
 
 impl UiRectParam for ui::UiRect {
@@ -166,10 +368,31 @@ impl<H: LengthParam, V: LengthParam> UiRectParam for (H, V) {
     fn to_uirect(self) -> ui::UiRect {
         ui::UiRect::axes(self.0.to_val(), self.1.to_val())
     }
+
+    fn to_lengths(self) -> [Length; 4] {
+        let h = self.0.to_length();
+        let v = self.1.to_length();
+        [h, h, v, v]
+    }
 }
 
 pub trait BorderColorParam {
     fn to_border_color(self) -> ui::BorderColor;
+
+    /// If this color is a reference to a named color, return the token.
+    fn token(&self) -> Option<ColorToken> {
+        None
+    }
+}
+
+impl BorderColorParam for ColorToken {
+    fn to_border_color(self) -> ui::BorderColor {
+        ui::BorderColor::all(Color::NONE)
+    }
+
+    fn token(&self) -> Option<ColorToken> {
+        Some(*self)
+    }
 }
 
 impl BorderColorParam for ui::BorderColor {
@@ -348,3 +571,126 @@ impl<'a, T: Asset> From<&'a HandleOrOwnedPath<T>> for MaybeHandleOrPath<'a, T> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+    use crate::relative_lengths::update_relative_lengths;
+    use crate::text_styles::InheritableFontStyles;
+    use crate::StyleBuilderLayout;
+
+    fn font_size(size: f32) -> InheritableFontStyles {
+        InheritableFontStyles {
+            font_size: Some(size),
+            ..Default::default()
+        }
+    }
+
+    /// Apply `style` to `entity` the way a style handle does.
+    fn apply(world: &mut World, entity: Entity, style: impl FnOnce(&mut StyleBuilder)) {
+        let mut target = world.entity_mut(entity);
+        let node = target.get::<ui::Node>().cloned().unwrap_or_default();
+        let mut sb = StyleBuilder::new(&mut target, node);
+        style(&mut sb);
+        sb.finish();
+    }
+
+    #[test]
+    fn test_length_resolve() {
+        assert_eq!(Length::Em(2.).resolve(10., 16.), ui::Val::Px(20.));
+        assert_eq!(Length::Rem(2.).resolve(10., 16.), ui::Val::Px(32.));
+        assert_eq!(Length::FontPercent(50.).resolve(10., 16.), ui::Val::Px(5.));
+        assert_eq!(
+            Length::Val(ui::Val::Percent(50.)).resolve(10., 16.),
+            ui::Val::Percent(50.)
+        );
+        assert_eq!(
+            Length::Em(2.).to_val(),
+            ui::Val::Px(2. * DEFAULT_ROOT_FONT_SIZE)
+        );
+    }
+
+    #[test]
+    fn test_resolve_em_rem() {
+        let mut world = World::new();
+        world.insert_resource(RootFontSize(16.));
+        let parent = world.spawn(font_size(10.)).id();
+        let child = world.spawn((ui::Node::default(), ChildOf(parent))).id();
+        apply(&mut world, child, |sb| {
+            sb.width(Length::Em(2.))
+                .height(Length::Rem(2.))
+                .min_width(Length::FontPercent(150.))
+                .min_height(5.);
+        });
+
+        let node = world.get::<ui::Node>(child).unwrap();
+        assert_eq!(node.width, ui::Val::Px(20.));
+        assert_eq!(node.height, ui::Val::Px(32.));
+        assert_eq!(node.min_width, ui::Val::Px(15.));
+        assert_eq!(node.min_height, ui::Val::Px(5.));
+
+        let rel = world.get::<RelativeLengths>(child).unwrap();
+        assert_eq!(rel.get(LengthField::Width), Some(Length::Em(2.)));
+        assert!(!rel.contains(LengthField::MinHeight));
+    }
+
+    #[test]
+    fn test_em_without_inherited_size_uses_root() {
+        let mut world = World::new();
+        world.insert_resource(RootFontSize(16.));
+        let entity = world.spawn(ui::Node::default()).id();
+        apply(&mut world, entity, |sb| {
+            sb.width(Length::Em(2.));
+        });
+        assert_eq!(
+            world.get::<ui::Node>(entity).unwrap().width,
+            ui::Val::Px(32.)
+        );
+    }
+
+    #[test]
+    fn test_absolute_length_forgets_relative() {
+        let mut world = World::new();
+        let entity = world.spawn(ui::Node::default()).id();
+        apply(&mut world, entity, |sb| {
+            sb.width(Length::Em(2.));
+        });
+        apply(&mut world, entity, |sb| {
+            sb.width(10.);
+        });
+        let rel = world.get::<RelativeLengths>(entity).unwrap();
+        assert!(!rel.contains(LengthField::Width));
+    }
+
+    #[test]
+    fn test_reresolve_on_font_size_change() {
+        let mut world = World::new();
+        world.insert_resource(RootFontSize(16.));
+        let parent = world.spawn(font_size(10.)).id();
+        let child = world.spawn((ui::Node::default(), ChildOf(parent))).id();
+        apply(&mut world, child, |sb| {
+            sb.width(Length::Em(2.))
+                .height(Length::Rem(1.))
+                .padding((Length::Em(1.), Length::Val(ui::Val::Px(3.))));
+        });
+
+        world
+            .get_mut::<InheritableFontStyles>(parent)
+            .unwrap()
+            .font_size = Some(20.);
+        world.run_system_once(update_relative_lengths).unwrap();
+        let node = world.get::<ui::Node>(child).unwrap();
+        assert_eq!(node.width, ui::Val::Px(40.));
+        assert_eq!(node.height, ui::Val::Px(16.));
+        assert_eq!(node.padding.left, ui::Val::Px(20.));
+        assert_eq!(node.padding.top, ui::Val::Px(3.));
+
+        world.resource_mut::<RootFontSize>().0 = 8.;
+        world.run_system_once(update_relative_lengths).unwrap();
+        let node = world.get::<ui::Node>(child).unwrap();
+        assert_eq!(node.width, ui::Val::Px(40.));
+        assert_eq!(node.height, ui::Val::Px(8.));
+    }
+}
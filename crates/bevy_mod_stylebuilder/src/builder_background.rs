@@ -1,6 +1,7 @@
 use bevy::{prelude::*, ui};
 
 use super::builder::{ColorParam, MaybeHandleOrPath, StyleBuilder};
+use crate::color_tokens::ColorField;
 
 #[allow(missing_docs)]
 pub trait StyleBuilderBackground {
@@ -21,6 +22,51 @@ pub trait StyleBuilderBackground {
 
     /// Set the background color, or `None` for transparent.
     fn background_image_color(&mut self, color: impl ColorParam) -> &mut Self;
+
+    /// Set how the background image is scaled to fit the element: stretched, nine-sliced or
+    /// tiled.
+    fn background_image_mode(&mut self, mode: NodeImageMode) -> &mut Self;
+
+    /// Draw the background image as a nine-slice, using the given slicer. The corners are drawn
+    /// at their natural size, while the edges and center are scaled according to the slicer's
+    /// scale modes.
+    fn background_image_sliced(&mut self, slicer: TextureSlicer) -> &mut Self;
+
+    /// Draw the background image as a nine-slice with the same inset (in image pixels) on all
+    /// four sides, and the same scale mode for edges and center.
+    fn background_image_nine_slice(&mut self, inset: f32, scale_mode: SliceScaleMode) -> &mut Self;
+
+    /// Repeat the background image across the element. Tiles are stretched by up to
+    /// `stretch_value` times their natural size before another tile is added.
+    fn background_image_tiled(
+        &mut self,
+        tile_x: bool,
+        tile_y: bool,
+        stretch_value: f32,
+    ) -> &mut Self;
+
+    /// Use only a sub-rectangle of the background image, in image pixels. This allows several
+    /// skins to be packed into a single bitmap. `None` uses the whole image.
+    fn background_image_rect(&mut self, rect: Option<Rect>) -> &mut Self;
+
+    /// Use a tile from a texture atlas as the background image source.
+    fn background_image_atlas(
+        &mut self,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    ) -> &mut Self;
+}
+
+/// Modify the `ImageNode` of the target, inserting a default one if needed.
+fn update_image_node(sb: &mut StyleBuilder, update: impl FnOnce(&mut ImageNode)) {
+    match sb.target.get_mut::<ImageNode>() {
+        Some(mut uii) => update(&mut uii),
+        None => {
+            let mut uii = ImageNode::default();
+            update(&mut uii);
+            sb.target.insert(uii);
+        }
+    }
 }
 
 impl<'a, 'w> StyleBuilderBackground for StyleBuilder<'a, 'w> {
@@ -62,7 +108,8 @@ impl<'a, 'w> StyleBuilderBackground for StyleBuilder<'a, 'w> {
     }
 
     fn background_color(&mut self, color: impl ColorParam) -> &mut Self {
-        if let Some(color) = color.to_val() {
+        let token = color.token();
+        if let Some(color) = self.resolve_color(ColorField::Background, token, color.to_val()) {
             self.target.insert(ui::BackgroundColor(color));
         } else {
             self.target.remove::<ui::BackgroundColor>();
@@ -71,7 +118,9 @@ impl<'a, 'w> StyleBuilderBackground for StyleBuilder<'a, 'w> {
     }
 
     fn background_image_color(&mut self, color: impl ColorParam) -> &mut Self {
-        match (color.to_val(), self.target.get_mut::<ImageNode>()) {
+        let token = color.token();
+        let color = self.resolve_color(ColorField::BackgroundImage, token, color.to_val());
+        match (color, self.target.get_mut::<ImageNode>()) {
             (Some(color), Some(mut uii)) => {
                 uii.color = color;
             }
@@ -85,4 +134,51 @@ impl<'a, 'w> StyleBuilderBackground for StyleBuilder<'a, 'w> {
         };
         self
     }
+
+    fn background_image_mode(&mut self, mode: NodeImageMode) -> &mut Self {
+        update_image_node(self, |uii| uii.image_mode = mode);
+        self
+    }
+
+    fn background_image_sliced(&mut self, slicer: TextureSlicer) -> &mut Self {
+        self.background_image_mode(NodeImageMode::Sliced(slicer))
+    }
+
+    fn background_image_nine_slice(&mut self, inset: f32, scale_mode: SliceScaleMode) -> &mut Self {
+        self.background_image_sliced(TextureSlicer {
+            border: BorderRect::all(inset),
+            center_scale_mode: scale_mode,
+            sides_scale_mode: scale_mode,
+            ..default()
+        })
+    }
+
+    fn background_image_tiled(
+        &mut self,
+        tile_x: bool,
+        tile_y: bool,
+        stretch_value: f32,
+    ) -> &mut Self {
+        self.background_image_mode(NodeImageMode::Tiled {
+            tile_x,
+            tile_y,
+            stretch_value,
+        })
+    }
+
+    fn background_image_rect(&mut self, rect: Option<Rect>) -> &mut Self {
+        update_image_node(self, |uii| uii.rect = rect);
+        self
+    }
+
+    fn background_image_atlas(
+        &mut self,
+        layout: Handle<TextureAtlasLayout>,
+        index: usize,
+    ) -> &mut Self {
+        update_image_node(self, |uii| {
+            uii.texture_atlas = Some(TextureAtlas { layout, index });
+        });
+        self
+    }
 }
@@ -1,4 +1,6 @@
-use crate::BorderColorParam;
+use bevy::ui;
+
+use crate::{color_tokens::ColorField, BorderColorParam};
 
 use super::builder::StyleBuilder;
 
@@ -9,7 +11,11 @@ pub trait StyleBuilderBorderColor {
 
 impl<'a, 'w> StyleBuilderBorderColor for StyleBuilder<'a, 'w> {
     fn border_color(&mut self, color: impl BorderColorParam) -> &mut Self {
-        self.target.insert(color.to_border_color());
+        let token = color.token();
+        match self.resolve_color(ColorField::Border, token, None) {
+            Some(resolved) => self.target.insert(ui::BorderColor::all(resolved)),
+            None => self.target.insert(color.to_border_color()),
+        };
         self
     }
 }
@@ -0,0 +1,133 @@
+use bevy::{color::Color, ui};
+
+use crate::color_tokens::ColorField;
+
+use super::builder::{ColorParam, LengthParam, StyleBuilder};
+
+/// Trait that represents one or more box shadow layers.
+pub trait BoxShadowParam {
+    /// Convert to a list of shadow layers. An empty list removes the shadow.
+    fn to_shadows(self) -> Vec<ui::ShadowStyle>;
+}
+
+impl BoxShadowParam for ui::BoxShadow {
+    fn to_shadows(self) -> Vec<ui::ShadowStyle> {
+        self.0
+    }
+}
+
+impl BoxShadowParam for ui::ShadowStyle {
+    fn to_shadows(self) -> Vec<ui::ShadowStyle> {
+        vec![self]
+    }
+}
+
+impl BoxShadowParam for Vec<ui::ShadowStyle> {
+    fn to_shadows(self) -> Vec<ui::ShadowStyle> {
+        self
+    }
+}
+
+impl<const N: usize> BoxShadowParam for [ui::ShadowStyle; N] {
+    fn to_shadows(self) -> Vec<ui::ShadowStyle> {
+        self.to_vec()
+    }
+}
+
+impl<T: BoxShadowParam> BoxShadowParam for Option<T> {
+    fn to_shadows(self) -> Vec<ui::ShadowStyle> {
+        self.map_or(Vec::new(), |s| s.to_shadows())
+    }
+}
+
+/// Construct a single box shadow layer.
+///
+/// * `x`, `y` - offset of the shadow from the element.
+/// * `blur` - blur radius.
+/// * `spread` - amount by which the shadow is grown (or shrunk, if negative) before blurring.
+///
+/// Layers are built outside of a style context, so the color must be a concrete color rather
+/// than a [`ColorToken`](crate::ColorToken), and font-relative lengths are resolved against
+/// [`DEFAULT_ROOT_FONT_SIZE`](crate::DEFAULT_ROOT_FONT_SIZE). Use
+/// [`drop_shadow`](StyleBuilderBoxShadow::drop_shadow) for a shadow colored by a token or sized
+/// relative to the element's font.
+pub fn shadow_layer(
+    x: impl LengthParam,
+    y: impl LengthParam,
+    blur: impl LengthParam,
+    spread: impl LengthParam,
+    color: impl Into<Color>,
+) -> ui::ShadowStyle {
+    ui::ShadowStyle {
+        color: color.into(),
+        x_offset: x.to_val(),
+        y_offset: y.to_val(),
+        spread_radius: spread.to_val(),
+        blur_radius: blur.to_val(),
+    }
+}
+
+#[allow(missing_docs)]
+pub trait StyleBuilderBoxShadow {
+    /// Set the box shadow layers of the element, replacing any existing shadows. Layers are
+    /// drawn in order, so the first layer is on the bottom.
+    fn box_shadow(&mut self, shadow: impl BoxShadowParam) -> &mut Self;
+
+    /// Set a single drop shadow with the given offset, blur radius and color. The color may be
+    /// a token, which is re-resolved when the token table changes.
+    fn drop_shadow(
+        &mut self,
+        x: impl LengthParam,
+        y: impl LengthParam,
+        blur: impl LengthParam,
+        color: impl ColorParam,
+    ) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderBoxShadow for StyleBuilder<'a, 'w> {
+    fn box_shadow(&mut self, shadow: impl BoxShadowParam) -> &mut Self {
+        // Explicit layers never carry a token, so forget any recorded by `drop_shadow`.
+        self.resolve_color(ColorField::Shadow, None, None);
+        set_shadows(self, shadow.to_shadows());
+        self
+    }
+
+    fn drop_shadow(
+        &mut self,
+        x: impl LengthParam,
+        y: impl LengthParam,
+        blur: impl LengthParam,
+        color: impl ColorParam,
+    ) -> &mut Self {
+        let token = color.token();
+        let color = self
+            .resolve_color(ColorField::Shadow, token, color.to_val())
+            .unwrap_or(Color::NONE);
+        let layer = shadow_layer(
+            self.resolve_length_untracked(x.to_length()),
+            self.resolve_length_untracked(y.to_length()),
+            self.resolve_length_untracked(blur.to_length()),
+            0,
+            color,
+        );
+        set_shadows(self, vec![layer]);
+        self
+    }
+}
+
+fn set_shadows(sb: &mut StyleBuilder, shadows: Vec<ui::ShadowStyle>) {
+    if shadows.is_empty() {
+        sb.target.remove::<ui::BoxShadow>();
+    } else {
+        match sb.target.get_mut::<ui::BoxShadow>() {
+            Some(mut bs) => {
+                if bs.0 != shadows {
+                    bs.0 = shadows;
+                }
+            }
+            None => {
+                sb.target.insert(ui::BoxShadow(shadows));
+            }
+        }
+    }
+}
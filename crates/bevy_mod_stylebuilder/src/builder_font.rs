@@ -1,6 +1,6 @@
 #![allow(missing_docs)]
 
-use crate::{text_styles::InheritableFontStyles, MaybeHandleOrPath};
+use crate::{color_tokens::ColorField, text_styles::InheritableFontStyles, MaybeHandleOrPath};
 
 use super::builder::{ColorParam, OptFloatParam, StyleBuilder};
 use bevy::prelude::*;
@@ -13,11 +13,13 @@ pub trait StyleBuilderFont {
 
 impl<'a, 'w> StyleBuilderFont for StyleBuilder<'a, 'w> {
     fn color(&mut self, color: impl ColorParam) -> &mut Self {
+        let token = color.token();
+        let color = self.resolve_color(ColorField::Text, token, color.to_val());
         match self.target.get_mut::<InheritableFontStyles>() {
-            Some(mut text_style) => text_style.color = color.to_val(),
+            Some(mut text_style) => text_style.color = color,
             None => {
                 self.target.insert(InheritableFontStyles {
-                    color: color.to_val(),
+                    color,
                     ..Default::default()
                 });
             }
@@ -0,0 +1,118 @@
+use bevy::{color::Color, ui};
+
+use super::builder::StyleBuilder;
+
+/// Trait that represents one or more gradient layers.
+pub trait GradientParam {
+    /// Convert to a list of gradients. An empty list removes the gradient.
+    fn to_gradients(self) -> Vec<ui::Gradient>;
+}
+
+impl GradientParam for ui::Gradient {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        vec![self]
+    }
+}
+
+impl GradientParam for ui::LinearGradient {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        vec![ui::Gradient::Linear(self)]
+    }
+}
+
+impl GradientParam for ui::RadialGradient {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        vec![ui::Gradient::Radial(self)]
+    }
+}
+
+impl GradientParam for ui::ConicGradient {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        vec![ui::Gradient::Conic(self)]
+    }
+}
+
+impl GradientParam for Vec<ui::Gradient> {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        self
+    }
+}
+
+impl<T: GradientParam> GradientParam for Option<T> {
+    fn to_gradients(self) -> Vec<ui::Gradient> {
+        self.map_or(Vec::new(), |g| g.to_gradients())
+    }
+}
+
+/// Convert a list of colors into evenly-spaced color stops.
+fn even_stops<C: Into<Color>>(colors: impl IntoIterator<Item = C>) -> Vec<ui::ColorStop> {
+    colors
+        .into_iter()
+        .map(|c| ui::ColorStop::auto(c.into()))
+        .collect()
+}
+
+#[allow(missing_docs)]
+pub trait StyleBuilderGradient {
+    /// Set the background gradient layers of the element, drawn on top of the background color.
+    fn background_gradient(&mut self, gradient: impl GradientParam) -> &mut Self;
+
+    /// Set a linear background gradient with evenly-spaced colors. The angle is in radians,
+    /// clockwise from "to top"; see the constants on [`ui::LinearGradient`].
+    fn background_linear_gradient<C: Into<Color>>(
+        &mut self,
+        angle: f32,
+        colors: impl IntoIterator<Item = C>,
+    ) -> &mut Self;
+
+    /// Set a radial background gradient with evenly-spaced colors, centered on the element and
+    /// extending to its farthest corner.
+    fn background_radial_gradient<C: Into<Color>>(
+        &mut self,
+        colors: impl IntoIterator<Item = C>,
+    ) -> &mut Self;
+
+    /// Set the border gradient layers of the element.
+    fn border_gradient(&mut self, gradient: impl GradientParam) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderGradient for StyleBuilder<'a, 'w> {
+    fn background_gradient(&mut self, gradient: impl GradientParam) -> &mut Self {
+        let gradients = gradient.to_gradients();
+        if gradients.is_empty() {
+            self.target.remove::<ui::BackgroundGradient>();
+        } else {
+            self.target.insert(ui::BackgroundGradient(gradients));
+        }
+        self
+    }
+
+    fn background_linear_gradient<C: Into<Color>>(
+        &mut self,
+        angle: f32,
+        colors: impl IntoIterator<Item = C>,
+    ) -> &mut Self {
+        self.background_gradient(ui::LinearGradient::new(angle, even_stops(colors)))
+    }
+
+    fn background_radial_gradient<C: Into<Color>>(
+        &mut self,
+        colors: impl IntoIterator<Item = C>,
+    ) -> &mut Self {
+        self.background_gradient(ui::RadialGradient::new(
+            ui::UiPosition::CENTER,
+            ui::RadialGradientShape::FarthestCorner,
+            even_stops(colors),
+        ))
+    }
+
+    fn border_gradient(&mut self, gradient: impl GradientParam) -> &mut Self {
+        let gradients = gradient.to_gradients();
+        if gradients.is_empty() {
+            self.target.remove::<ui::BorderGradient>();
+        } else {
+            self.target.insert(ui::BorderGradient(gradients));
+        }
+        self
+    }
+}
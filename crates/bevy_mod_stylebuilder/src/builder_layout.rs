@@ -1,4 +1,5 @@
 use super::builder::{LengthParam, OptFloatParam, StyleBuilder, UiRectParam};
+use crate::relative_lengths::LengthField;
 use bevy::ui;
 
 #[allow(missing_docs)]
@@ -118,61 +119,61 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
     }
 
     fn left(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.left = length.to_val();
+        self.node.left = self.resolve_length(LengthField::Left, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn right(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.right = length.to_val();
+        self.node.right = self.resolve_length(LengthField::Right, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn top(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.top = length.to_val();
+        self.node.top = self.resolve_length(LengthField::Top, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn bottom(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.bottom = length.to_val();
+        self.node.bottom = self.resolve_length(LengthField::Bottom, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn width(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.width = length.to_val();
+        self.node.width = self.resolve_length(LengthField::Width, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn height(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.height = length.to_val();
+        self.node.height = self.resolve_length(LengthField::Height, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn min_width(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.min_width = length.to_val();
+        self.node.min_width = self.resolve_length(LengthField::MinWidth, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn min_height(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.min_height = length.to_val();
+        self.node.min_height = self.resolve_length(LengthField::MinHeight, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn max_width(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.max_width = length.to_val();
+        self.node.max_width = self.resolve_length(LengthField::MaxWidth, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn max_height(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.max_height = length.to_val();
+        self.node.max_height = self.resolve_length(LengthField::MaxHeight, length.to_length());
         self.node_changed = true;
         self
     }
@@ -184,91 +185,95 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
     }
 
     fn margin(&mut self, rect: impl UiRectParam) -> &mut Self {
-        self.node.margin = rect.to_uirect();
+        self.node.margin = self.resolve_rect(LengthField::MARGIN, rect);
         self.node_changed = true;
         self
     }
 
     fn margin_left(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.margin.left = length.to_val();
+        self.node.margin.left = self.resolve_length(LengthField::MarginLeft, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn margin_right(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.margin.right = length.to_val();
+        self.node.margin.right = self.resolve_length(LengthField::MarginRight, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn margin_top(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.margin.top = length.to_val();
+        self.node.margin.top = self.resolve_length(LengthField::MarginTop, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn margin_bottom(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.margin.bottom = length.to_val();
+        self.node.margin.bottom =
+            self.resolve_length(LengthField::MarginBottom, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn padding(&mut self, rect: impl UiRectParam) -> &mut Self {
-        self.node.padding = rect.to_uirect();
+        self.node.padding = self.resolve_rect(LengthField::PADDING, rect);
         self.node_changed = true;
         self
     }
 
     fn padding_left(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.padding.left = length.to_val();
+        self.node.padding.left = self.resolve_length(LengthField::PaddingLeft, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn padding_right(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.padding.right = length.to_val();
+        self.node.padding.right =
+            self.resolve_length(LengthField::PaddingRight, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn padding_top(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.padding.top = length.to_val();
+        self.node.padding.top = self.resolve_length(LengthField::PaddingTop, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn padding_bottom(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.padding.bottom = length.to_val();
+        self.node.padding.bottom =
+            self.resolve_length(LengthField::PaddingBottom, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn border(&mut self, rect: impl UiRectParam) -> &mut Self {
-        self.node.border = rect.to_uirect();
+        self.node.border = self.resolve_rect(LengthField::BORDER, rect);
         self.node_changed = true;
         self
     }
 
     fn border_left(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.border.left = length.to_val();
+        self.node.border.left = self.resolve_length(LengthField::BorderLeft, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn border_right(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.border.right = length.to_val();
+        self.node.border.right = self.resolve_length(LengthField::BorderRight, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn border_top(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.border.top = length.to_val();
+        self.node.border.top = self.resolve_length(LengthField::BorderTop, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn border_bottom(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.border.bottom = length.to_val();
+        self.node.border.bottom =
+            self.resolve_length(LengthField::BorderBottom, length.to_length());
         self.node_changed = true;
         self
     }
@@ -288,7 +293,7 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
     fn flex(&mut self, grow: f32, shrink: f32, basis: impl LengthParam) -> &mut Self {
         self.node.flex_grow = grow;
         self.node.flex_shrink = shrink;
-        self.node.flex_basis = basis.to_val();
+        self.node.flex_basis = self.resolve_length(LengthField::FlexBasis, basis.to_length());
         self.node_changed = true;
         self
     }
@@ -306,26 +311,27 @@ impl<'a, 'w> StyleBuilderLayout for StyleBuilder<'a, 'w> {
     }
 
     fn flex_basis(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.flex_basis = length.to_val();
+        self.node.flex_basis = self.resolve_length(LengthField::FlexBasis, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn row_gap(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.row_gap = length.to_val();
+        self.node.row_gap = self.resolve_length(LengthField::RowGap, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn column_gap(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.column_gap = length.to_val();
+        self.node.column_gap = self.resolve_length(LengthField::ColumnGap, length.to_length());
         self.node_changed = true;
         self
     }
 
     fn gap(&mut self, length: impl LengthParam) -> &mut Self {
-        self.node.row_gap = length.to_val();
-        self.node.column_gap = self.node.row_gap;
+        let length = length.to_length();
+        self.node.row_gap = self.resolve_length(LengthField::RowGap, length);
+        self.node.column_gap = self.resolve_length(LengthField::ColumnGap, length);
         self.node_changed = true;
         self
     }
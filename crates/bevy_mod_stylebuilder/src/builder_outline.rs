@@ -1,4 +1,5 @@
 use super::builder::{ColorParam, LengthParam, StyleBuilder};
+use crate::color_tokens::ColorField;
 use bevy::ui;
 
 #[allow(missing_docs)]
@@ -10,7 +11,9 @@ pub trait StyleBuilderOutline {
 
 impl<'a, 'w> StyleBuilderOutline for StyleBuilder<'a, 'w> {
     fn outline_color(&mut self, color: impl ColorParam) -> &mut Self {
-        match (color.to_val(), self.target.get_mut::<ui::Outline>()) {
+        let token = color.token();
+        let color = self.resolve_color(ColorField::Outline, token, color.to_val());
+        match (color, self.target.get_mut::<ui::Outline>()) {
             (Some(color), Some(mut outline)) => {
                 outline.color = color;
             }
@@ -29,13 +32,14 @@ impl<'a, 'w> StyleBuilderOutline for StyleBuilder<'a, 'w> {
     }
 
     fn outline_width(&mut self, length: impl LengthParam) -> &mut Self {
+        let width = self.resolve_length_untracked(length.to_length());
         match self.target.get_mut::<ui::Outline>() {
             Some(mut outline) => {
-                outline.width = length.to_val();
+                outline.width = width;
             }
             None => {
                 self.target.insert(ui::Outline {
-                    width: length.to_val(),
+                    width,
                     ..Default::default()
                 });
             }
@@ -44,13 +48,14 @@ impl<'a, 'w> StyleBuilderOutline for StyleBuilder<'a, 'w> {
     }
 
     fn outline_offset(&mut self, length: impl LengthParam) -> &mut Self {
+        let offset = self.resolve_length_untracked(length.to_length());
         match self.target.get_mut::<ui::Outline>() {
             Some(mut outline) => {
-                outline.offset = length.to_val();
+                outline.offset = offset;
             }
             None => {
                 self.target.insert(ui::Outline {
-                    offset: length.to_val(),
+                    offset,
                     ..Default::default()
                 });
             }
@@ -0,0 +1,162 @@
+use bevy::{prelude::*, ui::ComputedNodeTarget, window::PrimaryWindow};
+
+use super::builder::StyleBuilder;
+
+/// The size and scale factor of the viewport that a UI node is rendered into.
+#[derive(Clone, Copy, Debug, PartialEq, Default)]
+pub struct ViewportMetrics {
+    /// Size of the viewport in logical pixels.
+    pub logical_size: Vec2,
+
+    /// Ratio of physical to logical pixels.
+    pub scale_factor: f32,
+}
+
+impl ViewportMetrics {
+    /// Get the metrics of the render target of a UI node. If the node has not yet been
+    /// assigned a target, this falls back to the primary window.
+    pub fn for_entity(world: &World, entity: Entity) -> Self {
+        if let Some(target) = world.get::<ComputedNodeTarget>(entity) {
+            if target.physical_size() != UVec2::ZERO {
+                return Self {
+                    logical_size: target.logical_size(),
+                    scale_factor: target.scale_factor(),
+                };
+            }
+        }
+
+        world
+            .try_query_filtered::<&Window, With<PrimaryWindow>>()
+            .and_then(|mut query| {
+                query.single(world).ok().map(|window| Self {
+                    logical_size: window.size(),
+                    scale_factor: window.scale_factor(),
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// A condition on the viewport size, used to apply styles conditionally.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MediaQuery {
+    /// Viewport logical width is less than the given value.
+    WidthBelow(f32),
+    /// Viewport logical width is greater than or equal to the given value.
+    WidthAtLeast(f32),
+    /// Viewport logical height is less than the given value.
+    HeightBelow(f32),
+    /// Viewport logical height is greater than or equal to the given value.
+    HeightAtLeast(f32),
+    /// Viewport scale factor is greater than or equal to the given value.
+    ScaleFactorAtLeast(f32),
+}
+
+impl MediaQuery {
+    /// Test whether the query matches the given viewport metrics.
+    pub fn matches(&self, metrics: &ViewportMetrics) -> bool {
+        match *self {
+            MediaQuery::WidthBelow(w) => metrics.logical_size.x < w,
+            MediaQuery::WidthAtLeast(w) => metrics.logical_size.x >= w,
+            MediaQuery::HeightBelow(h) => metrics.logical_size.y < h,
+            MediaQuery::HeightAtLeast(h) => metrics.logical_size.y >= h,
+            MediaQuery::ScaleFactorAtLeast(s) => metrics.scale_factor >= s,
+        }
+    }
+}
+
+/// The list of media queries evaluated while applying a style, along with their results.
+/// Style effects keep this so that they can re-apply the style when a result changes.
+#[derive(Clone, Debug, PartialEq, Default)]
+pub struct MediaQueryResults(pub Vec<(MediaQuery, bool)>);
+
+impl MediaQueryResults {
+    /// True if no queries were evaluated.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// True if any query would now produce a different result.
+    pub fn is_stale(&self, metrics: &ViewportMetrics) -> bool {
+        self.0
+            .iter()
+            .any(|(query, matched)| query.matches(metrics) != *matched)
+    }
+}
+
+/// Conditional style blocks which depend on the viewport of the target entity.
+///
+/// A conditional block only ever sets properties; when its query stops matching, the style is
+/// re-applied without the block, but nothing undoes what the block set earlier. So any property
+/// which a block sets must also be set unconditionally, before the block:
+///
+/// ```ignore
+/// ss.flex_direction(ui::FlexDirection::Row)
+///     .when_width_below(600., |ss| {
+///         ss.flex_direction(ui::FlexDirection::Column);
+///     });
+/// ```
+#[allow(missing_docs)]
+pub trait StyleBuilderResponsive {
+    /// Apply the styles in `style` only if the query matches the viewport of the target
+    /// entity. The style is re-applied when the viewport is resized and the result changes.
+    ///
+    /// The properties set by `style` are not reset when the query stops matching; set their
+    /// default values unconditionally before calling this method.
+    fn when(&mut self, query: MediaQuery, style: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Apply styles if the viewport is narrower than `width` logical pixels.
+    fn when_width_below(&mut self, width: f32, style: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Apply styles if the viewport is at least `width` logical pixels wide.
+    fn when_width_at_least(&mut self, width: f32, style: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Apply styles if the viewport is shorter than `height` logical pixels.
+    fn when_height_below(&mut self, height: f32, style: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Apply styles if the viewport is at least `height` logical pixels tall.
+    fn when_height_at_least(&mut self, height: f32, style: impl FnOnce(&mut Self)) -> &mut Self;
+
+    /// Apply styles if the viewport scale factor is at least `scale`.
+    fn when_scale_factor_at_least(
+        &mut self,
+        scale: f32,
+        style: impl FnOnce(&mut Self),
+    ) -> &mut Self;
+}
+
+impl<'a, 'w> StyleBuilderResponsive for StyleBuilder<'a, 'w> {
+    fn when(&mut self, query: MediaQuery, style: impl FnOnce(&mut Self)) -> &mut Self {
+        let metrics = ViewportMetrics::for_entity(self.target.world(), self.target.id());
+        let matched = query.matches(&metrics);
+        self.media_queries.0.push((query, matched));
+        if matched {
+            style(self);
+        }
+        self
+    }
+
+    fn when_width_below(&mut self, width: f32, style: impl FnOnce(&mut Self)) -> &mut Self {
+        self.when(MediaQuery::WidthBelow(width), style)
+    }
+
+    fn when_width_at_least(&mut self, width: f32, style: impl FnOnce(&mut Self)) -> &mut Self {
+        self.when(MediaQuery::WidthAtLeast(width), style)
+    }
+
+    fn when_height_below(&mut self, height: f32, style: impl FnOnce(&mut Self)) -> &mut Self {
+        self.when(MediaQuery::HeightBelow(height), style)
+    }
+
+    fn when_height_at_least(&mut self, height: f32, style: impl FnOnce(&mut Self)) -> &mut Self {
+        self.when(MediaQuery::HeightAtLeast(height), style)
+    }
+
+    fn when_scale_factor_at_least(
+        &mut self,
+        scale: f32,
+        style: impl FnOnce(&mut Self),
+    ) -> &mut Self {
+        self.when(MediaQuery::ScaleFactorAtLeast(scale), style)
+    }
+}
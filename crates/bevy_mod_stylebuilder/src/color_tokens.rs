@@ -0,0 +1,190 @@
+use bevy::{
+    color::{Alpha, Luminance},
+    platform::collections::HashMap,
+    prelude::*,
+    ui,
+};
+
+use crate::text_styles::InheritableFontStyles;
+
+/// A reference to a named color in the [`ColorTokens`] resource. When used as a style color,
+/// the token is resolved when the style is applied, and resolved again whenever the
+/// [`ColorTokens`] resource changes, which allows color schemes to be switched at runtime.
+///
+/// A token can also carry an alpha override and a lightness adjustment, which are applied to
+/// the named color after it has been looked up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ColorToken {
+    name: &'static str,
+    alpha: Option<f32>,
+    lightness: f32,
+}
+
+impl ColorToken {
+    /// Construct a new token referring to the named color.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            alpha: None,
+            lightness: 0.,
+        }
+    }
+
+    /// The name of the color this token refers to.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return a copy of this token with the alpha of the resolved color replaced.
+    pub const fn with_alpha(mut self, alpha: f32) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Return a copy of this token whose resolved color is lightened by the given amount.
+    pub const fn lighter(mut self, amount: f32) -> Self {
+        self.lightness += amount;
+        self
+    }
+
+    /// Return a copy of this token whose resolved color is darkened by the given amount.
+    pub const fn darker(mut self, amount: f32) -> Self {
+        self.lightness -= amount;
+        self
+    }
+
+    /// Look up the color in the token table and apply any modifiers.
+    pub fn resolve(&self, tokens: &ColorTokens) -> Option<Color> {
+        let mut color = tokens.get(self.name)?;
+        if self.lightness > 0. {
+            color = color.lighter(self.lightness);
+        } else if self.lightness < 0. {
+            color = color.darker(-self.lightness);
+        }
+        if let Some(alpha) = self.alpha {
+            color.set_alpha(alpha);
+        }
+        Some(color)
+    }
+}
+
+/// Resource containing the table of named colors which [`ColorToken`]s are resolved against.
+#[derive(Resource, Default, Clone, Debug)]
+pub struct ColorTokens(pub HashMap<String, Color>);
+
+impl ColorTokens {
+    /// Look up a color by name.
+    pub fn get(&self, name: &str) -> Option<Color> {
+        self.0.get(name).copied()
+    }
+
+    /// Add or replace a named color.
+    pub fn insert(&mut self, name: impl Into<String>, color: impl Into<Color>) {
+        self.0.insert(name.into(), color.into());
+    }
+}
+
+/// Identifies a style property that can hold a token color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ColorField {
+    Background,
+    BackgroundImage,
+    Border,
+    Outline,
+    Shadow,
+    Text,
+}
+
+/// Component which records the color tokens that were used to style an entity, so that they
+/// can be re-resolved when the token table changes.
+#[derive(Component, Default, Clone, Debug)]
+pub(crate) struct TokenColors(Vec<(ColorField, ColorToken)>);
+
+impl TokenColors {
+    pub(crate) fn contains(&self, field: ColorField) -> bool {
+        self.0.iter().any(|(f, _)| *f == field)
+    }
+
+    pub(crate) fn get(&self, field: ColorField) -> Option<ColorToken> {
+        self.0.iter().find(|(f, _)| *f == field).map(|(_, t)| *t)
+    }
+
+    pub(crate) fn set(&mut self, field: ColorField, token: ColorToken) {
+        match self.0.iter_mut().find(|(f, _)| *f == field) {
+            Some(entry) => entry.1 = token,
+            None => self.0.push((field, token)),
+        }
+    }
+
+    pub(crate) fn remove(&mut self, field: ColorField) {
+        self.0.retain(|(f, _)| *f != field);
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_token_colors(
+    mut query: Query<(
+        Ref<TokenColors>,
+        Option<&mut ui::BackgroundColor>,
+        Option<&mut ui::BorderColor>,
+        Option<&mut ui::Outline>,
+        Option<&mut ui::BoxShadow>,
+        Option<&mut ImageNode>,
+        Option<&mut InheritableFontStyles>,
+    )>,
+    tokens: Res<ColorTokens>,
+) {
+    for (token_colors, mut bg, mut border, mut outline, mut shadow, mut image, mut font) in
+        query.iter_mut()
+    {
+        if !token_colors.is_changed() && !tokens.is_changed() {
+            continue;
+        }
+
+        for (field, token) in token_colors.0.iter() {
+            let Some(color) = token.resolve(&tokens) else {
+                continue;
+            };
+            match field {
+                ColorField::Background => {
+                    if let Some(bg) = bg.as_mut() {
+                        bg.set_if_neq(ui::BackgroundColor(color));
+                    }
+                }
+                ColorField::BackgroundImage => {
+                    if let Some(image) = image.as_mut() {
+                        if image.color != color {
+                            image.color = color;
+                        }
+                    }
+                }
+                ColorField::Border => {
+                    if let Some(border) = border.as_mut() {
+                        border.set_if_neq(ui::BorderColor::all(color));
+                    }
+                }
+                ColorField::Outline => {
+                    if let Some(outline) = outline.as_mut() {
+                        if outline.color != color {
+                            outline.color = color;
+                        }
+                    }
+                }
+                ColorField::Shadow => {
+                    if let Some(shadow) = shadow.as_mut() {
+                        if shadow.0.iter().any(|layer| layer.color != color) {
+                            shadow.0.iter_mut().for_each(|layer| layer.color = color);
+                        }
+                    }
+                }
+                ColorField::Text => {
+                    if let Some(font) = font.as_mut() {
+                        if font.color != Some(color) {
+                            font.color = Some(color);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
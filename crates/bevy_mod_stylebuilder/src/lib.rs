@@ -3,12 +3,17 @@ mod builder;
 mod builder_background;
 mod builder_border_color;
 mod builder_border_radius;
+mod builder_box_shadow;
 mod builder_font;
+mod builder_gradient;
 mod builder_layout;
 mod builder_outline;
 mod builder_pointer_events;
+mod builder_responsive;
 mod builder_visibility;
 mod builder_z_index;
+mod color_tokens;
+mod relative_lengths;
 mod text_styles;
 // mod builder_texture_atlas;
 
@@ -23,12 +28,21 @@ pub use builder::*;
 pub use builder_background::StyleBuilderBackground;
 pub use builder_border_color::StyleBuilderBorderColor;
 pub use builder_border_radius::StyleBuilderBorderRadius;
+pub use builder_box_shadow::{shadow_layer, BoxShadowParam, StyleBuilderBoxShadow};
 pub use builder_font::StyleBuilderFont;
+pub use builder_gradient::{GradientParam, StyleBuilderGradient};
 pub use builder_layout::StyleBuilderLayout;
 pub use builder_outline::StyleBuilderOutline;
 pub use builder_pointer_events::StyleBuilderPointerEvents;
+pub use builder_responsive::{
+    MediaQuery, MediaQueryResults, StyleBuilderResponsive, ViewportMetrics,
+};
 pub use builder_visibility::StyleBuilderVisibility;
 pub use builder_z_index::StyleBuilderZIndex;
+use color_tokens::update_token_colors;
+pub use color_tokens::{ColorToken, ColorTokens};
+use relative_lengths::update_relative_lengths;
+pub use relative_lengths::{RootFontSize, DEFAULT_ROOT_FONT_SIZE};
 use text_styles::update_text_styles;
 pub use text_styles::{InheritableFontStyles, UseInheritedTextStyles};
 // pub use builder_texture_atlas::StyleBuilderTextureAtlas;
@@ -146,6 +160,17 @@ pub struct StyleBuilderPlugin;
 
 impl Plugin for StyleBuilderPlugin {
     fn build(&self, app: &mut bevy::app::App) {
-        app.add_systems(Update, update_text_styles.in_set(StyleBuilderSystemSet));
+        app.init_resource::<RootFontSize>()
+            .init_resource::<ColorTokens>()
+            .add_systems(
+                Update,
+                (
+                    update_token_colors,
+                    update_text_styles,
+                    update_relative_lengths,
+                )
+                    .chain()
+                    .in_set(StyleBuilderSystemSet),
+            );
     }
 }
@@ -0,0 +1,178 @@
+use bevy::{prelude::*, ui};
+
+use crate::{text_styles::InheritableFontStyles, Length};
+
+/// Font size used when there is no [`RootFontSize`] resource, and as the fallback for text
+/// that does not inherit a font size.
+pub const DEFAULT_ROOT_FONT_SIZE: f32 = 12.;
+
+/// Resource which defines the root font size. This is what `rem` lengths are resolved against,
+/// as well as `em` lengths for elements which don't inherit a font size. Changing it will cause
+/// all font-relative lengths to be recomputed, which is how a UI can be scaled to a user-chosen
+/// font size.
+#[derive(Resource, Clone, Copy, Debug, PartialEq)]
+pub struct RootFontSize(pub f32);
+
+impl Default for RootFontSize {
+    fn default() -> Self {
+        Self(DEFAULT_ROOT_FONT_SIZE)
+    }
+}
+
+/// Identifies a `Node` field that can hold a font-relative length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LengthField {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Width,
+    Height,
+    MinWidth,
+    MinHeight,
+    MaxWidth,
+    MaxHeight,
+    MarginLeft,
+    MarginRight,
+    MarginTop,
+    MarginBottom,
+    PaddingLeft,
+    PaddingRight,
+    PaddingTop,
+    PaddingBottom,
+    BorderLeft,
+    BorderRight,
+    BorderTop,
+    BorderBottom,
+    FlexBasis,
+    RowGap,
+    ColumnGap,
+}
+
+impl LengthField {
+    pub(crate) const MARGIN: [LengthField; 4] = [
+        LengthField::MarginLeft,
+        LengthField::MarginRight,
+        LengthField::MarginTop,
+        LengthField::MarginBottom,
+    ];
+
+    pub(crate) const PADDING: [LengthField; 4] = [
+        LengthField::PaddingLeft,
+        LengthField::PaddingRight,
+        LengthField::PaddingTop,
+        LengthField::PaddingBottom,
+    ];
+
+    pub(crate) const BORDER: [LengthField; 4] = [
+        LengthField::BorderLeft,
+        LengthField::BorderRight,
+        LengthField::BorderTop,
+        LengthField::BorderBottom,
+    ];
+
+    fn slot(self, node: &mut ui::Node) -> &mut ui::Val {
+        match self {
+            LengthField::Left => &mut node.left,
+            LengthField::Right => &mut node.right,
+            LengthField::Top => &mut node.top,
+            LengthField::Bottom => &mut node.bottom,
+            LengthField::Width => &mut node.width,
+            LengthField::Height => &mut node.height,
+            LengthField::MinWidth => &mut node.min_width,
+            LengthField::MinHeight => &mut node.min_height,
+            LengthField::MaxWidth => &mut node.max_width,
+            LengthField::MaxHeight => &mut node.max_height,
+            LengthField::MarginLeft => &mut node.margin.left,
+            LengthField::MarginRight => &mut node.margin.right,
+            LengthField::MarginTop => &mut node.margin.top,
+            LengthField::MarginBottom => &mut node.margin.bottom,
+            LengthField::PaddingLeft => &mut node.padding.left,
+            LengthField::PaddingRight => &mut node.padding.right,
+            LengthField::PaddingTop => &mut node.padding.top,
+            LengthField::PaddingBottom => &mut node.padding.bottom,
+            LengthField::BorderLeft => &mut node.border.left,
+            LengthField::BorderRight => &mut node.border.right,
+            LengthField::BorderTop => &mut node.border.top,
+            LengthField::BorderBottom => &mut node.border.bottom,
+            LengthField::FlexBasis => &mut node.flex_basis,
+            LengthField::RowGap => &mut node.row_gap,
+            LengthField::ColumnGap => &mut node.column_gap,
+        }
+    }
+}
+
+/// Component which records the font-relative lengths that were assigned to an entity's `Node`,
+/// so that they can be re-resolved when the inherited font size changes.
+#[derive(Component, Default, Clone, Debug)]
+pub(crate) struct RelativeLengths(Vec<(LengthField, Length)>);
+
+impl RelativeLengths {
+    pub(crate) fn contains(&self, field: LengthField) -> bool {
+        self.0.iter().any(|(f, _)| *f == field)
+    }
+
+    pub(crate) fn get(&self, field: LengthField) -> Option<Length> {
+        self.0.iter().find(|(f, _)| *f == field).map(|(_, l)| *l)
+    }
+
+    pub(crate) fn set(&mut self, field: LengthField, length: Length) {
+        match self.0.iter_mut().find(|(f, _)| *f == field) {
+            Some(entry) => entry.1 = length,
+            None => self.0.push((field, length)),
+        }
+    }
+
+    pub(crate) fn remove(&mut self, field: LengthField) {
+        self.0.retain(|(f, _)| *f != field);
+    }
+}
+
+/// Find the font size of the nearest entity, starting with `entity` itself, which defines one.
+pub(crate) fn inherited_font_size(world: &World, entity: Entity) -> Option<f32> {
+    let mut ancestor = entity;
+    loop {
+        if let Some(size) = world
+            .get::<InheritableFontStyles>(ancestor)
+            .and_then(|styles| styles.font_size)
+        {
+            return Some(size);
+        }
+        ancestor = world.get::<ChildOf>(ancestor)?.parent();
+    }
+}
+
+pub(crate) fn update_relative_lengths(
+    mut query: Query<(Entity, Ref<RelativeLengths>, &mut ui::Node)>,
+    inherited: Query<Ref<InheritableFontStyles>>,
+    parents: Query<&ChildOf>,
+    root: Res<RootFontSize>,
+) {
+    let inherited_changed = root.is_changed() || inherited.iter().any(|cmp| cmp.is_changed());
+    for (entity, lengths, mut node) in query.iter_mut() {
+        if !lengths.is_changed() && !inherited_changed {
+            continue;
+        }
+
+        let mut em = root.0;
+        let mut ancestor = entity;
+        loop {
+            if let Some(size) = inherited.get(ancestor).ok().and_then(|s| s.font_size) {
+                em = size;
+                break;
+            }
+            if let Ok(child_of) = parents.get(ancestor) {
+                ancestor = child_of.parent();
+            } else {
+                break;
+            }
+        }
+
+        for (field, length) in lengths.0.iter() {
+            let val = length.resolve(em, root.0);
+            if *field.slot(node.bypass_change_detection()) != val {
+                *field.slot(&mut node) = val;
+            }
+        }
+    }
+}
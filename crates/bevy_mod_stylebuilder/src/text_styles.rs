@@ -2,6 +2,8 @@
 
 use bevy::prelude::*;
 
+use crate::RootFontSize;
+
 /// Struct that holds the properties for text rendering, which can be inherited. This allows
 /// setting for font face, size and color to be established at a parent level and inherited by
 /// child text elements.
@@ -49,14 +51,15 @@ pub(crate) fn update_text_styles(
     query: Query<(Entity, Ref<Text>), With<UseInheritedTextStyles>>,
     inherited: Query<Ref<InheritableFontStyles>>,
     parents: Query<&ChildOf>,
+    root: Res<RootFontSize>,
     mut commands: Commands,
 ) {
-    let inherited_changed = inherited.iter().any(|cmp| cmp.is_changed());
+    let inherited_changed = root.is_changed() || inherited.iter().any(|cmp| cmp.is_changed());
     for (entity, text) in query.iter() {
         if text.is_changed() || inherited_changed {
-            commands
-                .entity(entity)
-                .insert(compute_inherited_style(entity, &inherited, &parents));
+            commands.entity(entity).insert(compute_inherited_style(
+                entity, &inherited, &parents, root.0,
+            ));
         }
     }
 }
@@ -65,6 +68,7 @@ fn compute_inherited_style(
     entity: Entity,
     inherited: &Query<Ref<InheritableFontStyles>, ()>,
     parents: &Query<&ChildOf, ()>,
+    root_font_size: f32,
 ) -> (TextFont, TextColor) {
     let mut styles = InheritableFontStyles::default();
     let mut ancestor = entity;
@@ -86,7 +90,7 @@ fn compute_inherited_style(
     }
     let font = TextFont {
         font: styles.font.unwrap_or_default(),
-        font_size: styles.font_size.unwrap_or(12.),
+        font_size: styles.font_size.unwrap_or(root_font_size),
         ..default()
     };
     let color = TextColor(styles.color.unwrap_or(Color::WHITE));
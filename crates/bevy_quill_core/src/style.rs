@@ -1,24 +1,52 @@
 use bevy::{prelude::Entity, ui};
-use bevy_mod_stylebuilder::{StyleBuilder, StyleTuple};
+use bevy_mod_stylebuilder::{MediaQueryResults, StyleBuilder, StyleTuple, ViewportMetrics};
 
 use crate::{effects::EntityEffect, Cx};
 
+/// Run a style function against the target entity, returning the media queries it evaluated.
+fn apply_styles(
+    cx: &mut Cx,
+    target: Entity,
+    f: impl FnOnce(&mut StyleBuilder),
+) -> MediaQueryResults {
+    let mut target_mut = cx.world_mut().entity_mut(target);
+    let mut node = ui::Node::default();
+    if let Some(s) = target_mut.get::<ui::Node>() {
+        node.clone_from(s);
+    }
+    let mut sb = StyleBuilder::new(&mut target_mut, node);
+    f(&mut sb);
+    let queries = sb.finish();
+    track_viewport(cx, target, &queries);
+    queries
+}
+
+/// If the style used media queries, subscribe to changes in the node's render target (which
+/// changes when the viewport is resized), and return true if any query result has changed.
+fn track_viewport(cx: &mut Cx, target: Entity, queries: &MediaQueryResults) -> bool {
+    if queries.is_empty() {
+        return false;
+    }
+    cx.use_component::<ui::ComputedNodeTarget>(target);
+    queries.is_stale(&ViewportMetrics::for_entity(cx.world(), target))
+}
+
 /// Inserts a static, pre-constructed bundle into the target entity. No reactivity.
 pub struct ApplyStaticStylesEffect<S: StyleTuple> {
     pub(crate) styles: S,
 }
 
 impl<S: StyleTuple> EntityEffect for ApplyStaticStylesEffect<S> {
-    type State = ();
+    type State = MediaQueryResults;
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        let mut node = ui::Node::default();
-        if let Some(s) = target.get::<ui::Node>() {
-            node.clone_from(s);
+        apply_styles(cx, target, |sb| self.styles.apply(sb))
+    }
+
+    fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
+        // Static styles only need to be re-applied if a media query result has changed.
+        if track_viewport(cx, target, state) {
+            *state = self.apply(cx, target);
         }
-        let mut sb = StyleBuilder::new(&mut target, node);
-        self.styles.apply(&mut sb);
-        sb.finish();
     }
 }
 
@@ -33,21 +61,15 @@ pub struct ApplyDynamicStylesEffect<F: Fn(D, &mut StyleBuilder), D: PartialEq +
 impl<F: Fn(D, &mut StyleBuilder) + Send + Sync, D: PartialEq + Clone + Send + Sync> EntityEffect
     for ApplyDynamicStylesEffect<F, D>
 {
-    type State = D;
+    type State = (D, MediaQueryResults);
     fn apply(&self, cx: &mut Cx, target: Entity) -> Self::State {
-        let mut target = cx.world_mut().entity_mut(target);
-        let mut node = ui::Node::default();
-        if let Some(s) = target.get::<ui::Node>() {
-            node.clone_from(s);
-        }
-        let mut sb = StyleBuilder::new(&mut target, node);
-        (self.style_fn)(self.deps.clone(), &mut sb);
-        sb.finish();
-        self.deps.clone()
+        let queries = apply_styles(cx, target, |sb| (self.style_fn)(self.deps.clone(), sb));
+        (self.deps.clone(), queries)
     }
 
     fn reapply(&self, cx: &mut Cx, target: Entity, state: &mut Self::State) {
-        if *state != self.deps {
+        let stale = track_viewport(cx, target, &state.1);
+        if state.0 != self.deps || stale {
             *state = self.apply(cx, target);
         }
     }
@@ -0,0 +1,370 @@
+use bevy::prelude::*;
+
+/// How a [`ScalarCurve`] is interpolated between a key and the key following it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum CurveInterpolation {
+    /// Hold the value of the key until the next key.
+    Constant,
+    /// Straight line to the next key.
+    Linear,
+    /// Cubic Bézier curve shaped by the tangent handles of both keys.
+    #[default]
+    Bezier,
+}
+
+/// A key in a [`ScalarCurve`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct CurveKey {
+    /// Position of the key: `x` is the input to the curve and `y` is the output.
+    pub position: Vec2,
+
+    /// Offset of the incoming tangent handle from the key. `x` is never positive.
+    pub in_handle: Vec2,
+
+    /// Offset of the outgoing tangent handle from the key. `x` is never negative.
+    pub out_handle: Vec2,
+
+    /// How the curve is interpolated from this key to the next one.
+    pub interpolation: CurveInterpolation,
+}
+
+impl CurveKey {
+    /// Construct a new key with flat tangents.
+    pub fn new(position: Vec2) -> Self {
+        Self {
+            position,
+            in_handle: Vec2::ZERO,
+            out_handle: Vec2::ZERO,
+            interpolation: CurveInterpolation::default(),
+        }
+    }
+
+    /// Set the tangent handles.
+    pub fn with_handles(mut self, in_handle: Vec2, out_handle: Vec2) -> Self {
+        self.in_handle = Vec2::new(in_handle.x.min(0.), in_handle.y);
+        self.out_handle = Vec2::new(out_handle.x.max(0.), out_handle.y);
+        self
+    }
+
+    /// Set the handles from a Hermite-style tangent: both handles lie along `slope`, and each
+    /// extends `reach` horizontally from the key.
+    pub fn with_slope(self, slope: f32, reach: f32) -> Self {
+        let handle = Vec2::new(reach, slope * reach);
+        self.with_handles(-handle, handle)
+    }
+
+    /// Set the interpolation to the next key.
+    pub fn with_interpolation(mut self, interpolation: CurveInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+}
+
+/// A piecewise curve mapping one scalar to another, defined by a list of keys joined by
+/// constant, linear or cubic Bézier segments. The keys are kept sorted by `x`. Inputs before
+/// the first key or after the last key take the value of that key.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Default)]
+pub struct ScalarCurve {
+    /// Keys, in order of `x` position.
+    pub keys: Vec<CurveKey>,
+}
+
+impl Default for ScalarCurve {
+    fn default() -> Self {
+        Self::linear()
+    }
+}
+
+impl ScalarCurve {
+    /// Construct a new curve from a list of keys, which need not be sorted.
+    pub fn new(keys: impl Into<Vec<CurveKey>>) -> Self {
+        let mut keys = keys.into();
+        keys.sort_by(|a, b| a.position.x.total_cmp(&b.position.x));
+        Self { keys }
+    }
+
+    /// Create a straight line from (0, 0) to (1, 1).
+    pub fn linear() -> Self {
+        Self::new([
+            CurveKey::new(Vec2::ZERO).with_interpolation(CurveInterpolation::Linear),
+            CurveKey::new(Vec2::ONE).with_interpolation(CurveInterpolation::Linear),
+        ])
+    }
+
+    /// Construct an easing curve from (0, 0) to (1, 1) with the given Bézier control points,
+    /// in the manner of the CSS `cubic-bezier()` function.
+    pub fn cubic_bezier(p1: Vec2, p2: Vec2) -> Self {
+        Self::new([
+            CurveKey::new(Vec2::ZERO).with_handles(Vec2::ZERO, p1),
+            CurveKey::new(Vec2::ONE).with_handles(p2 - Vec2::ONE, Vec2::ZERO),
+        ])
+    }
+
+    /// If this curve is a single Bézier segment from (0, 0) to (1, 1), return its two control
+    /// points, as accepted by [`CubicSegment::new_bezier`].
+    pub fn as_cubic_bezier(&self) -> Option<(Vec2, Vec2)> {
+        match self.keys.as_slice() {
+            [a, b]
+                if a.position == Vec2::ZERO
+                    && b.position == Vec2::ONE
+                    && a.interpolation == CurveInterpolation::Bezier =>
+            {
+                let [_, c1, c2, _] = self.segment(0);
+                Some((c1, c2))
+            }
+            _ => None,
+        }
+    }
+
+    /// Return a copy of the curve with the unit square mapped onto `domain`.
+    pub fn fit_to(&self, domain: Rect) -> Self {
+        let scale = domain.size();
+        Self {
+            keys: self
+                .keys
+                .iter()
+                .map(|key| CurveKey {
+                    position: domain.min + key.position * scale,
+                    in_handle: key.in_handle * scale,
+                    out_handle: key.out_handle * scale,
+                    interpolation: key.interpolation,
+                })
+                .collect(),
+        }
+    }
+
+    /// Return the four Bézier control points of the segment starting at key `index`. The
+    /// handles are shortened if need be so that they don't extend past the ends of the segment,
+    /// which keeps the curve a function of `x`.
+    pub fn segment(&self, index: usize) -> [Vec2; 4] {
+        let a = &self.keys[index];
+        let b = &self.keys[index + 1];
+        let width = b.position.x - a.position.x;
+        let mut c1 = a.out_handle;
+        let mut c2 = b.in_handle;
+        if c1.x > width {
+            c1 *= width / c1.x;
+        }
+        if -c2.x > width {
+            c2 *= width / -c2.x;
+        }
+        [a.position, a.position + c1, b.position + c2, b.position]
+    }
+
+    /// Evaluate the curve at `x`.
+    pub fn sample(&self, x: f32) -> f32 {
+        let Some(first) = self.keys.first() else {
+            return 0.;
+        };
+        if x <= first.position.x {
+            return first.position.y;
+        }
+        let next = self.keys.partition_point(|key| key.position.x <= x);
+        if next >= self.keys.len() {
+            return self.keys[next - 1].position.y;
+        }
+        let a = &self.keys[next - 1];
+        let b = &self.keys[next];
+        match a.interpolation {
+            CurveInterpolation::Constant => a.position.y,
+            CurveInterpolation::Linear => {
+                let t = (x - a.position.x) / (b.position.x - a.position.x);
+                a.position.y.lerp(b.position.y, t)
+            }
+            CurveInterpolation::Bezier => {
+                let points = self.segment(next - 1);
+                // x is monotonic in t, so find t by bisection.
+                let (mut lo, mut hi) = (0f32, 1f32);
+                for _ in 0..24 {
+                    let mid = (lo + hi) * 0.5;
+                    if cubic_point(&points, mid).x < x {
+                        lo = mid;
+                    } else {
+                        hi = mid;
+                    }
+                }
+                cubic_point(&points, (lo + hi) * 0.5).y
+            }
+        }
+    }
+
+    /// Add a key at the given position, with handles that follow the current slope of the
+    /// curve there. Returns the index of the new key.
+    pub fn insert(&mut self, position: Vec2) -> usize {
+        let index = self
+            .keys
+            .partition_point(|key| key.position.x <= position.x);
+        let prev = index.checked_sub(1).map(|i| self.keys[i].position.x);
+        let next = self.keys.get(index).map(|key| key.position.x);
+        let reach = match (prev, next) {
+            (Some(prev), Some(next)) => (position.x - prev).min(next - position.x),
+            (Some(prev), None) => position.x - prev,
+            (None, Some(next)) => next - position.x,
+            (None, None) => 0.,
+        } / 3.;
+        let epsilon = reach.max(0.0001) * 0.01;
+        let slope = (self.sample(position.x + epsilon) - self.sample(position.x - epsilon))
+            / (2. * epsilon);
+        self.keys
+            .insert(index, CurveKey::new(position).with_slope(slope, reach));
+        index
+    }
+
+    /// Remove the key at `index`.
+    pub fn remove(&mut self, index: usize) {
+        if index < self.keys.len() {
+            self.keys.remove(index);
+        }
+    }
+}
+
+/// Evaluate a cubic Bézier curve.
+fn cubic_point(points: &[Vec2; 4], t: f32) -> Vec2 {
+    let u = 1. - t;
+    points[0] * (u * u * u)
+        + points[1] * (3. * u * u * t)
+        + points[2] * (3. * u * t * t)
+        + points[3] * (t * t * t)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{CurveInterpolation, CurveKey, ScalarCurve};
+
+    fn assert_near(actual: f32, expected: f32) {
+        assert!(
+            (actual - expected).abs() < 1e-4,
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_sample_linear() {
+        let curve = ScalarCurve::linear();
+        assert_near(curve.sample(0.), 0.);
+        assert_near(curve.sample(0.25), 0.25);
+        assert_near(curve.sample(1.), 1.);
+    }
+
+    #[test]
+    fn test_sample_out_of_range() {
+        let curve = ScalarCurve::new([
+            CurveKey::new(Vec2::new(1., 2.)),
+            CurveKey::new(Vec2::new(3., 4.)),
+        ]);
+        assert_near(curve.sample(-10.), 2.);
+        assert_near(curve.sample(1.), 2.);
+        assert_near(curve.sample(3.), 4.);
+        assert_near(curve.sample(10.), 4.);
+        assert_near(ScalarCurve::new([]).sample(0.5), 0.);
+        assert_near(
+            ScalarCurve::new([CurveKey::new(Vec2::new(0.5, 7.))]).sample(0.),
+            7.,
+        );
+    }
+
+    #[test]
+    fn test_sample_constant() {
+        let curve = ScalarCurve::new([
+            CurveKey::new(Vec2::ZERO).with_interpolation(CurveInterpolation::Constant),
+            CurveKey::new(Vec2::ONE),
+        ]);
+        assert_near(curve.sample(0.5), 0.);
+        assert_near(curve.sample(0.99), 0.);
+        assert_near(curve.sample(1.), 1.);
+    }
+
+    #[test]
+    fn test_sample_bezier() {
+        // Flat handles on a diagonal segment still trace a straight line.
+        let curve = ScalarCurve::new([CurveKey::new(Vec2::ZERO), CurveKey::new(Vec2::ONE)]);
+        assert_near(curve.sample(0.3), 0.3);
+
+        // A symmetric ease passes through the midpoint.
+        let ease = ScalarCurve::cubic_bezier(Vec2::new(0.42, 0.), Vec2::new(0.58, 1.));
+        assert_near(ease.sample(0.5), 0.5);
+        assert!(ease.sample(0.25) < 0.25);
+        assert!(ease.sample(0.75) > 0.75);
+    }
+
+    #[test]
+    fn test_overlong_handles_are_shortened() {
+        let curve = ScalarCurve::cubic_bezier(Vec2::new(3., 0.), Vec2::new(-2., 1.));
+        assert_eq!(
+            curve.segment(0),
+            [Vec2::ZERO, Vec2::new(1., 0.), Vec2::new(0., 1.), Vec2::ONE]
+        );
+        assert_near(curve.sample(0.5), 0.5);
+        let mut last = 0.;
+        for i in 0..=20 {
+            let y = curve.sample(i as f32 / 20.);
+            assert!(y >= last - 1e-4 && y <= 1. + 1e-4);
+            last = y;
+        }
+    }
+
+    #[test]
+    fn test_handles_point_away_from_key() {
+        let key = CurveKey::new(Vec2::ZERO).with_handles(Vec2::new(1., 1.), Vec2::new(-1., 1.));
+        assert_eq!(key.in_handle, Vec2::new(0., 1.));
+        assert_eq!(key.out_handle, Vec2::new(0., 1.));
+    }
+
+    #[test]
+    fn test_as_cubic_bezier() {
+        let (p1, p2) = (Vec2::new(0.25, 0.1), Vec2::new(0.25, 1.));
+        assert_eq!(
+            ScalarCurve::cubic_bezier(p1, p2).as_cubic_bezier(),
+            Some((p1, p2))
+        );
+        assert_eq!(ScalarCurve::linear().as_cubic_bezier(), None);
+    }
+
+    #[test]
+    fn test_fit_to() {
+        let curve = ScalarCurve::linear().fit_to(Rect::new(0., 10., 2., 20.));
+        assert_near(curve.sample(0.), 10.);
+        assert_near(curve.sample(1.), 15.);
+        assert_near(curve.sample(2.), 20.);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut curve = ScalarCurve::linear();
+        assert_eq!(curve.insert(Vec2::new(0.5, 0.5)), 1);
+        assert_eq!(curve.keys.len(), 3);
+        // The new key follows the slope of the curve, so the shape is unchanged.
+        let key = curve.keys[1];
+        assert_near(key.out_handle.x, 0.5 / 3.);
+        assert_near(key.out_handle.y, 0.5 / 3.);
+        assert_near(curve.sample(0.75), 0.75);
+
+        // Past the last key the curve is flat.
+        assert_eq!(curve.insert(Vec2::new(2., 1.)), 3);
+        let key = curve.keys[3];
+        assert_near(key.in_handle.x, -1. / 3.);
+        assert_near(key.in_handle.y, 0.);
+
+        let mut empty = ScalarCurve::new([]);
+        assert_eq!(empty.insert(Vec2::new(0.5, 0.5)), 0);
+        assert_eq!(empty.keys[0].out_handle, Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_new_sorts_and_remove() {
+        let mut curve = ScalarCurve::new([
+            CurveKey::new(Vec2::ONE).with_interpolation(CurveInterpolation::Linear),
+            CurveKey::new(Vec2::ZERO).with_interpolation(CurveInterpolation::Linear),
+        ]);
+        assert_eq!(curve, ScalarCurve::linear());
+        curve.remove(2);
+        assert_eq!(curve.keys.len(), 2);
+        curve.remove(0);
+        assert_near(curve.sample(0.), 1.);
+    }
+}
@@ -6,6 +6,10 @@ use bevy::{
     ui::{self, BackgroundColor, BorderColor, Style},
 };
 
+mod curve;
+
+pub use curve::{CurveInterpolation, CurveKey, ScalarCurve};
+
 /// Trait that represents a property that can be animated, such as background color,
 /// transform, and so on.
 pub trait AnimatableProperty {
@@ -140,13 +144,31 @@ impl AnimatableProperty for AnimatedTranslation {
     }
 }
 
+/// Easing function used by an animated transition.
+enum TransitionTiming {
+    /// A single cubic Bézier segment, as with CSS `cubic-bezier()`.
+    Cubic(CubicSegment<Vec2>),
+
+    /// An arbitrary curve, such as one produced by a [`CurveEdit`](crate::controls::CurveEdit).
+    Curve(ScalarCurve),
+}
+
+impl TransitionTiming {
+    fn ease(&self, t: f32) -> f32 {
+        match self {
+            TransitionTiming::Cubic(segment) => segment.ease(t),
+            TransitionTiming::Curve(curve) => curve.sample(t),
+        }
+    }
+}
+
 /// ECS component that animates a visual property of a UI node.
 #[derive(Component)]
 pub struct AnimatedTransition<T>
 where
     T: AnimatableProperty,
 {
-    timing: CubicSegment<Vec2>,
+    timing: TransitionTiming,
     origin: T::ValueType,
     target: T::ValueType,
     delay: f32,
@@ -161,7 +183,10 @@ where
     /// Create a new animated transition.
     pub fn new(origin: T::ValueType, target: T::ValueType, duration: f32, delay: f32) -> Self {
         Self {
-            timing: CubicSegment::new_bezier(Vec2::new(0.25, 0.1), Vec2::new(0.25, 1.0)),
+            timing: TransitionTiming::Cubic(CubicSegment::new_bezier(
+                Vec2::new(0.25, 0.1),
+                Vec2::new(0.25, 1.0),
+            )),
             origin,
             target,
             clock: 0.0,
@@ -195,7 +220,16 @@ where
 
     /// Set the easing curve of the effect.
     pub fn with_timing(&mut self, p1: Vec2, p2: Vec2) {
-        self.timing = CubicSegment::new_bezier(p1, p2);
+        self.timing = TransitionTiming::Cubic(CubicSegment::new_bezier(p1, p2));
+    }
+
+    /// Set the easing curve of the effect from a [`ScalarCurve`], which maps elapsed time
+    /// (0 to 1) to progress (normally 0 to 1).
+    pub fn with_curve(&mut self, curve: &ScalarCurve) {
+        self.timing = match curve.as_cubic_bezier() {
+            Some((p1, p2)) => TransitionTiming::Cubic(CubicSegment::new_bezier(p1, p2)),
+            None => TransitionTiming::Curve(curve.clone()),
+        };
     }
 
     /// Restart the transition with a new target value.
@@ -0,0 +1,47 @@
+// This shader draws a color ramp from a table of evenly-spaced samples.
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+const NUM_SAMPLES: i32 = 64;
+
+@group(1) @binding(0)
+var<uniform> samples: array<vec4<f32>, 64>;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let t = clamp(in.uv.x, 0.0, 1.0) * f32(NUM_SAMPLES - 1);
+    let index_lo = clamp(i32(floor(t)), 0, NUM_SAMPLES - 1);
+    let index_hi = min(index_lo + 1, NUM_SAMPLES - 1);
+    let color = mix(samples[index_lo], samples[index_hi], t - f32(index_lo));
+
+    let uv = (in.uv - vec2<f32>(0.5, 0.5)) * in.size / 8.;
+    let check = select(0.0, 1.0, (fract(uv.x) < 0.5) != (fract(uv.y) < 0.5));
+    let bg = mix(vec3<f32>(0.4, 0.4, 0.4), vec3<f32>(0.6, 0.6, 0.6), check);
+    let c = srgb_to_linear(mix(bg, color.rgb, color.w));
+
+    let size = vec2<f32>(in.size.x, in.size.y);
+    let external_distance = sd_rounded_box((in.uv - 0.5) * size, size, vec4<f32>(3.0));
+    let alpha = smoothstep(0.5, -0.5, external_distance);
+
+    return vec4<f32>(c, alpha);
+}
+
+// Convert sRGB to linear color space because the samples are in sRGB space.
+fn srgb_to_linear(srgb: vec3<f32>) -> vec3<f32> {
+    let a = 0.055;
+    let srgbLow = srgb / 12.92;
+    let srgbHigh = pow((srgb + a) / (1.0 + a), vec3<f32>(2.4, 2.4, 2.4));
+    let linear = mix(srgbLow, srgbHigh, step(vec3<f32>(0.04045, 0.04045, 0.04045), srgb));
+    return linear;
+}
+
+// From: https://github.com/bevyengine/bevy/pull/8973
+// Returns the signed distance from the point to the boundary of the rounded box.
+fn sd_rounded_box(point: vec2<f32>, size: vec2<f32>, corner_radii: vec4<f32>) -> f32 {
+    let rs = select(corner_radii.xy, corner_radii.wz, 0.0 < point.y);
+    let radius = select(rs.x, rs.y, 0.0 < point.x);
+    let corner_to_point = abs(point) - 0.5 * size;
+    let q = corner_to_point + radius;
+    let l = length(max(q, vec2(0.0)));
+    let m = min(max(q.x, q.y), 0.0);
+    return l + m - radius;
+}
@@ -0,0 +1,51 @@
+// This shader draws a hue ring, with a saturation / value square inside it.
+#import bevy_ui::ui_vertex_output::UiVertexOutput
+
+@group(1) @binding(0)
+var<uniform> params: vec4<f32>;
+
+const PI: f32 = 3.14159265;
+
+@fragment
+fn fragment(in: UiVertexOutput) -> @location(0) vec4<f32> {
+    let hue = params.x;
+    let ring_width = params.y;
+    let gap = params.z;
+
+    let p = (in.uv - vec2<f32>(0.5, 0.5)) * in.size;
+    let d = length(p);
+    let outer = min(in.size.x, in.size.y) * 0.5;
+    let inner = outer - ring_width;
+    let half = (inner - gap) / sqrt(2.0);
+
+    // Hue ring, with hue increasing counter-clockwise from the right.
+    let ring_alpha = smoothstep(outer + 0.5, outer - 0.5, d)
+        * smoothstep(inner - 0.5, inner + 0.5, d);
+    let angle = atan2(-p.y, p.x) * 180.0 / PI;
+    let ring_color = hsv_to_rgb(select(angle, angle + 360.0, angle < 0.0), 1.0, 1.0);
+
+    // Saturation increases to the right, value increases upwards.
+    let square_alpha = step(abs(p.x), half) * step(abs(p.y), half);
+    let s = clamp((p.x + half) / (2.0 * half), 0.0, 1.0);
+    let v = clamp(1.0 - (p.y + half) / (2.0 * half), 0.0, 1.0);
+    let square_color = hsv_to_rgb(hue, s, v);
+
+    let color = select(ring_color, square_color, square_alpha > 0.0);
+    let alpha = max(ring_alpha, square_alpha);
+    return vec4<f32>(srgb_to_linear(color), alpha);
+}
+
+fn hsv_to_rgb(h: f32, s: f32, v: f32) -> vec3<f32> {
+    let k = vec3<f32>(5.0, 3.0, 1.0) + h / 60.0;
+    let t = clamp(min(4.0 - (k % 6.0), k % 6.0), vec3<f32>(0.0), vec3<f32>(1.0));
+    return v - v * s * t;
+}
+
+// Convert sRGB to linear color space because the picker is laid out in sRGB space.
+fn srgb_to_linear(srgb: vec3<f32>) -> vec3<f32> {
+    let a = 0.055;
+    let srgbLow = srgb / 12.92;
+    let srgbHigh = pow((srgb + a) / (1.0 + a), vec3<f32>(2.4, 2.4, 2.4));
+    let linear = mix(srgbLow, srgbHigh, step(vec3<f32>(0.04045, 0.04045, 0.04045), srgb));
+    return linear;
+}
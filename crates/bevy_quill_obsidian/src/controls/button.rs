@@ -1,18 +1,16 @@
 use super::{Disabled, IsDisabled};
 use crate::{
-    colors,
     cursor::StyleBuilderCursor,
     focus::{AutoFocus, KeyPressEvent, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
     size::Size,
-    typography, RoundedCorners,
+    tokens, typography, RoundedCorners,
 };
 use bevy::{
     a11y::{
         accesskit::{NodeBuilder, Role},
         AccessibilityNode, Focus,
     },
-    color::Luminance,
     prelude::*,
     ui,
 };
@@ -48,7 +46,7 @@ pub(crate) fn style_button(ss: &mut StyleBuilder) {
         .align_content(ui::AlignContent::Center)
         .padding((12, 0))
         .border(0)
-        .color(colors::FOREGROUND)
+        .color(tokens::FOREGROUND)
         .cursor(CursorIcon::Pointer);
 }
 
@@ -195,13 +193,13 @@ impl ViewTemplate for Button {
                 typography::text_default,
                 style_button,
                 move |ss: &mut StyleBuilder| {
-                    ss.min_height(size.height())
-                        .min_width(size.height().floor())
+                    ss.min_height(size.height_rem())
+                        .min_width(size.height_rem())
                         .font_size(size.font_size());
                     if minimal {
                         ss.padding(0);
                     } else {
-                        ss.padding((size.font_size() * 0.75, 0));
+                        ss.padding((size.padding_rem(), 0));
                     }
                 },
                 self.style.clone(),
@@ -209,9 +207,9 @@ impl ViewTemplate for Button {
             .style_dyn(
                 |disabled, sb| {
                     sb.color(if disabled {
-                        colors::FOREGROUND.with_alpha(0.2)
+                        tokens::FOREGROUND.with_alpha(0.2)
                     } else {
-                        colors::FOREGROUND
+                        tokens::FOREGROUND
                     });
                 },
                 self.disabled,
@@ -296,7 +294,7 @@ impl ViewTemplate for Button {
                     .style_dyn(
                         |(minimal, variant, disabled, pressed, hovering), sb| {
                             let color = if minimal {
-                                colors::TRANSPARENT
+                                tokens::TRANSPARENT
                             } else {
                                 button_bg_color(variant, disabled, pressed, hovering)
                             };
@@ -308,7 +306,7 @@ impl ViewTemplate for Button {
                         move |focused, sb| {
                             match focused {
                                 true => {
-                                    sb.outline_color(colors::FOCUS)
+                                    sb.outline_color(tokens::FOCUS)
                                         .outline_width(2)
                                         .outline_offset(2);
                                 }
@@ -329,12 +327,12 @@ pub(crate) fn button_bg_color(
     is_disabled: bool,
     is_pressed: bool,
     is_hovering: bool,
-) -> Srgba {
+) -> ColorToken {
     let base_color = match variant {
-        ButtonVariant::Default => colors::U3,
-        ButtonVariant::Primary => colors::PRIMARY,
-        ButtonVariant::Danger => colors::DESTRUCTIVE,
-        ButtonVariant::Selected => colors::U4,
+        ButtonVariant::Default => tokens::U3,
+        ButtonVariant::Primary => tokens::PRIMARY,
+        ButtonVariant::Danger => tokens::DESTRUCTIVE,
+        ButtonVariant::Selected => tokens::U4,
     };
     match (is_disabled, is_pressed, is_hovering) {
         (true, _, _) => base_color.with_alpha(0.2),
@@ -3,7 +3,6 @@ use bevy::{
         accesskit::{NodeBuilder, Role},
         AccessibilityNode, Focus,
     },
-    color::Luminance,
     prelude::*,
     ui,
 };
@@ -12,11 +11,10 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{
-    colors,
     cursor::StyleBuilderCursor,
     focus::{KeyPressEvent, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
-    typography,
+    tokens, typography,
 };
 
 use super::{Disabled, IsDisabled};
@@ -28,7 +26,7 @@ fn style_checkbox(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Center)
         .align_content(ui::AlignContent::Center)
         .gap(4)
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 fn style_checkbox_border(ss: &mut StyleBuilder) {
@@ -42,7 +40,7 @@ fn style_checkbox_border(ss: &mut StyleBuilder) {
 fn style_checkbox_inner(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
         .background_image("embedded://bevy_quill_obsidian/assets/icons/checkmark.png")
-        // .background_color(colors::FOREGROUND)
+        // .background_color(tokens::FOREGROUND)
         .position(ui::PositionType::Absolute)
         .left(2)
         .top(2)
@@ -55,7 +53,7 @@ fn style_checkbox_label(ss: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Row)
         .justify_content(ui::JustifyContent::FlexStart)
         .align_items(ui::AlignItems::Center)
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 /// A checkbox widget.
@@ -215,12 +213,12 @@ impl ViewTemplate for Checkbox {
                     .style_dyn(
                         |(checked, pressed, hovering), sb| {
                             let color = match (checked, pressed, hovering) {
-                                (true, true, _) => colors::ACCENT.darker(0.1),
-                                (true, false, true) => colors::ACCENT.darker(0.15),
-                                (true, _, _) => colors::ACCENT.darker(0.2),
-                                (false, true, _) => colors::U1.lighter(0.005),
-                                (false, false, true) => colors::U1.lighter(0.002),
-                                (false, false, false) => colors::U1,
+                                (true, true, _) => tokens::ACCENT.darker(0.1),
+                                (true, false, true) => tokens::ACCENT.darker(0.15),
+                                (true, _, _) => tokens::ACCENT.darker(0.2),
+                                (false, true, _) => tokens::U1.lighter(0.005),
+                                (false, false, true) => tokens::U1.lighter(0.002),
+                                (false, false, false) => tokens::U1,
                             };
                             sb.background_color(color);
                         },
@@ -229,7 +227,7 @@ impl ViewTemplate for Checkbox {
                     .style_dyn(
                         |focused, sb| {
                             if focused {
-                                sb.outline_color(colors::FOCUS)
+                                sb.outline_color(tokens::FOCUS)
                                     .outline_offset(1.0)
                                     .outline_width(2.0);
                             } else {
@@ -251,9 +249,9 @@ impl ViewTemplate for Checkbox {
                             // info!("Checkbox disabled: {}", disabled);
                             // This doesn't work because inherited text styles don't update.
                             sb.color(if disabled {
-                                colors::FOREGROUND.with_alpha(0.3)
+                                tokens::FOREGROUND.with_alpha(0.3)
                             } else {
-                                colors::FOREGROUND
+                                tokens::FOREGROUND
                             });
                         },
                         self.disabled,
@@ -1,23 +1,30 @@
 use bevy::{
-    color::{Alpha, Hsla, Hue, Srgba},
+    color::{Alpha, Hsla, Hsva, Hue, Oklcha, Srgba},
     ecs::system::Resource,
     math::UVec2,
-    prelude::{In, World},
+    prelude::*,
     ui::{self, node_bundles::NodeBundle},
 };
+use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::RoundedCorners;
+use crate::{cursor::StyleBuilderCursor, size::Size};
 
-use super::{Button, ButtonVariant, ColorGradient, GradientSlider, Swatch, SwatchGrid};
+use super::{
+    eyedropper::sample_pixel, parse_css_color, Button, ButtonVariant, ColorGradient,
+    GradientSlider, HsvPicker, RadioOption, SegmentedControl, Swatch, SwatchGrid, TextInput,
+};
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum ColorMode {
     #[default]
     Rgb,
     Hsl,
+    Hsv,
+    Oklch,
     Recent,
+    Library,
 }
 
 /// State for the color edit control. The state stores all color spaces simultaneously to avoid
@@ -27,6 +34,8 @@ pub struct ColorEditState {
     pub mode: ColorMode,
     pub rgb: Srgba,
     pub hsl: Hsla,
+    pub hsv: Hsva,
+    pub oklch: Oklcha,
 }
 
 const MAX_RECENT: usize = 32;
@@ -53,6 +62,25 @@ impl RecentColors {
     }
 }
 
+/// Colors saved by the user, shown in the library mode of the color edit control. Unlike
+/// [`RecentColors`], this list is only changed by explicitly saving or removing a color.
+#[derive(Resource, Default, Clone)]
+pub struct SavedColors(pub Vec<Srgba>);
+
+impl SavedColors {
+    /// Add a color to the end of the library, if it isn't already present.
+    pub fn add(&mut self, color: Srgba) {
+        if !self.0.contains(&color) {
+            self.0.push(color);
+        }
+    }
+
+    /// Remove a color from the library.
+    pub fn remove(&mut self, color: Srgba) {
+        self.0.retain(|c| *c != color);
+    }
+}
+
 impl ColorEditState {
     pub fn set_mode(self, mode: ColorMode) -> Self {
         let mut result = self;
@@ -64,13 +92,25 @@ impl ColorEditState {
         let mut result = self;
         result.rgb = rgb;
         result.hsl = rgb.into();
+        result.hsv = rgb.into();
+        result.oklch = rgb.into();
         // Preserve hue if saturation is near zero, or lightness is close to full white or black.
-        if self.hsl.saturation < 0.00001
-            || self.hsl.lightness < 0.00001
-            || self.hsl.lightness > 9.99999
+        if result.hsl.saturation < 0.00001
+            || result.hsl.lightness < 0.00001
+            || result.hsl.lightness > 0.99999
         {
             result.hsl.hue = self.hsl.hue;
         }
+        // Likewise for HSV, also preserving saturation when the value is zero.
+        if result.hsv.value < 0.00001 {
+            result.hsv.hue = self.hsv.hue;
+            result.hsv.saturation = self.hsv.saturation;
+        } else if result.hsv.saturation < 0.00001 {
+            result.hsv.hue = self.hsv.hue;
+        }
+        if result.oklch.chroma < 0.0001 {
+            result.oklch.hue = self.oklch.hue;
+        }
         result
     }
 
@@ -87,9 +127,8 @@ impl ColorEditState {
     }
 
     pub fn set_hsl(self, hsl: Hsla) -> Self {
-        let mut result = self;
+        let mut result = self.set_rgb(hsl.into());
         result.hsl = hsl;
-        result.rgb = hsl.into();
         result
     }
 
@@ -105,10 +144,36 @@ impl ColorEditState {
         self.set_hsl(self.hsl.with_lightness(value))
     }
 
+    pub fn set_hsv(self, hsv: Hsva) -> Self {
+        let mut result = self.set_rgb(hsv.into());
+        result.hsv = hsv;
+        result
+    }
+
+    pub fn set_oklch(self, oklch: Oklcha) -> Self {
+        let mut result = self.set_rgb(oklch.into());
+        result.oklch = oklch;
+        result
+    }
+
+    pub fn set_oklch_lightness(self, value: f32) -> Self {
+        self.set_oklch(self.oklch.with_lightness(value))
+    }
+
+    pub fn set_oklch_chroma(self, value: f32) -> Self {
+        self.set_oklch(self.oklch.with_chroma(value))
+    }
+
+    pub fn set_oklch_hue(self, value: f32) -> Self {
+        self.set_oklch(self.oklch.with_hue(value))
+    }
+
     pub fn set_alpha(self, alpha: f32) -> Self {
         let mut result = self;
         result.rgb.alpha = alpha;
         result.hsl.alpha = alpha;
+        result.hsv.alpha = alpha;
+        result.oklch.alpha = alpha;
         result
     }
 }
@@ -126,8 +191,7 @@ fn style_top_row(sb: &mut StyleBuilder) {
     sb.display(ui::Display::Flex)
         .align_items(ui::AlignItems::Stretch)
         .flex_direction(ui::FlexDirection::Row)
-        .gap(4)
-        .margin_bottom(4);
+        .gap(4);
 }
 
 fn style_mode_selector(sb: &mut StyleBuilder) {
@@ -135,7 +199,8 @@ fn style_mode_selector(sb: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Row)
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Center)
-        .gap(1);
+        .gap(1)
+        .margin_bottom(4);
 }
 
 fn style_sliders(sb: &mut StyleBuilder) {
@@ -165,6 +230,31 @@ fn style_swatch(ss: &mut StyleBuilder) {
         .border_radius(5.);
 }
 
+fn style_hex_input(ss: &mut StyleBuilder) {
+    ss.width(84);
+}
+
+fn style_hsv_picker(ss: &mut StyleBuilder) {
+    ss.grid_column_span(2).margin_bottom(4);
+}
+
+fn style_library_buttons(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::End)
+        .gap(4);
+}
+
+fn style_eyedropper_barrier(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .left(0)
+        .top(0)
+        .right(0)
+        .bottom(0)
+        .background_color(Srgba::NONE)
+        .cursor(CursorIcon::Crosshair);
+}
+
 #[derive(Clone, PartialEq)]
 pub struct ColorEdit {
     state: ColorEditState,
@@ -185,57 +275,56 @@ impl ViewTemplate for ColorEdit {
         let mode = state.mode;
         let on_change = self.on_change;
         let state_capture = cx.create_capture(state);
+        let picking = cx.create_mutable(false);
+
+        let on_pick = cx.create_callback(move |color: In<Srgba>, world: &mut World| {
+            let state = state_capture.get(world);
+            world.run_callback(on_change, state.set_rgb(color.with_alpha(state.rgb.alpha)));
+        });
 
         Element::<NodeBundle>::new().style(style_grid).children((
             Element::<NodeBundle>::new().style(style_top_row).children((
                 Swatch::new(state.rgb).style(style_swatch),
-                Element::<NodeBundle>::new()
-                    .style(style_mode_selector)
-                    .children((
-                        Button::new()
-                            .children("RGB")
-                            .corners(RoundedCorners::Left)
-                            .variant(if mode == ColorMode::Rgb {
-                                ButtonVariant::Selected
-                            } else {
-                                ButtonVariant::Default
-                            })
-                            .on_click(cx.create_callback(move |world: &mut World| {
-                                world.run_callback(
-                                    on_change,
-                                    state_capture.get(world).set_mode(ColorMode::Rgb),
-                                );
-                            })),
-                        Button::new()
-                            .children("HSL")
-                            .corners(RoundedCorners::None)
-                            .variant(if mode == ColorMode::Hsl {
-                                ButtonVariant::Selected
-                            } else {
-                                ButtonVariant::Default
-                            })
-                            .on_click(cx.create_callback(move |world: &mut World| {
-                                world.run_callback(
-                                    on_change,
-                                    state_capture.get(world).set_mode(ColorMode::Hsl),
-                                );
-                            })),
-                        Button::new()
-                            .children("Recent")
-                            .corners(RoundedCorners::Right)
-                            .variant(if mode == ColorMode::Recent {
-                                ButtonVariant::Selected
-                            } else {
-                                ButtonVariant::Default
-                            })
-                            .on_click(cx.create_callback(move |world: &mut World| {
-                                world.run_callback(
-                                    on_change,
-                                    state_capture.get(world).set_mode(ColorMode::Recent),
-                                );
-                            })),
-                    )),
+                TextInput::new()
+                    .value(state.rgb.to_hex())
+                    .style(style_hex_input)
+                    .select_on_focus(true)
+                    .on_commit(
+                        cx.create_callback(move |text: In<String>, world: &mut World| {
+                            if let Some(rgb) = parse_css_color(&text) {
+                                world
+                                    .run_callback(on_change, state_capture.get(world).set_rgb(rgb));
+                            }
+                        }),
+                    ),
+                Button::new()
+                    .children("Pick")
+                    .size(Size::Sm)
+                    .variant(if picking.get(cx) {
+                        ButtonVariant::Selected
+                    } else {
+                        ButtonVariant::Default
+                    })
+                    .on_click(cx.create_callback(move |world: &mut World| {
+                        picking.set(world, true);
+                    })),
             )),
+            SegmentedControl::new()
+                .options([
+                    RadioOption::new(ColorMode::Rgb, "RGB"),
+                    RadioOption::new(ColorMode::Hsl, "HSL"),
+                    RadioOption::new(ColorMode::Hsv, "HSV"),
+                    RadioOption::new(ColorMode::Oklch, "OKLCH"),
+                    RadioOption::new(ColorMode::Recent, "Recent"),
+                    RadioOption::new(ColorMode::Library, "Library"),
+                ])
+                .value(mode)
+                .style(style_mode_selector)
+                .on_change(
+                    cx.create_callback(move |mode: In<ColorMode>, world: &mut World| {
+                        world.run_callback(on_change, state_capture.get(world).set_mode(*mode));
+                    }),
+                ),
             Cond::new(
                 mode == ColorMode::Rgb,
                 RgbSliders {
@@ -252,6 +341,22 @@ impl ViewTemplate for ColorEdit {
                 },
                 (),
             ),
+            Cond::new(
+                mode == ColorMode::Hsv,
+                HsvPane {
+                    state: state_capture,
+                    on_change,
+                },
+                (),
+            ),
+            Cond::new(
+                mode == ColorMode::Oklch,
+                OklchSliders {
+                    state: state_capture,
+                    on_change,
+                },
+                (),
+            ),
             Cond::new(
                 mode == ColorMode::Recent,
                 RecentColorsGrid {
@@ -260,6 +365,44 @@ impl ViewTemplate for ColorEdit {
                 },
                 (),
             ),
+            Cond::new(
+                mode == ColorMode::Library,
+                LibraryGrid {
+                    state: state_capture,
+                    on_change,
+                },
+                (),
+            ),
+            Cond::new(
+                picking.get(cx),
+                Portal::new(
+                    Element::<NodeBundle>::new()
+                        .named("ColorEdit::Eyedropper")
+                        .style(style_eyedropper_barrier)
+                        .insert_dyn(
+                            move |_| {
+                                (
+                                    // Primary click samples the pixel, any other button cancels.
+                                    On::<Pointer<Click>>::run(move |world: &mut World| {
+                                        let mut event = world
+                                            .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                            .unwrap();
+                                        event.stop_propagation();
+                                        let button = event.button;
+                                        let location = event.pointer_location.clone();
+                                        picking.set(world, false);
+                                        if button == PointerButton::Primary {
+                                            sample_pixel(world, &location, on_pick);
+                                        }
+                                    }),
+                                    ZIndex::Global(300),
+                                )
+                            },
+                            (),
+                        ),
+                ),
+                (),
+            ),
         ))
     }
 }
@@ -423,6 +566,119 @@ impl ViewTemplate for HslSliders {
     }
 }
 
+#[derive(Clone, PartialEq)]
+struct HsvPane {
+    state: Mutable<ColorEditState>,
+    on_change: Callback<ColorEditState>,
+}
+
+impl ViewTemplate for HsvPane {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let state = self.state;
+        let hsv = state.get(cx).hsv;
+        let on_change = self.on_change;
+
+        Element::<NodeBundle>::new().style(style_sliders).children((
+            HsvPicker::new()
+                .value(hsv)
+                .style(style_hsv_picker)
+                .on_change(cx.create_callback(move |hsv: In<Hsva>, world: &mut World| {
+                    world.run_callback(on_change, state.get(world).set_hsv(*hsv));
+                })),
+            AlphaSlider { state, on_change },
+        ))
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct OklchSliders {
+    state: Mutable<ColorEditState>,
+    on_change: Callback<ColorEditState>,
+}
+
+impl ViewTemplate for OklchSliders {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let state = self.state;
+        let oklch = state.get(cx).oklch;
+        let on_change = self.on_change;
+        let stop = |l: f32, c: f32, h: f32| Srgba::from(Oklcha::new(l, c, h, 1.0));
+
+        Element::<NodeBundle>::new().style(style_sliders).children((
+            GradientSlider::new()
+                .gradient(ColorGradient::new(&[
+                    stop(0.0, oklch.chroma, oklch.hue),
+                    stop(0.25, oklch.chroma, oklch.hue),
+                    stop(0.5, oklch.chroma, oklch.hue),
+                    stop(0.75, oklch.chroma, oklch.hue),
+                    stop(1.0, oklch.chroma, oklch.hue),
+                ]))
+                .min(0.)
+                .max(100.)
+                .value(oklch.lightness * 100.0)
+                .style(style_slider)
+                .precision(1)
+                .on_change(
+                    cx.create_callback(move |value: In<f32>, world: &mut World| {
+                        world.run_callback(
+                            on_change,
+                            state.get(world).set_oklch_lightness(*value / 100.0),
+                        );
+                    }),
+                ),
+            Element::<NodeBundle>::new()
+                .style(style_numeric_input)
+                .children(format!("{:.0}", oklch.lightness * 100.0)),
+            GradientSlider::new()
+                .gradient(ColorGradient::new(&[
+                    stop(oklch.lightness, 0.0, oklch.hue),
+                    stop(oklch.lightness, 0.1, oklch.hue),
+                    stop(oklch.lightness, 0.2, oklch.hue),
+                    stop(oklch.lightness, 0.3, oklch.hue),
+                    stop(oklch.lightness, 0.4, oklch.hue),
+                ]))
+                .min(0.)
+                .max(0.4)
+                .value(oklch.chroma)
+                .style(style_slider)
+                .precision(3)
+                .on_change(
+                    cx.create_callback(move |value: In<f32>, world: &mut World| {
+                        world.run_callback(on_change, state.get(world).set_oklch_chroma(*value));
+                    }),
+                ),
+            Element::<NodeBundle>::new()
+                .style(style_numeric_input)
+                .children(format!("{:.2}", oklch.chroma)),
+            GradientSlider::new()
+                .gradient(ColorGradient::new(&[
+                    stop(oklch.lightness, oklch.chroma, 0.0),
+                    stop(oklch.lightness, oklch.chroma, 60.0),
+                    stop(oklch.lightness, oklch.chroma, 120.0),
+                    stop(oklch.lightness, oklch.chroma, 180.0),
+                    stop(oklch.lightness, oklch.chroma, 240.0),
+                    stop(oklch.lightness, oklch.chroma, 300.0),
+                    stop(oklch.lightness, oklch.chroma, 360.0),
+                ]))
+                .min(0.)
+                .max(360.)
+                .value(oklch.hue)
+                .style(style_slider)
+                .precision(1)
+                .on_change(
+                    cx.create_callback(move |value: In<f32>, world: &mut World| {
+                        world.run_callback(on_change, state.get(world).set_oklch_hue(*value));
+                    }),
+                ),
+            Element::<NodeBundle>::new()
+                .style(style_numeric_input)
+                .children(format!("{:.0}", oklch.hue)),
+            AlphaSlider { state, on_change },
+        ))
+    }
+}
+
 #[derive(Clone, PartialEq)]
 struct AlphaSlider {
     state: Mutable<ColorEditState>,
@@ -484,3 +740,52 @@ impl ViewTemplate for RecentColorsGrid {
             )
     }
 }
+
+#[derive(Clone, PartialEq)]
+struct LibraryGrid {
+    state: Mutable<ColorEditState>,
+    on_change: Callback<ColorEditState>,
+}
+
+impl ViewTemplate for LibraryGrid {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let state = self.state;
+        let rgb = state.get(cx).rgb;
+        let on_change = self.on_change;
+        let saved_colors = cx.use_resource::<SavedColors>();
+        let is_saved = saved_colors.0.contains(&rgb);
+
+        (
+            SwatchGrid::new(saved_colors.0.clone())
+                .style(style_recent_colors)
+                .grid_size(UVec2::new(12, 4))
+                .selected(rgb)
+                .on_change(
+                    cx.create_callback(move |color: In<Srgba>, world: &mut World| {
+                        world.run_callback(on_change, state.get(world).set_rgb(*color));
+                    }),
+                ),
+            Element::<NodeBundle>::new()
+                .style(style_library_buttons)
+                .children((
+                    Button::new()
+                        .children("Remove")
+                        .size(Size::Sm)
+                        .disabled(!is_saved)
+                        .on_click(cx.create_callback(move |world: &mut World| {
+                            let rgb = state.get(world).rgb;
+                            world.resource_mut::<SavedColors>().remove(rgb);
+                        })),
+                    Button::new()
+                        .children("Save")
+                        .size(Size::Sm)
+                        .disabled(is_saved)
+                        .on_click(cx.create_callback(move |world: &mut World| {
+                            let rgb = state.get(world).rgb;
+                            world.resource_mut::<SavedColors>().add(rgb);
+                        })),
+                )),
+        )
+    }
+}
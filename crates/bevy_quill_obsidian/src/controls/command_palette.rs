@@ -0,0 +1,414 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::KeyPressEvent,
+    scrolling::{scroll_into_view, ScrollAlignment},
+    tokens,
+};
+
+use super::{Dialog, ScrollView, ShortcutRegistry, TextInput};
+
+const ROW_HEIGHT: f32 = 24.;
+const MAX_LIST_HEIGHT: f32 = 320.;
+
+fn style_palette(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .padding(6)
+        .gap(6);
+}
+
+fn style_palette_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::SpaceBetween)
+        .align_items(ui::AlignItems::Center)
+        .height(ROW_HEIGHT)
+        .padding((6, 0))
+        .border_radius(3.0);
+}
+
+fn style_palette_shortcut(ss: &mut StyleBuilder) {
+    ss.margin_left(16).color(tokens::DIM);
+}
+
+fn style_palette_empty(ss: &mut StyleBuilder) {
+    ss.padding((6, 3)).color(tokens::DIM);
+}
+
+/// A popup which lists the commands in the [`ShortcutRegistry`], and runs the one the user
+/// picks. Typing filters the list with a fuzzy match against the command titles; the arrow keys
+/// move the highlight, and Enter runs the highlighted command.
+///
+/// Only commands which are available where focus was when the palette opened are listed, and
+/// focus is returned there before the command runs.
+#[derive(Default, Clone, PartialEq)]
+pub struct CommandPalette {
+    /// Whether the palette is open.
+    pub open: bool,
+
+    /// Callback called when the palette should close, either because a command was picked or
+    /// because it was dismissed.
+    pub on_close: Option<Callback>,
+}
+
+impl CommandPalette {
+    /// Create a new command palette.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the palette is open.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Set the callback called when the palette should close.
+    pub fn on_close(mut self, on_close: Callback) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+}
+
+/// State shared by the parts of the command palette.
+#[derive(Clone, Copy, PartialEq)]
+struct PaletteState {
+    filter: Mutable<String>,
+    highlight: Mutable<usize>,
+    /// The entity which had focus when the palette was opened.
+    context_focus: Mutable<Option<Entity>>,
+    on_close: Option<Callback>,
+}
+
+impl ViewTemplate for CommandPalette {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let st = PaletteState {
+            filter: cx.create_mutable::<String>(String::new()),
+            highlight: cx.create_mutable::<usize>(0),
+            context_focus: cx.create_mutable::<Option<Entity>>(None),
+            on_close: self.on_close,
+        };
+
+        cx.create_effect(
+            |world, (open, st)| {
+                if open {
+                    let focus = world.resource::<Focus>().0;
+                    st.context_focus.set(world, focus);
+                    st.filter.set_clone(world, String::new());
+                    st.highlight.set(world, 0);
+                }
+            },
+            (self.open, st),
+        );
+
+        let on_filter = cx.create_callback(move |text: In<String>, world: &mut World| {
+            st.filter.set_clone(world, text.0);
+            st.highlight.set(world, 0);
+        });
+        let on_commit = cx.create_callback(move |_: In<String>, world: &mut World| {
+            let filter = st.filter.get_clone(world);
+            let focus = st.context_focus.get(world);
+            let commands = matching_commands(world, &filter, focus);
+            if let Some(command) = commands.get(st.highlight.get(world)) {
+                run_command(world, st, command.id);
+            }
+        });
+        let on_run = cx.create_callback(move |id: In<&'static str>, world: &mut World| {
+            run_command(world, st, *id);
+        });
+        let on_cancel = cx.create_callback(move |world: &mut World| {
+            if let Some(on_close) = st.on_close {
+                world.run_callback(on_close, ());
+            }
+        });
+
+        cx.use_resource::<ShortcutRegistry>();
+        let filter = st.filter.get_clone(cx);
+        let focus = st.context_focus.get(cx);
+        let current = st.highlight.get(cx);
+        let commands = matching_commands(cx.world(), &filter, focus);
+        let no_matches = commands.is_empty();
+        let list_height = (commands.len() as f32 * ROW_HEIGHT).clamp(ROW_HEIGHT, MAX_LIST_HEIGHT);
+        let rows: Vec<ViewChild> = commands
+            .into_iter()
+            .enumerate()
+            .map(|(index, command)| {
+                PaletteRow {
+                    index,
+                    id: command.id,
+                    title: command.title,
+                    shortcut: command.shortcut,
+                    highlighted: index == current,
+                    highlight: st.highlight,
+                    on_run,
+                }
+                .into_view_child()
+            })
+            .collect();
+
+        Dialog::new()
+            .width(ui::Val::Px(480.))
+            .open(self.open)
+            .on_close(on_cancel)
+            .children(
+                Element::<NodeBundle>::new()
+                    .named("CommandPalette")
+                    .style(style_palette)
+                    .insert_dyn(
+                        move |_| {
+                            (
+                                AccessibilityNode::from(NodeBuilder::new(Role::ListBox)),
+                                // Navigation keys from the search field.
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    palette_key_press(world, st);
+                                }),
+                            )
+                        },
+                        (),
+                    )
+                    .children((
+                        TextInput::new()
+                            .value(filter)
+                            .placeholder("Type a command...")
+                            .autofocus(true)
+                            .on_change(on_filter)
+                            .on_commit(on_commit)
+                            .on_cancel(on_cancel),
+                        Cond::new(
+                            no_matches,
+                            Element::<NodeBundle>::new()
+                                .named("CommandPalette::Empty")
+                                .style(style_palette_empty)
+                                .children("No matching commands"),
+                            ScrollView::new()
+                                .scroll_enable_y(true)
+                                .style(move |ss: &mut StyleBuilder| {
+                                    ss.height(list_height);
+                                })
+                                .children(rows),
+                        ),
+                    )),
+            )
+    }
+}
+
+/// A single command in the list of a [`CommandPalette`].
+#[derive(Clone, PartialEq)]
+struct PaletteRow {
+    index: usize,
+    id: &'static str,
+    title: String,
+    shortcut: Option<String>,
+    highlighted: bool,
+    highlight: Mutable<usize>,
+    on_run: Callback<&'static str>,
+}
+
+impl ViewTemplate for PaletteRow {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let row = cx.create_entity();
+        let index = self.index;
+        let highlight = self.highlight;
+        let on_run = self.on_run;
+
+        cx.create_effect(
+            |world, (row, highlighted)| {
+                if highlighted {
+                    scroll_into_view(world, row, ScrollAlignment::Nearest, false);
+                }
+            },
+            (row, self.highlighted),
+        );
+
+        Element::<NodeBundle>::for_entity(row)
+            .named("CommandPalette::Row")
+            .style(style_palette_row)
+            .style_dyn(
+                |highlighted, sb| {
+                    sb.background_color(if highlighted {
+                        tokens::U1.lighter(0.03)
+                    } else {
+                        tokens::TRANSPARENT
+                    });
+                },
+                self.highlighted,
+            )
+            .insert_dyn(
+                move |(id, highlighted)| {
+                    (
+                        AccessibilityNode::from({
+                            let mut builder = NodeBuilder::new(Role::ListBoxOption);
+                            builder.set_selected(highlighted);
+                            builder
+                        }),
+                        On::<Pointer<Over>>::run(move |world: &mut World| {
+                            highlight.set(world, index);
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            world
+                                .resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .stop_propagation();
+                            world.run_callback(on_run, id);
+                        }),
+                    )
+                },
+                (self.id, self.highlighted),
+            )
+            .children((
+                self.title.clone(),
+                self.shortcut.clone().map(|shortcut| {
+                    Element::<NodeBundle>::new()
+                        .style(style_palette_shortcut)
+                        .children(shortcut)
+                }),
+            ))
+    }
+}
+
+/// A command listed in the palette.
+struct PaletteCommand {
+    id: &'static str,
+    title: String,
+    shortcut: Option<String>,
+}
+
+/// Return the available commands whose titles match the filter, best matches first.
+fn matching_commands(world: &World, filter: &str, focus: Option<Entity>) -> Vec<PaletteCommand> {
+    let registry = world.resource::<ShortcutRegistry>();
+    let mut matches: Vec<(i32, PaletteCommand)> = registry
+        .available(world, focus)
+        .into_iter()
+        .filter_map(|command| {
+            let score = fuzzy_match(filter, &command.title)?;
+            Some((
+                score,
+                PaletteCommand {
+                    id: command.id,
+                    title: command.title.clone(),
+                    shortcut: registry.shortcut(command.id).map(|s| s.to_string()),
+                },
+            ))
+        })
+        .collect();
+    // Stable sort, so that equally good matches stay in order of registration.
+    matches.sort_by(|a, b| b.0.cmp(&a.0));
+    matches.into_iter().map(|(_, command)| command).collect()
+}
+
+/// Match the characters of `pattern`, in order, against `text`, ignoring case. Returns `None`
+/// if they don't all appear, otherwise a score which favors consecutive characters and matches
+/// at the start of words.
+fn fuzzy_match(pattern: &str, text: &str) -> Option<i32> {
+    let mut score = 0;
+    let mut chars = text.chars().enumerate().peekable();
+    let mut previous: Option<usize> = None;
+    for p in pattern.chars().filter(|c| !c.is_whitespace()) {
+        let (index, _) = chars
+            .by_ref()
+            .find(|(_, c)| c.to_lowercase().eq(p.to_lowercase()))?;
+        let word_start = index == 0
+            || text
+                .chars()
+                .nth(index - 1)
+                .is_some_and(|c| !c.is_alphanumeric());
+        score += 1;
+        if word_start {
+            score += 8;
+        }
+        if previous.is_some_and(|prev| prev + 1 == index) {
+            score += 4;
+        }
+        previous = Some(index);
+    }
+    // Prefer shorter titles among otherwise equal matches.
+    Some(score * 100 - text.len() as i32)
+}
+
+/// Close the palette and run a command, with focus back where it was when the palette opened.
+fn run_command(world: &mut World, st: PaletteState, id: &'static str) {
+    let callback = world.resource::<ShortcutRegistry>().callback(id);
+    if let Some(on_close) = st.on_close {
+        world.run_callback(on_close, ());
+    }
+    let focus = st
+        .context_focus
+        .get(world)
+        .filter(|&focus| world.get_entity(focus).is_some());
+    world.resource_mut::<Focus>().0 = focus;
+    if let Some(callback) = callback {
+        world.run_callback(callback, ());
+    }
+}
+
+fn palette_key_press(world: &mut World, st: PaletteState) {
+    let event = world.resource::<ListenerInput<KeyPressEvent>>();
+    let step: isize = match event.key_code {
+        KeyCode::ArrowDown => 1,
+        KeyCode::ArrowUp => -1,
+        _ => return,
+    };
+    world
+        .resource_mut::<ListenerInput<KeyPressEvent>>()
+        .stop_propagation();
+    let filter = st.filter.get_clone(world);
+    let focus = st.context_focus.get(world);
+    let count = matching_commands(world, &filter, focus).len() as isize;
+    if count > 0 {
+        let current = st.highlight.get(world) as isize;
+        st.highlight
+            .set(world, (current + step).rem_euclid(count) as usize);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::fuzzy_match;
+
+    #[test]
+    fn test_fuzzy_match_scores() {
+        // Each matched character scores 1, plus 8 at the start of a word and 4 when it follows
+        // the previous match, less the length of the text.
+        assert_eq!(fuzzy_match("of", "Open File"), Some(1791));
+        assert_eq!(fuzzy_match("op", "Open File"), Some(1391));
+        assert_eq!(fuzzy_match("", "Open File"), Some(-9));
+    }
+
+    #[test]
+    fn test_fuzzy_match_ignores_case_and_whitespace() {
+        assert_eq!(fuzzy_match("OPEN", "open"), fuzzy_match("open", "open"));
+        assert_eq!(
+            fuzzy_match("o f", "Open File"),
+            fuzzy_match("of", "Open File")
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_match_prefers_shorter_text() {
+        assert!(fuzzy_match("save", "Save") > fuzzy_match("save", "Save As"));
+    }
+
+    #[test]
+    fn test_fuzzy_match_rejects() {
+        assert_eq!(fuzzy_match("xyz", "Open File"), None);
+        // Characters must appear in order.
+        assert_eq!(fuzzy_match("fo", "Open File"), None);
+        assert_eq!(fuzzy_match("opened", "Open"), None);
+        assert_eq!(fuzzy_match("a", ""), None);
+    }
+}
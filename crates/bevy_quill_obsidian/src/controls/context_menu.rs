@@ -0,0 +1,209 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::KeyPressEvent,
+    hooks::{BistableTransitionState, CreateBistableTransition},
+};
+
+use super::{
+    menu::{style_menu_barrier, MenuAnchor, MenuCloseEvent},
+    Disabled,
+};
+
+fn style_context_menu(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch);
+}
+
+// Zero-sized element at the pointer location, which the popup menu floats next to.
+fn style_context_menu_anchor(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute).width(0).height(0);
+}
+
+/// Wraps a view, displaying a popup menu at the pointer location when the view is clicked with
+/// the secondary mouse button, or when the Menu key (or Shift+F10) is pressed while an element
+/// within the view has focus. The popup is typically a [`MenuPopup`](super::MenuPopup)
+/// containing [`MenuItem`](super::MenuItem)s and [`MenuDivider`](super::MenuDivider)s.
+#[derive(Default, Clone, PartialEq)]
+pub struct ContextMenu {
+    /// The view which responds to secondary clicks.
+    pub children: ViewChild,
+
+    /// The popup to display when the context menu is opened.
+    pub popup: ViewChild,
+
+    /// Whether the context menu is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the wrapper element.
+    pub style: StyleHandle,
+
+    /// Callback called just before the menu opens. The argument is the entity that was
+    /// clicked, or the focused entity if the menu was opened from the keyboard. This can be
+    /// used to decide what the popup should contain.
+    pub on_open: Option<Callback<Entity>>,
+}
+
+impl ContextMenu {
+    /// Create a new context menu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the view which responds to secondary clicks.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set the popup to display when the context menu is opened.
+    pub fn popup(mut self, popup: impl IntoViewChild) -> Self {
+        self.popup = popup.into_view_child();
+        self
+    }
+
+    /// Set whether the context menu is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the wrapper element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called just before the menu opens.
+    pub fn on_open(mut self, callback: Callback<Entity>) -> Self {
+        self.on_open = Some(callback);
+        self
+    }
+}
+
+impl ViewTemplate for ContextMenu {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let id_anchor = cx.create_entity();
+        let open = cx.create_mutable::<bool>(false);
+        let position = cx.create_mutable::<Vec2>(Vec2::ZERO);
+        let state = cx.create_bistable_transition(open.get(cx), 0.01);
+        let popup = self.popup.clone();
+
+        cx.insert(MenuAnchor(id_anchor));
+        cx.insert(On::<MenuCloseEvent>::run(move |world: &mut World| {
+            let mut event = world
+                .get_resource_mut::<ListenerInput<MenuCloseEvent>>()
+                .unwrap();
+            event.stop_propagation();
+            open.set(world, false);
+        }));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("ContextMenu")
+            .style((style_context_menu, self.style.clone()))
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                move |on_open| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            if world.entity(id).contains::<Disabled>() {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .unwrap();
+                            if event.button != PointerButton::Secondary {
+                                return;
+                            }
+                            event.stop_propagation();
+                            let target = event.target;
+                            let pos = event.pointer_location.position;
+                            if let Some(on_open) = on_open {
+                                world.run_callback(on_open, target);
+                            }
+                            position.set(world, pos);
+                            open.set(world, true);
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.entity(id).contains::<Disabled>() {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            if event.repeat
+                                || !(event.key_code == KeyCode::ContextMenu
+                                    || event.shift && event.key_code == KeyCode::F10)
+                            {
+                                return;
+                            }
+                            event.stop_propagation();
+                            let target = event.target;
+
+                            // Open below the focused element.
+                            let pos = world
+                                .get::<Node>(target)
+                                .zip(world.get::<GlobalTransform>(target))
+                                .map(|(node, transform)| {
+                                    let rect = node.logical_rect(transform);
+                                    Vec2::new(rect.min.x, rect.max.y)
+                                })
+                                .unwrap_or_default();
+                            if let Some(on_open) = on_open {
+                                world.run_callback(on_open, target);
+                            }
+                            position.set(world, pos);
+                            open.set(world, true);
+                        }),
+                    )
+                },
+                self.on_open,
+            )
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    state != BistableTransitionState::Exited,
+                    Portal::new(
+                        Element::<NodeBundle>::new()
+                            .named("ContextMenu::Barrier")
+                            .style(style_menu_barrier)
+                            .insert_dyn(
+                                move |_| {
+                                    (
+                                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                                            let mut event = world
+                                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                                .unwrap();
+                                            event.stop_propagation();
+                                            open.set(world, false);
+                                        }),
+                                        ZIndex::Global(100),
+                                    )
+                                },
+                                (),
+                            )
+                            .children((
+                                Element::<NodeBundle>::for_entity(id_anchor)
+                                    .named("ContextMenu::Anchor")
+                                    .style(style_context_menu_anchor)
+                                    .style_dyn(
+                                        |pos, ss| {
+                                            ss.left(pos.x).top(pos.y);
+                                        },
+                                        position.get(cx),
+                                    ),
+                                popup.clone(),
+                            )),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
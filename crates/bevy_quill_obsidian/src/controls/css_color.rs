@@ -0,0 +1,382 @@
+use bevy::color::{Hsla, Oklcha, Srgba};
+
+/// Parse a color in any of the forms accepted by CSS: hex (`#rgb`, `#rrggbb`, with optional
+/// alpha), `rgb()`, `hsl()` and `oklch()` functions, or a named color such as `rebeccapurple`.
+/// A hex color may also be given without the leading `#`.
+pub fn parse_css_color(text: &str) -> Option<Srgba> {
+    let text = text.trim().to_ascii_lowercase();
+    if let Some(hex) = text.strip_prefix('#') {
+        return Srgba::hex(hex).ok();
+    }
+    if let Some((func, args)) = text.strip_suffix(')').and_then(|t| t.split_once('(')) {
+        let args: Vec<&str> = args
+            .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+            .filter(|arg| !arg.is_empty())
+            .collect();
+        let (args, alpha) = match args.as_slice() {
+            [a, b, c] => ([*a, *b, *c], Some(1.0)),
+            [a, b, c, alpha] => ([*a, *b, *c], fraction(alpha)),
+            _ => return None,
+        };
+        let alpha = alpha?.clamp(0., 1.);
+        return match func.trim() {
+            "rgb" | "rgba" => Some(Srgba::new(
+                channel(args[0])?,
+                channel(args[1])?,
+                channel(args[2])?,
+                alpha,
+            )),
+            "hsl" | "hsla" => Some(Srgba::from(Hsla::new(
+                hue(args[0])?,
+                percentage(args[1])?,
+                percentage(args[2])?,
+                alpha,
+            ))),
+            "oklch" => Some(Srgba::from(Oklcha::new(
+                fraction(args[0])?,
+                chroma(args[1])?,
+                hue(args[2])?,
+                alpha,
+            ))),
+            _ => None,
+        };
+    }
+    if text == "transparent" {
+        return Some(Srgba::NONE);
+    }
+    if let Some((_, rgb)) = NAMED_COLORS.iter().find(|(name, _)| *name == text) {
+        return Some(Srgba::rgb_u8(
+            (rgb >> 16) as u8,
+            (rgb >> 8) as u8,
+            *rgb as u8,
+        ));
+    }
+    Srgba::hex(&text).ok()
+}
+
+/// A color channel, either from 0 to 255 or a percentage.
+fn channel(arg: &str) -> Option<f32> {
+    let value = match arg.strip_suffix('%') {
+        Some(percent) => percent.parse::<f32>().ok()? / 100.,
+        None => arg.parse::<f32>().ok()? / 255.,
+    };
+    Some(value.clamp(0., 1.))
+}
+
+/// A number from 0 to 1, or a percentage.
+fn fraction(arg: &str) -> Option<f32> {
+    match arg.strip_suffix('%') {
+        Some(percent) => Some(percent.parse::<f32>().ok()? / 100.),
+        None => arg.parse::<f32>().ok(),
+    }
+}
+
+/// A percentage; the `%` sign is optional.
+fn percentage(arg: &str) -> Option<f32> {
+    let value = arg.strip_suffix('%').unwrap_or(arg).parse::<f32>().ok()?;
+    Some((value / 100.).clamp(0., 1.))
+}
+
+/// OKLCH chroma, where 100% corresponds to 0.4.
+fn chroma(arg: &str) -> Option<f32> {
+    match arg.strip_suffix('%') {
+        Some(percent) => Some(percent.parse::<f32>().ok()? * 0.004),
+        None => arg.parse::<f32>().ok(),
+    }
+}
+
+/// An angle in degrees, or with a `deg`, `rad` or `turn` unit.
+fn hue(arg: &str) -> Option<f32> {
+    let degrees = if let Some(deg) = arg.strip_suffix("deg") {
+        deg.parse::<f32>().ok()?
+    } else if let Some(rad) = arg.strip_suffix("rad") {
+        rad.parse::<f32>().ok()?.to_degrees()
+    } else if let Some(turn) = arg.strip_suffix("turn") {
+        turn.parse::<f32>().ok()? * 360.
+    } else {
+        arg.parse::<f32>().ok()?
+    };
+    Some(degrees.rem_euclid(360.))
+}
+
+const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::Srgba;
+
+    use super::parse_css_color;
+
+    fn assert_color(color: Option<Srgba>, expected: Srgba) {
+        let color = color.expect("color should parse");
+        let error = (color.red - expected.red)
+            .abs()
+            .max((color.green - expected.green).abs())
+            .max((color.blue - expected.blue).abs())
+            .max((color.alpha - expected.alpha).abs());
+        assert!(error < 1e-3, "expected {:?}, got {:?}", expected, color);
+    }
+
+    #[test]
+    fn test_hex() {
+        assert_eq!(parse_css_color("#ff0000"), Some(Srgba::RED));
+        assert_eq!(parse_css_color("#f00"), Some(Srgba::RED));
+        assert_eq!(parse_css_color("#FF0000"), Some(Srgba::RED));
+        assert_eq!(parse_css_color("ff0000"), Some(Srgba::RED));
+        assert_eq!(
+            parse_css_color("#ff000080"),
+            Some(Srgba::rgba_u8(255, 0, 0, 128))
+        );
+        assert_eq!(parse_css_color("  #00ff00  "), Some(Srgba::GREEN));
+    }
+
+    #[test]
+    fn test_rgb() {
+        assert_eq!(parse_css_color("rgb(255, 0, 0)"), Some(Srgba::RED));
+        assert_eq!(parse_css_color("rgb(0 0 255)"), Some(Srgba::BLUE));
+        assert_eq!(
+            parse_css_color("rgb(100%, 50%, 0%)"),
+            Some(Srgba::new(1., 0.5, 0., 1.))
+        );
+        assert_eq!(
+            parse_css_color("rgba(255, 0, 0, 0.5)"),
+            Some(Srgba::new(1., 0., 0., 0.5))
+        );
+        assert_eq!(
+            parse_css_color("rgb(255 0 0 / 50%)"),
+            Some(Srgba::new(1., 0., 0., 0.5))
+        );
+        assert_eq!(parse_css_color("RGB(255,0,0)"), Some(Srgba::RED));
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_clamped() {
+        assert_eq!(parse_css_color("rgb(300, -10, 0)"), Some(Srgba::RED));
+        assert_eq!(
+            parse_css_color("rgb(0 0 0 / 2)"),
+            Some(Srgba::new(0., 0., 0., 1.))
+        );
+        assert_eq!(
+            parse_css_color("rgb(0 0 0 / -50%)"),
+            Some(Srgba::new(0., 0., 0., 0.))
+        );
+        assert_color(
+            parse_css_color("hsl(0, 150%, 50%)"),
+            Srgba::new(1., 0., 0., 1.),
+        );
+    }
+
+    #[test]
+    fn test_hsl() {
+        assert_color(
+            parse_css_color("hsl(0, 100%, 50%)"),
+            Srgba::new(1., 0., 0., 1.),
+        );
+        assert_color(
+            parse_css_color("hsl(120deg 100% 25%)"),
+            Srgba::new(0., 0.5, 0., 1.),
+        );
+        assert_color(
+            parse_css_color("hsla(240, 100%, 50%, 0.25)"),
+            Srgba::new(0., 0., 1., 0.25),
+        );
+        assert_color(
+            parse_css_color("hsl(0.5turn 100% 50%)"),
+            Srgba::new(0., 1., 1., 1.),
+        );
+        // Hues wrap around.
+        assert_color(
+            parse_css_color("hsl(480 100% 50%)"),
+            Srgba::new(0., 1., 0., 1.),
+        );
+        assert_color(
+            parse_css_color("hsl(-120 100% 50%)"),
+            Srgba::new(0., 0., 1., 1.),
+        );
+    }
+
+    #[test]
+    fn test_oklch() {
+        assert_color(parse_css_color("oklch(100% 0 0)"), Srgba::WHITE);
+        assert_color(parse_css_color("oklch(0 0 0)"), Srgba::BLACK);
+        assert_color(
+            parse_css_color("oklch(0 0 0 / 0.5)"),
+            Srgba::new(0., 0., 0., 0.5),
+        );
+    }
+
+    #[test]
+    fn test_named() {
+        assert_eq!(
+            parse_css_color("rebeccapurple"),
+            Some(Srgba::rgb_u8(0x66, 0x33, 0x99))
+        );
+        assert_eq!(parse_css_color("Red"), Some(Srgba::RED));
+        assert_eq!(parse_css_color(" white "), Some(Srgba::WHITE));
+        assert_eq!(parse_css_color("transparent"), Some(Srgba::NONE));
+    }
+
+    #[test]
+    fn test_invalid() {
+        assert_eq!(parse_css_color(""), None);
+        assert_eq!(parse_css_color("#"), None);
+        assert_eq!(parse_css_color("#ff000"), None);
+        assert_eq!(parse_css_color("#gggggg"), None);
+        assert_eq!(parse_css_color("notacolor"), None);
+        assert_eq!(parse_css_color("rgb(1, 2)"), None);
+        assert_eq!(parse_css_color("rgb(1, 2, 3, 4, 5)"), None);
+        assert_eq!(parse_css_color("rgb(a, 0, 0)"), None);
+        assert_eq!(parse_css_color("rgb(255, 0, 0"), None);
+        assert_eq!(parse_css_color("cmyk(0, 0, 0)"), None);
+        assert_eq!(parse_css_color("hsl(10qux, 50%, 50%)"), None);
+    }
+}
@@ -0,0 +1,964 @@
+use bevy::{a11y::Focus, prelude::*, ui};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::UseElementRect,
+    materials::{DrawPathMaterial, DrawablePath},
+    scrolling::ScrollWheel,
+    size::Size,
+    theme::Theme,
+    tokens,
+};
+
+use crate::animation::{CurveInterpolation, CurveKey, ScalarCurve};
+
+use super::{
+    numeric_entry::DOUBLE_CLICK_TIME, Button, ButtonVariant, Disabled, IsDisabled, Select,
+    SelectOption, Spacer, SpinBox,
+};
+
+const KEY_SIZE: f32 = 8.;
+const HANDLE_SIZE: f32 = 7.;
+
+/// Minimum spacing between grid lines, in pixels.
+const GRID_SPACING: f32 = 32.;
+
+/// Preset curves offered by [`CurveEdit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurvePreset {
+    /// Straight line.
+    Linear,
+    /// Starts slow and speeds up.
+    EaseIn,
+    /// Starts fast and slows down.
+    EaseOut,
+    /// Starts and ends slowly.
+    EaseInOut,
+    /// Jumps from the start value to the end value.
+    Step,
+}
+
+impl CurvePreset {
+    /// Return the preset curve, running from (0, 0) to (1, 1).
+    pub fn curve(self) -> ScalarCurve {
+        match self {
+            CurvePreset::Linear => ScalarCurve::linear(),
+            CurvePreset::EaseIn => ScalarCurve::cubic_bezier(Vec2::new(0.42, 0.), Vec2::ONE),
+            CurvePreset::EaseOut => ScalarCurve::cubic_bezier(Vec2::ZERO, Vec2::new(0.58, 1.)),
+            CurvePreset::EaseInOut => {
+                ScalarCurve::cubic_bezier(Vec2::new(0.42, 0.), Vec2::new(0.58, 1.))
+            }
+            CurvePreset::Step => ScalarCurve::new([
+                CurveKey::new(Vec2::ZERO).with_interpolation(CurveInterpolation::Constant),
+                CurveKey::new(Vec2::ONE).with_interpolation(CurveInterpolation::Constant),
+            ]),
+        }
+    }
+}
+
+/// What is being dragged in the curve editor.
+#[derive(Clone, Copy, PartialEq, Default)]
+enum CurveDragTarget {
+    #[default]
+    None,
+    Pan,
+    Key(usize),
+    InHandle(usize),
+    OutHandle(usize),
+}
+
+#[derive(Component, Clone, Copy)]
+struct CurveDrag {
+    target: CurveDragTarget,
+    /// Value of the dragged point, or the view for panning, when the drag started.
+    origin: Vec2,
+    /// The view when the drag started.
+    view: Rect,
+    /// Time of the last click on the background, used to detect double-clicks.
+    last_click: f64,
+}
+
+/// Component used to hold the edit params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct CurveEditParams {
+    value: ScalarCurve,
+    domain: Rect,
+    snap: Option<Vec2>,
+    on_change: Option<Callback<ScalarCurve>>,
+}
+
+fn style_curve_edit(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .gap(4)
+        .min_width(240);
+}
+
+fn style_toolbar(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .gap(4);
+}
+
+fn style_canvas(ss: &mut StyleBuilder) {
+    ss.height(160)
+        .background_color(tokens::U1)
+        .border_radius(4.)
+        .overflow(ui::OverflowAxis::Clip)
+        .cursor(CursorIcon::Crosshair);
+}
+
+fn style_grid_line(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .background_color(tokens::U2)
+        .pointer_events(false);
+}
+
+fn style_domain(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .border(1)
+        .border_color(tokens::U3)
+        .pointer_events(false);
+}
+
+fn style_path(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .pointer_events(false);
+}
+
+fn style_point(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .border(1)
+        .cursor(CursorIcon::Move);
+}
+
+fn style_coordinate(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.);
+}
+
+/// Editor for a [`ScalarCurve`], such as an easing curve.
+///
+/// Double-click on the background to add a key, drag keys and their tangent handles to shape
+/// the curve, and press Delete to remove the selected key. Drag the background to pan, and use
+/// the mouse wheel to zoom.
+#[derive(Clone, PartialEq)]
+pub struct CurveEdit {
+    /// Curve being edited.
+    pub value: ScalarCurve,
+
+    /// Region of the curve which is editable: key `x` positions are kept within it, and it is
+    /// the region shown by default. Key `y` positions may overshoot, as easing curves often do.
+    pub domain: Rect,
+
+    /// Grid to which keys snap when snapping is turned on.
+    pub snap: Option<Vec2>,
+
+    /// Whether the editor is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the editor.
+    pub style: StyleHandle,
+
+    /// Callback called when the curve changes.
+    pub on_change: Option<Callback<ScalarCurve>>,
+}
+
+impl CurveEdit {
+    /// Create a new curve editor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the curve being edited.
+    pub fn value(mut self, value: ScalarCurve) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set the editable region of the curve.
+    pub fn domain(mut self, domain: Rect) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Set the snapping grid, or `None` to disable snapping.
+    pub fn snap(mut self, snap: impl Into<Option<Vec2>>) -> Self {
+        self.snap = snap.into();
+        self
+    }
+
+    /// Set whether the editor is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the style handle for the editor root element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the curve changes.
+    pub fn on_change(mut self, on_change: Callback<ScalarCurve>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl Default for CurveEdit {
+    fn default() -> Self {
+        Self {
+            value: ScalarCurve::default(),
+            domain: Rect::new(0., 0., 1., 1.),
+            snap: Some(Vec2::splat(0.05)),
+            disabled: false,
+            style: StyleHandle::default(),
+            on_change: None,
+        }
+    }
+}
+
+impl ViewTemplate for CurveEdit {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let edit_id = cx.create_entity();
+        let canvas_id = cx.create_entity();
+        let disabled = self.disabled;
+        let domain = self.domain;
+        let value = self.value.clone();
+        let selection = cx.create_mutable::<Option<usize>>(None);
+        let view = cx.create_mutable(fit_view(domain));
+        let snapping = cx.create_mutable(true);
+        let size = cx.use_element_size(canvas_id);
+        let focused = cx.is_focus_visible(canvas_id);
+        let current_view = view.get(cx);
+        let selected = selection
+            .get(cx)
+            .and_then(|index| value.keys.get(index).map(|key| (index, *key)));
+
+        // Show the whole domain whenever it changes.
+        cx.create_effect(
+            move |world, domain| {
+                view.set(world, fit_view(domain));
+            },
+            domain,
+        );
+
+        // Ensure CurveDrag component exists before rendering.
+        let mut entt = cx.world_mut().entity_mut(canvas_id);
+        if !entt.contains::<CurveDrag>() {
+            entt.insert(CurveDrag {
+                target: CurveDragTarget::None,
+                origin: Vec2::ZERO,
+                view: Rect::default(),
+                last_click: f64::NEG_INFINITY,
+            });
+        }
+
+        // Paths are drawn with a material, so resolve their colors from the theme.
+        let theme = cx.use_resource::<Theme>();
+        let handle_color = theme.color(tokens::U4);
+        let curve_color = theme.color(tokens::ACCENT);
+
+        let grid_x = grid_lines(current_view.min.x, current_view.max.x, size.x)
+            .into_iter()
+            .map(|x| view_to_px(Vec2::new(x, 0.), current_view, size).x)
+            .collect::<Vec<_>>();
+        let grid_y = grid_lines(current_view.min.y, current_view.max.y, size.y)
+            .into_iter()
+            .map(|y| view_to_px(Vec2::new(0., y), current_view, size).y)
+            .collect::<Vec<_>>();
+        let domain_px = Rect::from_corners(
+            view_to_px(domain.min, current_view, size),
+            view_to_px(domain.max, current_view, size),
+        );
+        let handles = match selected {
+            Some((index, key)) if size.x > 0. => {
+                let mut handles = Vec::new();
+                if index > 0 && value.keys[index - 1].interpolation == CurveInterpolation::Bezier {
+                    handles.push((CurveDragTarget::InHandle(index), key.in_handle));
+                }
+                if index + 1 < value.keys.len() && key.interpolation == CurveInterpolation::Bezier {
+                    handles.push((CurveDragTarget::OutHandle(index), key.out_handle));
+                }
+                handles
+                    .into_iter()
+                    .map(|(target, handle)| CurvePoint {
+                        canvas_id,
+                        target,
+                        position: view_to_px(key.position + handle, current_view, size),
+                        origin: handle,
+                        selected: false,
+                        selection,
+                        view,
+                        disabled,
+                    })
+                    .collect::<Vec<_>>()
+            }
+            _ => Vec::new(),
+        };
+
+        Element::<NodeBundle>::for_entity(edit_id)
+            .named("CurveEdit")
+            .style((style_curve_edit, self.style.clone()))
+            .insert_dyn(
+                |(value, domain, snap, on_change)| CurveEditParams {
+                    value,
+                    domain,
+                    snap,
+                    on_change,
+                },
+                (value.clone(), domain, self.snap, self.on_change),
+            )
+            .children((
+                Element::<NodeBundle>::new().style(style_toolbar).children((
+                    Select::new()
+                        .value(None::<CurvePreset>)
+                        .options(vec![
+                            SelectOption::new(CurvePreset::Linear, "Linear"),
+                            SelectOption::new(CurvePreset::EaseIn, "Ease In"),
+                            SelectOption::new(CurvePreset::EaseOut, "Ease Out"),
+                            SelectOption::new(CurvePreset::EaseInOut, "Ease In Out"),
+                            SelectOption::new(CurvePreset::Step, "Step"),
+                        ])
+                        .placeholder("Preset")
+                        .size(Size::Sm)
+                        .disabled(disabled)
+                        .on_change(cx.create_callback(
+                            move |preset: In<CurvePreset>, world: &mut World| {
+                                let domain = curve_params(world, edit_id).domain;
+                                selection.set(world, None);
+                                emit_curve(world, edit_id, preset.curve().fit_to(domain));
+                            },
+                        )),
+                    Select::new()
+                        .value(selected.map(|(_, key)| key.interpolation))
+                        .options(vec![
+                            SelectOption::new(CurveInterpolation::Bezier, "Bézier"),
+                            SelectOption::new(CurveInterpolation::Linear, "Linear"),
+                            SelectOption::new(CurveInterpolation::Constant, "Constant"),
+                        ])
+                        .placeholder("Interpolation")
+                        .size(Size::Sm)
+                        .disabled(disabled || selected.is_none())
+                        .on_change(cx.create_callback(
+                            move |interpolation: In<CurveInterpolation>, world: &mut World| {
+                                let mut curve = curve_params(world, edit_id).value;
+                                if let Some(index) = selection.get(world) {
+                                    if let Some(key) = curve.keys.get_mut(index) {
+                                        key.interpolation = *interpolation;
+                                        emit_curve(world, edit_id, curve);
+                                    }
+                                }
+                            },
+                        )),
+                    Spacer,
+                    Button::new()
+                        .children("Snap")
+                        .size(Size::Sm)
+                        .disabled(self.snap.is_none())
+                        .variant(if snapping.get(cx) && self.snap.is_some() {
+                            ButtonVariant::Selected
+                        } else {
+                            ButtonVariant::Default
+                        })
+                        .on_click(cx.create_callback(move |world: &mut World| {
+                            let enabled = snapping.get(world);
+                            snapping.set(world, !enabled);
+                        })),
+                    Button::new()
+                        .children("Fit")
+                        .size(Size::Sm)
+                        .on_click(cx.create_callback(move |world: &mut World| {
+                            let domain = curve_params(world, edit_id).domain;
+                            view.set(world, fit_view(domain));
+                        })),
+                )),
+                Element::<NodeBundle>::for_entity(canvas_id)
+                    .named("CurveEdit::Canvas")
+                    .style(style_canvas)
+                    .insert_dyn(TabIndex, if disabled { -1 } else { 0 })
+                    .style_dyn(
+                        |focused, sb| {
+                            if focused {
+                                sb.outline_color(tokens::FOCUS)
+                                    .outline_offset(1.0)
+                                    .outline_width(2.0);
+                            } else {
+                                sb.outline_color(Option::<Color>::None);
+                            }
+                        },
+                        focused,
+                    )
+                    .insert_dyn(
+                        move |disabled| {
+                            (
+                                On::<Pointer<Down>>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                                        .unwrap();
+                                    event.stop_propagation();
+                                    let button = event.button;
+                                    let position = event.pointer_location.position;
+                                    world.get_resource_mut::<Focus>().unwrap().0 = Some(canvas_id);
+                                    let now = world.resource::<Time>().elapsed_seconds_f64();
+                                    let current_view = view.get(world);
+                                    let mut drag = *world.get::<CurveDrag>(canvas_id).unwrap();
+                                    let double_click = button == PointerButton::Primary
+                                        && now - drag.last_click < DOUBLE_CLICK_TIME;
+                                    drag.last_click =
+                                        if double_click { f64::NEG_INFINITY } else { now };
+                                    drag.target = CurveDragTarget::Pan;
+                                    drag.origin = Vec2::ZERO;
+                                    drag.view = current_view;
+                                    if double_click && !disabled {
+                                        // Double-click adds a key, and starts dragging it.
+                                        if let Some(point) =
+                                            px_to_view(world, canvas_id, current_view, position)
+                                        {
+                                            let params = curve_params(world, edit_id);
+                                            let point = clamp_to_domain(
+                                                snap_point(point, params.snap, snapping.get(world)),
+                                                params.domain,
+                                            );
+                                            let mut curve = params.value;
+                                            let index = curve.insert(point);
+                                            selection.set(world, Some(index));
+                                            drag.target = CurveDragTarget::Key(index);
+                                            drag.origin = point;
+                                            world.entity_mut(canvas_id).insert(drag);
+                                            emit_curve(world, edit_id, curve);
+                                            return;
+                                        }
+                                    }
+                                    if button == PointerButton::Primary {
+                                        selection.set(world, None);
+                                    }
+                                    world.entity_mut(canvas_id).insert(drag);
+                                }),
+                                On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                        .unwrap();
+                                    event.stop_propagation();
+                                }),
+                                On::<Pointer<DragEnd>>::listener_component_mut::<CurveDrag>(
+                                    |_, drag| {
+                                        drag.target = CurveDragTarget::None;
+                                    },
+                                ),
+                                On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                    let event = world
+                                        .get_resource::<ListenerInput<Pointer<Drag>>>()
+                                        .unwrap();
+                                    let distance = event.distance;
+                                    let drag = *world.get::<CurveDrag>(canvas_id).unwrap();
+                                    let Some(size) = canvas_size(world, canvas_id) else {
+                                        return;
+                                    };
+                                    let delta = Vec2::new(
+                                        distance.x / size.x * drag.view.width(),
+                                        -distance.y / size.y * drag.view.height(),
+                                    );
+                                    drag_curve(
+                                        world, edit_id, drag, delta, view, snapping, disabled,
+                                    );
+                                }),
+                                On::<ScrollWheel>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<ScrollWheel>>()
+                                        .unwrap();
+                                    event.stop_propagation();
+                                    let scale = (1. - event.delta.y * 0.002).clamp(0.5, 2.);
+                                    let current = view.get(world);
+                                    view.set(
+                                        world,
+                                        Rect::from_center_size(
+                                            current.center(),
+                                            current.size() * scale,
+                                        ),
+                                    );
+                                }),
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                        .unwrap();
+                                    match event.key_code {
+                                        KeyCode::Delete | KeyCode::Backspace if !disabled => {
+                                            event.stop_propagation();
+                                            let mut curve = curve_params(world, edit_id).value;
+                                            if let Some(index) = selection.get(world) {
+                                                if curve.keys.len() > 1 {
+                                                    curve.remove(index);
+                                                    selection.set(world, None);
+                                                    emit_curve(world, edit_id, curve);
+                                                }
+                                            }
+                                        }
+                                        KeyCode::Home => {
+                                            event.stop_propagation();
+                                            let domain = curve_params(world, edit_id).domain;
+                                            view.set(world, fit_view(domain));
+                                        }
+                                        _ => {}
+                                    }
+                                }),
+                            )
+                        },
+                        disabled,
+                    )
+                    .children((
+                        For::each(grid_x, |x| {
+                            Element::<NodeBundle>::new()
+                                .style(style_grid_line)
+                                .style_dyn(
+                                    |x, sb| {
+                                        sb.left(x).top(0).bottom(0).width(1);
+                                    },
+                                    *x,
+                                )
+                        }),
+                        For::each(grid_y, |y| {
+                            Element::<NodeBundle>::new()
+                                .style(style_grid_line)
+                                .style_dyn(
+                                    |y, sb| {
+                                        sb.top(y).left(0).right(0).height(1);
+                                    },
+                                    *y,
+                                )
+                        }),
+                        Element::<NodeBundle>::new().style(style_domain).style_dyn(
+                            |rect, sb| {
+                                sb.left(rect.min.x)
+                                    .top(rect.min.y)
+                                    .width(rect.width())
+                                    .height(rect.height());
+                            },
+                            domain_px,
+                        ),
+                        Cond::new(
+                            !handles.is_empty(),
+                            CurvePath {
+                                path: handle_path(&handles, selected, current_view, size),
+                                color: handle_color,
+                            },
+                            (),
+                        ),
+                        CurvePath {
+                            path: curve_path(&value, current_view, size),
+                            color: curve_color,
+                        },
+                        For::index(&value.keys, move |key, index| CurvePoint {
+                            canvas_id,
+                            target: CurveDragTarget::Key(index),
+                            position: view_to_px(key.position, current_view, size),
+                            origin: key.position,
+                            selected: selected.is_some_and(|(i, _)| i == index),
+                            selection,
+                            view,
+                            disabled,
+                        }),
+                        For::each(handles, |handle| handle.clone()),
+                    )),
+                Cond::new(
+                    selected.is_some(),
+                    Element::<NodeBundle>::new().style(style_toolbar).children((
+                        SpinBox::new()
+                            .value(selected.map_or(0., |(_, key)| key.position.x))
+                            .range(domain.min.x..=domain.max.x)
+                            .precision(3)
+                            .step(self.snap.map_or(0.01, |snap| snap.x))
+                            .disabled(disabled)
+                            .style(style_coordinate)
+                            .on_change(cx.create_callback(move |x: In<f32>, world: &mut World| {
+                                set_selected_key(world, edit_id, selection, |key| {
+                                    key.position.x = *x;
+                                });
+                            })),
+                        SpinBox::new()
+                            .value(selected.map_or(0., |(_, key)| key.position.y))
+                            .range(f32::MIN..=f32::MAX)
+                            .precision(3)
+                            .step(self.snap.map_or(0.01, |snap| snap.y))
+                            .disabled(disabled)
+                            .style(style_coordinate)
+                            .on_change(cx.create_callback(move |y: In<f32>, world: &mut World| {
+                                set_selected_key(world, edit_id, selection, |key| {
+                                    key.position.y = *y;
+                                });
+                            })),
+                    )),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// A stroked path drawn within the curve canvas.
+#[derive(Clone, PartialEq)]
+struct CurvePath {
+    path: Vec<Vec2>,
+    color: Srgba,
+}
+
+impl ViewTemplate for CurvePath {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let material = cx.create_memo(
+            |world, _| {
+                let mut materials = world
+                    .get_resource_mut::<Assets<DrawPathMaterial>>()
+                    .unwrap();
+                materials.add(DrawPathMaterial::default())
+            },
+            (),
+        );
+        let material_id = material.id();
+
+        Element::<MaterialNodeBundle<DrawPathMaterial>>::new()
+            .named("CurveEdit::Path")
+            .insert(material)
+            .style(style_path)
+            .effect(
+                move |cx, ent, (points, color)| {
+                    // Points come in groups of three: a cubic segment's two control points
+                    // and end point, following an initial move.
+                    let mut path = DrawablePath::new(1.5);
+                    if let Some(start) = points.first() {
+                        path.move_to(*start);
+                        for segment in points[1..].chunks_exact(3) {
+                            path.cubic_to(segment[0], segment[1], segment[2]);
+                        }
+                    }
+                    let bounds = path.bounds();
+
+                    let mut style = cx.world_mut().get_mut::<Style>(ent).unwrap();
+                    style.left = ui::Val::Px(bounds.min.x);
+                    style.top = ui::Val::Px(bounds.min.y);
+                    style.width = ui::Val::Px(bounds.width());
+                    style.height = ui::Val::Px(bounds.height());
+
+                    let mut materials = cx
+                        .world_mut()
+                        .get_resource_mut::<Assets<DrawPathMaterial>>()
+                        .unwrap();
+                    let material = materials.get_mut(material_id).unwrap();
+                    material.update_path(&path);
+                    material.update_color(color, Vec2::ZERO, color, Vec2::X);
+                },
+                (self.path.clone(), self.color),
+            )
+    }
+}
+
+/// A draggable key or tangent handle.
+#[derive(Clone, PartialEq)]
+struct CurvePoint {
+    canvas_id: Entity,
+    target: CurveDragTarget,
+    /// Position within the canvas, in pixels.
+    position: Vec2,
+    /// Value of the point being dragged, in curve space.
+    origin: Vec2,
+    selected: bool,
+    selection: Mutable<Option<usize>>,
+    view: Mutable<Rect>,
+    disabled: bool,
+}
+
+impl ViewTemplate for CurvePoint {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let canvas_id = self.canvas_id;
+        let selection = self.selection;
+        let view = self.view;
+        let is_key = matches!(self.target, CurveDragTarget::Key(_));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("CurveEdit::Point")
+            .style(style_point)
+            .style_dyn(
+                |(position, is_key, selected), sb| {
+                    let size = if is_key { KEY_SIZE } else { HANDLE_SIZE };
+                    sb.left(position.x - size * 0.5)
+                        .top(position.y - size * 0.5)
+                        .width(size)
+                        .height(size)
+                        .border_radius(if is_key { 1. } else { size * 0.5 })
+                        .border_color(tokens::FOREGROUND)
+                        .background_color(if selected {
+                            tokens::FOREGROUND
+                        } else {
+                            tokens::U1
+                        });
+                },
+                (self.position, is_key, self.selected),
+            )
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                move |(target, origin)| {
+                    On::<Pointer<Down>>::run(move |world: &mut World| {
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                            .unwrap();
+                        event.stop_propagation();
+                        world.get_resource_mut::<Focus>().unwrap().0 = Some(canvas_id);
+                        if world.is_disabled(id) {
+                            return;
+                        }
+                        if let CurveDragTarget::Key(index) = target {
+                            selection.set(world, Some(index));
+                        }
+                        let current_view = view.get(world);
+                        let mut drag = world.get_mut::<CurveDrag>(canvas_id).unwrap();
+                        drag.target = target;
+                        drag.origin = origin;
+                        drag.view = current_view;
+                    })
+                },
+                (self.target, self.origin),
+            )
+    }
+}
+
+/// Apply a pointer drag, measured in curve units from where the drag started.
+fn drag_curve(
+    world: &mut World,
+    edit_id: Entity,
+    drag: CurveDrag,
+    delta: Vec2,
+    view: Mutable<Rect>,
+    snapping: Mutable<bool>,
+    disabled: bool,
+) {
+    let params = curve_params(world, edit_id);
+    let mut curve = params.value;
+    match drag.target {
+        CurveDragTarget::None => {}
+        CurveDragTarget::Pan => {
+            view.set(
+                world,
+                Rect::from_corners(drag.view.min - delta, drag.view.max - delta),
+            );
+        }
+        _ if disabled => {}
+        CurveDragTarget::Key(index) => {
+            if index >= curve.keys.len() {
+                return;
+            }
+            let point = snap_point(drag.origin + delta, params.snap, snapping.get(world));
+            // Keys can't be dragged past their neighbors, so indices stay stable while dragging.
+            let min_x = index
+                .checked_sub(1)
+                .map_or(params.domain.min.x, |i| curve.keys[i].position.x);
+            let max_x = curve
+                .keys
+                .get(index + 1)
+                .map_or(params.domain.max.x, |key| key.position.x);
+            curve.keys[index].position = Vec2::new(point.x.clamp(min_x, max_x), point.y);
+            emit_curve(world, edit_id, curve);
+        }
+        CurveDragTarget::InHandle(index) => {
+            if let Some(key) = curve.keys.get_mut(index) {
+                let handle = drag.origin + delta;
+                key.in_handle = Vec2::new(handle.x.min(0.), handle.y);
+                emit_curve(world, edit_id, curve);
+            }
+        }
+        CurveDragTarget::OutHandle(index) => {
+            if let Some(key) = curve.keys.get_mut(index) {
+                let handle = drag.origin + delta;
+                key.out_handle = Vec2::new(handle.x.max(0.), handle.y);
+                emit_curve(world, edit_id, curve);
+            }
+        }
+    }
+}
+
+/// Modify the selected key, keeping it between its neighbors.
+fn set_selected_key(
+    world: &mut World,
+    edit_id: Entity,
+    selection: Mutable<Option<usize>>,
+    f: impl FnOnce(&mut CurveKey),
+) {
+    let params = curve_params(world, edit_id);
+    let mut curve = params.value;
+    let Some(index) = selection.get(world).filter(|i| *i < curve.keys.len()) else {
+        return;
+    };
+    let min_x = index
+        .checked_sub(1)
+        .map_or(params.domain.min.x, |i| curve.keys[i].position.x);
+    let max_x = curve
+        .keys
+        .get(index + 1)
+        .map_or(params.domain.max.x, |key| key.position.x);
+    let key = &mut curve.keys[index];
+    f(key);
+    key.position.x = key.position.x.clamp(min_x, max_x);
+    emit_curve(world, edit_id, curve);
+}
+
+fn curve_params(world: &World, edit_id: Entity) -> CurveEditParams {
+    world.get::<CurveEditParams>(edit_id).unwrap().clone()
+}
+
+/// Pass a modified curve to the editor's `on_change` callback.
+fn emit_curve(world: &mut World, edit_id: Entity, curve: ScalarCurve) {
+    if let Some(on_change) = world.get::<CurveEditParams>(edit_id).unwrap().on_change {
+        world.run_callback(on_change, curve);
+    }
+}
+
+/// The default view of a domain, with a margin around it.
+fn fit_view(domain: Rect) -> Rect {
+    Rect::from_center_size(domain.center(), domain.size() * 1.2)
+}
+
+fn snap_point(point: Vec2, snap: Option<Vec2>, enabled: bool) -> Vec2 {
+    match snap {
+        Some(step) if enabled => Vec2::new(
+            if step.x > 0. {
+                (point.x / step.x).round() * step.x
+            } else {
+                point.x
+            },
+            if step.y > 0. {
+                (point.y / step.y).round() * step.y
+            } else {
+                point.y
+            },
+        ),
+        _ => point,
+    }
+}
+
+fn clamp_to_domain(point: Vec2, domain: Rect) -> Vec2 {
+    Vec2::new(point.x.clamp(domain.min.x, domain.max.x), point.y)
+}
+
+fn canvas_size(world: &World, canvas_id: Entity) -> Option<Vec2> {
+    let size = world.get::<Node>(canvas_id)?.size();
+    (size.x > 0. && size.y > 0.).then_some(size)
+}
+
+/// Convert a point in curve space to a pixel offset within the canvas.
+fn view_to_px(point: Vec2, view: Rect, size: Vec2) -> Vec2 {
+    Vec2::new(
+        (point.x - view.min.x) / view.width() * size.x,
+        (view.max.y - point.y) / view.height() * size.y,
+    )
+}
+
+/// Convert a pointer position in window coordinates to a point in curve space.
+fn px_to_view(world: &World, canvas_id: Entity, view: Rect, position: Vec2) -> Option<Vec2> {
+    let ent = world.entity(canvas_id);
+    let node = ent.get::<Node>()?;
+    let transform = ent.get::<GlobalTransform>()?;
+    let rect = node.logical_rect(transform);
+    if rect.width() <= 0. || rect.height() <= 0. {
+        return None;
+    }
+    let local = position - rect.min;
+    Some(Vec2::new(
+        view.min.x + local.x / rect.width() * view.width(),
+        view.max.y - local.y / rect.height() * view.height(),
+    ))
+}
+
+/// Positions of grid lines between `min` and `max`, spaced at 1, 2 or 5 times a power of ten.
+fn grid_lines(min: f32, max: f32, pixels: f32) -> Vec<f32> {
+    if pixels <= 0. || max <= min {
+        return Vec::new();
+    }
+    let raw_step = (max - min) * GRID_SPACING / pixels;
+    let base = 10f32.powf(raw_step.log10().floor());
+    let step = [1., 2., 5., 10.]
+        .into_iter()
+        .map(|m| m * base)
+        .find(|step| *step >= raw_step)
+        .unwrap_or(base * 10.);
+    let first = (min / step).ceil() as i32;
+    let last = (max / step).floor() as i32;
+    (first..=last).map(|i| i as f32 * step).collect()
+}
+
+/// Control points of the curve in pixels, as a start point followed by cubic segments. Constant
+/// and linear segments are expressed as degenerate cubics, and the curve is extended flat to the
+/// edges of the canvas.
+fn curve_path(curve: &ScalarCurve, view: Rect, size: Vec2) -> Vec<Vec2> {
+    let (Some(first), Some(last)) = (curve.keys.first(), curve.keys.last()) else {
+        return Vec::new();
+    };
+    if size.x <= 0. || size.y <= 0. {
+        return Vec::new();
+    }
+    let to_px = |point: Vec2| view_to_px(point, view, size);
+    let mut points = Vec::new();
+    let line_to = |points: &mut Vec<Vec2>, point: Vec2| {
+        let start = *points.last().unwrap();
+        points.extend([
+            start.lerp(point, 1. / 3.),
+            start.lerp(point, 2. / 3.),
+            point,
+        ]);
+    };
+    let start = to_px(first.position);
+    points.push(Vec2::new(start.x.min(0.), start.y));
+    line_to(&mut points, start);
+    for index in 0..curve.keys.len() - 1 {
+        let a = &curve.keys[index];
+        let b = &curve.keys[index + 1];
+        match a.interpolation {
+            CurveInterpolation::Constant => {
+                line_to(&mut points, to_px(Vec2::new(b.position.x, a.position.y)));
+                line_to(&mut points, to_px(b.position));
+            }
+            CurveInterpolation::Linear => line_to(&mut points, to_px(b.position)),
+            CurveInterpolation::Bezier => {
+                let [_, c1, c2, end] = curve.segment(index);
+                points.extend([to_px(c1), to_px(c2), to_px(end)]);
+            }
+        }
+    }
+    let end = to_px(last.position);
+    line_to(&mut points, Vec2::new(end.x.max(size.x), end.y));
+    points
+}
+
+/// Lines from the selected key to its tangent handles, in the same format as [`curve_path`].
+fn handle_path(
+    handles: &[CurvePoint],
+    selected: Option<(usize, CurveKey)>,
+    view: Rect,
+    size: Vec2,
+) -> Vec<Vec2> {
+    let Some((_, key)) = selected else {
+        return Vec::new();
+    };
+    let center = view_to_px(key.position, view, size);
+    let mut points = vec![center];
+    for handle in handles {
+        let start = *points.last().unwrap();
+        if start != center {
+            points.extend([start, center, center]);
+        }
+        points.extend([
+            center.lerp(handle.position, 1. / 3.),
+            center.lerp(handle.position, 2. / 3.),
+            handle.position,
+        ]);
+    }
+    points
+}
@@ -0,0 +1,827 @@
+use std::cmp::Ordering;
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    scrolling::ScrollArea,
+    tokens, typography,
+};
+
+use super::{
+    listview::row_bg_color,
+    shortcut::{is_ctrl_pressed, is_shift_pressed},
+    tree_view::SelectMode,
+    Icon, ScrollView,
+};
+
+/// Number of rows moved by the Page Up and Page Down keys.
+const PAGE_ROWS: usize = 10;
+
+fn style_table(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .background_color(tokens::U1)
+        .border_radius(5.0)
+        .padding(3);
+}
+
+fn style_table_header(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .flex_shrink(0.)
+        .border_bottom(1)
+        .border_color(tokens::U3)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_table_header_inner(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .position(ui::PositionType::Relative);
+}
+
+fn style_header_cell(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .flex_shrink(0.)
+        .gap(4)
+        .padding((6, 3))
+        .color(tokens::DIM)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_resize_handle(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .top(0)
+        .bottom(0)
+        .right(0)
+        .width(5)
+        .border_right(1)
+        .border_color(tokens::U3)
+        .cursor(CursorIcon::ColResize);
+}
+
+fn style_table_body(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).min_height(0);
+}
+
+fn style_table_body_inner(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .height(ui::Val::Auto)
+        .min_width(ui::Val::Percent(100.));
+}
+
+fn style_table_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .min_height(20);
+}
+
+fn style_table_cell(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .flex_shrink(0.)
+        .padding((6, 2))
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+/// The column by which a [`DataTable`] is sorted.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct TableSort {
+    /// Index of the sort column.
+    pub column: usize,
+
+    /// Whether the rows are sorted in descending order.
+    pub descending: bool,
+}
+
+/// Definition of a column in a [`DataTable`].
+#[derive(Clone, PartialEq)]
+pub struct TableColumn<Row: PartialEq + Clone> {
+    /// Content of the column header.
+    pub header: ViewChild,
+
+    /// Function which produces the content of a cell in this column.
+    pub cell: fn(&Row) -> ViewChild,
+
+    /// Initial width of the column, in pixels.
+    pub width: f32,
+
+    /// Minimum width of the column when resizing, in pixels.
+    pub min_width: f32,
+
+    /// Comparison function used when sorting by this column. Columns without one can't be
+    /// sorted.
+    pub compare: Option<fn(&Row, &Row) -> Ordering>,
+}
+
+impl<Row: PartialEq + Clone> TableColumn<Row> {
+    /// Create a new column with the given header and cell content.
+    pub fn new(header: impl IntoViewChild, cell: fn(&Row) -> ViewChild) -> Self {
+        Self {
+            header: header.into_view_child(),
+            cell,
+            width: 100.,
+            min_width: 24.,
+            compare: None,
+        }
+    }
+
+    /// Set the initial width of the column.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Set the minimum width of the column.
+    pub fn min_width(mut self, min_width: f32) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Make the column sortable, using the given comparison function.
+    pub fn sort_by(mut self, compare: fn(&Row, &Row) -> Ordering) -> Self {
+        self.compare = Some(compare);
+        self
+    }
+}
+
+/// Component used to hold the table params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct DataTableState {
+    /// Row indices, in display order.
+    order: Vec<usize>,
+    selection: Vec<usize>,
+    multi_select: bool,
+    /// The row which has keyboard focus.
+    cursor: Mutable<Option<usize>>,
+    /// The row where a range selection starts.
+    anchor: Mutable<Option<usize>>,
+    on_select: Option<Callback<Vec<usize>>>,
+}
+
+/// A scrollable table of rows, with sortable and resizable columns.
+///
+/// Like [`TreeView`](super::TreeView), the table is controlled: the owner passes in the `sort`
+/// order and the `selected` rows, identified by their index in `rows`, and updates them in
+/// response to `on_sort` and `on_select`. Column widths are kept by the table itself; they are
+/// reset whenever the column definitions change.
+///
+/// Clicking a column header cycles between ascending, descending and unsorted order; dragging
+/// the right edge of a header resizes the column. The header stays in place while the rows
+/// scroll vertically. Rows are selected by clicking, and when `multi_select` is enabled,
+/// Ctrl-clicking toggles a row and Shift-clicking selects a range. The up and down arrows,
+/// Page Up, Page Down, Home and End move the selection, Space toggles the focused row, and
+/// Ctrl+A selects all rows.
+#[derive(Clone, PartialEq)]
+pub struct DataTable<Row: PartialEq + Clone> {
+    /// The table rows.
+    pub rows: Vec<Row>,
+
+    /// The column definitions.
+    pub columns: Vec<TableColumn<Row>>,
+
+    /// The current sort order, if any.
+    pub sort: Option<TableSort>,
+
+    /// Indices of the selected rows.
+    pub selected: Vec<usize>,
+
+    /// Whether more than one row can be selected.
+    pub multi_select: bool,
+
+    /// Additional styles to be applied to the table.
+    pub style: StyleHandle,
+
+    /// Callback called when a column header is clicked, with the new sort order.
+    pub on_sort: Option<Callback<Option<TableSort>>>,
+
+    /// Callback called when the selection changes, with the new list of selected rows.
+    pub on_select: Option<Callback<Vec<usize>>>,
+
+    /// Callback called when a column is resized, with the column index and new width.
+    pub on_resize: Option<Callback<(usize, f32)>>,
+}
+
+impl<Row: PartialEq + Clone> DataTable<Row> {
+    /// Create a new data table.
+    pub fn new() -> Self {
+        Self {
+            rows: Vec::new(),
+            columns: Vec::new(),
+            sort: None,
+            selected: Vec::new(),
+            multi_select: false,
+            style: StyleHandle::default(),
+            on_sort: None,
+            on_select: None,
+            on_resize: None,
+        }
+    }
+
+    /// Set the table rows.
+    pub fn rows(mut self, rows: Vec<Row>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Set the column definitions.
+    pub fn columns(mut self, columns: Vec<TableColumn<Row>>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the current sort order.
+    pub fn sort(mut self, sort: Option<TableSort>) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// Set the indices of the selected rows.
+    pub fn selected(mut self, selected: Vec<usize>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set whether more than one row can be selected.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Set additional styles to be applied to the table.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the sort order changes.
+    pub fn on_sort(mut self, on_sort: Callback<Option<TableSort>>) -> Self {
+        self.on_sort = Some(on_sort);
+        self
+    }
+
+    /// Set the callback called when the selection changes.
+    pub fn on_select(mut self, on_select: Callback<Vec<usize>>) -> Self {
+        self.on_select = Some(on_select);
+        self
+    }
+
+    /// Set the callback called when a column is resized.
+    pub fn on_resize(mut self, on_resize: Callback<(usize, f32)>) -> Self {
+        self.on_resize = Some(on_resize);
+        self
+    }
+}
+
+impl<Row: PartialEq + Clone> Default for DataTable<Row> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Row: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for DataTable<Row> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let scroll_id = cx.create_entity();
+        let cursor = cx.create_mutable::<Option<usize>>(None);
+        let anchor = cx.create_mutable::<Option<usize>>(None);
+        let initial_widths: Vec<f32> = self.columns.iter().map(|c| c.width).collect();
+        let widths = cx.create_mutable(initial_widths.clone());
+        let focused = cx.is_focus_visible(id);
+        let cursor_row = cursor.get(cx);
+
+        // Reset the column widths when the column definitions change.
+        cx.create_effect(
+            move |world, initial_widths| {
+                widths.set_clone(world, initial_widths);
+            },
+            initial_widths,
+        );
+
+        let current_widths = widths.get_clone(cx);
+        let column_widths: Vec<f32> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| {
+                current_widths
+                    .get(index)
+                    .copied()
+                    .unwrap_or(column.width)
+                    .max(column.min_width)
+            })
+            .collect();
+
+        let mut order: Vec<usize> = (0..self.rows.len()).collect();
+        if let Some(sort) = self.sort {
+            if let Some(compare) = self.columns.get(sort.column).and_then(|c| c.compare) {
+                order.sort_by(|a, b| {
+                    let ordering = compare(&self.rows[*a], &self.rows[*b]);
+                    if sort.descending {
+                        ordering.reverse()
+                    } else {
+                        ordering
+                    }
+                });
+            }
+        }
+
+        let headers: Vec<TableHeaderProps> = self
+            .columns
+            .iter()
+            .enumerate()
+            .map(|(index, column)| TableHeaderProps {
+                index,
+                header: column.header.clone(),
+                width: column_widths[index],
+                min_width: column.min_width,
+                sortable: column.compare.is_some(),
+                sorted: self
+                    .sort
+                    .filter(|sort| sort.column == index)
+                    .map(|sort| sort.descending),
+            })
+            .collect();
+
+        let rows: Vec<TableRowProps<Row>> = order
+            .iter()
+            .map(|index| TableRowProps {
+                index: *index,
+                row: self.rows[*index].clone(),
+                selected: self.selected.contains(index),
+                is_cursor: cursor_row == Some(*index),
+            })
+            .collect();
+        let cells: Vec<fn(&Row) -> ViewChild> = self.columns.iter().map(|c| c.cell).collect();
+
+        let on_sort = self.on_sort;
+        let on_resize = self.on_resize;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DataTable")
+            .style((typography::text_default, style_table, self.style.clone()))
+            .insert(TabIndex(0))
+            .insert_dyn(
+                move |(order, selection, multi_select, on_select)| DataTableState {
+                    order,
+                    selection,
+                    multi_select,
+                    cursor,
+                    anchor,
+                    on_select,
+                },
+                (
+                    order.clone(),
+                    self.selected.clone(),
+                    self.multi_select,
+                    self.on_select,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::Table)),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            handle_table_key(world, id);
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("DataTable::Header")
+                    .style(style_table_header)
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .style(style_table_header_inner)
+                            .insert((
+                                TableHeaderScroll(scroll_id),
+                                AccessibilityNode::from(NodeBuilder::new(Role::Row)),
+                            ))
+                            .children(For::index(&headers, move |header, _| TableHeader {
+                                header: header.clone(),
+                                widths,
+                                on_sort,
+                                on_resize,
+                            })),
+                    ),
+                ScrollView::new()
+                    .entity(Some(scroll_id))
+                    .style(style_table_body)
+                    .scroll_enable_x(true)
+                    .scroll_enable_y(true)
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("DataTable::Body")
+                            .style(style_table_body_inner)
+                            .children(For::index(&rows, move |row, _| TableRow {
+                                table: id,
+                                row: row.clone(),
+                                cells: cells.clone(),
+                                widths: column_widths.clone(),
+                                table_focused: focused,
+                            })),
+                    ),
+            ))
+    }
+}
+
+/// Marks the inner row of a table header, which follows the horizontal scroll position of the
+/// table body `0`.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct TableHeaderScroll(Entity);
+
+/// Keep the headers of data tables aligned with their horizontally scrolled bodies. This is
+/// done here rather than in the table template, since the scroll area is updated every frame.
+pub(crate) fn sync_table_headers(
+    mut query: Query<(&mut Style, &TableHeaderScroll)>,
+    query_scroll: Query<&ScrollArea>,
+) {
+    for (mut style, header) in query.iter_mut() {
+        let left = query_scroll
+            .get(header.0)
+            .map_or(0., |scrolling| scrolling.scroll_left);
+        let left = ui::Val::Px(-left);
+        if style.left != left {
+            style.left = left;
+        }
+    }
+}
+
+/// Update the selection in response to a click or key press on the given row.
+fn select_row(world: &mut World, table: Entity, row: usize, mode: SelectMode) {
+    let state = world.get::<DataTableState>(table).unwrap().clone();
+    let selection = match (mode, state.multi_select) {
+        (SelectMode::Toggle, true) => {
+            let mut selection = state.selection.clone();
+            match selection.iter().position(|r| *r == row) {
+                Some(pos) => {
+                    selection.remove(pos);
+                }
+                None => selection.push(row),
+            }
+            selection
+        }
+        (SelectMode::Range, true) => {
+            let anchor = state.anchor.get(world).unwrap_or(row);
+            let position = |r: usize| state.order.iter().position(|o| *o == r);
+            match (position(anchor), position(row)) {
+                (Some(a), Some(b)) => state.order[a.min(b)..=a.max(b)].to_vec(),
+                _ => vec![row],
+            }
+        }
+        _ => vec![row],
+    };
+    if mode != SelectMode::Range {
+        state.anchor.set(world, Some(row));
+    }
+    state.cursor.set(world, Some(row));
+    if selection != state.selection {
+        if let Some(on_select) = state.on_select {
+            world.run_callback(on_select, selection);
+        }
+    }
+}
+
+fn handle_table_key(world: &mut World, table: Entity) {
+    let event = world.resource::<ListenerInput<KeyPressEvent>>();
+    let (key_code, shift) = (event.key_code, event.shift);
+    let state = world.get::<DataTableState>(table).unwrap().clone();
+    if state.order.is_empty() {
+        return;
+    }
+    let last = state.order.len() - 1;
+    let current = state
+        .cursor
+        .get(world)
+        .and_then(|row| state.order.iter().position(|r| *r == row));
+
+    let target = match (key_code, current) {
+        (KeyCode::ArrowUp, Some(index)) => index.saturating_sub(1),
+        (KeyCode::ArrowDown, Some(index)) => (index + 1).min(last),
+        (KeyCode::PageUp, Some(index)) => index.saturating_sub(PAGE_ROWS),
+        (KeyCode::PageDown, Some(index)) => (index + PAGE_ROWS).min(last),
+        (
+            KeyCode::ArrowUp
+            | KeyCode::ArrowDown
+            | KeyCode::PageUp
+            | KeyCode::PageDown
+            | KeyCode::Home,
+            _,
+        ) => 0,
+        (KeyCode::End, _) => last,
+        (KeyCode::Space, Some(index)) => {
+            world
+                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                .stop_propagation();
+            select_row(world, table, state.order[index], SelectMode::Toggle);
+            return;
+        }
+        (KeyCode::KeyA, _) if state.multi_select && is_ctrl_pressed(world) => {
+            world
+                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                .stop_propagation();
+            if let Some(on_select) = state.on_select {
+                world.run_callback(on_select, state.order.clone());
+            }
+            return;
+        }
+        _ => return,
+    };
+
+    world
+        .resource_mut::<ListenerInput<KeyPressEvent>>()
+        .stop_propagation();
+    let mode = if shift {
+        SelectMode::Range
+    } else {
+        SelectMode::Replace
+    };
+    select_row(world, table, state.order[target], mode);
+}
+
+/// Header props produced from the column definitions.
+#[derive(Clone, PartialEq)]
+struct TableHeaderProps {
+    index: usize,
+    header: ViewChild,
+    width: f32,
+    min_width: f32,
+    sortable: bool,
+    /// If this is the sort column, whether the sort is descending.
+    sorted: Option<bool>,
+}
+
+/// A column header within a [`DataTable`].
+#[derive(Clone, PartialEq)]
+struct TableHeader {
+    header: TableHeaderProps,
+    widths: Mutable<Vec<f32>>,
+    on_sort: Option<Callback<Option<TableSort>>>,
+    on_resize: Option<Callback<(usize, f32)>>,
+}
+
+impl ViewTemplate for TableHeader {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let column = self.header.index;
+        let width = self.header.width;
+        let min_width = self.header.min_width;
+        let sortable = self.header.sortable;
+        let sorted = self.header.sorted;
+        let widths = self.widths;
+        let on_sort = self.on_sort;
+        let on_resize = self.on_resize;
+        // Width of the column when the resize drag started.
+        let drag_start = cx.create_mutable::<Option<f32>>(None);
+
+        Element::<NodeBundle>::new()
+            .named("DataTable::HeaderCell")
+            .style(style_header_cell)
+            .style_dyn(
+                |(width, sortable), sb| {
+                    sb.width(width).cursor(if sortable {
+                        CursorIcon::Pointer
+                    } else {
+                        CursorIcon::Default
+                    });
+                },
+                (width, sortable),
+            )
+            .insert_dyn(
+                move |(sorted, sortable, column, on_sort)| {
+                    (
+                        AccessibilityNode::from({
+                            let mut builder = NodeBuilder::new(Role::ColumnHeader);
+                            if sorted.is_some() {
+                                builder.set_selected(true);
+                            }
+                            builder
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            if !sortable {
+                                return;
+                            }
+                            // Cycle between ascending, descending and unsorted.
+                            let next = match sorted {
+                                None => Some(TableSort {
+                                    column,
+                                    descending: false,
+                                }),
+                                Some(false) => Some(TableSort {
+                                    column,
+                                    descending: true,
+                                }),
+                                Some(true) => None,
+                            };
+                            if let Some(on_sort) = on_sort {
+                                world.run_callback(on_sort, next);
+                            }
+                        }),
+                    )
+                },
+                (sorted, sortable, column, on_sort),
+            )
+            .children((
+                self.header.header.clone(),
+                Cond::new(
+                    sorted.is_some(),
+                    Icon::new(if sorted == Some(true) {
+                        "embedded://bevy_quill_obsidian/assets/icons/chevron_down.png"
+                    } else {
+                        "embedded://bevy_quill_obsidian/assets/icons/chevron_up.png"
+                    })
+                    .color(tokens::DIM),
+                    (),
+                ),
+                Element::<NodeBundle>::new()
+                    .named("DataTable::ResizeHandle")
+                    .style(style_resize_handle)
+                    .insert_dyn(
+                        move |(column, width, min_width, on_resize)| {
+                            (
+                                On::<Pointer<Click>>::run(move |world: &mut World| {
+                                    world
+                                        .resource_mut::<ListenerInput<Pointer<Click>>>()
+                                        .stop_propagation();
+                                }),
+                                On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                    world
+                                        .resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                        .stop_propagation();
+                                    let current = widths
+                                        .get_clone(world)
+                                        .get(column)
+                                        .copied()
+                                        .unwrap_or(width);
+                                    drag_start.set(world, Some(current.max(min_width)));
+                                }),
+                                On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                                    drag_start.set(world, None);
+                                }),
+                                On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                    let event = world.resource::<ListenerInput<Pointer<Drag>>>();
+                                    let distance = event.distance;
+                                    let Some(start) = drag_start.get(world) else {
+                                        return;
+                                    };
+                                    let new_width = (start + distance.x).max(min_width);
+                                    widths.update(world, |mut widths| {
+                                        if widths.len() <= column {
+                                            widths.resize(column + 1, width);
+                                        }
+                                        widths[column] = new_width;
+                                    });
+                                    if let Some(on_resize) = on_resize {
+                                        world.run_callback(on_resize, (column, new_width));
+                                    }
+                                }),
+                            )
+                        },
+                        (column, width, min_width, on_resize),
+                    ),
+            ))
+    }
+}
+
+/// Row props produced from the table rows, in display order.
+#[derive(Clone, PartialEq)]
+struct TableRowProps<Row: PartialEq + Clone> {
+    /// Index of the row in the table's `rows`.
+    index: usize,
+    row: Row,
+    selected: bool,
+    is_cursor: bool,
+}
+
+/// An individual row within a [`DataTable`]. Rows are only rebuilt when their own props change,
+/// and cells only when the row data or column width changes.
+#[derive(Clone, PartialEq)]
+struct TableRow<Row: PartialEq + Clone> {
+    table: Entity,
+    row: TableRowProps<Row>,
+    cells: Vec<fn(&Row) -> ViewChild>,
+    widths: Vec<f32>,
+    table_focused: bool,
+}
+
+impl<Row: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for TableRow<Row> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let table = self.table;
+        let index = self.row.index;
+        let hovering = cx.is_hovered(id);
+        let row = self.row.row.clone();
+        let widths = self.widths.clone();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DataTable::Row")
+            .style(style_table_row)
+            .style_dyn(
+                |(hovering, selected), sb| {
+                    sb.background_color(row_bg_color(false, selected, hovering));
+                },
+                (hovering, self.row.selected),
+            )
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(-1.0)
+                            .outline_width(1.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                self.row.is_cursor && self.table_focused,
+            )
+            .insert_dyn(
+                |selected| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::Row);
+                        builder.set_selected(selected);
+                        builder
+                    })
+                },
+                self.row.selected,
+            )
+            .insert_dyn(
+                move |index| {
+                    On::<Pointer<Click>>::run(move |world: &mut World| {
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                            .unwrap();
+                        event.stop_propagation();
+                        let mode = if is_shift_pressed(world) {
+                            SelectMode::Range
+                        } else if is_ctrl_pressed(world) {
+                            SelectMode::Toggle
+                        } else {
+                            SelectMode::Replace
+                        };
+                        let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                        focus.0 = Some(table);
+                        select_row(world, table, index, mode);
+                    })
+                },
+                index,
+            )
+            .children(For::index(&self.cells, move |cell, column| TableCell {
+                row: row.clone(),
+                cell: *cell,
+                width: widths.get(column).copied().unwrap_or(0.),
+            }))
+    }
+}
+
+/// A cell within a [`DataTable`] row.
+#[derive(Clone, PartialEq)]
+struct TableCell<Row: PartialEq + Clone> {
+    row: Row,
+    cell: fn(&Row) -> ViewChild,
+    width: f32,
+}
+
+impl<Row: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for TableCell<Row> {
+    type View = impl View;
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        Element::<NodeBundle>::new()
+            .style(style_table_cell)
+            .style_dyn(
+                |width, sb| {
+                    sb.width(width);
+                },
+                self.width,
+            )
+            .insert(AccessibilityNode::from(NodeBuilder::new(Role::Cell)))
+            .children((self.cell)(&self.row))
+    }
+}
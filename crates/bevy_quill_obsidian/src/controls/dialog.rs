@@ -1,9 +1,4 @@
-use bevy::{
-    a11y::Focus,
-    color::{Alpha, Luminance},
-    prelude::*,
-    ui,
-};
+use bevy::{a11y::Focus, color::Alpha, prelude::*, ui};
 use bevy_mod_picking::{
     events::{Click, Pointer},
     prelude::{ListenerInput, On},
@@ -13,9 +8,10 @@ use bevy_quill_core::*;
 
 use crate::{
     animation::{AnimatedBackgroundColor, AnimatedScale, AnimatedTransition},
-    colors,
     focus::{KeyPressEvent, TabGroup},
     hooks::{BistableTransitionState, CreateBistableTransition},
+    theme::Theme,
+    tokens,
     typography::text_default,
 };
 
@@ -29,21 +25,21 @@ fn style_dialog_overlay(ss: &mut StyleBuilder) {
         .top(0)
         .right(0)
         .bottom(0)
-        .z_index(100)
-        .background_color(colors::U1.with_alpha(0.0));
+        .z_index(100);
 }
 
 fn style_dialog(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U2)
+    ss.background_color(tokens::U2)
         .border_radius(6.0)
         .position(PositionType::Relative)
         .display(ui::Display::Flex)
         .flex_direction(ui::FlexDirection::Column)
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Stretch)
-        .border_color(colors::U1)
+        .border_color(tokens::U1)
         .width(400)
-        .border(3);
+        .border(3)
+        .drop_shadow(0, 6, 24, Srgba::new(0., 0., 0., 0.6));
     // .scale(0.5)
     // .transition(&[Transition {
     //     property: TransitionProperty::Transform,
@@ -186,14 +182,20 @@ impl ViewTemplate for Dialog {
                     )
                     .effect(
                         move |cx, ent, state| {
+                            // The overlay color is animated, so it is resolved from the theme
+                            // here; a token would be re-applied over the animation.
+                            let overlay = cx.world().resource::<Theme>().color(tokens::U1);
                             let mut entt = cx.world_mut().entity_mut(ent);
+                            if !entt.contains::<BackgroundColor>() {
+                                entt.insert(BackgroundColor(overlay.with_alpha(0.0).into()));
+                            }
                             let target = match state {
                                 BistableTransitionState::Entering
                                 | BistableTransitionState::Entered
-                                | BistableTransitionState::ExitStart => colors::U1.with_alpha(0.7),
+                                | BistableTransitionState::ExitStart => overlay.with_alpha(0.7),
                                 BistableTransitionState::EnterStart
                                 | BistableTransitionState::Exiting
-                                | BistableTransitionState::Exited => colors::U1.with_alpha(0.0),
+                                | BistableTransitionState::Exited => overlay.with_alpha(0.0),
                             };
                             AnimatedTransition::<AnimatedBackgroundColor>::start(
                                 &mut entt,
@@ -260,7 +262,7 @@ fn style_dialog_header(ss: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Row)
         .justify_content(ui::JustifyContent::SpaceBetween)
         .font_size(18)
-        .border_color(colors::U2.darker(0.01))
+        .border_color(tokens::U2.darker(0.01))
         .border_bottom(1)
         .padding((12, 6));
 }
@@ -336,7 +338,7 @@ fn style_dialog_footer(ss: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Row)
         .justify_content(ui::JustifyContent::FlexEnd)
         .align_items(ui::AlignItems::Center)
-        .border_color(colors::U2.darker(0.01))
+        .border_color(tokens::U2.darker(0.01))
         .border_top(1)
         .column_gap(4)
         .padding((8, 6));
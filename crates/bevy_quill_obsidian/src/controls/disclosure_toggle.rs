@@ -1,11 +1,11 @@
 use super::Icon;
 use crate::{
     animation::{AnimatedRotation, AnimatedTransition},
-    colors,
     cursor::StyleBuilderCursor,
     focus::{KeyPressEvent, TabIndex},
     hooks::{UseIsFocus, UseIsHover},
     size::Size,
+    tokens,
 };
 use bevy::{
     a11y::{
@@ -25,7 +25,7 @@ fn style_toggle(ss: &mut StyleBuilder) {
         .justify_content(ui::JustifyContent::Center)
         .align_items(ui::AlignItems::Center)
         .align_content(ui::AlignContent::Center)
-        .color(colors::FOREGROUND)
+        .color(tokens::FOREGROUND)
         .cursor(CursorIcon::Pointer);
 }
 
@@ -172,7 +172,7 @@ impl ViewTemplate for DisclosureToggle {
                 move |focused, sb| {
                     match focused {
                         true => {
-                            sb.outline_color(colors::FOCUS)
+                            sb.outline_color(tokens::FOCUS)
                                 .outline_width(2)
                                 .outline_offset(2);
                         }
@@ -187,9 +187,9 @@ impl ViewTemplate for DisclosureToggle {
                 Icon::new("embedded://bevy_quill_obsidian/assets/icons/chevron_right.png")
                     .color({
                         match (self.disabled, hovering) {
-                            (true, _) => Color::from(colors::DIM).with_alpha(0.2),
-                            (false, true) => Color::from(colors::FOREGROUND),
-                            (false, false) => Color::from(colors::DIM),
+                            (true, _) => tokens::DIM.with_alpha(0.2),
+                            (false, true) => tokens::FOREGROUND,
+                            (false, false) => tokens::DIM,
                         }
                     })
                     .size(match self.size {
@@ -0,0 +1,1518 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{cursor::StyleBuilderCursor, hooks::UseElementRect, size::Size, tokens, typography};
+
+use super::{DisclosureToggle, Splitter, SplitterDirection, Tab, TabBar, TabDrag, TabPanel};
+
+/// Height of a group's tab header.
+const HEADER_HEIGHT: f32 = 24.;
+
+/// Fraction of a group's width or height, measured from each edge, which splits the group when
+/// a panel is dropped there.
+const EDGE_ZONE: f32 = 0.25;
+
+/// Default size of a panel which is dragged out into a floating window.
+const FLOATING_SIZE: Vec2 = Vec2::new(240., 180.);
+
+/// Minimum size of a floating window.
+const FLOATING_MIN_SIZE: Vec2 = Vec2::new(120., 80.);
+
+/// Minimum split ratio; keeps either side of a split from disappearing entirely.
+const MIN_RATIO: f32 = 0.05;
+
+fn style_dock_space(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .position(ui::PositionType::Relative)
+        .flex_grow(1.)
+        .min_width(0)
+        .min_height(0);
+}
+
+fn style_dock_split(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_grow(1.)
+        .min_width(0)
+        .min_height(0);
+}
+
+fn style_dock_split_pane(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Stretch)
+        .min_width(0)
+        .min_height(0)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_dock_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .position(ui::PositionType::Relative)
+        .flex_grow(1.)
+        .min_width(0)
+        .min_height(0)
+        .background_color(tokens::U2);
+}
+
+fn style_dock_header(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_shrink(0.)
+        .height(HEADER_HEIGHT)
+        .padding_right(4)
+        .background_color(tokens::U1)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_dock_tabs(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).min_width(0).height(ui::Val::Percent(100.));
+}
+
+fn style_dock_toggle(ss: &mut StyleBuilder) {
+    ss.align_self(ui::AlignSelf::Center);
+}
+
+fn style_dock_content(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_grow(1.)
+        .min_height(0)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_drop_zone(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .background_color(tokens::FOCUS.with_alpha(0.2))
+        .border(2)
+        .border_color(tokens::FOCUS)
+        .pointer_events(false);
+}
+
+fn style_floating_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .position(ui::PositionType::Absolute)
+        .border(1)
+        .border_color(tokens::U4)
+        .border_radius(4)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_resize_grip(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .right(0)
+        .bottom(0)
+        .width(10)
+        .height(10)
+        .cursor(CursorIcon::SeResize);
+}
+
+fn style_drag_ghost(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .width(FLOATING_SIZE.x)
+        .height(FLOATING_SIZE.y)
+        .background_color(tokens::FOCUS.with_alpha(0.1))
+        .border(1)
+        .border_color(tokens::FOCUS)
+        .border_radius(4)
+        .pointer_events(false);
+}
+
+/// Direction in which a split arranges its two children.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub enum DockDirection {
+    /// The children are placed side by side.
+    #[default]
+    Row,
+
+    /// The children are stacked one above the other.
+    Column,
+}
+
+/// Where a panel is dropped, relative to a group.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DockZone {
+    /// Add the panel as a tab in the group.
+    #[default]
+    Center,
+
+    /// Split the group, placing the panel on the left.
+    Left,
+
+    /// Split the group, placing the panel on the right.
+    Right,
+
+    /// Split the group, placing the panel above.
+    Top,
+
+    /// Split the group, placing the panel below.
+    Bottom,
+}
+
+impl DockZone {
+    /// The direction of the split produced by dropping into this zone, and whether the dropped
+    /// panel comes first. Returns `None` for the center zone, which doesn't split.
+    fn split(self) -> Option<(DockDirection, bool)> {
+        match self {
+            DockZone::Center => None,
+            DockZone::Left => Some((DockDirection::Row, true)),
+            DockZone::Right => Some((DockDirection::Row, false)),
+            DockZone::Top => Some((DockDirection::Column, true)),
+            DockZone::Bottom => Some((DockDirection::Column, false)),
+        }
+    }
+}
+
+/// A group of panels, displayed as tabs.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct DockGroup {
+    /// Ids of the panels in the group.
+    pub panels: Vec<String>,
+
+    /// Index of the visible panel.
+    pub selected: usize,
+
+    /// Whether the group is collapsed to just its tabs.
+    pub collapsed: bool,
+}
+
+impl DockGroup {
+    /// Create a new group containing the given panels.
+    pub fn new(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        Self {
+            panels: panels.into_iter().map(|p| p.into()).collect(),
+            selected: 0,
+            collapsed: false,
+        }
+    }
+
+    fn contains(&self, panel: &str) -> bool {
+        self.panels.iter().any(|p| p == panel)
+    }
+
+    /// Add a panel as the last tab, and select it.
+    fn push(&mut self, panel: String) {
+        self.panels.push(panel);
+        self.selected = self.panels.len() - 1;
+        self.collapsed = false;
+    }
+
+    fn remove(&mut self, panel: &str) {
+        if let Some(index) = self.panels.iter().position(|p| p == panel) {
+            self.panels.remove(index);
+            if index < self.selected || self.selected >= self.panels.len() {
+                self.selected = self.selected.saturating_sub(1);
+            }
+        }
+    }
+}
+
+/// A node in the docked layout tree: either a group of tabbed panels, or a split between two
+/// child nodes.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub enum DockNode {
+    /// A group of tabbed panels.
+    Group(DockGroup),
+
+    /// Two nodes separated by a draggable splitter.
+    Split {
+        /// Whether the children are side by side or stacked.
+        direction: DockDirection,
+        /// Fraction of the space given to the first child.
+        ratio: f32,
+        /// The left or top child.
+        first: Box<DockNode>,
+        /// The right or bottom child.
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    /// Construct a group node containing the given panels.
+    pub fn group(panels: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        DockNode::Group(DockGroup::new(panels))
+    }
+
+    /// Construct a split node.
+    pub fn split(direction: DockDirection, ratio: f32, first: DockNode, second: DockNode) -> Self {
+        DockNode::Split {
+            direction,
+            ratio,
+            first: Box::new(first),
+            second: Box::new(second),
+        }
+    }
+
+    /// Whether the node takes up only the height of its tabs: either a collapsed group, or a
+    /// split whose children are both collapsed.
+    pub fn is_collapsed(&self) -> bool {
+        match self {
+            DockNode::Group(group) => group.collapsed,
+            DockNode::Split { first, second, .. } => first.is_collapsed() && second.is_collapsed(),
+        }
+    }
+
+    fn group_mut(&mut self, panel: &str) -> Option<&mut DockGroup> {
+        match self {
+            DockNode::Group(group) if group.contains(panel) => Some(group),
+            DockNode::Group(_) => None,
+            DockNode::Split { first, second, .. } => first
+                .group_mut(panel)
+                .or_else(move || second.group_mut(panel)),
+        }
+    }
+
+    fn first_group_mut(&mut self) -> &mut DockGroup {
+        match self {
+            DockNode::Group(group) => group,
+            DockNode::Split { first, .. } => first.first_group_mut(),
+        }
+    }
+
+    fn node_mut(&mut self, path: &[usize]) -> Option<&mut DockNode> {
+        match (path.split_first(), self) {
+            (None, node) => Some(node),
+            (Some((0, rest)), DockNode::Split { first, .. }) => first.node_mut(rest),
+            (Some((1, rest)), DockNode::Split { second, .. }) => second.node_mut(rest),
+            _ => None,
+        }
+    }
+
+    /// Remove a panel, returning what remains of the node. Empty groups are removed, and a
+    /// split with only one remaining child is replaced by that child.
+    fn without(self, panel: &str) -> Option<DockNode> {
+        match self {
+            DockNode::Group(mut group) => {
+                group.remove(panel);
+                (!group.panels.is_empty()).then_some(DockNode::Group(group))
+            }
+            DockNode::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => match (first.without(panel), second.without(panel)) {
+                (Some(first), Some(second)) => {
+                    Some(DockNode::split(direction, ratio, first, second))
+                }
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    /// Split the group containing `target`, placing a new group containing `panel` beside it.
+    fn split_group(self, target: &str, panel: &str, zone: DockZone) -> DockNode {
+        match self {
+            DockNode::Group(group) if group.contains(target) => {
+                let Some((direction, before)) = zone.split() else {
+                    return DockNode::Group(group);
+                };
+                let new = DockNode::group([panel]);
+                let old = DockNode::Group(group);
+                if before {
+                    DockNode::split(direction, 0.5, new, old)
+                } else {
+                    DockNode::split(direction, 0.5, old, new)
+                }
+            }
+            DockNode::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => DockNode::split(
+                direction,
+                ratio,
+                first.split_group(target, panel, zone),
+                second.split_group(target, panel, zone),
+            ),
+            node => node,
+        }
+    }
+}
+
+/// A group of panels floating above the docked layout.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct FloatingGroup {
+    /// The panels in the floating window.
+    pub group: DockGroup,
+
+    /// Left edge of the window, relative to the dock space.
+    pub x: f32,
+
+    /// Top edge of the window, relative to the dock space.
+    pub y: f32,
+
+    /// Width of the window.
+    pub width: f32,
+
+    /// Height of the window.
+    pub height: f32,
+}
+
+impl FloatingGroup {
+    /// The bounds of the window, relative to the dock space.
+    pub fn rect(&self) -> Rect {
+        Rect::new(self.x, self.y, self.x + self.width, self.y + self.height)
+    }
+}
+
+/// The arrangement of panels within a [`DockSpace`]. Panels are referred to by id, so the layout
+/// can be serialized, for example with [`DockLayout::to_ron`], and restored later.
+#[derive(Clone, PartialEq, Debug, Default, Serialize, Deserialize)]
+pub struct DockLayout {
+    /// The docked panels, or `None` if all panels are floating or closed.
+    pub root: Option<DockNode>,
+
+    /// Floating windows, from back to front.
+    pub floating: Vec<FloatingGroup>,
+}
+
+impl DockLayout {
+    /// Create a new layout with the given docked panels.
+    pub fn new(root: DockNode) -> Self {
+        Self {
+            root: Some(root),
+            floating: Vec::new(),
+        }
+    }
+
+    /// Whether the layout contains the given panel, either docked or floating.
+    pub fn contains(&self, panel: &str) -> bool {
+        self.group(panel).is_some()
+    }
+
+    /// Return the group containing the given panel.
+    pub fn group(&self, panel: &str) -> Option<&DockGroup> {
+        fn find<'a>(node: &'a DockNode, panel: &str) -> Option<&'a DockGroup> {
+            match node {
+                DockNode::Group(group) => group.contains(panel).then_some(group),
+                DockNode::Split { first, second, .. } => {
+                    find(first, panel).or_else(|| find(second, panel))
+                }
+            }
+        }
+        self.root
+            .as_ref()
+            .and_then(|root| find(root, panel))
+            .or_else(|| {
+                self.floating
+                    .iter()
+                    .map(|f| &f.group)
+                    .find(|g| g.contains(panel))
+            })
+    }
+
+    fn group_mut(&mut self, panel: &str) -> Option<&mut DockGroup> {
+        if let Some(group) = self.root.as_mut().and_then(|root| root.group_mut(panel)) {
+            return Some(group);
+        }
+        self.floating
+            .iter_mut()
+            .map(|f| &mut f.group)
+            .find(|g| g.contains(panel))
+    }
+
+    /// Add a panel to the layout, if it isn't already present. The panel becomes a tab in the
+    /// first docked group.
+    pub fn add_panel(&mut self, panel: impl Into<String>) {
+        let panel = panel.into();
+        if self.contains(&panel) {
+            return;
+        }
+        match self.root {
+            Some(ref mut root) => root.first_group_mut().push(panel),
+            None => self.root = Some(DockNode::group([panel])),
+        }
+    }
+
+    /// Remove a panel from the layout, along with any groups and splits left empty.
+    pub fn remove_panel(&mut self, panel: &str) {
+        self.root = self.root.take().and_then(|root| root.without(panel));
+        for floating in self.floating.iter_mut() {
+            floating.group.remove(panel);
+        }
+        self.floating.retain(|f| !f.group.panels.is_empty());
+    }
+
+    /// Move a panel relative to the group containing `target`: either into that group as a tab,
+    /// or beside it by splitting the group. Floating groups can't be split, so the panel joins
+    /// them as a tab. If `target` is `None`, the panel is placed along an edge of the whole
+    /// docked layout.
+    pub fn dock_panel(&mut self, panel: &str, target: Option<&str>, zone: DockZone) {
+        if let Some(target) = target {
+            if target == panel || !self.contains(target) {
+                return;
+            }
+            if zone == DockZone::Center && self.group(target).is_some_and(|g| g.contains(panel)) {
+                return;
+            }
+        }
+        self.remove_panel(panel);
+        match (target, zone.split()) {
+            (Some(target), split) => {
+                if let Some(floating) = self.floating.iter_mut().find(|f| f.group.contains(target))
+                {
+                    floating.group.push(panel.to_string());
+                } else if split.is_some() {
+                    self.root = self
+                        .root
+                        .take()
+                        .map(|root| root.split_group(target, panel, zone));
+                } else if let Some(group) = self.group_mut(target) {
+                    group.push(panel.to_string());
+                }
+            }
+            (None, None) => self.add_panel(panel),
+            (None, Some((direction, before))) => {
+                let new = DockNode::group([panel]);
+                self.root = Some(match self.root.take() {
+                    None => new,
+                    Some(root) if before => DockNode::split(direction, 0.25, new, root),
+                    Some(root) => DockNode::split(direction, 0.75, root, new),
+                });
+            }
+        }
+    }
+
+    /// Move a panel into a new floating window.
+    pub fn float_panel(&mut self, panel: &str, rect: Rect) {
+        self.remove_panel(panel);
+        self.floating.push(FloatingGroup {
+            group: DockGroup::new([panel]),
+            x: rect.min.x,
+            y: rect.min.y,
+            width: rect.width(),
+            height: rect.height(),
+        });
+    }
+
+    /// Make a panel the visible tab in its group, expanding the group if it was collapsed.
+    pub fn select_panel(&mut self, panel: &str) {
+        if let Some(group) = self.group_mut(panel) {
+            group.selected = group.panels.iter().position(|p| p == panel).unwrap();
+            group.collapsed = false;
+        }
+    }
+
+    /// Collapse or expand the group containing a panel.
+    pub fn set_collapsed(&mut self, panel: &str, collapsed: bool) {
+        if let Some(group) = self.group_mut(panel) {
+            group.collapsed = collapsed;
+        }
+    }
+
+    /// Bring the floating window containing a panel to the front.
+    pub fn raise_floating(&mut self, panel: &str) {
+        if let Some(index) = self.floating.iter().position(|f| f.group.contains(panel)) {
+            let floating = self.floating.remove(index);
+            self.floating.push(floating);
+        }
+    }
+
+    /// Set the ratio of the split at `path`, where each element of the path is 0 for the first
+    /// child of a split or 1 for the second.
+    pub fn set_ratio(&mut self, path: &[usize], ratio: f32) {
+        if let Some(DockNode::Split { ratio: r, .. }) =
+            self.root.as_mut().and_then(|root| root.node_mut(path))
+        {
+            *r = ratio.clamp(MIN_RATIO, 1. - MIN_RATIO);
+        }
+    }
+
+    /// Serialize the layout in RON format.
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default())
+    }
+
+    /// Deserialize a layout from RON format.
+    pub fn from_ron(text: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::de::from_str(text)
+    }
+}
+
+/// A panel which can be placed in a [`DockSpace`].
+#[derive(Clone, PartialEq)]
+pub struct DockPanel {
+    /// Unique id of the panel, used to refer to it in the [`DockLayout`].
+    pub id: String,
+
+    /// Title displayed in the panel's tab.
+    pub title: String,
+
+    /// Content of the panel.
+    pub content: ViewChild,
+
+    /// Whether the panel's tab shows a close button.
+    pub closable: bool,
+}
+
+impl DockPanel {
+    /// Create a new panel.
+    pub fn new(
+        id: impl Into<String>,
+        title: impl Into<String>,
+        content: impl IntoViewChild,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            title: title.into(),
+            content: content.into_view_child(),
+            closable: false,
+        }
+    }
+
+    /// Set whether the panel's tab shows a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// Component used to hold the dock params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct DockSpaceState {
+    layout: DockLayout,
+    on_change: Option<Callback<DockLayout>>,
+}
+
+/// A panel tab being dragged.
+#[derive(Clone, PartialEq)]
+struct DockDrag {
+    panel: String,
+    /// Pointer position, relative to the dock space.
+    position: Vec2,
+    /// Group and zone the panel would be dropped into.
+    drop: Option<(String, DockZone)>,
+}
+
+/// Marks a group element as a drop target; the group is identified by its first panel.
+#[derive(Component, Clone)]
+struct DockGroupTarget {
+    dock: Entity,
+    anchor: String,
+    /// The panels of the group, in tab order.
+    panels: Vec<String>,
+    /// Stacking order of a floating group, or `None` for a docked group.
+    floating: Option<usize>,
+}
+
+/// Identifies a split entity within the layout.
+#[derive(Component, Clone)]
+struct DockSplitPath {
+    path: Vec<usize>,
+    direction: DockDirection,
+}
+
+/// A workspace of panels which can be rearranged by the user.
+///
+/// Panels are docked into a tree of splits, with each leaf being a group of tabbed panels.
+/// Dragging a tab onto another group adds it to that group, or when dropped near an edge of the
+/// group, splits the group. Dropping a tab elsewhere moves the panel into a floating window,
+/// which can be moved by its header and resized by its lower-right corner. Docked groups can be
+/// collapsed down to their tabs.
+///
+/// The dock space is controlled: the owner passes in the `layout` and updates it in response to
+/// `on_change`. Panels which are not in the layout are not shown.
+#[derive(Clone, PartialEq, Default)]
+pub struct DockSpace {
+    /// The panels which can be shown.
+    pub panels: Vec<DockPanel>,
+
+    /// The arrangement of the panels.
+    pub layout: DockLayout,
+
+    /// Additional styles to be applied to the dock space.
+    pub style: StyleHandle,
+
+    /// Callback called when the user rearranges the panels, with the new layout.
+    pub on_change: Option<Callback<DockLayout>>,
+}
+
+impl DockSpace {
+    /// Create a new dock space.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a panel.
+    pub fn panel(mut self, panel: DockPanel) -> Self {
+        self.panels.push(panel);
+        self
+    }
+
+    /// Set the panels which can be shown.
+    pub fn panels(mut self, panels: impl IntoIterator<Item = DockPanel>) -> Self {
+        self.panels = panels.into_iter().collect();
+        self
+    }
+
+    /// Set the arrangement of the panels.
+    pub fn layout(mut self, layout: DockLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Set additional styles to be applied to the dock space.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the layout changes.
+    pub fn on_change(mut self, on_change: Callback<DockLayout>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl ViewTemplate for DockSpace {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let drag = cx.create_mutable::<Option<DockDrag>>(None);
+        // Show where the panel will float if it is dropped outside of any group.
+        let ghost = drag
+            .get_clone(cx)
+            .filter(|drag| drag.drop.is_none())
+            .map(|drag| drag.position);
+        let panels = self.panels.clone();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DockSpace")
+            .style((
+                typography::text_default,
+                style_dock_space,
+                self.style.clone(),
+            ))
+            .insert_dyn(
+                |(layout, on_change)| DockSpaceState { layout, on_change },
+                (self.layout.clone(), self.on_change),
+            )
+            .children((
+                self.layout.root.clone().map(|node| DockNodeView {
+                    dock: id,
+                    path: Vec::new(),
+                    node,
+                    panels: self.panels.clone(),
+                    drag,
+                }),
+                For::index(&self.layout.floating, move |floating, index| {
+                    FloatingGroupView {
+                        dock: id,
+                        index,
+                        floating: floating.clone(),
+                        panels: panels.clone(),
+                        drag,
+                    }
+                }),
+                Cond::new(
+                    ghost.is_some(),
+                    Element::<NodeBundle>::new()
+                        .named("DockSpace::Ghost")
+                        .style(style_drag_ghost)
+                        .style_dyn(
+                            |position, sb| {
+                                sb.left(position.x).top(position.y);
+                            },
+                            ghost.unwrap_or_default(),
+                        ),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// Apply a change to the layout, and pass the result to the dock's `on_change` callback.
+fn update_layout(world: &mut World, dock: Entity, f: impl FnOnce(&mut DockLayout)) {
+    let state = world.get::<DockSpaceState>(dock).unwrap().clone();
+    let mut layout = state.layout.clone();
+    f(&mut layout);
+    if layout != state.layout {
+        if let Some(on_change) = state.on_change {
+            world.run_callback(on_change, layout);
+        }
+    }
+}
+
+/// Return the panel at `index` in the tab order of the group element `group`.
+fn group_panel(world: &World, group: Entity, index: usize) -> Option<String> {
+    world
+        .get::<DockGroupTarget>(group)?
+        .panels
+        .get(index)
+        .cloned()
+}
+
+/// Return the bounds of a UI node in window coordinates.
+fn node_rect(world: &World, entity: Entity) -> Option<Rect> {
+    let ent = world.entity(entity);
+    let node = ent.get::<Node>()?;
+    let transform = ent.get::<GlobalTransform>()?;
+    Some(node.logical_rect(transform))
+}
+
+/// Find the group and zone under the pointer. Floating groups take precedence over docked
+/// groups, and later floating groups over earlier ones.
+fn find_drop(world: &mut World, dock: Entity, position: Vec2) -> Option<(String, DockZone)> {
+    let mut query = world.query::<(&DockGroupTarget, &Node, &GlobalTransform)>();
+    let mut best: Option<(Option<usize>, String, DockZone)> = None;
+    for (target, node, transform) in query.iter(world) {
+        if target.dock != dock {
+            continue;
+        }
+        let rect = node.logical_rect(transform);
+        if !rect.contains(position) {
+            continue;
+        }
+        if best
+            .as_ref()
+            .is_some_and(|(order, _, _)| *order >= target.floating)
+        {
+            continue;
+        }
+        let zone = if target.floating.is_some() {
+            DockZone::Center
+        } else {
+            zone_at(rect, position)
+        };
+        best = Some((target.floating, target.anchor.clone(), zone));
+    }
+    best.map(|(_, anchor, zone)| (anchor, zone))
+}
+
+/// Determine the drop zone for a position within a docked group. Dropping onto the tabs always
+/// adds to the group.
+fn zone_at(rect: Rect, position: Vec2) -> DockZone {
+    if position.y - rect.min.y < HEADER_HEIGHT {
+        return DockZone::Center;
+    }
+    let rel = (position - rect.min) / rect.size();
+    [
+        (rel.x, DockZone::Left),
+        (1. - rel.x, DockZone::Right),
+        (rel.y, DockZone::Top),
+        (1. - rel.y, DockZone::Bottom),
+    ]
+    .into_iter()
+    .filter(|(distance, _)| *distance < EDGE_ZONE)
+    .min_by(|a, b| a.0.total_cmp(&b.0))
+    .map_or(DockZone::Center, |(_, zone)| zone)
+}
+
+/// View of a node of the docked layout tree.
+#[derive(Clone, PartialEq)]
+struct DockNodeView {
+    dock: Entity,
+    path: Vec<usize>,
+    node: DockNode,
+    panels: Vec<DockPanel>,
+    drag: Mutable<Option<DockDrag>>,
+}
+
+impl ViewTemplate for DockNodeView {
+    type View = ViewChild;
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        match &self.node {
+            DockNode::Group(group) => DockGroupView {
+                dock: self.dock,
+                group: group.clone(),
+                panels: self.panels.clone(),
+                floating: None,
+                drag: self.drag,
+            }
+            .into_view_child(),
+            DockNode::Split {
+                direction,
+                ratio,
+                first,
+                second,
+            } => DockSplitView {
+                dock: self.dock,
+                path: self.path.clone(),
+                direction: *direction,
+                ratio: *ratio,
+                first: first.as_ref().clone(),
+                second: second.as_ref().clone(),
+                panels: self.panels.clone(),
+                drag: self.drag,
+            }
+            .into_view_child(),
+        }
+    }
+}
+
+/// View of a split between two nodes.
+#[derive(Clone, PartialEq)]
+struct DockSplitView {
+    dock: Entity,
+    path: Vec<usize>,
+    direction: DockDirection,
+    ratio: f32,
+    first: DockNode,
+    second: DockNode,
+    panels: Vec<DockPanel>,
+    drag: Mutable<Option<DockDrag>>,
+}
+
+impl ViewTemplate for DockSplitView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let dock = self.dock;
+        let size = cx.use_element_size(id);
+        let direction = self.direction;
+        let first_collapsed = self.first.is_collapsed();
+        let second_collapsed = self.second.is_collapsed();
+        // The splitter measures a vertical bar from the left, and a horizontal bar from the
+        // bottom.
+        let splitter_value = match direction {
+            DockDirection::Row => self.ratio * size.x,
+            DockDirection::Column => (1. - self.ratio) * size.y,
+        };
+
+        let on_split = cx.create_callback(move |value: In<f32>, world: &mut World| {
+            let Some(rect) = node_rect(world, id) else {
+                return;
+            };
+            let split = world.get::<DockSplitPath>(id).unwrap().clone();
+            let ratio = match split.direction {
+                DockDirection::Row if rect.width() > 0. => *value / rect.width(),
+                DockDirection::Column if rect.height() > 0. => 1. - *value / rect.height(),
+                _ => return,
+            };
+            update_layout(world, dock, |layout| layout.set_ratio(&split.path, ratio));
+        });
+
+        let mut first_path = self.path.clone();
+        first_path.push(0);
+        let mut second_path = self.path.clone();
+        second_path.push(1);
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DockSplit")
+            .style(style_dock_split)
+            .style_dyn(
+                |direction, sb| {
+                    sb.flex_direction(match direction {
+                        DockDirection::Row => ui::FlexDirection::Row,
+                        DockDirection::Column => ui::FlexDirection::Column,
+                    });
+                },
+                direction,
+            )
+            .insert_dyn(
+                |(path, direction)| DockSplitPath { path, direction },
+                (self.path.clone(), direction),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(style_dock_split_pane)
+                    .style_dyn(
+                        |(ratio, first_collapsed, second_collapsed), sb| {
+                            if first_collapsed {
+                                sb.flex_grow(0.).flex_basis(ui::Val::Auto);
+                            } else if second_collapsed {
+                                sb.flex_grow(1.).flex_basis(ui::Val::Auto);
+                            } else {
+                                sb.flex_grow(0.)
+                                    .flex_shrink(1.)
+                                    .flex_basis(ui::Val::Percent(ratio * 100.));
+                            }
+                        },
+                        (self.ratio, first_collapsed, second_collapsed),
+                    )
+                    .children(DockNodeView {
+                        dock,
+                        path: first_path,
+                        node: self.first.clone(),
+                        panels: self.panels.clone(),
+                        drag: self.drag,
+                    }),
+                Cond::new(
+                    !first_collapsed && !second_collapsed,
+                    Splitter::new()
+                        .direction(match direction {
+                            DockDirection::Row => SplitterDirection::Vertical,
+                            DockDirection::Column => SplitterDirection::Horizontal,
+                        })
+                        .value(splitter_value)
+                        .on_change(on_split),
+                    (),
+                ),
+                Element::<NodeBundle>::new()
+                    .style(style_dock_split_pane)
+                    .style_dyn(
+                        |second_collapsed, sb| {
+                            sb.flex_grow(if second_collapsed { 0. } else { 1. })
+                                .flex_basis(ui::Val::Auto);
+                        },
+                        second_collapsed,
+                    )
+                    .children(DockNodeView {
+                        dock,
+                        path: second_path,
+                        node: self.second.clone(),
+                        panels: self.panels.clone(),
+                        drag: self.drag,
+                    }),
+            ))
+    }
+}
+
+/// View of a floating window.
+#[derive(Clone, PartialEq)]
+struct FloatingGroupView {
+    dock: Entity,
+    index: usize,
+    floating: FloatingGroup,
+    panels: Vec<DockPanel>,
+    drag: Mutable<Option<DockDrag>>,
+}
+
+impl ViewTemplate for FloatingGroupView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let dock = self.dock;
+        let anchor = self
+            .floating
+            .group
+            .panels
+            .first()
+            .cloned()
+            .unwrap_or_default();
+        // Size of the window when the resize drag started.
+        let resize_start = cx.create_mutable::<Option<Vec2>>(None);
+
+        Element::<NodeBundle>::new()
+            .named("DockSpace::Floating")
+            .style(style_floating_group)
+            .style_dyn(
+                |rect, sb| {
+                    sb.left(rect.min.x)
+                        .top(rect.min.y)
+                        .width(rect.width())
+                        .height(rect.height());
+                },
+                self.floating.rect(),
+            )
+            .insert_dyn(
+                move |anchor| {
+                    On::<Pointer<Down>>::run(move |world: &mut World| {
+                        update_layout(world, dock, |layout| layout.raise_floating(&anchor));
+                    })
+                },
+                anchor.clone(),
+            )
+            .children((
+                DockGroupView {
+                    dock,
+                    group: self.floating.group.clone(),
+                    panels: self.panels.clone(),
+                    floating: Some(self.index),
+                    drag: self.drag,
+                },
+                Element::<NodeBundle>::new()
+                    .named("DockSpace::ResizeGrip")
+                    .style(style_resize_grip)
+                    .insert_dyn(
+                        move |anchor| {
+                            let drag_anchor = anchor.clone();
+                            (
+                                On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                    world
+                                        .resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                        .stop_propagation();
+                                    let state = world.get::<DockSpaceState>(dock).unwrap();
+                                    let size = state
+                                        .layout
+                                        .floating
+                                        .iter()
+                                        .find(|f| f.group.contains(&anchor))
+                                        .map(|f| f.rect().size());
+                                    resize_start.set(world, size);
+                                }),
+                                On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                                    resize_start.set(world, None);
+                                }),
+                                On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                    let event = world.resource::<ListenerInput<Pointer<Drag>>>();
+                                    let distance = event.distance;
+                                    let Some(start) = resize_start.get(world) else {
+                                        return;
+                                    };
+                                    let size = (start + distance).max(FLOATING_MIN_SIZE);
+                                    update_layout(world, dock, |layout| {
+                                        if let Some(floating) = layout
+                                            .floating
+                                            .iter_mut()
+                                            .find(|f| f.group.contains(&drag_anchor))
+                                        {
+                                            floating.width = size.x;
+                                            floating.height = size.y;
+                                        }
+                                    });
+                                }),
+                            )
+                        },
+                        anchor,
+                    ),
+            ))
+    }
+}
+
+/// View of a group of tabbed panels.
+#[derive(Clone, PartialEq)]
+struct DockGroupView {
+    dock: Entity,
+    group: DockGroup,
+    panels: Vec<DockPanel>,
+    /// Stacking order if this is a floating group.
+    floating: Option<usize>,
+    drag: Mutable<Option<DockDrag>>,
+}
+
+impl ViewTemplate for DockGroupView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let dock = self.dock;
+        let drag = self.drag;
+        let floating = self.floating;
+        let anchor = self.group.panels.first().cloned().unwrap_or_default();
+        // Position of the floating window when the move drag started.
+        let move_start = cx.create_mutable::<Option<Vec2>>(None);
+        let drop_zone = drag
+            .get_clone(cx)
+            .and_then(|drag| drag.drop)
+            .filter(|(target, _)| *target == anchor)
+            .map(|(_, zone)| zone);
+
+        let tabs: Vec<Tab> = self
+            .group
+            .panels
+            .iter()
+            .map(|panel| {
+                let info = self.panels.iter().find(|p| p.id == *panel);
+                Tab::new(info.map_or_else(|| panel.clone(), |p| p.title.clone()))
+                    .closable(info.is_some_and(|p| p.closable))
+            })
+            .collect();
+        let pages: Vec<ViewChild> = self
+            .group
+            .panels
+            .iter()
+            .map(|panel| {
+                self.panels
+                    .iter()
+                    .find(|p| p.id == *panel)
+                    .map_or_else(ViewChild::default, |p| p.content.clone())
+            })
+            .collect();
+
+        let on_select = cx.create_callback(move |index: In<usize>, world: &mut World| {
+            if let Some(panel) = group_panel(world, id, *index) {
+                update_layout(world, dock, |layout| layout.select_panel(&panel));
+            }
+        });
+        let on_close = cx.create_callback(move |index: In<usize>, world: &mut World| {
+            if let Some(panel) = group_panel(world, id, *index) {
+                update_layout(world, dock, |layout| layout.remove_panel(&panel));
+            }
+        });
+        let on_drag = cx.create_callback(move |event: In<TabDrag>, world: &mut World| {
+            let origin = node_rect(world, dock).map_or(Vec2::ZERO, |r| r.min);
+            match *event {
+                TabDrag::Start(index, position) => {
+                    let Some(panel) = group_panel(world, id, index) else {
+                        return;
+                    };
+                    drag.set_clone(
+                        world,
+                        Some(DockDrag {
+                            panel,
+                            position: position - origin,
+                            drop: None,
+                        }),
+                    );
+                }
+                TabDrag::Move(_, position) => {
+                    let Some(mut state) = drag.get_clone(world) else {
+                        return;
+                    };
+                    state.position = position - origin;
+                    state.drop = find_drop(world, dock, position);
+                    drag.set_clone(world, Some(state));
+                }
+                TabDrag::End(_) => {
+                    let Some(state) = drag.get_clone(world) else {
+                        return;
+                    };
+                    drag.set_clone(world, None);
+                    let bounds = node_rect(world, dock).unwrap_or_default();
+                    update_layout(world, dock, |layout| match state.drop {
+                        Some((target, zone)) => {
+                            layout.dock_panel(&state.panel, Some(&target), zone);
+                        }
+                        None => {
+                            // Keep the new window within the dock space.
+                            let max = (bounds.size() - FLOATING_SIZE).max(Vec2::ZERO);
+                            let min = state.position.clamp(Vec2::ZERO, max);
+                            layout.float_panel(
+                                &state.panel,
+                                Rect::from_corners(min, min + FLOATING_SIZE),
+                            );
+                        }
+                    });
+                }
+            }
+        });
+
+        let on_collapse = cx.create_callback(move |expanded: In<bool>, world: &mut World| {
+            let anchor = world.get::<DockGroupTarget>(id).unwrap().anchor.clone();
+            update_layout(world, dock, |layout| {
+                layout.set_collapsed(&anchor, !*expanded);
+            });
+        });
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("DockGroup")
+            .style(style_dock_group)
+            .insert_dyn(
+                move |(anchor, panels, floating)| DockGroupTarget {
+                    dock,
+                    anchor,
+                    panels,
+                    floating,
+                },
+                (anchor.clone(), self.group.panels.clone(), floating),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("DockGroup::Header")
+                    .style(style_dock_header)
+                    .insert_dyn(
+                        move |(anchor, floating)| {
+                            let drag_anchor = anchor.clone();
+                            (
+                                On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                    if floating.is_none() {
+                                        return;
+                                    }
+                                    world
+                                        .resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                        .stop_propagation();
+                                    let state = world.get::<DockSpaceState>(dock).unwrap();
+                                    let position = state
+                                        .layout
+                                        .floating
+                                        .iter()
+                                        .find(|f| f.group.contains(&anchor))
+                                        .map(|f| f.rect().min);
+                                    move_start.set(world, position);
+                                }),
+                                On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                                    move_start.set(world, None);
+                                }),
+                                On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                    let event = world.resource::<ListenerInput<Pointer<Drag>>>();
+                                    let distance = event.distance;
+                                    let Some(start) = move_start.get(world) else {
+                                        return;
+                                    };
+                                    let position = (start + distance).max(Vec2::ZERO);
+                                    update_layout(world, dock, |layout| {
+                                        if let Some(floating) = layout
+                                            .floating
+                                            .iter_mut()
+                                            .find(|f| f.group.contains(&drag_anchor))
+                                        {
+                                            floating.x = position.x;
+                                            floating.y = position.y;
+                                        }
+                                    });
+                                }),
+                            )
+                        },
+                        (anchor, floating),
+                    )
+                    .children((
+                        TabBar::new()
+                            .tabs(tabs)
+                            .selected(self.group.selected)
+                            .style(style_dock_tabs)
+                            .on_select(on_select)
+                            .on_close(on_close)
+                            .on_drag(on_drag),
+                        Cond::new(
+                            floating.is_none(),
+                            DisclosureToggle::new()
+                                .size(Size::Xs)
+                                .tab_index(-1)
+                                .expanded(!self.group.collapsed)
+                                .style(style_dock_toggle)
+                                .on_change(on_collapse),
+                            (),
+                        ),
+                    )),
+                Cond::new(
+                    !self.group.collapsed,
+                    TabPanel::new()
+                        .pages(pages)
+                        .selected(self.group.selected)
+                        .style(style_dock_content),
+                    (),
+                ),
+                Cond::new(
+                    drop_zone.is_some(),
+                    Element::<NodeBundle>::new()
+                        .named("DockGroup::DropZone")
+                        .style(style_drop_zone)
+                        .style_dyn(
+                            |zone, sb| {
+                                let (left, top, width, height) = match zone {
+                                    DockZone::Center => (0., 0., 100., 100.),
+                                    DockZone::Left => (0., 0., 50., 100.),
+                                    DockZone::Right => (50., 0., 50., 100.),
+                                    DockZone::Top => (0., 0., 100., 50.),
+                                    DockZone::Bottom => (0., 50., 100., 50.),
+                                };
+                                sb.left(ui::Val::Percent(left))
+                                    .top(ui::Val::Percent(top))
+                                    .width(ui::Val::Percent(width))
+                                    .height(ui::Val::Percent(height));
+                            },
+                            drop_zone.unwrap_or_default(),
+                        ),
+                    (),
+                ),
+            ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{DockDirection, DockGroup, DockLayout, DockNode, DockZone};
+
+    /// `[a, b]` on the left, with `[c]` stacked above `[d]` on the right.
+    fn layout() -> DockLayout {
+        DockLayout::new(DockNode::split(
+            DockDirection::Row,
+            0.3,
+            DockNode::group(["a", "b"]),
+            DockNode::split(
+                DockDirection::Column,
+                0.6,
+                DockNode::group(["c"]),
+                DockNode::group(["d"]),
+            ),
+        ))
+    }
+
+    #[test]
+    fn test_without() {
+        let root = layout().root.unwrap();
+        assert_eq!(
+            root.clone().without("c"),
+            Some(DockNode::split(
+                DockDirection::Row,
+                0.3,
+                DockNode::group(["a", "b"]),
+                DockNode::group(["d"]),
+            ))
+        );
+        assert_eq!(
+            root.clone().without("a"),
+            Some(DockNode::split(
+                DockDirection::Row,
+                0.3,
+                DockNode::group(["b"]),
+                DockNode::split(
+                    DockDirection::Column,
+                    0.6,
+                    DockNode::group(["c"]),
+                    DockNode::group(["d"]),
+                ),
+            ))
+        );
+        assert_eq!(root.clone().without("missing"), Some(root));
+        assert_eq!(DockNode::group(["a"]).without("a"), None);
+    }
+
+    #[test]
+    fn test_group_remove_keeps_selection() {
+        let mut group = DockGroup::new(["a", "b", "c"]);
+        group.selected = 2;
+        group.remove("a");
+        assert_eq!(group.panels, ["b", "c"]);
+        assert_eq!(group.selected, 1);
+        group.remove("c");
+        assert_eq!(group.selected, 0);
+        group.remove("missing");
+        assert_eq!(group.panels, ["b"]);
+    }
+
+    #[test]
+    fn test_remove_panel() {
+        let mut layout = layout();
+        for panel in ["a", "b", "c"] {
+            layout.remove_panel(panel);
+        }
+        assert_eq!(layout.root, Some(DockNode::group(["d"])));
+        layout.remove_panel("d");
+        assert_eq!(layout.root, None);
+        assert!(!layout.contains("d"));
+    }
+
+    #[test]
+    fn test_add_panel() {
+        let mut layout = DockLayout::default();
+        layout.add_panel("a");
+        assert_eq!(layout.root, Some(DockNode::group(["a"])));
+        layout.add_panel("b");
+        layout.add_panel("a");
+        assert_eq!(layout.group("a").unwrap().panels, ["a", "b"]);
+        assert_eq!(layout.group("b").unwrap().selected, 1);
+    }
+
+    #[test]
+    fn test_dock_center() {
+        let mut layout = layout();
+        layout.dock_panel("c", Some("a"), DockZone::Center);
+        let group = layout.group("c").unwrap();
+        assert_eq!(group.panels, ["a", "b", "c"]);
+        assert_eq!(group.selected, 2);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                DockDirection::Row,
+                0.3,
+                DockNode::Group(group.clone()),
+                DockNode::group(["d"]),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_dock_split() {
+        let mut layout = layout();
+        layout.dock_panel("d", Some("a"), DockZone::Left);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                DockDirection::Row,
+                0.3,
+                DockNode::split(
+                    DockDirection::Row,
+                    0.5,
+                    DockNode::group(["d"]),
+                    DockNode::group(["a", "b"]),
+                ),
+                DockNode::group(["c"]),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_dock_edge() {
+        let mut layout = layout();
+        layout.dock_panel("a", None, DockZone::Bottom);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                DockDirection::Column,
+                0.75,
+                DockNode::split(
+                    DockDirection::Row,
+                    0.3,
+                    DockNode::group(["b"]),
+                    DockNode::split(
+                        DockDirection::Column,
+                        0.6,
+                        DockNode::group(["c"]),
+                        DockNode::group(["d"]),
+                    ),
+                ),
+                DockNode::group(["a"]),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_dock_ignored() {
+        let mut layout = layout();
+        layout.dock_panel("a", Some("a"), DockZone::Left);
+        layout.dock_panel("a", Some("missing"), DockZone::Center);
+        layout.dock_panel("b", Some("a"), DockZone::Center);
+        assert_eq!(layout, self::layout());
+    }
+
+    #[test]
+    fn test_floating() {
+        let mut layout = layout();
+        layout.float_panel("c", Rect::new(10., 20., 110., 220.));
+        assert_eq!(layout.floating.len(), 1);
+        assert_eq!(layout.floating[0].rect(), Rect::new(10., 20., 110., 220.));
+        assert_eq!(layout.group("c").unwrap().panels, ["c"]);
+
+        // Floating groups can't be split, so the panel becomes a tab instead.
+        layout.dock_panel("a", Some("c"), DockZone::Right);
+        assert_eq!(layout.floating[0].group.panels, ["c", "a"]);
+        assert_eq!(
+            layout.root,
+            Some(DockNode::split(
+                DockDirection::Row,
+                0.3,
+                DockNode::group(["b"]),
+                DockNode::group(["d"]),
+            ))
+        );
+
+        layout.remove_panel("c");
+        layout.remove_panel("a");
+        assert!(layout.floating.is_empty());
+    }
+
+    #[test]
+    fn test_set_ratio() {
+        let mut layout = layout();
+        layout.set_ratio(&[1], 2.);
+        layout.set_ratio(&[], -1.);
+        layout.set_ratio(&[0], 0.5);
+        layout.set_ratio(&[1, 1, 1], 0.5);
+        let Some(DockNode::Split { ratio, second, .. }) = layout.root else {
+            panic!("expected a split");
+        };
+        assert_eq!(ratio, 0.05);
+        assert!(matches!(*second, DockNode::Split { ratio, .. } if ratio == 0.95));
+    }
+
+    #[test]
+    fn test_ron_round_trip() {
+        let mut layout = layout();
+        layout.set_collapsed("d", true);
+        layout.float_panel("b", Rect::new(10., 20., 110., 220.));
+        let text = layout.to_ron().unwrap();
+        assert_eq!(DockLayout::from_ron(&text).unwrap(), layout);
+        assert_eq!(
+            DockLayout::from_ron(&DockLayout::default().to_ron().unwrap()).unwrap(),
+            DockLayout::default()
+        );
+        assert!(DockLayout::from_ron("(root: Some(Split(()))").is_err());
+        assert!(DockLayout::from_ron("").is_err());
+    }
+}
@@ -0,0 +1,68 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    drag_drop::{DragDrop, DragPosition},
+    tokens, typography,
+};
+
+/// Offset of the preview from the pointer. The preview is kept clear of the pointer, so that it
+/// doesn't hide the drop targets underneath.
+const PREVIEW_OFFSET: Vec2 = Vec2::new(12., 12.);
+
+fn style_drag_preview(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .padding((6, 2))
+        .border_radius(3)
+        .background_color(tokens::U3.with_alpha(0.8))
+        .color(tokens::FOREGROUND)
+        .z_index(250)
+        .pointer_events(false);
+}
+
+/// Displays the preview of the drag operation in progress, following the pointer. Place one of
+/// these at the top level of the UI when using [`draggable`](crate::drag_drop::draggable).
+#[derive(Clone, PartialEq, Default)]
+pub struct DragPreviewOverlay;
+
+impl ViewTemplate for DragPreviewOverlay {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let preview = cx.use_resource::<DragDrop>().preview().cloned();
+        Cond::new(
+            preview.is_some(),
+            Portal::new(DragPreview {
+                content: preview.unwrap_or_default(),
+            }),
+            (),
+        )
+    }
+}
+
+/// The preview itself; separate from the overlay so that only this is rebuilt when the pointer
+/// moves.
+#[derive(Clone, PartialEq)]
+struct DragPreview {
+    content: ViewChild,
+}
+
+impl ViewTemplate for DragPreview {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let position = cx.use_resource::<DragPosition>().0 + PREVIEW_OFFSET;
+        Element::<NodeBundle>::new()
+            .named("DragPreview")
+            .style((typography::text_default, style_drag_preview))
+            .style_dyn(
+                |position, sb| {
+                    sb.left(position.x).top(position.y);
+                },
+                position,
+            )
+            .children(self.content.clone())
+    }
+}
@@ -0,0 +1,103 @@
+use std::sync::{Arc, Mutex};
+
+use bevy::{
+    color::{LinearRgba, Srgba},
+    prelude::*,
+    render::{
+        camera::NormalizedRenderTarget, render_resource::TextureFormat,
+        view::screenshot::ScreenshotManager,
+    },
+};
+use bevy_mod_picking::pointer::Location;
+use bevy_quill_core::*;
+
+/// A sample which is waiting on a window screenshot.
+struct PendingSample {
+    image: Arc<Mutex<Option<Image>>>,
+    position: UVec2,
+    on_pick: Callback<Srgba>,
+}
+
+/// Resource which tracks the eyedropper sample in progress, if any.
+#[derive(Resource, Default)]
+pub(crate) struct Eyedropper(Option<PendingSample>);
+
+/// Sample the color of the pixel under a pointer location, and pass it to `on_pick`.
+///
+/// For an image render target, the pixel is read from the image asset directly; note that this
+/// only sees the CPU-side copy of the image. For a window, a screenshot is requested, and the
+/// callback is run once it arrives, usually a frame or two later.
+pub(crate) fn sample_pixel(world: &mut World, location: &Location, on_pick: Callback<Srgba>) {
+    match &location.target {
+        NormalizedRenderTarget::Image(handle) => {
+            let position = location.position.as_uvec2();
+            let images = world.resource::<Assets<Image>>();
+            if let Some(color) = images
+                .get(handle)
+                .and_then(|image| image_pixel(image, position))
+            {
+                world.run_callback(on_pick, color);
+            }
+        }
+        NormalizedRenderTarget::Window(window_ref) => {
+            let window = window_ref.entity();
+            let Some(scale) = world.get::<Window>(window).map(|w| w.scale_factor()) else {
+                return;
+            };
+            let image = Arc::new(Mutex::new(None));
+            let result = image.clone();
+            let mut screenshots = world.resource_mut::<ScreenshotManager>();
+            if screenshots
+                .take_screenshot(window, move |img| {
+                    *result.lock().unwrap() = Some(img);
+                })
+                .is_ok()
+            {
+                world.resource_mut::<Eyedropper>().0 = Some(PendingSample {
+                    image,
+                    position: (location.position * scale).as_uvec2(),
+                    on_pick,
+                });
+            }
+        }
+        NormalizedRenderTarget::TextureView(_) => {}
+    }
+}
+
+/// System which delivers the eyedropper color once the screenshot has been captured.
+pub(crate) fn update_eyedropper(world: &mut World) {
+    let image = match world.resource::<Eyedropper>().0 {
+        Some(ref pending) => pending.image.lock().unwrap().take(),
+        None => return,
+    };
+    if let Some(image) = image {
+        let pending = world.resource_mut::<Eyedropper>().0.take().unwrap();
+        if let Some(color) = image_pixel(&image, pending.position) {
+            world.run_callback(pending.on_pick, color);
+        }
+    }
+}
+
+/// Read a single pixel from an 8-bit RGBA or BGRA image. The result is always opaque.
+fn image_pixel(image: &Image, position: UVec2) -> Option<Srgba> {
+    let size = image.size();
+    if position.x >= size.x || position.y >= size.y {
+        return None;
+    }
+    let offset = ((position.y * size.x + position.x) * 4) as usize;
+    let [a, b, c, _] = <[u8; 4]>::try_from(image.data.get(offset..offset + 4)?).ok()?;
+    let linear = |r: u8, g: u8, b: u8| {
+        Srgba::from(LinearRgba::rgb(
+            r as f32 / 255.,
+            g as f32 / 255.,
+            b as f32 / 255.,
+        ))
+    };
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8UnormSrgb => Some(Srgba::rgb_u8(a, b, c)),
+        TextureFormat::Bgra8UnormSrgb => Some(Srgba::rgb_u8(c, b, a)),
+        TextureFormat::Rgba8Unorm => Some(linear(a, b, c)),
+        TextureFormat::Bgra8Unorm => Some(linear(c, b, a)),
+        _ => None,
+    }
+}
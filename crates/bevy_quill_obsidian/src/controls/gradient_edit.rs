@@ -0,0 +1,791 @@
+use bevy::{
+    a11y::Focus,
+    color::{LinearRgba, Mix, Oklaba, Srgba},
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::{KeyPressEvent, TabIndex},
+    materials::{GradientRampMaterial, GRADIENT_RAMP_SAMPLES},
+    size::Size,
+    tokens,
+};
+
+use super::{
+    Button, ColorEdit, ColorEditState, Disabled, IsDisabled, Select, SelectOption, Spacer, SpinBox,
+};
+
+/// Width of a color stop marker.
+const STOP_WIDTH: f32 = 10.;
+
+/// How colors are interpolated between the stops of a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum GradientInterpolation {
+    /// Interpolate the gamma-encoded sRGB components.
+    #[default]
+    Srgb,
+    /// Interpolate in linear RGB space.
+    LinearRgb,
+    /// Interpolate in the perceptually uniform OKLab color space.
+    Oklab,
+}
+
+impl GradientInterpolation {
+    /// Interpolate between two colors, where a `factor` of 0 is `a` and 1 is `b`.
+    pub fn mix(self, a: Srgba, b: Srgba, factor: f32) -> Srgba {
+        match self {
+            Self::Srgb => a.mix(&b, factor),
+            Self::LinearRgb => LinearRgba::from(a).mix(&LinearRgba::from(b), factor).into(),
+            Self::Oklab => Oklaba::from(a).mix(&Oklaba::from(b), factor).into(),
+        }
+    }
+}
+
+/// A single color stop in a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct GradientStop {
+    /// Position of the stop, from 0 to 1.
+    pub position: f32,
+
+    /// Color of the stop.
+    pub color: Srgba,
+}
+
+impl GradientStop {
+    /// Construct a new color stop.
+    pub fn new(position: f32, color: impl Into<Srgba>) -> Self {
+        Self {
+            position,
+            color: color.into(),
+        }
+    }
+}
+
+/// A color gradient with any number of stops at arbitrary positions. The stops are kept
+/// sorted by position.
+#[derive(Debug, Clone, PartialEq, Reflect)]
+#[reflect(Default)]
+pub struct Gradient {
+    /// Color stops, in order of position.
+    pub stops: Vec<GradientStop>,
+
+    /// How colors are interpolated between stops.
+    pub interpolation: GradientInterpolation,
+}
+
+impl Default for Gradient {
+    fn default() -> Self {
+        Self {
+            stops: vec![
+                GradientStop::new(0., Srgba::BLACK),
+                GradientStop::new(1., Srgba::WHITE),
+            ],
+            interpolation: GradientInterpolation::default(),
+        }
+    }
+}
+
+impl Gradient {
+    /// Construct a new gradient from a list of stops, which need not be sorted.
+    pub fn new(stops: impl Into<Vec<GradientStop>>) -> Self {
+        let mut stops = stops.into();
+        stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+        Self {
+            stops,
+            interpolation: GradientInterpolation::default(),
+        }
+    }
+
+    /// Set the interpolation mode.
+    pub fn with_interpolation(mut self, interpolation: GradientInterpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Return the color of the gradient at position `t`. Positions before the first stop or
+    /// after the last stop take the color of that stop.
+    pub fn sample(&self, t: f32) -> Srgba {
+        match self.stops.iter().position(|stop| stop.position > t) {
+            None => self.stops.last().map_or(Srgba::NONE, |stop| stop.color),
+            Some(0) => self.stops[0].color,
+            Some(index) => {
+                let a = &self.stops[index - 1];
+                let b = &self.stops[index];
+                let factor = (t - a.position) / (b.position - a.position);
+                self.interpolation.mix(a.color, b.color, factor)
+            }
+        }
+    }
+
+    /// Add a stop at the given position, using the current color of the gradient there.
+    /// Returns the index of the new stop.
+    pub fn insert(&mut self, position: f32) -> usize {
+        let position = position.clamp(0., 1.);
+        let stop = GradientStop::new(position, self.sample(position));
+        let index = self.stops.partition_point(|s| s.position <= position);
+        self.stops.insert(index, stop);
+        index
+    }
+
+    /// Remove the stop at `index`.
+    pub fn remove(&mut self, index: usize) {
+        if index < self.stops.len() {
+            self.stops.remove(index);
+        }
+    }
+
+    /// Move the stop at `index` to a new position, keeping the stops sorted. Returns the new
+    /// index of the stop.
+    pub fn set_position(&mut self, index: usize, position: f32) -> usize {
+        let mut stop = self.stops.remove(index);
+        stop.position = position.clamp(0., 1.);
+        let index = self.stops.partition_point(|s| s.position <= stop.position);
+        self.stops.insert(index, stop);
+        index
+    }
+
+    /// Change the color of the stop at `index`.
+    pub fn set_color(&mut self, index: usize, color: Srgba) {
+        if let Some(stop) = self.stops.get_mut(index) {
+            stop.color = color;
+        }
+    }
+}
+
+/// Component used to hold the edit params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct GradientEditParams {
+    value: Gradient,
+    on_change: Option<Callback<Gradient>>,
+}
+
+#[derive(Component, Clone, Copy, Default)]
+struct StopDrag {
+    /// Index of the stop being dragged, if any.
+    index: Option<usize>,
+    /// Position of the stop when the drag started.
+    offset: f32,
+}
+
+fn style_gradient_edit(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .gap(4)
+        .min_width(240);
+}
+
+fn style_bar(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .padding((STOP_WIDTH * 0.5, 0))
+        .border_radius(3.);
+}
+
+fn style_ramp(ss: &mut StyleBuilder) {
+    ss.height(20);
+}
+
+fn style_stop_track(ss: &mut StyleBuilder) {
+    ss.height(14).margin_top(2);
+}
+
+fn style_stop(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .top(0)
+        .bottom(0)
+        .width(STOP_WIDTH)
+        .margin_left(-STOP_WIDTH * 0.5)
+        .border(1)
+        .border_radius(2.);
+}
+
+fn style_stop_controls(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .gap(4);
+}
+
+fn style_position(ss: &mut StyleBuilder) {
+    ss.width(64);
+}
+
+/// Editor for a multi-stop [`Gradient`]. Click on the gradient bar to add a stop, and drag the
+/// stop markers beneath it to move them. The selected stop can be recolored with the embedded
+/// [`ColorEdit`], or removed with the Delete key.
+#[derive(Clone, PartialEq)]
+pub struct GradientEdit {
+    /// Gradient being edited.
+    pub value: Gradient,
+
+    /// Whether the editor is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the editor.
+    pub style: StyleHandle,
+
+    /// Callback called when the gradient changes.
+    pub on_change: Option<Callback<Gradient>>,
+}
+
+impl GradientEdit {
+    /// Create a new gradient editor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the gradient being edited.
+    pub fn value(mut self, value: Gradient) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Set whether the editor is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the style handle for the editor root element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the gradient changes.
+    pub fn on_change(mut self, on_change: Callback<Gradient>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl Default for GradientEdit {
+    fn default() -> Self {
+        Self {
+            value: Gradient::default(),
+            disabled: false,
+            style: StyleHandle::default(),
+            on_change: None,
+        }
+    }
+}
+
+impl ViewTemplate for GradientEdit {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let edit_id = cx.create_entity();
+        let bar_id = cx.create_entity();
+        let disabled = self.disabled;
+        let value = self.value.clone();
+        let num_stops = value.stops.len();
+        let selected = cx.create_mutable::<usize>(0);
+        let selected_index = selected.get(cx).min(num_stops.saturating_sub(1));
+        let selected_stop = value.stops.get(selected_index).copied();
+        let focused = cx.is_focus_visible(bar_id);
+
+        let samples = cx.create_memo(
+            |_, gradient: Gradient| {
+                let mut samples = [Vec4::ZERO; GRADIENT_RAMP_SAMPLES];
+                for (i, sample) in samples.iter_mut().enumerate() {
+                    let t = i as f32 / (GRADIENT_RAMP_SAMPLES - 1) as f32;
+                    *sample = gradient.sample(t).to_vec4();
+                }
+                samples
+            },
+            value.clone(),
+        );
+
+        let material = cx.create_memo(
+            |world, _| {
+                let mut materials = world
+                    .get_resource_mut::<Assets<GradientRampMaterial>>()
+                    .unwrap();
+                materials.add(GradientRampMaterial {
+                    samples: [Vec4::ZERO; GRADIENT_RAMP_SAMPLES],
+                })
+            },
+            (),
+        );
+
+        // Effect to update the material samples.
+        cx.create_effect(
+            move |world, (material, samples)| {
+                let mut materials = world
+                    .get_resource_mut::<Assets<GradientRampMaterial>>()
+                    .unwrap();
+                let material = materials.get_mut(material.id()).unwrap();
+                material.samples = samples;
+            },
+            (material.clone(), samples),
+        );
+
+        // Color edit state for the selected stop, kept in sync with the stop color.
+        let color_state = cx.create_mutable(ColorEditState::default());
+        cx.create_effect(
+            move |world, color| {
+                let state = color_state.get(world);
+                if let Some(color) = color {
+                    if state.rgb != color {
+                        color_state.set(world, state.set_rgb(color));
+                    }
+                }
+            },
+            selected_stop.map(|stop| stop.color),
+        );
+
+        // Ensure StopDrag component exists before rendering.
+        let mut entt = cx.world_mut().entity_mut(bar_id);
+        if !entt.contains::<StopDrag>() {
+            entt.insert(StopDrag::default());
+        }
+
+        Element::<NodeBundle>::for_entity(edit_id)
+            .named("GradientEdit")
+            .style((style_gradient_edit, self.style.clone()))
+            .insert_dyn(
+                |(value, on_change)| GradientEditParams { value, on_change },
+                (value.clone(), self.on_change),
+            )
+            .children((
+                Element::<NodeBundle>::for_entity(bar_id)
+                    .named("GradientEdit::Bar")
+                    .style(style_bar)
+                    .insert_dyn(TabIndex, if disabled { -1 } else { 0 })
+                    .style_dyn(
+                        |focused, sb| {
+                            if focused {
+                                sb.outline_color(tokens::FOCUS)
+                                    .outline_offset(1.0)
+                                    .outline_width(2.0);
+                            } else {
+                                sb.outline_color(Option::<Color>::None);
+                            }
+                        },
+                        focused,
+                    )
+                    .insert_dyn(
+                        move |disabled| {
+                            (
+                                // Clicking on the bar outside of a stop marker adds a new stop.
+                                On::<Pointer<Down>>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                                        .unwrap();
+                                    event.stop_propagation();
+                                    if disabled || event.button != PointerButton::Primary {
+                                        return;
+                                    }
+                                    let hit_x = event.pointer_location.position.x;
+                                    world.get_resource_mut::<Focus>().unwrap().0 = Some(bar_id);
+                                    let Some(position) = bar_position(world, bar_id, hit_x) else {
+                                        return;
+                                    };
+                                    let mut gradient = gradient_value(world, edit_id);
+                                    let index = gradient.insert(position);
+                                    selected.set(world, index);
+                                    world.entity_mut(bar_id).insert(StopDrag {
+                                        index: Some(index),
+                                        offset: position,
+                                    });
+                                    emit_gradient(world, edit_id, gradient);
+                                }),
+                                On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                        .unwrap();
+                                    event.stop_propagation();
+                                }),
+                                On::<Pointer<DragEnd>>::listener_component_mut::<StopDrag>(
+                                    |_, drag| {
+                                        drag.index = None;
+                                    },
+                                ),
+                                On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                    let drag = *world.get::<StopDrag>(bar_id).unwrap();
+                                    let Some(index) = drag.index else {
+                                        return;
+                                    };
+                                    let event = world
+                                        .get_resource::<ListenerInput<Pointer<Drag>>>()
+                                        .unwrap();
+                                    let distance = event.distance.x;
+                                    let Some(width) = bar_width(world, bar_id) else {
+                                        return;
+                                    };
+                                    let mut gradient = gradient_value(world, edit_id);
+                                    if index >= gradient.stops.len() {
+                                        return;
+                                    }
+                                    let new_index = gradient
+                                        .set_position(index, drag.offset + distance / width);
+                                    selected.set(world, new_index);
+                                    world.get_mut::<StopDrag>(bar_id).unwrap().index =
+                                        Some(new_index);
+                                    emit_gradient(world, edit_id, gradient);
+                                }),
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    let mut event = world
+                                        .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                        .unwrap();
+                                    if disabled {
+                                        return;
+                                    }
+                                    let key_code = event.key_code;
+                                    match key_code {
+                                        KeyCode::Delete | KeyCode::Backspace => {
+                                            event.stop_propagation();
+                                            remove_selected_stop(world, edit_id, selected);
+                                        }
+                                        KeyCode::ArrowLeft | KeyCode::ArrowRight => {
+                                            event.stop_propagation();
+                                            let num_stops =
+                                                gradient_value(world, edit_id).stops.len();
+                                            let index = selected.get(world);
+                                            selected.set(
+                                                world,
+                                                if key_code == KeyCode::ArrowLeft {
+                                                    index.saturating_sub(1)
+                                                } else {
+                                                    (index + 1).min(num_stops.saturating_sub(1))
+                                                },
+                                            );
+                                        }
+                                        _ => {}
+                                    }
+                                }),
+                            )
+                        },
+                        disabled,
+                    )
+                    .children((
+                        Element::<MaterialNodeBundle<GradientRampMaterial>>::new()
+                            .insert(material.clone())
+                            .style(style_ramp),
+                        Element::<NodeBundle>::new()
+                            .named("GradientEdit::Stops")
+                            .style(style_stop_track)
+                            .children(For::index(&value.stops, move |stop, index| {
+                                GradientStopMarker {
+                                    bar_id,
+                                    index,
+                                    stop: *stop,
+                                    selected,
+                                    disabled,
+                                }
+                            })),
+                    )),
+                Element::<NodeBundle>::new()
+                    .style(style_stop_controls)
+                    .children((
+                        Select::new()
+                            .value(value.interpolation)
+                            .options(vec![
+                                SelectOption::new(GradientInterpolation::Srgb, "sRGB"),
+                                SelectOption::new(GradientInterpolation::LinearRgb, "Linear RGB"),
+                                SelectOption::new(GradientInterpolation::Oklab, "OKLab"),
+                            ])
+                            .size(Size::Sm)
+                            .disabled(disabled)
+                            .on_change(cx.create_callback(
+                                move |interpolation: In<GradientInterpolation>,
+                                      world: &mut World| {
+                                    let mut gradient = gradient_value(world, edit_id);
+                                    gradient.interpolation = *interpolation;
+                                    emit_gradient(world, edit_id, gradient);
+                                },
+                            )),
+                        Spacer,
+                        SpinBox::new()
+                            .value(selected_stop.map_or(0., |stop| stop.position * 100.))
+                            .range(0. ..=100.)
+                            .precision(1)
+                            .step(1.)
+                            .disabled(disabled || selected_stop.is_none())
+                            .style(style_position)
+                            .on_change(cx.create_callback(
+                                move |position: In<f32>, world: &mut World| {
+                                    let mut gradient = gradient_value(world, edit_id);
+                                    let index = selected.get(world);
+                                    if index < gradient.stops.len() {
+                                        let index = gradient.set_position(index, *position / 100.);
+                                        selected.set(world, index);
+                                        emit_gradient(world, edit_id, gradient);
+                                    }
+                                },
+                            )),
+                        Button::new()
+                            .children("Add")
+                            .size(Size::Sm)
+                            .disabled(disabled)
+                            .on_click(cx.create_callback(move |world: &mut World| {
+                                let mut gradient = gradient_value(world, edit_id);
+                                let index = selected.get(world);
+                                let index = gradient.insert(new_stop_position(&gradient, index));
+                                selected.set(world, index);
+                                emit_gradient(world, edit_id, gradient);
+                            })),
+                        Button::new()
+                            .children("Remove")
+                            .size(Size::Sm)
+                            .disabled(disabled || num_stops <= 1)
+                            .on_click(cx.create_callback(move |world: &mut World| {
+                                remove_selected_stop(world, edit_id, selected);
+                            })),
+                    )),
+                Cond::new(
+                    selected_stop.is_some() && !disabled,
+                    ColorEdit::new(
+                        color_state.get(cx),
+                        cx.create_callback(move |state: In<ColorEditState>, world: &mut World| {
+                            color_state.set(world, *state);
+                            let mut gradient = gradient_value(world, edit_id);
+                            let index = selected.get(world);
+                            if index < gradient.stops.len()
+                                && gradient.stops[index].color != state.rgb
+                            {
+                                gradient.set_color(index, state.rgb);
+                                emit_gradient(world, edit_id, gradient);
+                            }
+                        }),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// Marker displayed under the gradient bar for a single color stop.
+#[derive(Clone, PartialEq)]
+struct GradientStopMarker {
+    bar_id: Entity,
+    index: usize,
+    stop: GradientStop,
+    selected: Mutable<usize>,
+    disabled: bool,
+}
+
+impl ViewTemplate for GradientStopMarker {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let bar_id = self.bar_id;
+        let index = self.index;
+        let selected = self.selected;
+        let is_selected = selected.get(cx) == index;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("GradientEdit::Stop")
+            .style(style_stop)
+            .style_dyn(
+                |(position, color, is_selected), sb| {
+                    sb.left(ui::Val::Percent(position * 100.))
+                        .background_color(color.with_alpha(1.))
+                        .border_color(if is_selected {
+                            tokens::FOREGROUND
+                        } else {
+                            tokens::U1
+                        });
+                },
+                (self.stop.position, self.stop.color, is_selected),
+            )
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                move |(index, position)| {
+                    On::<Pointer<Down>>::run(move |world: &mut World| {
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                            .unwrap();
+                        event.stop_propagation();
+                        if world.is_disabled(id) {
+                            return;
+                        }
+                        world.get_resource_mut::<Focus>().unwrap().0 = Some(bar_id);
+                        selected.set(world, index);
+                        world.entity_mut(bar_id).insert(StopDrag {
+                            index: Some(index),
+                            offset: position,
+                        });
+                    })
+                },
+                (index, self.stop.position),
+            )
+    }
+}
+
+/// Return the current gradient value of the editor.
+fn gradient_value(world: &World, edit_id: Entity) -> Gradient {
+    world
+        .get::<GradientEditParams>(edit_id)
+        .unwrap()
+        .value
+        .clone()
+}
+
+/// Pass a modified gradient to the editor's `on_change` callback.
+fn emit_gradient(world: &mut World, edit_id: Entity, gradient: Gradient) {
+    if let Some(on_change) = world.get::<GradientEditParams>(edit_id).unwrap().on_change {
+        world.run_callback(on_change, gradient);
+    }
+}
+
+/// Width of the gradient ramp, not counting the padding for the stop markers.
+fn bar_width(world: &World, bar_id: Entity) -> Option<f32> {
+    let ent = world.entity(bar_id);
+    let node = ent.get::<Node>()?;
+    let transform = ent.get::<GlobalTransform>()?;
+    let width = node.logical_rect(transform).width() - STOP_WIDTH;
+    (width > 0.).then_some(width)
+}
+
+/// Convert a horizontal pointer position into a gradient position.
+fn bar_position(world: &World, bar_id: Entity, x: f32) -> Option<f32> {
+    let ent = world.entity(bar_id);
+    let node = ent.get::<Node>()?;
+    let transform = ent.get::<GlobalTransform>()?;
+    let rect = node.logical_rect(transform);
+    let width = bar_width(world, bar_id)?;
+    Some(((x - rect.min.x - STOP_WIDTH * 0.5) / width).clamp(0., 1.))
+}
+
+/// Choose a position for a new stop: halfway between the selected stop and its neighbor.
+fn new_stop_position(gradient: &Gradient, index: usize) -> f32 {
+    let stops = &gradient.stops;
+    match (stops.get(index), stops.get(index + 1)) {
+        (Some(a), Some(b)) => (a.position + b.position) * 0.5,
+        (Some(a), None) if index > 0 => (stops[index - 1].position + a.position) * 0.5,
+        (Some(a), None) => {
+            if a.position < 0.5 {
+                (a.position + 1.) * 0.5
+            } else {
+                a.position * 0.5
+            }
+        }
+        (None, _) => 0.5,
+    }
+}
+
+fn remove_selected_stop(world: &mut World, edit_id: Entity, selected: Mutable<usize>) {
+    let mut gradient = gradient_value(world, edit_id);
+    let index = selected.get(world);
+    if gradient.stops.len() > 1 && index < gradient.stops.len() {
+        gradient.remove(index);
+        selected.set(world, index.min(gradient.stops.len() - 1));
+        emit_gradient(world, edit_id, gradient);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::color::Srgba;
+
+    use super::{Gradient, GradientInterpolation, GradientStop};
+
+    fn assert_color(actual: Srgba, expected: Srgba) {
+        let a = actual.to_f32_array();
+        let e = expected.to_f32_array();
+        assert!(
+            a.iter().zip(e.iter()).all(|(a, e)| (a - e).abs() < 1e-3),
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_sample_endpoints() {
+        let gradient = Gradient::default();
+        assert_color(gradient.sample(0.), Srgba::BLACK);
+        assert_color(gradient.sample(1.), Srgba::WHITE);
+        assert_color(gradient.sample(0.5), Srgba::rgb(0.5, 0.5, 0.5));
+    }
+
+    #[test]
+    fn test_sample_out_of_range() {
+        let gradient = Gradient::new([
+            GradientStop::new(0.25, Srgba::RED),
+            GradientStop::new(0.75, Srgba::BLUE),
+        ]);
+        assert_color(gradient.sample(-1.), Srgba::RED);
+        assert_color(gradient.sample(0.1), Srgba::RED);
+        assert_color(gradient.sample(0.9), Srgba::BLUE);
+        assert_color(gradient.sample(2.), Srgba::BLUE);
+        assert_color(gradient.sample(0.5), Srgba::rgb(0.5, 0., 0.5));
+    }
+
+    #[test]
+    fn test_sample_degenerate() {
+        assert_color(Gradient::new([]).sample(0.5), Srgba::NONE);
+
+        let single = Gradient::new([GradientStop::new(0.5, Srgba::GREEN)]);
+        assert_color(single.sample(0.), Srgba::GREEN);
+        assert_color(single.sample(1.), Srgba::GREEN);
+
+        // Two stops at the same position make a hard edge rather than dividing by zero.
+        let hard = Gradient::new([
+            GradientStop::new(0., Srgba::RED),
+            GradientStop::new(0.5, Srgba::RED),
+            GradientStop::new(0.5, Srgba::BLUE),
+            GradientStop::new(1., Srgba::BLUE),
+        ]);
+        assert_color(hard.sample(0.49), Srgba::RED);
+        assert_color(hard.sample(0.5), Srgba::BLUE);
+    }
+
+    #[test]
+    fn test_new_sorts_stops() {
+        let gradient = Gradient::new([
+            GradientStop::new(1., Srgba::WHITE),
+            GradientStop::new(0., Srgba::BLACK),
+        ]);
+        assert_eq!(gradient, Gradient::default());
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let gradient = Gradient::default().with_interpolation(GradientInterpolation::LinearRgb);
+        assert_color(gradient.sample(0.5), Srgba::rgb(0.7354, 0.7354, 0.7354));
+
+        let gradient = Gradient::default().with_interpolation(GradientInterpolation::Oklab);
+        assert_color(gradient.sample(0.), Srgba::BLACK);
+        assert_color(gradient.sample(1.), Srgba::WHITE);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut gradient = Gradient::default();
+        assert_eq!(gradient.insert(0.5), 1);
+        assert_eq!(gradient.stops.len(), 3);
+        assert_color(gradient.stops[1].color, Srgba::rgb(0.5, 0.5, 0.5));
+        // The new stop doesn't change the appearance of the gradient.
+        assert_color(gradient.sample(0.25), Srgba::rgb(0.25, 0.25, 0.25));
+
+        // Out-of-range positions are clamped, and land after any stop at the same position.
+        assert_eq!(gradient.insert(-1.), 1);
+        assert_eq!(gradient.stops[1].position, 0.);
+        assert_color(gradient.stops[1].color, Srgba::BLACK);
+        assert_eq!(gradient.insert(2.), 4);
+        assert_eq!(gradient.stops[4].position, 1.);
+        assert_color(gradient.stops[4].color, Srgba::WHITE);
+    }
+
+    #[test]
+    fn test_set_position_and_remove() {
+        let mut gradient = Gradient::default();
+        assert_eq!(gradient.set_position(0, 2.), 1);
+        assert_eq!(gradient.stops[1].position, 1.);
+        assert_color(gradient.stops[1].color, Srgba::BLACK);
+
+        gradient.remove(5);
+        assert_eq!(gradient.stops.len(), 2);
+        gradient.remove(0);
+        assert_eq!(gradient.stops.len(), 1);
+        assert_color(gradient.sample(0.), Srgba::BLACK);
+    }
+}
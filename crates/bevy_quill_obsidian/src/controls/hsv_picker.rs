@@ -0,0 +1,261 @@
+use std::f32::consts::SQRT_2;
+
+use bevy::{
+    color::{Hsva, Hue},
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{cursor::StyleBuilderCursor, materials::HsvRectMaterial};
+
+/// Width and height of the picker.
+const PICKER_SIZE: f32 = 160.;
+
+/// Width of the hue ring.
+const RING_WIDTH: f32 = 14.;
+
+/// Gap between the hue ring and the saturation / value square.
+const RING_GAP: f32 = 4.;
+
+const THUMB_SIZE: f32 = 10.;
+
+/// Half the width of the saturation / value square.
+fn square_half_size() -> f32 {
+    (PICKER_SIZE * 0.5 - RING_WIDTH - RING_GAP) / SQRT_2
+}
+
+/// Which part of the picker is being dragged.
+#[derive(Component, Clone, Copy, PartialEq, Default)]
+enum HsvDrag {
+    #[default]
+    None,
+    Ring,
+    Square,
+}
+
+/// Component used to hold the current color so that it can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone, Copy)]
+struct HsvPickerState(Hsva);
+
+fn style_picker(ss: &mut StyleBuilder) {
+    ss.width(PICKER_SIZE)
+        .height(PICKER_SIZE)
+        .align_self(ui::AlignSelf::Center)
+        .cursor(CursorIcon::Crosshair);
+}
+
+fn style_wheel(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .left(0)
+        .top(0)
+        .right(0)
+        .bottom(0);
+}
+
+fn style_thumb(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .width(THUMB_SIZE)
+        .height(THUMB_SIZE)
+        .border(2)
+        .border_color(Srgba::WHITE)
+        .border_radius(THUMB_SIZE * 0.5)
+        .pointer_events(false);
+}
+
+/// Color picker which displays a hue ring surrounding a saturation / value square. Dragging
+/// on the ring changes the hue, dragging within the square changes saturation and value.
+#[derive(Clone, PartialEq)]
+pub struct HsvPicker {
+    /// Current color.
+    pub value: Hsva,
+
+    /// Additional styles to be applied to the picker.
+    pub style: StyleHandle,
+
+    /// Callback called when the color changes.
+    pub on_change: Option<Callback<Hsva>>,
+}
+
+impl HsvPicker {
+    /// Create a new HSV picker.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current color.
+    pub fn value(mut self, value: impl Into<Hsva>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the style handle for the picker root element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the color changes.
+    pub fn on_change(mut self, on_change: Callback<Hsva>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+}
+
+impl Default for HsvPicker {
+    fn default() -> Self {
+        Self {
+            value: Hsva::default(),
+            style: StyleHandle::default(),
+            on_change: None,
+        }
+    }
+}
+
+impl ViewTemplate for HsvPicker {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let picker_id = cx.create_entity();
+        let on_change = self.on_change;
+        let hsv = self.value;
+
+        let material = cx.create_memo(
+            |world, _| {
+                let mut materials = world.get_resource_mut::<Assets<HsvRectMaterial>>().unwrap();
+                materials.add(HsvRectMaterial {
+                    params: Vec4::new(0., RING_WIDTH, RING_GAP, 0.),
+                })
+            },
+            (),
+        );
+
+        // Effect to update the hue displayed in the square.
+        cx.create_effect(
+            move |world, (material, hue)| {
+                let mut materials = world.get_resource_mut::<Assets<HsvRectMaterial>>().unwrap();
+                let material = materials.get_mut(material.id()).unwrap();
+                material.params.x = hue;
+            },
+            (material.clone(), hsv.hue),
+        );
+
+        // Ensure HsvDrag component exists before rendering.
+        let mut entt = cx.world_mut().entity_mut(picker_id);
+        if !entt.contains::<HsvDrag>() {
+            entt.insert(HsvDrag::None);
+        }
+
+        Element::<NodeBundle>::for_entity(picker_id)
+            .named("HsvPicker")
+            .style((style_picker, self.style.clone()))
+            .insert_dyn(HsvPickerState, hsv)
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Down>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            let position = event.pointer_location.position;
+                            let Some(offset) = picker_offset(world, picker_id, position) else {
+                                return;
+                            };
+                            // Grab the ring if the pointer is outside of the square.
+                            let half = square_half_size();
+                            let drag = if offset.x.abs() <= half && offset.y.abs() <= half {
+                                HsvDrag::Square
+                            } else if offset.length() <= PICKER_SIZE * 0.5 {
+                                HsvDrag::Ring
+                            } else {
+                                HsvDrag::None
+                            };
+                            world.entity_mut(picker_id).insert(drag);
+                            pick_hsv(world, picker_id, offset, on_change);
+                        }),
+                        On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                        }),
+                        On::<Pointer<DragEnd>>::listener_component_mut::<HsvDrag>(|_, drag| {
+                            *drag = HsvDrag::None;
+                        }),
+                        On::<Pointer<Drag>>::run(move |world: &mut World| {
+                            let event = world
+                                .get_resource::<ListenerInput<Pointer<Drag>>>()
+                                .unwrap();
+                            let position = event.pointer_location.position;
+                            if let Some(offset) = picker_offset(world, picker_id, position) {
+                                pick_hsv(world, picker_id, offset, on_change);
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<MaterialNodeBundle<HsvRectMaterial>>::new()
+                    .insert(material.clone())
+                    .style(style_wheel),
+                Element::<NodeBundle>::new()
+                    .named("HsvPicker::HueThumb")
+                    .style(style_thumb)
+                    .style_dyn(
+                        |hue, sb| {
+                            let radius = (PICKER_SIZE - RING_WIDTH) * 0.5;
+                            let angle = hue.to_radians();
+                            sb.left(PICKER_SIZE * 0.5 + radius * angle.cos() - THUMB_SIZE * 0.5)
+                                .top(PICKER_SIZE * 0.5 - radius * angle.sin() - THUMB_SIZE * 0.5);
+                        },
+                        hsv.hue,
+                    ),
+                Element::<NodeBundle>::new()
+                    .named("HsvPicker::SquareThumb")
+                    .style(style_thumb)
+                    .style_dyn(
+                        |(saturation, value), sb| {
+                            let half = square_half_size();
+                            let x = (saturation * 2. - 1.) * half;
+                            let y = (1. - value * 2.) * half;
+                            sb.left(PICKER_SIZE * 0.5 + x - THUMB_SIZE * 0.5)
+                                .top(PICKER_SIZE * 0.5 + y - THUMB_SIZE * 0.5);
+                        },
+                        (hsv.saturation, hsv.value),
+                    ),
+            ))
+    }
+}
+
+/// Return the pointer position relative to the center of the picker.
+fn picker_offset(world: &World, picker_id: Entity, position: Vec2) -> Option<Vec2> {
+    let ent = world.entity(picker_id);
+    let node = ent.get::<Node>()?;
+    let transform = ent.get::<GlobalTransform>()?;
+    Some(position - node.logical_rect(transform).center())
+}
+
+/// Update the color from a pointer offset, depending on which part of the picker is dragged.
+fn pick_hsv(world: &mut World, picker_id: Entity, offset: Vec2, on_change: Option<Callback<Hsva>>) {
+    let ent = world.entity(picker_id);
+    let hsv = ent.get::<HsvPickerState>().unwrap().0;
+    let half = square_half_size();
+    let hsv = match ent.get::<HsvDrag>().copied().unwrap_or_default() {
+        HsvDrag::None => return,
+        HsvDrag::Ring => hsv.with_hue(
+            f32::atan2(-offset.y, offset.x)
+                .to_degrees()
+                .rem_euclid(360.),
+        ),
+        HsvDrag::Square => hsv
+            .with_saturation(((offset.x + half) / (2. * half)).clamp(0., 1.))
+            .with_value((1. - (offset.y + half) / (2. * half)).clamp(0., 1.)),
+    };
+    if let Some(on_change) = on_change {
+        world.run_callback(on_change, hsv);
+    }
+}
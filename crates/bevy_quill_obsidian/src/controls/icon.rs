@@ -2,7 +2,50 @@ use bevy::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::colors;
+use crate::tokens;
+
+/// The color of an [`Icon`], either a literal color or a token which follows the current theme.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum IconColor {
+    /// A fixed color.
+    Color(Srgba),
+    /// A named color, resolved against the current theme.
+    Token(ColorToken),
+}
+
+impl From<Srgba> for IconColor {
+    fn from(color: Srgba) -> Self {
+        Self::Color(color)
+    }
+}
+
+impl From<Color> for IconColor {
+    fn from(color: Color) -> Self {
+        Self::Color(color.into())
+    }
+}
+
+impl From<ColorToken> for IconColor {
+    fn from(token: ColorToken) -> Self {
+        Self::Token(token)
+    }
+}
+
+impl ColorParam for IconColor {
+    fn to_val(self) -> Option<Color> {
+        match self {
+            Self::Color(color) => Some(color.into()),
+            Self::Token(_) => None,
+        }
+    }
+
+    fn token(&self) -> Option<ColorToken> {
+        match self {
+            Self::Color(_) => None,
+            Self::Token(token) => Some(*token),
+        }
+    }
+}
 
 /// Control that displays an icon.
 #[derive(Clone, PartialEq)]
@@ -14,7 +57,7 @@ pub struct Icon {
     pub size: Vec2,
 
     /// Color of the icon.
-    pub color: Srgba,
+    pub color: IconColor,
 
     /// Additional styles to apply to the icon
     pub style: StyleHandle,
@@ -35,8 +78,8 @@ impl Icon {
         self
     }
 
-    /// Set the color of the icon.
-    pub fn color(mut self, color: impl Into<Srgba>) -> Self {
+    /// Set the color of the icon, either a color or a token.
+    pub fn color(mut self, color: impl Into<IconColor>) -> Self {
         self.color = color.into();
         self
     }
@@ -53,7 +96,7 @@ impl Default for Icon {
         Self {
             icon: HandleOrOwnedPath::default(),
             size: Vec2::splat(12.0),
-            color: colors::FOREGROUND,
+            color: IconColor::Token(tokens::FOREGROUND),
             style: StyleHandle::default(),
         }
     }
@@ -1,5 +1,5 @@
 use super::{Button, Icon};
-use crate::{colors, size::Size, RoundedCorners};
+use crate::{size::Size, tokens, RoundedCorners};
 use bevy::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
@@ -125,9 +125,9 @@ impl ViewTemplate for IconButton {
         .children(
             Icon::new(&self.icon)
                 .color(if disabled {
-                    Color::from(colors::DIM).with_alpha(0.2)
+                    tokens::DIM.with_alpha(0.2)
                 } else {
-                    Color::from(colors::DIM)
+                    tokens::DIM
                 })
                 .size(match self.size {
                     Size::Xl => Vec2::splat(20.),
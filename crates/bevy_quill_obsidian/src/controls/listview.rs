@@ -3,7 +3,6 @@ use bevy::{
         accesskit::{NodeBuilder, Role},
         AccessibilityNode, Focus,
     },
-    color::{Alpha, Srgba},
     prelude::*,
     ui::{self, node_bundles::NodeBundle},
 };
@@ -12,16 +11,15 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{
-    colors,
     focus::TabIndex,
     hooks::{UseIsFocus, UseIsHover},
-    typography,
+    tokens, typography,
 };
 
 use super::{IsDisabled, ScrollView};
 
 fn style_listview(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U1)
+    ss.background_color(tokens::U1)
         .border_radius(5.0)
         .padding(3);
 }
@@ -164,7 +162,7 @@ impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for ListRow<K> {
                 move |focused, sb| {
                     match focused {
                         true => {
-                            sb.border_color(colors::FOCUS).border(1).padding((5, 2));
+                            sb.border_color(tokens::FOCUS).border(1).padding((5, 2));
                         }
                         false => {
                             sb.border_color(Option::<Color>::None)
@@ -201,11 +199,11 @@ fn style_listrow(ss: &mut StyleBuilder) {
     ss.padding((6, 3));
 }
 
-pub(crate) fn row_bg_color(is_disabled: bool, is_selected: bool, is_hovering: bool) -> Srgba {
+pub(crate) fn row_bg_color(is_disabled: bool, is_selected: bool, is_hovering: bool) -> ColorToken {
     match (is_disabled, is_selected, is_hovering) {
-        (true, _, _) => Srgba::NONE,
-        (_, true, _) => colors::TEXT_SELECT.with_alpha(0.05),
-        (_, false, true) => colors::TEXT_SELECT.with_alpha(0.02),
-        (_, false, false) => Srgba::NONE,
+        (true, _, _) => tokens::TRANSPARENT,
+        (_, true, _) => tokens::TEXT_SELECT.with_alpha(0.05),
+        (_, false, true) => tokens::TEXT_SELECT.with_alpha(0.02),
+        (_, false, false) => tokens::TRANSPARENT,
     }
 }
@@ -1,10 +1,9 @@
 use crate::{
-    colors,
     floating::{FloatAlign, FloatPosition, FloatSide, Floating},
-    focus::{AutoFocus, KeyPressEvent, NavAction, TabGroup, TabIndex, TabNavigation},
+    focus::{AutoFocus, FocusVisible, KeyPressEvent, NavAction, TabGroup, TabIndex, TabNavigation},
     hooks::{BistableTransitionState, CreateBistableTransition, UseIsFocus, UseIsHover},
     size::Size,
-    typography, RoundedCorners,
+    tokens, typography, RoundedCorners,
 };
 use bevy::{
     a11y::{
@@ -20,12 +19,21 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use super::{
-    button_bg_color, style_button, style_button_bg, ButtonVariant, Disabled, Icon, Spacer,
+    button_bg_color, style_button, style_button_bg, ButtonVariant, Disabled, Icon,
+    ShortcutRegistry, Spacer,
 };
 
 /// View context component which stores the anchor element id for a menu.
 #[derive(Component)]
-struct MenuAnchor(Entity);
+pub(super) struct MenuAnchor(pub(super) Entity);
+
+/// View context component which stores the element id of the enclosing menu bar.
+#[derive(Component, Clone, Copy)]
+pub(crate) struct MenuBarId(Entity);
+
+/// Component on a menu button which holds its open state, so that the menu bar can open it.
+#[derive(Component, Clone, Copy)]
+struct MenuOpenState(Mutable<bool>);
 
 #[derive(Clone, Event, EntityEvent)]
 #[can_bubble]
@@ -33,10 +41,13 @@ pub(crate) struct MenuCloseEvent {
     /// The target of the event
     #[target]
     pub target: Entity,
+
+    /// If true, close all open menus, not just the innermost one.
+    pub all: bool,
 }
 
 // Dialog background overlay
-fn style_menu_barrier(ss: &mut StyleBuilder) {
+pub(super) fn style_menu_barrier(ss: &mut StyleBuilder) {
     ss.position(PositionType::Absolute)
         .display(ui::Display::Flex)
         .justify_content(ui::JustifyContent::Center)
@@ -46,7 +57,7 @@ fn style_menu_barrier(ss: &mut StyleBuilder) {
         .right(0)
         .bottom(0)
         .z_index(100)
-        .background_color(colors::U2.with_alpha(0.0));
+        .background_color(tokens::U2.with_alpha(0.0));
 }
 
 /// A widget that displays a drop-down menu when clicked.
@@ -187,6 +198,7 @@ impl ViewTemplate for MenuButton {
         let size = self.size;
         let popup = self.popup.clone();
         let on_state_change = self.on_state_change;
+        let bar = cx.use_inherited_component::<MenuBarId>().map(|b| b.0);
 
         cx.create_effect_ext(
             |world, open| {
@@ -215,7 +227,7 @@ impl ViewTemplate for MenuButton {
                 typography::text_default,
                 style_button,
                 move |ss: &mut StyleBuilder| {
-                    ss.min_height(size.height()).font_size(size.font_size());
+                    ss.min_height(size.height_rem()).font_size(size.font_size());
                     if minimal {
                         ss.padding(0);
                     } else {
@@ -224,7 +236,7 @@ impl ViewTemplate for MenuButton {
                 },
                 self.style.clone(),
             ))
-            .insert(TabIndex(self.tab_index))
+            .insert((TabIndex(self.tab_index), MenuOpenState(open)))
             .insert_dyn(
                 move |_| {
                     (
@@ -294,7 +306,7 @@ impl ViewTemplate for MenuButton {
                         move |is_focused, sb| {
                             match is_focused {
                                 true => {
-                                    sb.outline_color(colors::FOCUS)
+                                    sb.outline_color(tokens::FOCUS)
                                         .outline_width(2)
                                         .outline_offset(2);
                                 }
@@ -312,7 +324,7 @@ impl ViewTemplate for MenuButton {
                     (
                         Spacer,
                         Icon::new("embedded://bevy_quill_obsidian/assets/icons/chevron_down.png")
-                            .color(Color::from(colors::DIM))
+                            .color(tokens::DIM)
                             .style(|ss: &mut StyleBuilder| {
                                 ss.margin_right(4);
                             }),
@@ -340,10 +352,17 @@ impl ViewTemplate for MenuButton {
                                                     });
                                                 }
                                             }),
+                                            // Within a menu bar, left and right arrows move to the
+                                            // adjacent menu.
+                                            On::<KeyPressEvent>::run(move |world: &mut World| {
+                                                if let Some(bar) = bar {
+                                                    switch_bar_menu(world, bar, id_anchor, open);
+                                                }
+                                            }),
                                             ZIndex::Global(100),
                                         )
                                     },
-                                    (),
+                                    bar,
                                 )
                                 .children(popup.clone()),
                         )
@@ -355,7 +374,7 @@ impl ViewTemplate for MenuButton {
 }
 
 fn style_popup(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U1)
+    ss.background_color(tokens::U1)
         .border_radius(4.0)
         .position(PositionType::Absolute)
         .display(ui::Display::Flex)
@@ -364,6 +383,7 @@ fn style_popup(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Stretch)
         .border_color(Srgba::BLACK)
         .border(1)
+        .drop_shadow(0, 3, 8, Srgba::new(0., 0., 0., 0.5))
         .padding((0, 2));
 }
 
@@ -382,6 +402,9 @@ pub struct MenuPopup {
     /// Default side of the popup (top, bottom, left, right). Note that the popup will also
     /// automatically flip to the opposite side if it doesn't fit on the default side.
     pub side: FloatSide,
+
+    /// If true (the default), focus the first menu item when the popup opens.
+    pub autofocus: bool,
 }
 
 impl Default for MenuPopup {
@@ -391,6 +414,7 @@ impl Default for MenuPopup {
             style: Default::default(),
             align: FloatAlign::Start,
             side: FloatSide::Bottom,
+            autofocus: true,
         }
     }
 }
@@ -424,6 +448,12 @@ impl MenuPopup {
         self.side = side;
         self
     }
+
+    /// Set whether to focus the first menu item when the popup opens.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
 }
 
 impl ViewTemplate for MenuPopup {
@@ -481,7 +511,10 @@ impl ViewTemplate for MenuPopup {
                                 match event.key_code {
                                     KeyCode::Escape => {
                                         event.stop_propagation();
-                                        world.send_event(MenuCloseEvent { target: owner_id });
+                                        world.send_event(MenuCloseEvent {
+                                            target: owner_id,
+                                            all: false,
+                                        });
                                     }
                                     KeyCode::ArrowUp => {
                                         event.stop_propagation();
@@ -509,15 +542,15 @@ impl ViewTemplate for MenuPopup {
             )
             .children(self.children.clone())
             .effect(
-                move |cx, ent, state| {
-                    if state == BistableTransitionState::Entered {
+                move |cx, ent, (state, autofocus)| {
+                    if autofocus && state == BistableTransitionState::Entered {
                         let mut st: SystemState<(ResMut<Focus>, TabNavigation)> =
                             SystemState::new(cx.world_mut());
                         let (mut focus, nav) = st.get_mut(cx.world_mut());
                         focus.0 = nav.navigate(Some(ent), NavAction::First);
                     }
                 },
-                state,
+                (state, self.autofocus),
             )
     }
 }
@@ -532,6 +565,10 @@ fn style_menu_item(ss: &mut StyleBuilder) {
         .margin((2, 0));
 }
 
+fn style_menu_item_shortcut(ss: &mut StyleBuilder) {
+    ss.margin_left(16).color(tokens::DIM);
+}
+
 /// UI component representing a menu item.
 #[derive(Default, Clone, PartialEq)]
 pub struct MenuItem {
@@ -547,10 +584,14 @@ pub struct MenuItem {
     /// Whether the menu item is disabled.
     pub disabled: bool,
 
-    /// Callback called when clicked
+    /// Callback called when clicked. If not set, the callback registered for the shortcut
+    /// is called instead.
     pub on_click: Option<Callback>,
+
+    /// Id of an action in the [`ShortcutRegistry`], whose key combination is displayed
+    /// next to the label.
+    pub shortcut: Option<&'static str>,
     // icon
-    // shortcut
 }
 
 impl MenuItem {
@@ -588,6 +629,12 @@ impl MenuItem {
         self.on_click = Some(on_click);
         self
     }
+
+    /// Set the id of the shortcut action associated with the menu item.
+    pub fn shortcut(mut self, id: &'static str) -> Self {
+        self.shortcut = Some(id);
+        self
+    }
 }
 
 impl ViewTemplate for MenuItem {
@@ -599,14 +646,24 @@ impl ViewTemplate for MenuItem {
         let pressed = cx.create_mutable::<bool>(false);
         let hovering = cx.is_hovered(id);
         let focused = cx.is_focused(id);
-        let on_click = self.on_click;
+        let (shortcut_label, shortcut_callback) = match self.shortcut {
+            Some(action) => {
+                let registry = cx.use_resource::<ShortcutRegistry>();
+                (
+                    registry.shortcut(action).map(|s| s.to_string()),
+                    registry.callback(action),
+                )
+            }
+            None => (None, None),
+        };
+        let on_click = self.on_click.or(shortcut_callback);
 
         Element::<NodeBundle>::for_entity(id)
             .named("MenuItem")
             .insert_if(self.disabled, || Disabled)
             .style((style_menu_item, self.style.clone()))
             .insert_dyn(
-                move |_| {
+                move |on_click| {
                     (
                         TabIndex(0),
                         AccessibilityNode::from(NodeBuilder::new(Role::Button)),
@@ -618,7 +675,10 @@ impl ViewTemplate for MenuItem {
                                 let (mut writer, mut focus) = st.get_mut(world);
                                 focus.0 = Some(id);
                                 if let Some(on_click) = on_click {
-                                    writer.send(MenuCloseEvent { target: owner_id });
+                                    writer.send(MenuCloseEvent {
+                                        target: owner_id,
+                                        all: true,
+                                    });
                                     world.run_callback(on_click, ());
                                 }
                             }
@@ -667,7 +727,10 @@ impl ViewTemplate for MenuItem {
                                 {
                                     event.stop_propagation();
                                     if let Some(on_click) = on_click {
-                                        writer.send(MenuCloseEvent { target: owner_id });
+                                        writer.send(MenuCloseEvent {
+                                            target: owner_id,
+                                            all: true,
+                                        });
                                         world.run_callback(on_click, ());
                                     }
                                 }
@@ -675,21 +738,34 @@ impl ViewTemplate for MenuItem {
                         }),
                     )
                 },
-                (),
+                on_click,
             )
             .style_dyn(
                 move |(is_pressed, is_hovering, is_focused), sb| {
                     let color = match (is_pressed || is_focused, is_hovering) {
-                        (true, true) => colors::U1.lighter(0.03),
-                        (true, false) => colors::U1.lighter(0.02),
-                        (false, true) => colors::U1.lighter(0.01),
-                        (false, false) => Srgba::NONE,
+                        (true, true) => tokens::U1.lighter(0.03),
+                        (true, false) => tokens::U1.lighter(0.02),
+                        (false, true) => tokens::U1.lighter(0.01),
+                        (false, false) => tokens::TRANSPARENT,
                     };
                     sb.background_color(color);
                 },
                 (pressed.get(cx), hovering, focused),
             )
-            .children(self.label.clone())
+            .children((
+                self.label.clone(),
+                Cond::new(
+                    shortcut_label.is_some(),
+                    (
+                        Spacer,
+                        Element::<NodeBundle>::new()
+                            .named("MenuItem::Shortcut")
+                            .style(style_menu_item_shortcut)
+                            .children(shortcut_label.unwrap_or_default()),
+                    ),
+                    (),
+                ),
+            ))
     }
 }
 
@@ -709,3 +785,391 @@ impl ViewTemplate for MenuDivider {
             .style(style_menu_divider)
     }
 }
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum SubMenuOpen {
+    #[default]
+    Closed,
+    /// Opened by hovering; closes again when the pointer leaves.
+    Hover,
+    /// Opened from the keyboard; focus moves into the submenu.
+    Keyboard,
+}
+
+// Zero-sized element which holds the submenu popup.
+fn style_submenu_popup(ss: &mut StyleBuilder) {
+    ss.position(PositionType::Absolute)
+        .left(0)
+        .top(0)
+        .width(0)
+        .height(0);
+}
+
+/// A menu item which opens a nested popup menu, either when hovered or when the right arrow
+/// key is pressed. The left arrow key or Escape closes the nested menu again.
+#[derive(Default, Clone, PartialEq)]
+pub struct SubMenu {
+    /// The label of the menu item.
+    pub label: ViewChild,
+
+    /// The items of the nested menu.
+    pub children: ViewChild,
+
+    /// Additional styles to apply to the menu item.
+    pub style: StyleHandle,
+
+    /// Whether the menu item is disabled.
+    pub disabled: bool,
+}
+
+impl SubMenu {
+    /// Create a new submenu.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the label of the menu item.
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    /// Set the items of the nested menu.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set additional styles to apply to the menu item.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the disabled state of the menu item.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl ViewTemplate for SubMenu {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let id_popup = cx.create_entity();
+        let open = cx.create_mutable(SubMenuOpen::Closed);
+        let hovering = cx.is_hovered(id);
+        let hovering_popup = cx.is_hovered(id_popup);
+        let focused = cx.is_focused(id);
+        let mode = open.get(cx);
+        let state = cx.create_bistable_transition(mode != SubMenuOpen::Closed, 0.01);
+
+        // Open when the pointer rests on the item, and close (if opened by hovering) once the
+        // pointer has left both the item and the nested menu for a short time.
+        let hover_state = cx.create_bistable_transition(hovering || hovering_popup, 0.3);
+        cx.create_effect(
+            move |world, (hover_state, hovering, disabled)| match hover_state {
+                BistableTransitionState::Entered
+                    if hovering && !disabled && open.get(world) == SubMenuOpen::Closed =>
+                {
+                    open.set(world, SubMenuOpen::Hover);
+                }
+                BistableTransitionState::Exited if open.get(world) == SubMenuOpen::Hover => {
+                    open.set(world, SubMenuOpen::Closed);
+                }
+                _ => {}
+            },
+            (hover_state, hovering, self.disabled),
+        );
+
+        cx.insert(MenuAnchor(id));
+        cx.insert(On::<MenuCloseEvent>::run(move |world: &mut World| {
+            let mut event = world
+                .get_resource_mut::<ListenerInput<MenuCloseEvent>>()
+                .unwrap();
+            // Escape only closes the innermost menu; choosing an item closes them all.
+            let all = event.all;
+            if !all {
+                event.stop_propagation();
+            }
+            open.set(world, SubMenuOpen::Closed);
+            if !all {
+                world.resource_mut::<Focus>().0 = Some(id);
+            }
+        }));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("SubMenu")
+            .insert_if(self.disabled, || Disabled)
+            .style((style_menu_item, self.style.clone()))
+            .insert_dyn(
+                move |_| {
+                    (
+                        TabIndex(0),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            if world.entity(id).contains::<Disabled>() {
+                                return;
+                            }
+                            world.resource_mut::<Focus>().0 = Some(id);
+                            if open.get(world) == SubMenuOpen::Closed {
+                                open.set(world, SubMenuOpen::Hover);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.entity(id).contains::<Disabled>() {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            if !event.repeat
+                                && matches!(
+                                    event.key_code,
+                                    KeyCode::ArrowRight | KeyCode::Enter | KeyCode::Space
+                                )
+                            {
+                                event.stop_propagation();
+                                open.set(world, SubMenuOpen::Keyboard);
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .insert_dyn(
+                move |open| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::Button);
+                        builder.set_has_popup(HasPopup::Menu);
+                        builder.set_expanded(open);
+                        builder
+                    })
+                },
+                mode != SubMenuOpen::Closed,
+            )
+            .style_dyn(
+                move |(is_open, is_hovering, is_focused), sb| {
+                    let color = match (is_open || is_focused, is_hovering) {
+                        (true, true) => tokens::U1.lighter(0.03),
+                        (true, false) => tokens::U1.lighter(0.02),
+                        (false, true) => tokens::U1.lighter(0.01),
+                        (false, false) => tokens::TRANSPARENT,
+                    };
+                    sb.background_color(color);
+                },
+                (mode != SubMenuOpen::Closed, hovering, focused),
+            )
+            .children((
+                self.label.clone(),
+                Spacer,
+                Icon::new("embedded://bevy_quill_obsidian/assets/icons/chevron_right.png")
+                    .color(tokens::DIM)
+                    .style(|ss: &mut StyleBuilder| {
+                        ss.margin_left(16);
+                    }),
+                Cond::new(
+                    state != BistableTransitionState::Exited,
+                    Portal::new(
+                        Element::<NodeBundle>::for_entity(id_popup)
+                            .named("SubMenu::Popup")
+                            .style(style_submenu_popup)
+                            .insert_dyn(
+                                move |_| {
+                                    (
+                                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                                            let mut event = world
+                                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                                .unwrap();
+                                            if !event.repeat && event.key_code == KeyCode::ArrowLeft
+                                            {
+                                                event.stop_propagation();
+                                                open.set(world, SubMenuOpen::Closed);
+                                                world.resource_mut::<Focus>().0 = Some(id);
+                                            }
+                                        }),
+                                        ZIndex::Global(110),
+                                    )
+                                },
+                                (),
+                            )
+                            .children(
+                                MenuPopup::new()
+                                    .side(FloatSide::Right)
+                                    .align(FloatAlign::Start)
+                                    .autofocus(mode == SubMenuOpen::Keyboard)
+                                    .children(self.children.clone()),
+                            ),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
+
+fn style_menu_bar(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .gap(2)
+        .padding((4, 2));
+}
+
+/// A horizontal bar of [`MenuButton`]s. Pressing and releasing the Alt key focuses the first
+/// menu, the left and right arrow keys move between menus (even while one is open), and the
+/// down arrow key opens the focused menu.
+#[derive(Default, Clone, PartialEq)]
+pub struct MenuBar {
+    /// The menu buttons within the bar.
+    pub children: ViewChild,
+
+    /// Additional styles to apply to the bar.
+    pub style: StyleHandle,
+}
+
+impl MenuBar {
+    /// Create a new menu bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the menu buttons within the bar.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set additional styles to apply to the bar.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for MenuBar {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        cx.insert(MenuBarId(id));
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("MenuBar")
+            .style((style_menu_bar, self.style.clone()))
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::MenuBar)),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            let target = event.target;
+                            match event.key_code {
+                                KeyCode::ArrowLeft | KeyCode::ArrowRight => {
+                                    event.stop_propagation();
+                                    let step = if event.key_code == KeyCode::ArrowLeft {
+                                        -1
+                                    } else {
+                                        1
+                                    };
+                                    if let Some(next) = adjacent_menu(world, id, target, step) {
+                                        world.resource_mut::<Focus>().0 = Some(next);
+                                    }
+                                }
+                                KeyCode::ArrowDown if !event.repeat => {
+                                    if let Some(open) = world.get::<MenuOpenState>(target).copied()
+                                    {
+                                        world
+                                            .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                            .unwrap()
+                                            .stop_propagation();
+                                        open.0.set(world, true);
+                                    }
+                                }
+                                _ => {}
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children(self.children.clone())
+    }
+}
+
+/// Returns the focusable elements of a menu bar, in order.
+fn menu_bar_buttons(world: &World, bar: Entity) -> Vec<Entity> {
+    let mut result = Vec::new();
+    let mut stack = vec![bar];
+    while let Some(entity) = stack.pop() {
+        if entity != bar && world.get::<TabIndex>(entity).is_some() {
+            result.push(entity);
+        } else if let Some(children) = world.get::<Children>(entity) {
+            stack.extend(children.iter().rev());
+        }
+    }
+    result
+}
+
+/// Returns the menu bar button which is `step` places away from `from`, wrapping around.
+fn adjacent_menu(world: &World, bar: Entity, from: Entity, step: i32) -> Option<Entity> {
+    let buttons = menu_bar_buttons(world, bar);
+    let index = buttons.iter().position(|b| *b == from)? as i32;
+    let next = buttons[(index + step).rem_euclid(buttons.len() as i32) as usize];
+    (next != from).then_some(next)
+}
+
+/// Handles left and right arrow keys within an open menu bar menu, by closing it and opening
+/// the adjacent menu instead.
+fn switch_bar_menu(world: &mut World, bar: Entity, from: Entity, open: Mutable<bool>) {
+    let mut event = world
+        .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+        .unwrap();
+    let step = match event.key_code {
+        KeyCode::ArrowLeft => -1,
+        KeyCode::ArrowRight => 1,
+        _ => return,
+    };
+    event.stop_propagation();
+    if event.repeat {
+        return;
+    }
+    let Some(next) = adjacent_menu(world, bar, from, step) else {
+        return;
+    };
+    open.set(world, false);
+    if let Some(next_open) = world.get::<MenuOpenState>(next).copied() {
+        next_open.0.set(world, true);
+    }
+    world.resource_mut::<Focus>().0 = Some(next);
+}
+
+/// Focuses the first menu of the menu bar when the Alt key is pressed and released on its own,
+/// or unfocuses it again if a menu bar button already has focus.
+pub(crate) fn activate_menu_bar(world: &mut World, mut alt_alone: Local<bool>) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    let alt = [KeyCode::AltLeft, KeyCode::AltRight];
+    if keys.any_just_pressed(alt) {
+        *alt_alone = true;
+    } else if keys.get_just_pressed().next().is_some() {
+        *alt_alone = false;
+    }
+    if !keys.any_just_released(alt) || !std::mem::take(&mut *alt_alone) {
+        return;
+    }
+
+    let mut bars = world.query::<&MenuBarId>();
+    let Some(bar) = bars.iter(world).next().map(|b| b.0) else {
+        return;
+    };
+    let buttons = menu_bar_buttons(world, bar);
+    let focus = world.resource::<Focus>().0;
+    if focus.is_some_and(|f| buttons.contains(&f)) {
+        world.resource_mut::<Focus>().0 = None;
+    } else if let Some(first) = buttons.first() {
+        world.resource_mut::<Focus>().0 = Some(*first);
+        world.resource_mut::<FocusVisible>().0 = true;
+    }
+}
@@ -1,41 +1,95 @@
 mod button;
 mod checkbox;
 mod color_edit;
+mod command_palette;
+mod context_menu;
+mod css_color;
+mod curve_edit;
+mod data_table;
 mod dialog;
 mod disabled;
 mod disclosure_toggle;
+mod dock;
+mod drag_preview;
+mod eyedropper;
 mod flex;
+mod gradient_edit;
 mod gradient_slider;
+mod hsv_picker;
 mod icon;
 mod icon_button;
 mod listview;
 mod menu;
+mod numeric_entry;
+mod radio_group;
 mod scrollview;
+mod segmented_control;
+mod select;
+mod shortcut;
 mod slider;
 mod spacer;
 mod spinbox;
 mod splitter;
 mod swatch;
 mod swatch_grid;
+mod tabs;
+mod text_area;
+mod text_buffer;
+mod text_input;
+mod toast;
+mod toggle_switch;
 mod tool_palette;
+mod tooltip;
+mod tree_view;
 
 pub use button::*;
 pub use checkbox::*;
-pub use color_edit::{ColorEdit, ColorEditState, ColorMode, RecentColors};
+pub use color_edit::{ColorEdit, ColorEditState, ColorMode, RecentColors, SavedColors};
+pub use command_palette::CommandPalette;
+pub use context_menu::ContextMenu;
+pub use css_color::parse_css_color;
+pub use curve_edit::{CurveEdit, CurvePreset};
+pub(crate) use data_table::sync_table_headers;
+pub use data_table::{DataTable, TableColumn, TableSort};
 pub use dialog::*;
 pub use disabled::*;
 pub use disclosure_toggle::*;
+pub use dock::{
+    DockDirection, DockGroup, DockLayout, DockNode, DockPanel, DockSpace, DockZone, FloatingGroup,
+};
+pub use drag_preview::DragPreviewOverlay;
+pub(crate) use eyedropper::{update_eyedropper, Eyedropper};
 pub use flex::*;
+pub use gradient_edit::{Gradient, GradientEdit, GradientInterpolation, GradientStop};
 pub use gradient_slider::*;
+pub use hsv_picker::HsvPicker;
 pub use icon::*;
 pub use icon_button::*;
 pub use listview::{ListRow, ListView};
 pub use menu::*;
+pub use radio_group::{RadioGroup, RadioOption};
 pub use scrollview::*;
+pub use segmented_control::SegmentedControl;
+pub use select::{Select, SelectOption};
+pub(crate) use shortcut::trigger_shortcuts;
+pub use shortcut::{CommandContext, CommandDef, Shortcut, ShortcutRegistry};
 pub use slider::*;
 pub use spacer::*;
 pub use spinbox::*;
 pub use splitter::*;
 pub use swatch::Swatch;
 pub use swatch_grid::SwatchGrid;
+pub use tabs::{Tab, TabBar, TabDrag, TabOverflow, TabPanel};
+pub use text_area::{HighlightSpan, TextArea};
+pub(crate) use text_buffer::reveal_text_caret;
+pub use text_buffer::TextClipboard;
+pub use text_input::TextInput;
+pub(crate) use toast::update_notifications;
+pub use toast::{
+    NotificationOverlay, Notifications, Notify, Toast, ToastAction, ToastCorner, ToastId, ToastKind,
+};
+pub use toggle_switch::ToggleSwitch;
 pub use tool_palette::*;
+pub(crate) use tooltip::dismiss_tooltips;
+pub use tooltip::Tooltip;
+pub use tree_view::{TreeNode, TreeView};
@@ -0,0 +1,427 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::hooks::UseIsFocus;
+
+use super::TextInput;
+
+/// Maximum time between clicks, in seconds, for them to count as a double-click.
+pub(super) const DOUBLE_CLICK_TIME: f64 = 0.4;
+
+fn style_numeric_entry(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_grow(1.)
+        .height(ui::Val::Percent(100.));
+}
+
+fn style_numeric_entry_input(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).height(ui::Val::Percent(100.)).min_width(0);
+}
+
+/// Recursive-descent parser for simple arithmetic expressions.
+struct ExpressionParser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl ExpressionParser {
+    fn peek(&mut self) -> Option<char> {
+        while self.chars.get(self.pos).is_some_and(|c| c.is_whitespace()) {
+            self.pos += 1;
+        }
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// expr := term (('+' | '-') term)*
+    fn expr(&mut self) -> Option<f64> {
+        let mut value = self.term()?;
+        loop {
+            if self.eat('+') {
+                value += self.term()?;
+            } else if self.eat('-') {
+                value -= self.term()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// term := unary (('*' | '/' | '%') unary)*
+    fn term(&mut self) -> Option<f64> {
+        let mut value = self.unary()?;
+        loop {
+            if self.eat('*') {
+                value *= self.unary()?;
+            } else if self.eat('/') {
+                value /= self.unary()?;
+            } else if self.eat('%') {
+                value %= self.unary()?;
+            } else {
+                return Some(value);
+            }
+        }
+    }
+
+    /// unary := ('-' | '+') unary | power
+    fn unary(&mut self) -> Option<f64> {
+        if self.eat('-') {
+            Some(-self.unary()?)
+        } else if self.eat('+') {
+            self.unary()
+        } else {
+            self.power()
+        }
+    }
+
+    /// power := primary ('^' unary)?
+    fn power(&mut self) -> Option<f64> {
+        let base = self.primary()?;
+        if self.eat('^') {
+            Some(base.powf(self.unary()?))
+        } else {
+            Some(base)
+        }
+    }
+
+    /// primary := number | constant | function '(' expr ')' | '(' expr ')'
+    fn primary(&mut self) -> Option<f64> {
+        let ch = self.peek()?;
+        if self.eat('(') {
+            let value = self.expr()?;
+            return self.eat(')').then_some(value);
+        }
+
+        let start = self.pos;
+        if ch.is_ascii_digit() || ch == '.' {
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+            {
+                self.pos += 1;
+            }
+            // Exponent, only if followed by digits so that it isn't confused with `e`.
+            if matches!(self.chars.get(self.pos), Some('e' | 'E')) {
+                let mut end = self.pos + 1;
+                if matches!(self.chars.get(end), Some('+' | '-')) {
+                    end += 1;
+                }
+                if self.chars.get(end).is_some_and(|c| c.is_ascii_digit()) {
+                    self.pos = end;
+                    while self.chars.get(self.pos).is_some_and(|c| c.is_ascii_digit()) {
+                        self.pos += 1;
+                    }
+                }
+            }
+            let text: String = self.chars[start..self.pos].iter().collect();
+            return text.parse().ok();
+        }
+
+        if ch.is_ascii_alphabetic() {
+            while self
+                .chars
+                .get(self.pos)
+                .is_some_and(|c| c.is_ascii_alphanumeric())
+            {
+                self.pos += 1;
+            }
+            let name: String = self.chars[start..self.pos].iter().collect();
+            let function: fn(f64) -> f64 = match name.to_ascii_lowercase().as_str() {
+                "pi" => return Some(std::f64::consts::PI),
+                "tau" => return Some(std::f64::consts::TAU),
+                "e" => return Some(std::f64::consts::E),
+                "sqrt" => f64::sqrt,
+                "abs" => f64::abs,
+                "floor" => f64::floor,
+                "ceil" => f64::ceil,
+                "round" => f64::round,
+                "sin" => f64::sin,
+                "cos" => f64::cos,
+                "tan" => f64::tan,
+                "exp" => f64::exp,
+                "ln" => f64::ln,
+                "log" => f64::log10,
+                "rad" => f64::to_radians,
+                "deg" => f64::to_degrees,
+                _ => return None,
+            };
+            if !self.eat('(') {
+                return None;
+            }
+            let value = self.expr()?;
+            return self.eat(')').then(|| function(value));
+        }
+
+        None
+    }
+}
+
+/// Evaluate a numeric expression such as `"2*pi"`. If the expression starts with a compound
+/// assignment operator (`"+=0.5"`), it is applied to `current`. Returns `None` if the
+/// expression can't be parsed or the result is not finite.
+fn eval_expression(input: &str, current: f64) -> Option<f64> {
+    let input = input.trim();
+    let (op, input) = match input.get(..2) {
+        Some(prefix @ ("+=" | "-=" | "*=" | "/=")) => (prefix.chars().next(), &input[2..]),
+        _ => (None, input),
+    };
+    let mut parser = ExpressionParser {
+        chars: input.chars().collect(),
+        pos: 0,
+    };
+    let value = parser.expr()?;
+    if parser.peek().is_some() {
+        return None;
+    }
+    let result = match op {
+        Some('+') => current + value,
+        Some('-') => current - value,
+        Some('*') => current * value,
+        Some('/') => current / value,
+        _ => value,
+    };
+    result.is_finite().then_some(result)
+}
+
+/// Component used to hold the numeric entry params so that they can be accessed by the
+/// callbacks without capturing.
+#[derive(Component, Clone, Copy)]
+struct NumericEntryParams {
+    value: f32,
+    min: f32,
+    max: f32,
+    precision: usize,
+    on_change: Option<Callback<f32>>,
+    on_close: Callback,
+}
+
+/// The text which has been typed so far. Removed once the entry has been committed.
+#[derive(Component, Clone, Default)]
+struct NumericEntryText(String);
+
+/// Parse the typed text, call `on_change` with the clamped and rounded result if it is valid,
+/// then close the entry.
+fn commit_numeric_entry(world: &mut World, id: Entity) {
+    let Some(text) = world.entity_mut(id).take::<NumericEntryText>() else {
+        return;
+    };
+    let params = *world.get::<NumericEntryParams>(id).unwrap();
+    if let Some(value) = eval_expression(&text.0, params.value as f64) {
+        let rounding = f32::powi(10., params.precision as i32);
+        let value = ((value as f32 * rounding).round() / rounding).clamp(params.min, params.max);
+        if value != params.value {
+            if let Some(on_change) = params.on_change {
+                world.run_callback(on_change, value);
+            }
+        }
+    }
+    world.run_callback(params.on_close, ());
+}
+
+/// Inline text field used by [`SpinBox`](super::SpinBox) and [`Slider`](super::Slider) to type
+/// in an exact value. The entry is committed on Enter or when it loses focus, and `on_close`
+/// is called afterwards, or when the user presses Escape.
+#[derive(Clone, PartialEq)]
+pub(super) struct NumericEntry {
+    pub(super) value: f32,
+    pub(super) min: f32,
+    pub(super) max: f32,
+    pub(super) precision: usize,
+    pub(super) on_change: Option<Callback<f32>>,
+    pub(super) on_close: Callback,
+}
+
+impl ViewTemplate for NumericEntry {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focus_within(id);
+        let was_focused = cx.create_mutable(false);
+        let text = format!("{:.*}", self.precision, self.value);
+
+        let mut entt = cx.world_mut().entity_mut(id);
+        if !entt.contains::<NumericEntryText>() {
+            entt.insert(NumericEntryText(text.clone()));
+        }
+
+        // Commit when focus leaves the entry. The text input is focused after the first
+        // render, so wait until it has been focused once.
+        cx.create_effect(
+            move |world, focused| {
+                if focused {
+                    was_focused.set(world, true);
+                } else if was_focused.get(world) {
+                    commit_numeric_entry(world, id);
+                }
+            },
+            focused,
+        );
+
+        let on_text_change = cx.create_callback(move |value: In<String>, world: &mut World| {
+            if let Some(mut text) = world.get_mut::<NumericEntryText>(id) {
+                text.0 = value.clone();
+            }
+        });
+        let on_commit = cx.create_callback(move |_: In<String>, world: &mut World| {
+            commit_numeric_entry(world, id);
+        });
+        let on_cancel = cx.create_callback(move |world: &mut World| {
+            world.entity_mut(id).remove::<NumericEntryText>();
+            let on_close = world.get::<NumericEntryParams>(id).unwrap().on_close;
+            world.run_callback(on_close, ());
+        });
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("NumericEntry")
+            .style(style_numeric_entry)
+            .insert_dyn(
+                |(value, min, max, precision, on_change, on_close)| NumericEntryParams {
+                    value,
+                    min,
+                    max,
+                    precision,
+                    on_change,
+                    on_close,
+                },
+                (
+                    self.value,
+                    self.min,
+                    self.max,
+                    self.precision,
+                    self.on_change,
+                    self.on_close,
+                ),
+            )
+            .children(
+                TextInput::new()
+                    .value(text)
+                    .style(style_numeric_entry_input)
+                    .autofocus(true)
+                    .select_on_focus(true)
+                    .on_change(on_text_change)
+                    .on_commit(on_commit)
+                    .on_cancel(on_cancel),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::eval_expression;
+
+    fn eval(input: &str) -> Option<f64> {
+        eval_expression(input, 0.)
+    }
+
+    fn assert_near(value: Option<f64>, expected: f64) {
+        let value = value.expect("expression should evaluate");
+        assert!(
+            (value - expected).abs() < 1e-9,
+            "expected {}, got {}",
+            expected,
+            value
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(eval("42"), Some(42.));
+        assert_eq!(eval("  3.5 "), Some(3.5));
+        assert_eq!(eval(".25"), Some(0.25));
+        assert_eq!(eval("1e3"), Some(1000.));
+        assert_eq!(eval("2.5E-1"), Some(0.25));
+        assert_eq!(eval("1e+2"), Some(100.));
+    }
+
+    #[test]
+    fn test_precedence() {
+        assert_eq!(eval("1 + 2 * 3"), Some(7.));
+        assert_eq!(eval("(1 + 2) * 3"), Some(9.));
+        assert_eq!(eval("10 - 4 - 3"), Some(3.));
+        assert_eq!(eval("8 / 2 / 2"), Some(2.));
+        assert_eq!(eval("7 % 4 + 1"), Some(4.));
+        assert_eq!(eval("2 * 3 ^ 2"), Some(18.));
+        // Exponentiation is right-associative.
+        assert_eq!(eval("2 ^ 3 ^ 2"), Some(512.));
+        assert_eq!(eval("((2))"), Some(2.));
+    }
+
+    #[test]
+    fn test_unary() {
+        assert_eq!(eval("-3"), Some(-3.));
+        assert_eq!(eval("--3"), Some(3.));
+        assert_eq!(eval("+5"), Some(5.));
+        assert_eq!(eval("2 * -3"), Some(-6.));
+        assert_eq!(eval("4 - -1"), Some(5.));
+        // Unary minus binds more loosely than exponentiation.
+        assert_eq!(eval("-2 ^ 2"), Some(-4.));
+        assert_eq!(eval("2 ^ -1"), Some(0.5));
+    }
+
+    #[test]
+    fn test_constants_and_functions() {
+        assert_near(eval("2 * pi"), std::f64::consts::TAU);
+        assert_near(eval("PI"), std::f64::consts::PI);
+        assert_near(eval("e"), std::f64::consts::E);
+        assert_near(eval("sqrt(16)"), 4.);
+        assert_near(eval("abs(-2.5)"), 2.5);
+        assert_near(eval("floor(1.7) + ceil(1.2)"), 3.);
+        assert_near(eval("deg(pi)"), 180.);
+        assert_near(eval("rad(180)"), std::f64::consts::PI);
+        assert_near(eval("log(1000)"), 3.);
+        assert_near(eval("sin(0) + cos(0)"), 1.);
+    }
+
+    #[test]
+    fn test_compound_assignment() {
+        assert_eq!(eval_expression("+=0.5", 1.), Some(1.5));
+        assert_eq!(eval_expression("-= 1", 0.), Some(-1.));
+        assert_eq!(eval_expression("*=2", 3.), Some(6.));
+        assert_eq!(eval_expression("/=4", 2.), Some(0.5));
+        assert_eq!(eval_expression(" *= 1 + 1 ", 5.), Some(10.));
+        // Without an assignment operator, the current value is ignored.
+        assert_eq!(eval_expression("2", 5.), Some(2.));
+        assert_eq!(eval_expression("-2", 5.), Some(-2.));
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        assert_eq!(eval("1 / 0"), None);
+        assert_eq!(eval("0 / 0"), None);
+        assert_eq!(eval("5 % 0"), None);
+        assert_eq!(eval_expression("/=0", 5.), None);
+        assert_eq!(eval("ln(0)"), None);
+        assert_eq!(eval("sqrt(-1)"), None);
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(eval(""), None);
+        assert_eq!(eval("   "), None);
+        assert_eq!(eval("1 +"), None);
+        assert_eq!(eval("* 2"), None);
+        assert_eq!(eval("(1 + 2"), None);
+        assert_eq!(eval("1 + 2)"), None);
+        assert_eq!(eval("()"), None);
+        assert_eq!(eval("1 2"), None);
+        assert_eq!(eval("1..2"), None);
+        assert_eq!(eval("2e"), None);
+        assert_eq!(eval("foo(1)"), None);
+        assert_eq!(eval("sqrt 4"), None);
+        assert_eq!(eval("sqrt(4"), None);
+        assert_eq!(eval("+="), None);
+        assert_eq!(eval("1 # 2"), None);
+    }
+}
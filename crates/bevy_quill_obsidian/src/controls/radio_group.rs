@@ -0,0 +1,438 @@
+use bevy::{
+    a11y::{
+        accesskit::{Checked, NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{nav_group_with, KeyPressEvent, NavAxis, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    tokens, typography,
+};
+
+use super::{Disabled, IsDisabled};
+
+fn style_radio_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::FlexStart)
+        .gap(4);
+}
+
+fn style_radio(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::Center)
+        .align_items(ui::AlignItems::Center)
+        .gap(4)
+        .color(tokens::FOREGROUND);
+}
+
+fn style_radio_border(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .justify_content(ui::JustifyContent::Center)
+        .align_items(ui::AlignItems::Center)
+        .width(16)
+        .height(16)
+        .border_radius(8.0)
+        .cursor(CursorIcon::Pointer);
+}
+
+fn style_radio_dot(ss: &mut StyleBuilder) {
+    ss.width(6)
+        .height(6)
+        .border_radius(3.0)
+        .background_color(tokens::FOREGROUND);
+}
+
+fn style_radio_label(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::FlexStart)
+        .align_items(ui::AlignItems::Center);
+}
+
+/// A single choice within a [`RadioGroup`] or [`SegmentedControl`](super::SegmentedControl).
+#[derive(Clone, PartialEq)]
+pub struct RadioOption<T> {
+    /// The value selected by this option.
+    pub value: T,
+
+    /// The content to display for this option.
+    pub label: ViewChild,
+
+    /// Whether this option is disabled.
+    pub disabled: bool,
+}
+
+impl<T> RadioOption<T> {
+    /// Create a new option.
+    pub fn new(value: T, label: impl IntoViewChild) -> Self {
+        Self {
+            value,
+            label: label.into_view_child(),
+            disabled: false,
+        }
+    }
+
+    /// Set the disabled state of the option.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Component used to hold the group params so that they can be accessed by the callbacks
+/// without capturing. Shared between [`RadioGroup`] and
+/// [`SegmentedControl`](super::SegmentedControl).
+#[derive(Component)]
+pub(super) struct ChoiceGroupState<T: Send + Sync + 'static> {
+    pub(super) values: Vec<T>,
+    pub(super) disabled: Vec<bool>,
+    pub(super) selected: Option<usize>,
+    pub(super) on_change: Option<Callback<T>>,
+}
+
+/// Identifies an option entity within a choice group.
+#[derive(Component, Clone, Copy)]
+pub(super) struct ChoiceItem {
+    pub(super) group: Entity,
+    pub(super) index: usize,
+}
+
+/// Return the index of the option which receives focus when tabbing into the group: the
+/// selected option if it's enabled, otherwise the first enabled option.
+pub(super) fn roving_index(disabled: &[bool], selected: Option<usize>) -> Option<usize> {
+    selected
+        .filter(|index| !disabled.get(*index).copied().unwrap_or(true))
+        .or_else(|| disabled.iter().position(|d| !d))
+}
+
+/// Select an option, and move focus to it.
+pub(super) fn select_choice<T: Clone + PartialEq + Send + Sync + 'static>(
+    world: &mut World,
+    group: Entity,
+    index: usize,
+) {
+    if world.is_disabled(group) {
+        return;
+    }
+    let mut query = world.query::<(Entity, &ChoiceItem)>();
+    let item = query
+        .iter(world)
+        .find(|(_, item)| item.group == group && item.index == index)
+        .map(|(entity, _)| entity);
+    if let Some(item) = item {
+        world.get_resource_mut::<Focus>().unwrap().0 = Some(item);
+    }
+    let state = world.get::<ChoiceGroupState<T>>(group).unwrap();
+    if state.disabled.get(index).copied().unwrap_or(true) || state.selected == Some(index) {
+        return;
+    }
+    if let Some(on_change) = state.on_change {
+        let value = state.values[index].clone();
+        world.run_callback(on_change, value);
+    }
+}
+
+/// Select the option which navigation within the group moved focus to, so that the
+/// selection follows the focus.
+pub(super) fn follow_choice_focus<T: Clone + PartialEq + Send + Sync + 'static>(
+    world: &mut World,
+    focused: Entity,
+) {
+    if let Some(item) = world.get::<ChoiceItem>(focused).copied() {
+        select_choice::<T>(world, item.group, item.index);
+    }
+}
+
+/// A group of mutually exclusive options, each displayed as a round radio button with a
+/// label.
+///
+/// The group acts as a single tab stop: only the selected option is reachable with the tab
+/// key, and the arrow keys move the selection between options.
+#[derive(Clone, PartialEq)]
+pub struct RadioGroup<T: Clone + PartialEq + Send + Sync + 'static> {
+    /// The options to choose from.
+    pub options: Vec<RadioOption<T>>,
+
+    /// The currently selected value.
+    pub value: Option<T>,
+
+    /// Whether the entire group is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the group.
+    pub style: StyleHandle,
+
+    /// Callback called when the selection changes.
+    pub on_change: Option<Callback<T>>,
+
+    /// The tab index of the group (default 0).
+    pub tab_index: i32,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> Default for RadioGroup<T> {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            value: None,
+            disabled: false,
+            style: StyleHandle::default(),
+            on_change: None,
+            tab_index: 0,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> RadioGroup<T> {
+    /// Create a new radio group.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an option to the group.
+    pub fn option(mut self, option: RadioOption<T>) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Set the options of the group.
+    pub fn options(mut self, options: impl IntoIterator<Item = RadioOption<T>>) -> Self {
+        self.options = options.into_iter().collect();
+        self
+    }
+
+    /// Set the selected value.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the disabled state of the group.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the group.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the selection changes.
+    pub fn on_change(mut self, on_change: Callback<T>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the tab index of the group.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ViewTemplate for RadioGroup<T> {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let selected = self
+            .value
+            .as_ref()
+            .and_then(|value| self.options.iter().position(|o| o.value == *value));
+        let disabled: Vec<bool> = self
+            .options
+            .iter()
+            .map(|o| o.disabled || self.disabled)
+            .collect();
+        let roving = roving_index(&disabled, selected);
+        let tab_index = self.tab_index;
+        let group_disabled = self.disabled;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("RadioGroup")
+            .style((
+                typography::text_default,
+                style_radio_group,
+                self.style.clone(),
+            ))
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                |(values, disabled, selected, on_change)| ChoiceGroupState::<T> {
+                    values,
+                    disabled,
+                    selected,
+                    on_change,
+                },
+                (
+                    self.options
+                        .iter()
+                        .map(|o| o.value.clone())
+                        .collect::<Vec<_>>(),
+                    disabled.clone(),
+                    selected,
+                    self.on_change,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::RadioGroup)),
+                        nav_group_with(NavAxis::Linear, true, follow_choice_focus::<T>),
+                    )
+                },
+                (),
+            )
+            .children(For::index(&self.options, move |option, index| {
+                RadioButton {
+                    group: id,
+                    index,
+                    label: option.label.clone(),
+                    checked: selected == Some(index),
+                    disabled: option.disabled || group_disabled,
+                    tab_index: if roving == Some(index) { tab_index } else { -1 },
+                    select: select_choice::<T>,
+                }
+            }))
+    }
+}
+
+/// A single radio button within a [`RadioGroup`].
+#[derive(Clone, PartialEq)]
+struct RadioButton {
+    group: Entity,
+    index: usize,
+    label: ViewChild,
+    checked: bool,
+    disabled: bool,
+    tab_index: i32,
+    select: fn(&mut World, Entity, usize),
+}
+
+impl ViewTemplate for RadioButton {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let checked = self.checked;
+        let group = self.group;
+        let index = self.index;
+        let select = self.select;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("RadioButton")
+            .style(style_radio)
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(|(group, index)| ChoiceItem { group, index }, (group, index))
+            .insert_dyn(
+                |(checked, disabled)| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::RadioButton);
+                        builder.set_checked(if checked {
+                            Checked::True
+                        } else {
+                            Checked::False
+                        });
+                        if disabled {
+                            builder.set_disabled();
+                        }
+                        builder
+                    })
+                },
+                (checked, self.disabled),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            world
+                                .resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .stop_propagation();
+                            if !world.is_disabled(id) {
+                                select(world, group, index);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Enter
+                                        || event.key_code == KeyCode::Space)
+                                {
+                                    event.stop_propagation();
+                                    select(world, group, index);
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("RadioButton::Border")
+                    .style(style_radio_border)
+                    .style_dyn(
+                        |(checked, disabled, hovering), sb| {
+                            let color = match (checked, hovering) {
+                                (true, true) => tokens::ACCENT.darker(0.15),
+                                (true, false) => tokens::ACCENT.darker(0.2),
+                                (false, true) => tokens::U1.lighter(0.002),
+                                (false, false) => tokens::U1,
+                            };
+                            sb.background_color(if disabled {
+                                color.with_alpha(0.3)
+                            } else {
+                                color
+                            });
+                        },
+                        (checked, self.disabled, hovering),
+                    )
+                    .style_dyn(
+                        |focused, sb| {
+                            if focused {
+                                sb.outline_color(tokens::FOCUS)
+                                    .outline_offset(1.0)
+                                    .outline_width(2.0);
+                            } else {
+                                sb.outline_color(Option::<Color>::None);
+                            }
+                        },
+                        focused,
+                    )
+                    .children(Cond::new(
+                        checked,
+                        Element::<NodeBundle>::new().style(style_radio_dot),
+                        (),
+                    )),
+                Element::<NodeBundle>::new()
+                    .style(style_radio_label)
+                    .style_dyn(
+                        |disabled, sb| {
+                            sb.color(if disabled {
+                                tokens::FOREGROUND.with_alpha(0.3)
+                            } else {
+                                tokens::FOREGROUND
+                            });
+                        },
+                        self.disabled,
+                    )
+                    .children(self.label.clone()),
+            ))
+    }
+}
@@ -4,8 +4,8 @@ use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{
-    colors,
     scrolling::{ScrollArea, ScrollBar, ScrollBarThumb, ScrollWheel},
+    tokens,
 };
 
 // Style definitions for scrollview widget.
@@ -38,7 +38,7 @@ fn style_scrollbar_x(ss: &mut StyleBuilder) {
 }
 
 fn style_scrollbar_x_thumb(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U3.with_alpha(0.5))
+    ss.background_color(tokens::U3.with_alpha(0.5))
         .position(ui::PositionType::Absolute)
         .top(1)
         .bottom(1)
@@ -52,7 +52,7 @@ fn style_scrollbar_y(ss: &mut StyleBuilder) {
 }
 
 fn style_scrollbar_y_thumb(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U3.with_alpha(0.5))
+    ss.background_color(tokens::U3.with_alpha(0.5))
         .position(ui::PositionType::Absolute)
         .left(1)
         .right(1)
@@ -84,6 +84,8 @@ pub struct ScrollView {
     pub scroll_enable_x: bool,
     /// Whether to enable vertical scrolling.
     pub scroll_enable_y: bool,
+    /// Whether wheel scrolling coasts to a stop after the input ends.
+    pub kinetic: bool,
     /// Optional entity id to use for the scrolling element. This is useful for querying the
     /// current scroll position.
     pub entity: Option<Entity>,
@@ -119,6 +121,13 @@ impl ScrollView {
         self
     }
 
+    /// Enable kinetic scrolling, where touchpad scrolling continues with decaying momentum
+    /// after the fingers are lifted.
+    pub fn kinetic(mut self, kinetic: bool) -> Self {
+        self.kinetic = kinetic;
+        self
+    }
+
     /// Set the entity id to use for the scrolling element.
     /// This is useful for querying the current scroll position.
     pub fn entity(mut self, entity: Option<Entity>) -> Self {
@@ -149,7 +158,7 @@ impl ViewTemplate for ScrollView {
                 Element::<NodeBundle>::for_entity(id_scroll_area)
                     .named("ScrollView::ScrollArea")
                     .insert_dyn(
-                        move |_| {
+                        move |kinetic| {
                             (
                                 ScrollArea {
                                     id_scrollbar_x: if enable_x {
@@ -162,17 +171,24 @@ impl ViewTemplate for ScrollView {
                                     } else {
                                         None
                                     },
+                                    kinetic,
                                     ..default()
                                 },
-                                On::<ScrollWheel>::listener_component_mut::<ScrollArea>(
-                                    move |ev, scrolling| {
-                                        ev.stop_propagation();
-                                        scrolling.scroll_by(-ev.delta.x, -ev.delta.y);
-                                    },
-                                ),
+                                On::<ScrollWheel>::run(move |world: &mut World| {
+                                    let mut event =
+                                        world.resource_mut::<ListenerInput<ScrollWheel>>();
+                                    event.stop_propagation();
+                                    let delta = event.delta;
+                                    let dt = world.resource::<Time>().delta_seconds();
+                                    if let Some(mut scrolling) =
+                                        world.get_mut::<ScrollArea>(id_scroll_area)
+                                    {
+                                        scrolling.scroll_wheel(delta, dt);
+                                    }
+                                }),
                             )
                         },
-                        (),
+                        self.kinetic,
                     )
                     .style(style_scroll_region)
                     .children(self.children.clone()),
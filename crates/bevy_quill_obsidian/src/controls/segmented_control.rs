@@ -0,0 +1,333 @@
+use bevy::{
+    a11y::{
+        accesskit::{Checked, NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::{nav_group_with, KeyPressEvent, NavAxis, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    size::Size,
+    tokens, typography, RoundedCorners,
+};
+
+use super::{
+    button::{button_bg_color, style_button, style_button_bg},
+    radio_group::{follow_choice_focus, roving_index, select_choice, ChoiceGroupState, ChoiceItem},
+    ButtonVariant, Disabled, IsDisabled, RadioOption,
+};
+
+fn style_segmented_control(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .gap(1);
+}
+
+/// A row of joined buttons, exactly one of which is selected. This is a more compact
+/// alternative to [`RadioGroup`](super::RadioGroup), suited to choosing between modes.
+///
+/// Like a radio group, the control acts as a single tab stop, and the arrow keys move the
+/// selection between segments.
+#[derive(Clone, PartialEq)]
+pub struct SegmentedControl<T: Clone + PartialEq + Send + Sync + 'static> {
+    /// The segments to choose from.
+    pub options: Vec<RadioOption<T>>,
+
+    /// The currently selected value.
+    pub value: Option<T>,
+
+    /// Size of the segments.
+    pub size: Size,
+
+    /// Whether the entire control is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the control.
+    pub style: StyleHandle,
+
+    /// Callback called when the selection changes.
+    pub on_change: Option<Callback<T>>,
+
+    /// The tab index of the control (default 0).
+    pub tab_index: i32,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> Default for SegmentedControl<T> {
+    fn default() -> Self {
+        Self {
+            options: Vec::new(),
+            value: None,
+            size: Size::default(),
+            disabled: false,
+            style: StyleHandle::default(),
+            on_change: None,
+            tab_index: 0,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> SegmentedControl<T> {
+    /// Create a new segmented control.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a segment.
+    pub fn option(mut self, option: RadioOption<T>) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Set the segments.
+    pub fn options(mut self, options: impl IntoIterator<Item = RadioOption<T>>) -> Self {
+        self.options = options.into_iter().collect();
+        self
+    }
+
+    /// Set the selected value.
+    pub fn value(mut self, value: T) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Set the size of the segments.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set the disabled state of the control.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the control.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when the selection changes.
+    pub fn on_change(mut self, on_change: Callback<T>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the tab index of the control.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ViewTemplate for SegmentedControl<T> {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let selected = self
+            .value
+            .as_ref()
+            .and_then(|value| self.options.iter().position(|o| o.value == *value));
+        let disabled: Vec<bool> = self
+            .options
+            .iter()
+            .map(|o| o.disabled || self.disabled)
+            .collect();
+        let roving = roving_index(&disabled, selected);
+        let tab_index = self.tab_index;
+        let size = self.size;
+        let count = self.options.len();
+        let group_disabled = self.disabled;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("SegmentedControl")
+            .style((
+                typography::text_default,
+                style_segmented_control,
+                self.style.clone(),
+            ))
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                |(values, disabled, selected, on_change)| ChoiceGroupState::<T> {
+                    values,
+                    disabled,
+                    selected,
+                    on_change,
+                },
+                (
+                    self.options
+                        .iter()
+                        .map(|o| o.value.clone())
+                        .collect::<Vec<_>>(),
+                    disabled.clone(),
+                    selected,
+                    self.on_change,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::RadioGroup)),
+                        nav_group_with(NavAxis::Linear, true, follow_choice_focus::<T>),
+                    )
+                },
+                (),
+            )
+            .children(For::index(&self.options, move |option, index| Segment {
+                group: id,
+                index,
+                label: option.label.clone(),
+                selected: selected == Some(index),
+                disabled: option.disabled || group_disabled,
+                size,
+                corners: match (index == 0, index + 1 == count) {
+                    (true, true) => RoundedCorners::All,
+                    (true, false) => RoundedCorners::Left,
+                    (false, true) => RoundedCorners::Right,
+                    (false, false) => RoundedCorners::None,
+                },
+                tab_index: if roving == Some(index) { tab_index } else { -1 },
+                select: select_choice::<T>,
+            }))
+    }
+}
+
+/// A single button within a [`SegmentedControl`].
+#[derive(Clone, PartialEq)]
+struct Segment {
+    group: Entity,
+    index: usize,
+    label: ViewChild,
+    selected: bool,
+    disabled: bool,
+    size: Size,
+    corners: RoundedCorners,
+    tab_index: i32,
+    select: fn(&mut World, Entity, usize),
+}
+
+impl ViewTemplate for Segment {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let group = self.group;
+        let index = self.index;
+        let select = self.select;
+        let size = self.size;
+        let variant = if self.selected {
+            ButtonVariant::Selected
+        } else {
+            ButtonVariant::Default
+        };
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("SegmentedControl::Segment")
+            .style((style_button, move |ss: &mut StyleBuilder| {
+                ss.min_height(size.height_rem())
+                    .min_width(size.height_rem())
+                    .font_size(size.font_size())
+                    .padding((size.padding_rem(), 0));
+            }))
+            .style_dyn(
+                |disabled, sb| {
+                    sb.color(if disabled {
+                        tokens::FOREGROUND.with_alpha(0.2)
+                    } else {
+                        tokens::FOREGROUND
+                    });
+                },
+                self.disabled,
+            )
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(|(group, index)| ChoiceItem { group, index }, (group, index))
+            .insert_dyn(
+                |(checked, disabled)| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::RadioButton);
+                        builder.set_checked(if checked {
+                            Checked::True
+                        } else {
+                            Checked::False
+                        });
+                        if disabled {
+                            builder.set_disabled();
+                        }
+                        builder
+                    })
+                },
+                (self.selected, self.disabled),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            world
+                                .resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .stop_propagation();
+                            if !world.is_disabled(id) {
+                                select(world, group, index);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Enter
+                                        || event.key_code == KeyCode::Space)
+                                {
+                                    event.stop_propagation();
+                                    select(world, group, index);
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("SegmentedControl::Background")
+                    .style(style_button_bg)
+                    .insert_dyn(
+                        move |(corners, size)| corners.to_border_radius(size.border_radius()),
+                        (self.corners, size),
+                    )
+                    .style_dyn(
+                        |(variant, disabled, hovering), sb| {
+                            sb.background_color(button_bg_color(
+                                variant, disabled, false, hovering,
+                            ));
+                        },
+                        (variant, self.disabled, hovering),
+                    )
+                    .style_dyn(
+                        |focused, sb| {
+                            if focused {
+                                sb.outline_color(tokens::FOCUS)
+                                    .outline_width(2)
+                                    .outline_offset(2);
+                            } else {
+                                sb.outline_color(Option::<Color>::None);
+                            }
+                        },
+                        focused,
+                    ),
+                self.label.clone(),
+            ))
+    }
+}
@@ -0,0 +1,1085 @@
+use bevy::{
+    a11y::{
+        accesskit::{HasPopup, NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    floating::{FloatAlign, FloatPosition, FloatSide, Floating},
+    focus::{KeyCharEvent, KeyPressEvent, TabIndex},
+    hooks::{BistableTransitionState, CreateBistableTransition, UseIsFocus, UseIsHover},
+    scrolling::{scroll_into_view, ScrollAlignment},
+    size::Size,
+    tokens, typography,
+};
+
+use super::{
+    button_bg_color, menu::style_menu_barrier, style_button, ButtonVariant, Disabled, Icon,
+    IsDisabled, ScrollView, TextInput,
+};
+
+const ROW_HEIGHT: f32 = 24.;
+const GROUP_HEIGHT: f32 = 20.;
+const MAX_LIST_HEIGHT: f32 = 300.;
+
+const CHEVRON_DOWN: &str = "embedded://bevy_quill_obsidian/assets/icons/chevron_down.png";
+
+/// How long after the last keystroke type-ahead starts a new search, in seconds.
+const TYPE_AHEAD_TIMEOUT: f32 = 1.0;
+
+fn style_select(ss: &mut StyleBuilder) {
+    ss.padding_left(6).padding_right(2).border_radius(4.0);
+}
+
+fn style_select_label(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.)
+        .flex_shrink(1.)
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_select_combo(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center);
+}
+
+fn style_select_combo_toggle(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Center)
+        .align_self(ui::AlignSelf::Stretch)
+        .padding((2, 0));
+}
+
+fn style_select_popup(ss: &mut StyleBuilder) {
+    ss.background_color(tokens::U1)
+        .border_radius(4.0)
+        .position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .border_color(Srgba::BLACK)
+        .border(1)
+        .drop_shadow(0, 3, 8, Srgba::new(0., 0., 0., 0.5))
+        .padding(2)
+        .gap(2);
+}
+
+fn style_select_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .height(ROW_HEIGHT)
+        .padding_right(6)
+        .border_radius(3.0);
+}
+
+fn style_select_check(ss: &mut StyleBuilder) {
+    ss.width(16).height(16).margin((2, 0));
+}
+
+fn style_select_group(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Center)
+        .height(GROUP_HEIGHT)
+        .padding_left(6)
+        .font_size(12)
+        .color(tokens::DIM);
+}
+
+fn style_select_empty(ss: &mut StyleBuilder) {
+    ss.padding((6, 3)).color(tokens::DIM);
+}
+
+/// An option which can be chosen in a [`Select`].
+#[derive(Clone, PartialEq)]
+pub struct SelectOption<T> {
+    /// The value of the option.
+    pub value: T,
+
+    /// The text to display for the option. This is also what type-ahead and searching match.
+    pub label: String,
+
+    /// Whether the option can be chosen.
+    pub disabled: bool,
+
+    /// Name of the group the option is listed under. Consecutive options with the same group
+    /// are displayed under a single heading.
+    pub group: Option<String>,
+}
+
+impl<T> SelectOption<T> {
+    /// Create a new option with the given value and label.
+    pub fn new(value: T, label: impl Into<String>) -> Self {
+        Self {
+            value,
+            label: label.into(),
+            disabled: false,
+            group: None,
+        }
+    }
+
+    /// Set whether the option is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the group the option is listed under.
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        self.group = Some(group.into());
+        self
+    }
+}
+
+/// A drop-down control which shows the current value, and lets the user choose a different
+/// one from a list of options.
+///
+/// While the control has focus, typing the start of an option's label selects it. The list
+/// can optionally include a search field; in editable (combo box) mode, the control is a text
+/// field which filters the options as the user types, and which can also accept text that
+/// doesn't match any option.
+#[derive(Clone, PartialEq)]
+pub struct Select<T: Clone + PartialEq> {
+    /// The currently selected value, or `None` to show the placeholder.
+    pub value: Option<T>,
+
+    /// The options to choose from.
+    pub options: Vec<SelectOption<T>>,
+
+    /// Text to display when no value is selected.
+    pub placeholder: String,
+
+    /// If true, show a search field at the top of the list.
+    pub searchable: bool,
+
+    /// If true, display an editable text field instead of a button.
+    pub editable: bool,
+
+    /// Control size.
+    pub size: Size,
+
+    /// Whether the control is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the control.
+    pub style: StyleHandle,
+
+    /// The tab index of the control (default 0).
+    pub tab_index: i32,
+
+    /// Callback called when an option is chosen.
+    pub on_change: Option<Callback<T>>,
+
+    /// Callback called when text which doesn't match any option is entered in editable mode.
+    pub on_text: Option<Callback<String>>,
+}
+
+impl<T: Clone + PartialEq> Default for Select<T> {
+    fn default() -> Self {
+        Self {
+            value: None,
+            options: Vec::new(),
+            placeholder: String::new(),
+            searchable: false,
+            editable: false,
+            size: Size::default(),
+            disabled: false,
+            style: StyleHandle::default(),
+            tab_index: 0,
+            on_change: None,
+            on_text: None,
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> Select<T> {
+    /// Create a new select control.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the currently selected value.
+    pub fn value(mut self, value: impl Into<Option<T>>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the options to choose from.
+    pub fn options(mut self, options: Vec<SelectOption<T>>) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Add an option to choose from.
+    pub fn option(mut self, option: SelectOption<T>) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// Set the text to display when no value is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set whether to show a search field at the top of the list.
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// Set whether to display an editable text field instead of a button.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
+    /// Set the control size.
+    pub fn size(mut self, size: Size) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Set whether the control is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the control.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the tab index of the control.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Set the callback called when an option is chosen.
+    pub fn on_change(mut self, on_change: Callback<T>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the callback called when unmatched text is entered in editable mode.
+    pub fn on_text(mut self, on_text: Callback<String>) -> Self {
+        self.on_text = Some(on_text);
+        self
+    }
+}
+
+/// The current properties of a select control, read by its event handlers.
+#[derive(Component)]
+struct SelectParams<T: Send + Sync + 'static> {
+    options: Vec<SelectOption<T>>,
+    selected: Option<usize>,
+    searchable: bool,
+    editable: bool,
+    on_change: Option<Callback<T>>,
+    on_text: Option<Callback<String>>,
+}
+
+/// Characters typed recently, for type-ahead.
+#[derive(Component, Default)]
+struct TypeAhead {
+    prefix: String,
+    time: f32,
+}
+
+/// State shared by the parts of a select control.
+#[derive(Clone, Copy)]
+struct SelectState {
+    id: Entity,
+    open: Mutable<bool>,
+    filter: Mutable<String>,
+    highlight: Mutable<Option<usize>>,
+}
+
+impl<T: Clone + PartialEq + Send + Sync + 'static> ViewTemplate for Select<T> {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let open = cx.create_mutable::<bool>(false);
+        let filter = cx.create_mutable::<String>(String::new());
+        let highlight = cx.create_mutable::<Option<usize>>(None);
+        let st = SelectState {
+            id,
+            open,
+            filter,
+            highlight,
+        };
+        let state = cx.create_bistable_transition(open.get(cx), 0.01);
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let editable = self.editable;
+        let searchable = self.searchable && !editable;
+        let size = self.size;
+
+        let mut entt = cx.world_mut().entity_mut(id);
+        if !entt.contains::<TypeAhead>() {
+            entt.insert(TypeAhead::default());
+        }
+
+        let on_pick = cx.create_callback(move |index: In<usize>, world: &mut World| {
+            pick_option::<T>(world, st, *index);
+        });
+        let on_filter = cx.create_callback(move |text: In<String>, world: &mut World| {
+            let text = text.0;
+            let Some(params) = world.get::<SelectParams<T>>(id) else {
+                return;
+            };
+            let visible = visible_options(&params.options, &text);
+            let first = step_option(&params.options, &visible, None, 1);
+            let editable = params.editable;
+            filter.set_clone(world, text);
+            highlight.set(world, first);
+            if editable {
+                open.set(world, true);
+            }
+        });
+        let on_commit = cx.create_callback(move |text: In<String>, world: &mut World| {
+            commit_select_text::<T>(world, st, &text);
+        });
+        let on_cancel = cx.create_callback(move |world: &mut World| {
+            close_select::<T>(world, st);
+        });
+
+        let selected = self
+            .value
+            .as_ref()
+            .and_then(|value| self.options.iter().position(|opt| opt.value == *value));
+        let label = selected.map(|index| self.options[index].label.clone());
+        let show_placeholder = label.is_none();
+
+        // Build the list of visible options, with a heading wherever the group changes.
+        let filter_text = filter.get_clone(cx);
+        let visible = visible_options(&self.options, &filter_text);
+        let current = highlight.get(cx);
+        let mut rows: Vec<ViewChild> = Vec::with_capacity(visible.len());
+        let mut list_height = 0.;
+        let mut group: Option<&String> = None;
+        for index in visible.iter().copied() {
+            let option = &self.options[index];
+            if option.group.is_some() && option.group.as_ref() != group {
+                rows.push(
+                    Element::<NodeBundle>::new()
+                        .named("Select::Group")
+                        .style(style_select_group)
+                        .children(option.group.clone().unwrap())
+                        .into_view_child(),
+                );
+                list_height += GROUP_HEIGHT;
+            }
+            group = option.group.as_ref();
+            rows.push(
+                SelectRow {
+                    index,
+                    label: option.label.clone(),
+                    selected: selected == Some(index),
+                    highlighted: current == Some(index),
+                    disabled: option.disabled,
+                    highlight,
+                    on_pick,
+                }
+                .into_view_child(),
+            );
+            list_height += ROW_HEIGHT;
+        }
+        let no_matches = rows.is_empty();
+        let list_height = list_height.clamp(ROW_HEIGHT, MAX_LIST_HEIGHT);
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Select")
+            .style((
+                typography::text_default,
+                style_button,
+                style_select,
+                move |ss: &mut StyleBuilder| {
+                    ss.min_height(size.height_rem()).font_size(size.font_size());
+                },
+                self.style.clone(),
+            ))
+            .insert_dyn(
+                |(options, selected, searchable, editable, on_change, on_text)| SelectParams {
+                    options,
+                    selected,
+                    searchable,
+                    editable,
+                    on_change,
+                    on_text,
+                },
+                (
+                    self.options.clone(),
+                    selected,
+                    searchable,
+                    editable,
+                    self.on_change,
+                    self.on_text,
+                ),
+            )
+            // In editable mode, the text field takes focus instead.
+            .insert_dyn(TabIndex, if editable { -1 } else { self.tab_index })
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let editable = world
+                                .get::<SelectParams<T>>(id)
+                                .is_some_and(|params| params.editable);
+                            if editable || world.is_disabled(id) {
+                                return;
+                            }
+                            world.resource_mut::<Focus>().0 = Some(id);
+                            stop_click_propagation(world);
+                            toggle_select::<T>(world, st);
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            select_key_press::<T>(world, st);
+                        }),
+                        On::<KeyCharEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyCharEvent>>()
+                                .unwrap();
+                            let key = event.key;
+                            if key.is_control() || key == ' ' {
+                                return;
+                            }
+                            event.stop_propagation();
+                            type_ahead::<T>(world, st, key);
+                        }),
+                    )
+                },
+                (),
+            )
+            .insert_dyn(
+                move |open| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::ComboBox);
+                        builder.set_has_popup(HasPopup::Listbox);
+                        builder.set_expanded(open);
+                        builder
+                    })
+                },
+                open.get(cx),
+            )
+            .insert_if(self.disabled, || Disabled)
+            .style_dyn(
+                move |(editable, disabled, open, hovering), sb| {
+                    if editable {
+                        sb.background_color(tokens::TRANSPARENT)
+                            .border(0)
+                            .padding(0);
+                    } else {
+                        let color =
+                            button_bg_color(ButtonVariant::Default, disabled, open, hovering);
+                        sb.background_color(color);
+                    }
+                },
+                (editable, self.disabled, open.get(cx), hovering),
+            )
+            .style_dyn(
+                move |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_width(2)
+                            .outline_offset(2);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused && !editable,
+            )
+            .children((
+                Cond::new(
+                    editable,
+                    Element::<NodeBundle>::new()
+                        .named("Select::Combo")
+                        .style((style_select_combo, style_select_label))
+                        .children((
+                            TextInput::new()
+                                .value(label.clone().unwrap_or_default())
+                                .placeholder(self.placeholder.clone())
+                                .disabled(self.disabled)
+                                .tab_index(self.tab_index)
+                                .select_on_focus(true)
+                                .style(|ss: &mut StyleBuilder| {
+                                    ss.flex_grow(1.);
+                                })
+                                .on_change(on_filter)
+                                .on_commit(on_commit)
+                                .on_cancel(on_cancel),
+                            Element::<NodeBundle>::new()
+                                .named("Select::Toggle")
+                                .style(style_select_combo_toggle)
+                                .insert_dyn(
+                                    move |_| {
+                                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                                            stop_click_propagation(world);
+                                            if !world.is_disabled(id) {
+                                                toggle_select::<T>(world, st);
+                                            }
+                                        })
+                                    },
+                                    (),
+                                )
+                                .children(Icon::new(CHEVRON_DOWN).color(tokens::DIM)),
+                        )),
+                    (
+                        Element::<NodeBundle>::new()
+                            .named("Select::Label")
+                            .style(style_select_label)
+                            .style_dyn(
+                                |show_placeholder, sb| {
+                                    sb.color(if show_placeholder {
+                                        tokens::DIM
+                                    } else {
+                                        tokens::FOREGROUND
+                                    });
+                                },
+                                show_placeholder,
+                            )
+                            .children(label.unwrap_or_else(|| self.placeholder.clone())),
+                        Icon::new(CHEVRON_DOWN).color(tokens::DIM).style(
+                            |ss: &mut StyleBuilder| {
+                                ss.margin_left(4);
+                            },
+                        ),
+                    ),
+                ),
+                Cond::new(
+                    state != BistableTransitionState::Exited,
+                    Portal::new(
+                        Element::<NodeBundle>::new()
+                            .named("Select::Barrier")
+                            .style(style_menu_barrier)
+                            .insert_dyn(
+                                move |_| {
+                                    (
+                                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                                            stop_click_propagation(world);
+                                            close_select::<T>(world, st);
+                                        }),
+                                        ZIndex::Global(100),
+                                    )
+                                },
+                                (),
+                            )
+                            .children(
+                                Element::<NodeBundle>::new()
+                                    .named("Select::Popup")
+                                    .style((typography::text_default, style_select_popup))
+                                    .insert_dyn(
+                                        |anchor| Floating {
+                                            anchor,
+                                            position: vec![
+                                                FloatPosition {
+                                                    side: FloatSide::Bottom,
+                                                    align: FloatAlign::Start,
+                                                    stretch: true,
+                                                    gap: 2.0,
+                                                },
+                                                FloatPosition {
+                                                    side: FloatSide::Top,
+                                                    align: FloatAlign::Start,
+                                                    stretch: true,
+                                                    gap: 2.0,
+                                                },
+                                            ],
+                                        },
+                                        id,
+                                    )
+                                    .insert_dyn(
+                                        move |_| {
+                                            (
+                                                AccessibilityNode::from(NodeBuilder::new(
+                                                    Role::ListBox,
+                                                )),
+                                                On::<Pointer<Click>>::run(
+                                                    move |world: &mut World| {
+                                                        stop_click_propagation(world);
+                                                    },
+                                                ),
+                                                // Navigation keys from the search field.
+                                                On::<KeyPressEvent>::run(
+                                                    move |world: &mut World| {
+                                                        select_key_press::<T>(world, st);
+                                                    },
+                                                ),
+                                            )
+                                        },
+                                        (),
+                                    )
+                                    .children((
+                                        Cond::new(
+                                            searchable,
+                                            TextInput::new()
+                                                .value(filter_text)
+                                                .placeholder("Search...")
+                                                .autofocus(true)
+                                                .on_change(on_filter)
+                                                .on_commit(on_commit)
+                                                .on_cancel(on_cancel),
+                                            (),
+                                        ),
+                                        Cond::new(
+                                            no_matches,
+                                            Element::<NodeBundle>::new()
+                                                .named("Select::Empty")
+                                                .style(style_select_empty)
+                                                .children("No matches"),
+                                            ScrollView::new()
+                                                .scroll_enable_y(true)
+                                                .style(move |ss: &mut StyleBuilder| {
+                                                    ss.height(list_height);
+                                                })
+                                                .children(rows),
+                                        ),
+                                    )),
+                            ),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// A single option in the list of a [`Select`].
+#[derive(Clone, PartialEq)]
+struct SelectRow {
+    index: usize,
+    label: String,
+    selected: bool,
+    highlighted: bool,
+    disabled: bool,
+    highlight: Mutable<Option<usize>>,
+    on_pick: Callback<usize>,
+}
+
+impl ViewTemplate for SelectRow {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let row = cx.create_entity();
+        let index = self.index;
+        let highlight = self.highlight;
+        let on_pick = self.on_pick;
+
+        // Keep the option highlighted by the keyboard or type-ahead within the visible list.
+        cx.create_effect(
+            |world, (row, highlighted)| {
+                if highlighted {
+                    scroll_into_view(world, row, ScrollAlignment::Nearest, false);
+                }
+            },
+            (row, self.highlighted),
+        );
+
+        Element::<NodeBundle>::for_entity(row)
+            .named("Select::Option")
+            .style(style_select_row)
+            .style_dyn(
+                |(highlighted, disabled), sb| {
+                    sb.background_color(if highlighted && !disabled {
+                        tokens::U1.lighter(0.03)
+                    } else {
+                        tokens::TRANSPARENT
+                    })
+                    .color(if disabled {
+                        tokens::FOREGROUND.with_alpha(0.2)
+                    } else {
+                        tokens::FOREGROUND
+                    });
+                },
+                (self.highlighted, self.disabled),
+            )
+            .insert_dyn(
+                move |(selected, disabled)| {
+                    (
+                        AccessibilityNode::from({
+                            let mut builder = NodeBuilder::new(Role::ListBoxOption);
+                            builder.set_selected(selected);
+                            if disabled {
+                                builder.set_disabled();
+                            }
+                            builder
+                        }),
+                        On::<Pointer<Over>>::run(move |world: &mut World| {
+                            if !disabled {
+                                highlight.set(world, Some(index));
+                            }
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            stop_click_propagation(world);
+                            world.run_callback(on_pick, index);
+                        }),
+                    )
+                },
+                (self.selected, self.disabled),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(style_select_check)
+                    .children(Cond::new(
+                        self.selected,
+                        Icon::new("embedded://bevy_quill_obsidian/assets/icons/checkmark.png")
+                            .color(tokens::DIM),
+                        (),
+                    )),
+                self.label.clone(),
+            ))
+    }
+}
+
+fn stop_click_propagation(world: &mut World) {
+    world
+        .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+        .unwrap()
+        .stop_propagation();
+}
+
+/// Indices of the options whose labels contain the filter text, ignoring case.
+fn visible_options<T>(options: &[SelectOption<T>], filter: &str) -> Vec<usize> {
+    let filter = filter.to_lowercase();
+    options
+        .iter()
+        .enumerate()
+        .filter(|(_, option)| option.label.to_lowercase().contains(&filter))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// Move `step` places from `current` through the visible options which are enabled, stopping
+/// at either end. If there's no current option, start from the first (or last) one.
+fn step_option<T>(
+    options: &[SelectOption<T>],
+    visible: &[usize],
+    current: Option<usize>,
+    step: i32,
+) -> Option<usize> {
+    let enabled: Vec<usize> = visible
+        .iter()
+        .copied()
+        .filter(|index| !options[*index].disabled)
+        .collect();
+    if enabled.is_empty() {
+        return None;
+    }
+    let last = enabled.len() as i32 - 1;
+    let next = match current.and_then(|c| enabled.iter().position(|index| *index == c)) {
+        Some(pos) => (pos as i32 + step).clamp(0, last),
+        None if step > 0 => 0,
+        None => last,
+    };
+    Some(enabled[next as usize])
+}
+
+fn open_select<T: Send + Sync + 'static>(world: &mut World, st: SelectState) {
+    let Some(params) = world.get::<SelectParams<T>>(st.id) else {
+        return;
+    };
+    let all: Vec<usize> = (0..params.options.len()).collect();
+    let current = params
+        .selected
+        .filter(|index| !params.options[*index].disabled)
+        .or_else(|| step_option(&params.options, &all, None, 1));
+    st.filter.set_clone(world, String::new());
+    st.highlight.set(world, current);
+    st.open.set(world, true);
+}
+
+fn close_select<T: Send + Sync + 'static>(world: &mut World, st: SelectState) {
+    if !st.open.get(world) {
+        return;
+    }
+    st.open.set(world, false);
+    // Focus was in the search field, which is about to go away.
+    if world
+        .get::<SelectParams<T>>(st.id)
+        .is_some_and(|params| params.searchable)
+    {
+        world.resource_mut::<Focus>().0 = Some(st.id);
+    }
+}
+
+fn toggle_select<T: Send + Sync + 'static>(world: &mut World, st: SelectState) {
+    if st.open.get(world) {
+        close_select::<T>(world, st);
+    } else {
+        open_select::<T>(world, st);
+    }
+}
+
+fn pick_option<T: Clone + Send + Sync + 'static>(world: &mut World, st: SelectState, index: usize) {
+    let Some(params) = world.get::<SelectParams<T>>(st.id) else {
+        return;
+    };
+    let Some(option) = params.options.get(index) else {
+        return;
+    };
+    if option.disabled {
+        return;
+    }
+    let value = option.value.clone();
+    let on_change = params.on_change;
+    close_select::<T>(world, st);
+    if let Some(on_change) = on_change {
+        world.run_callback(on_change, value);
+    }
+}
+
+/// Called when Enter is pressed in the search field, or text is committed in editable mode.
+fn commit_select_text<T: Clone + Send + Sync + 'static>(
+    world: &mut World,
+    st: SelectState,
+    text: &str,
+) {
+    if st.open.get(world) {
+        if let Some(index) = st.highlight.get(world) {
+            pick_option::<T>(world, st, index);
+            return;
+        }
+    }
+    let Some(params) = world.get::<SelectParams<T>>(st.id) else {
+        return;
+    };
+    if !params.editable {
+        return;
+    }
+    let matching = params
+        .options
+        .iter()
+        .position(|option| !option.disabled && option.label == text);
+    let on_text = params.on_text;
+    match matching {
+        Some(index) => pick_option::<T>(world, st, index),
+        None => {
+            close_select::<T>(world, st);
+            if let Some(on_text) = on_text {
+                world.run_callback(on_text, text.to_string());
+            }
+        }
+    }
+}
+
+enum SelectAction {
+    Open,
+    Close,
+    Highlight(Option<usize>),
+    Pick(usize),
+}
+
+fn select_key_press<T: Clone + Send + Sync + 'static>(world: &mut World, st: SelectState) {
+    if world.is_disabled(st.id) {
+        return;
+    }
+    let Some(params) = world.get::<SelectParams<T>>(st.id) else {
+        return;
+    };
+    let event = world.resource::<ListenerInput<KeyPressEvent>>();
+    let action = if !st.open.get(world) {
+        match event.key_code {
+            KeyCode::ArrowDown | KeyCode::ArrowUp => Some(SelectAction::Open),
+            KeyCode::Enter | KeyCode::Space if !params.editable && !event.repeat => {
+                Some(SelectAction::Open)
+            }
+            _ => None,
+        }
+    } else {
+        let visible = visible_options(&params.options, &st.filter.get_clone(world));
+        let current = st.highlight.get(world);
+        match event.key_code {
+            KeyCode::ArrowDown => Some(SelectAction::Highlight(step_option(
+                &params.options,
+                &visible,
+                current,
+                1,
+            ))),
+            KeyCode::ArrowUp => Some(SelectAction::Highlight(step_option(
+                &params.options,
+                &visible,
+                current,
+                -1,
+            ))),
+            KeyCode::Home => Some(SelectAction::Highlight(step_option(
+                &params.options,
+                &visible,
+                None,
+                1,
+            ))),
+            KeyCode::End => Some(SelectAction::Highlight(step_option(
+                &params.options,
+                &visible,
+                None,
+                -1,
+            ))),
+            KeyCode::Enter | KeyCode::Space if !event.repeat => match current {
+                Some(index) => Some(SelectAction::Pick(index)),
+                None => Some(SelectAction::Close),
+            },
+            KeyCode::Escape => Some(SelectAction::Close),
+            _ => None,
+        }
+    };
+    let Some(action) = action else {
+        return;
+    };
+
+    world
+        .resource_mut::<ListenerInput<KeyPressEvent>>()
+        .stop_propagation();
+    match action {
+        SelectAction::Open => open_select::<T>(world, st),
+        SelectAction::Close => close_select::<T>(world, st),
+        SelectAction::Highlight(index) => st.highlight.set(world, index),
+        SelectAction::Pick(index) => pick_option::<T>(world, st, index),
+    }
+}
+
+/// Select (or highlight, if the list is open) the next option whose label starts with the
+/// characters typed so far.
+fn type_ahead<T: Clone + Send + Sync + 'static>(world: &mut World, st: SelectState, key: char) {
+    let now = world.resource::<Time>().elapsed_seconds();
+    let mut typed = world.get_mut::<TypeAhead>(st.id).unwrap();
+    if now - typed.time > TYPE_AHEAD_TIMEOUT {
+        typed.prefix.clear();
+    }
+    typed.time = now;
+    typed.prefix.extend(key.to_lowercase());
+    let prefix = typed.prefix.clone();
+
+    let Some(params) = world.get::<SelectParams<T>>(st.id) else {
+        return;
+    };
+    let open = st.open.get(world);
+    let current = if open {
+        st.highlight.get(world)
+    } else {
+        params.selected
+    };
+
+    let on_change = params.on_change;
+    let found = find_type_ahead(&params.options, &prefix, current);
+
+    match (found, open) {
+        (Some(index), true) => st.highlight.set(world, Some(index)),
+        (Some(index), false) if current != Some(index) => {
+            let value = params.options[index].value.clone();
+            if let Some(on_change) = on_change {
+                world.run_callback(on_change, value);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Find the next enabled option after `current` whose label starts with the lowercase `typed`
+/// text, wrapping around at the end of the list.
+fn find_type_ahead<T>(
+    options: &[SelectOption<T>],
+    typed: &str,
+    current: Option<usize>,
+) -> Option<usize> {
+    // Typing the same character repeatedly cycles through the options starting with it.
+    let mut chars = typed.chars();
+    let first = chars.next()?;
+    let (prefix, skip) = if chars.all(|c| c == first) {
+        (first.to_string(), 1)
+    } else {
+        (typed.to_string(), 0)
+    };
+    let count = options.len();
+    let start = current.map_or(0, |c| c + skip);
+    (0..count).map(|i| (start + i) % count).find(|index| {
+        let option = &options[*index];
+        !option.disabled && option.label.to_lowercase().starts_with(&prefix)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{find_type_ahead, step_option, visible_options, SelectOption};
+
+    fn options() -> Vec<SelectOption<u32>> {
+        vec![
+            SelectOption::new(0, "Apple"),
+            SelectOption::new(1, "Banana"),
+            SelectOption::new(2, "Blueberry").disabled(true),
+            SelectOption::new(3, "Blackberry"),
+            SelectOption::new(4, "Cherry").group("Red"),
+        ]
+    }
+
+    #[test]
+    fn test_visible_options() {
+        let options = options();
+        assert_eq!(visible_options(&options, ""), [0, 1, 2, 3, 4]);
+        assert_eq!(visible_options(&options, "BERRY"), [2, 3]);
+        assert_eq!(visible_options(&options, "an"), [1]);
+        assert!(visible_options(&options, "xyz").is_empty());
+        assert!(visible_options::<u32>(&[], "").is_empty());
+    }
+
+    #[test]
+    fn test_step_option() {
+        let options = options();
+        let all = visible_options(&options, "");
+        assert_eq!(step_option(&options, &all, None, 1), Some(0));
+        assert_eq!(step_option(&options, &all, None, -1), Some(4));
+        // Disabled options are skipped.
+        assert_eq!(step_option(&options, &all, Some(1), 1), Some(3));
+        assert_eq!(step_option(&options, &all, Some(3), -1), Some(1));
+    }
+
+    #[test]
+    fn test_step_option_out_of_range() {
+        let options = options();
+        let all = visible_options(&options, "");
+        // Stepping stops at either end rather than wrapping.
+        assert_eq!(step_option(&options, &all, Some(4), 1), Some(4));
+        assert_eq!(step_option(&options, &all, Some(0), -1), Some(0));
+        assert_eq!(step_option(&options, &all, Some(0), 10), Some(4));
+        // A current option which is hidden or disabled counts as no current option.
+        let berries = visible_options(&options, "berry");
+        assert_eq!(step_option(&options, &berries, Some(0), 1), Some(3));
+        assert_eq!(step_option(&options, &all, Some(2), -1), Some(4));
+        assert_eq!(step_option(&options, &[2], None, 1), None);
+        assert_eq!(step_option(&options, &[], None, 1), None);
+    }
+
+    #[test]
+    fn test_type_ahead() {
+        let options = options();
+        assert_eq!(find_type_ahead(&options, "b", None), Some(1));
+        assert_eq!(find_type_ahead(&options, "bl", None), Some(3));
+        assert_eq!(find_type_ahead(&options, "che", Some(4)), Some(4));
+        assert_eq!(find_type_ahead(&options, "z", None), None);
+        assert_eq!(find_type_ahead(&options, "", None), None);
+        assert_eq!(find_type_ahead::<u32>(&[], "a", None), None);
+    }
+
+    #[test]
+    fn test_type_ahead_cycles() {
+        let options = options();
+        // Repeating a character moves on to the next option starting with it, wrapping around
+        // and skipping disabled options.
+        assert_eq!(find_type_ahead(&options, "b", Some(1)), Some(3));
+        assert_eq!(find_type_ahead(&options, "bb", Some(1)), Some(3));
+        assert_eq!(find_type_ahead(&options, "b", Some(3)), Some(1));
+        assert_eq!(find_type_ahead(&options, "c", Some(4)), Some(4));
+        // A longer prefix matches from the current option onwards.
+        assert_eq!(find_type_ahead(&options, "ba", Some(1)), Some(1));
+    }
+}
@@ -0,0 +1,474 @@
+use std::{collections::HashMap, fmt};
+
+use bevy::{a11y::Focus, prelude::*};
+use bevy_quill_core::*;
+use serde::{Deserialize, Serialize};
+
+use super::text_buffer::{is_edit_key, TextBuffer};
+
+/// A key combination which triggers an action, such as Ctrl+Z.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Shortcut {
+    /// The key which triggers the shortcut.
+    pub key: KeyCode,
+    /// Whether the Control (or Command) key must be held.
+    pub ctrl: bool,
+    /// Whether the Shift key must be held.
+    pub shift: bool,
+    /// Whether the Alt key must be held.
+    pub alt: bool,
+}
+
+impl Shortcut {
+    /// Create a new shortcut for the given key, with no modifiers.
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+        }
+    }
+
+    /// Require the Control (or Command) key.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Require the Shift key.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Require the Alt key.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// True if this shortcut's key was just pressed, with exactly the required modifiers held.
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>) -> bool {
+        keys.just_pressed(self.key)
+            && self.ctrl == ctrl_held(keys)
+            && self.shift == shift_held(keys)
+            && self.alt == keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+    }
+}
+
+/// True if either Control key, or either Command key, is held.
+fn ctrl_held(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ])
+}
+
+fn shift_held(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight])
+}
+
+/// True if the Control (or Command) key is currently held.
+pub(super) fn is_ctrl_pressed(world: &World) -> bool {
+    ctrl_held(world.resource::<ButtonInput<KeyCode>>())
+}
+
+/// True if the Shift key is currently held.
+pub(super) fn is_shift_pressed(world: &World) -> bool {
+    shift_held(world.resource::<ButtonInput<KeyCode>>())
+}
+
+impl fmt::Display for Shortcut {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.ctrl {
+            f.write_str("Ctrl+")?;
+        }
+        if self.alt {
+            f.write_str("Alt+")?;
+        }
+        if self.shift {
+            f.write_str("Shift+")?;
+        }
+        let name = format!("{:?}", self.key);
+        let name = name
+            .strip_prefix("Key")
+            .or_else(|| name.strip_prefix("Digit"))
+            .unwrap_or(&name);
+        match self.key {
+            KeyCode::ArrowUp => f.write_str("Up"),
+            KeyCode::ArrowDown => f.write_str("Down"),
+            KeyCode::ArrowLeft => f.write_str("Left"),
+            KeyCode::ArrowRight => f.write_str("Right"),
+            KeyCode::Escape => f.write_str("Esc"),
+            KeyCode::Delete => f.write_str("Del"),
+            KeyCode::Equal => f.write_str("="),
+            KeyCode::Minus => f.write_str("-"),
+            KeyCode::Comma => f.write_str(","),
+            KeyCode::Period => f.write_str("."),
+            KeyCode::Slash => f.write_str("/"),
+            _ => f.write_str(name),
+        }
+    }
+}
+
+/// Determines when a command is available. Commands whose context is inactive are not triggered
+/// by their shortcut, and are not listed in the [`CommandPalette`](super::CommandPalette).
+#[derive(Debug, Clone, Copy, Default)]
+pub enum CommandContext {
+    /// The command is always available.
+    #[default]
+    Global,
+    /// The command is available while the given entity, or one of its descendants, has focus.
+    /// Use this for commands which belong to a particular widget or panel.
+    FocusWithin(Entity),
+    /// The command is available while the predicate returns true.
+    When(fn(&World) -> bool),
+}
+
+impl CommandContext {
+    /// Returns how closely this context matches the current state, or `None` if it is inactive.
+    /// Lower values are more specific: a command bound to the focused panel takes precedence
+    /// over one which is conditionally enabled, which takes precedence over a global command.
+    fn rank(&self, world: &World, focus: Option<Entity>) -> Option<usize> {
+        match self {
+            CommandContext::Global => Some(usize::MAX),
+            CommandContext::When(predicate) => predicate(world).then_some(usize::MAX - 1),
+            CommandContext::FocusWithin(target) => {
+                let mut entity = focus;
+                let mut depth = 0;
+                while let Some(ent) = entity {
+                    if ent == *target {
+                        return Some(depth);
+                    }
+                    entity = world.get::<Parent>(ent).map(|p| p.get());
+                    depth += 1;
+                }
+                None
+            }
+        }
+    }
+
+    /// True if commands in both contexts could be triggered by the same key press, with
+    /// neither taking precedence over the other.
+    fn overlaps(&self, other: &CommandContext) -> bool {
+        match (self, other) {
+            (CommandContext::Global, CommandContext::Global) => true,
+            (CommandContext::When(_), CommandContext::When(_)) => true,
+            (CommandContext::FocusWithin(a), CommandContext::FocusWithin(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// True if the context is active, given the entity which has focus.
+    pub fn is_active(&self, world: &World, focus: Option<Entity>) -> bool {
+        self.rank(world, focus).is_some()
+    }
+}
+
+/// A named command which can be run from a keyboard shortcut, a menu, or the
+/// [`CommandPalette`](super::CommandPalette).
+#[derive(Clone)]
+pub struct CommandDef {
+    /// Unique id of the command, such as "graph.duplicate".
+    pub id: &'static str,
+    /// Human-readable name of the command.
+    pub title: String,
+    /// The default key combination which triggers the command, if any.
+    pub shortcut: Option<Shortcut>,
+    /// When the command is available.
+    pub context: CommandContext,
+    /// Callback which runs the command.
+    pub callback: Callback,
+}
+
+impl CommandDef {
+    /// Create a new command with no shortcut, which is always available.
+    pub fn new(id: &'static str, title: impl Into<String>, callback: Callback) -> Self {
+        Self {
+            id,
+            title: title.into(),
+            shortcut: None,
+            context: CommandContext::Global,
+            callback,
+        }
+    }
+
+    /// Set the default key combination of the command.
+    pub fn shortcut(mut self, shortcut: Shortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
+    /// Set when the command is available.
+    pub fn context(mut self, context: CommandContext) -> Self {
+        self.context = context;
+        self
+    }
+}
+
+/// Resource which holds the registered commands and their keyboard shortcuts. Registered
+/// shortcuts are triggered whenever their keys are pressed, and [`MenuItem`](super::MenuItem)s
+/// which refer to them by id display the key combination.
+///
+/// Each command has a default shortcut, which the user can override with
+/// [`set_binding`](Self::set_binding). Overrides are kept separately from the commands, so they
+/// survive commands being re-registered, and can be saved and restored as a whole.
+///
+/// While a text field has focus, shortcuts are not triggered by typing, or by keys which the
+/// text field handles itself.
+#[derive(Resource, Default)]
+pub struct ShortcutRegistry {
+    commands: Vec<CommandDef>,
+    overrides: HashMap<String, Option<Shortcut>>,
+}
+
+impl ShortcutRegistry {
+    /// Bind a shortcut to an action id, replacing any previous binding for that id. This
+    /// registers a global command whose title is the id.
+    pub fn register(&mut self, id: &'static str, shortcut: Shortcut, callback: Callback) {
+        self.add(CommandDef::new(id, id, callback).shortcut(shortcut));
+    }
+
+    /// Register a command, replacing any previous command with the same id.
+    pub fn add(&mut self, command: CommandDef) {
+        self.unregister(command.id);
+        self.commands.push(command);
+    }
+
+    /// Remove the command for an action id.
+    pub fn unregister(&mut self, id: &str) {
+        self.commands.retain(|c| c.id != id);
+    }
+
+    /// Return the command registered for an action id.
+    pub fn command(&self, id: &str) -> Option<&CommandDef> {
+        self.commands.iter().find(|c| c.id == id)
+    }
+
+    /// Iterate over all registered commands, in order of registration.
+    pub fn commands(&self) -> impl Iterator<Item = &CommandDef> {
+        self.commands.iter()
+    }
+
+    /// Return the shortcut bound to an action id, taking user overrides into account.
+    pub fn shortcut(&self, id: &str) -> Option<Shortcut> {
+        match self.overrides.get(id) {
+            Some(shortcut) => *shortcut,
+            None => self.command(id).and_then(|c| c.shortcut),
+        }
+    }
+
+    /// Return the callback bound to an action id.
+    pub fn callback(&self, id: &str) -> Option<Callback> {
+        self.command(id).map(|c| c.callback)
+    }
+
+    /// Override the shortcut of an action id. `None` leaves the command without a shortcut.
+    pub fn set_binding(&mut self, id: impl Into<String>, shortcut: Option<Shortcut>) {
+        self.overrides.insert(id.into(), shortcut);
+    }
+
+    /// Restore the default shortcut of an action id.
+    pub fn reset_binding(&mut self, id: &str) {
+        self.overrides.remove(id);
+    }
+
+    /// Restore the default shortcuts of all commands.
+    pub fn reset_all_bindings(&mut self) {
+        self.overrides.clear();
+    }
+
+    /// The shortcuts which the user has overridden, for saving to preferences.
+    pub fn overrides(&self) -> &HashMap<String, Option<Shortcut>> {
+        &self.overrides
+    }
+
+    /// Replace all overridden shortcuts, for example with ones loaded from preferences.
+    /// Overrides for commands which haven't been registered yet are kept, and take effect
+    /// when the command is registered.
+    pub fn set_overrides(&mut self, overrides: HashMap<String, Option<Shortcut>>) {
+        self.overrides = overrides;
+    }
+
+    /// Return the ids of other commands which are bound to the given shortcut, and would
+    /// conflict if it were bound to `id`. Commands in different contexts don't conflict, since
+    /// the more specific one takes precedence.
+    pub fn conflicts_with(&self, id: &str, shortcut: Shortcut) -> Vec<&'static str> {
+        let context = self.command(id).map(|c| c.context).unwrap_or_default();
+        self.commands
+            .iter()
+            .filter(|c| {
+                c.id != id && self.shortcut(c.id) == Some(shortcut) && c.context.overlaps(&context)
+            })
+            .map(|c| c.id)
+            .collect()
+    }
+
+    /// Return the groups of commands which are bound to the same shortcut in the same context.
+    /// Only one command in each group can be triggered by the shortcut.
+    pub fn conflicts(&self) -> Vec<(Shortcut, Vec<&'static str>)> {
+        let mut result: Vec<(Shortcut, Vec<&'static str>)> = Vec::new();
+        for (index, command) in self.commands.iter().enumerate() {
+            let Some(shortcut) = self.shortcut(command.id) else {
+                continue;
+            };
+            if result
+                .iter()
+                .any(|(s, ids)| *s == shortcut && ids.contains(&command.id))
+            {
+                continue;
+            }
+            let mut ids: Vec<&'static str> = self.commands[index + 1..]
+                .iter()
+                .filter(|c| {
+                    self.shortcut(c.id) == Some(shortcut) && c.context.overlaps(&command.context)
+                })
+                .map(|c| c.id)
+                .collect();
+            if !ids.is_empty() {
+                ids.insert(0, command.id);
+                result.push((shortcut, ids));
+            }
+        }
+        result
+    }
+
+    /// Return the commands which are available, given the entity which has focus.
+    pub fn available(&self, world: &World, focus: Option<Entity>) -> Vec<&CommandDef> {
+        self.commands
+            .iter()
+            .filter(|c| c.context.is_active(world, focus))
+            .collect()
+    }
+}
+
+/// Runs the callbacks of any shortcuts which were just pressed. When several active commands
+/// share a shortcut, only the one with the most specific context is run.
+pub(crate) fn trigger_shortcuts(world: &mut World) {
+    let keys = world.resource::<ButtonInput<KeyCode>>();
+    let focus = world.resource::<Focus>().0;
+    let registry = world.resource::<ShortcutRegistry>();
+    let mut triggered: Vec<(Shortcut, usize, Callback)> = Vec::new();
+    for command in registry.commands.iter() {
+        let Some(shortcut) = registry.shortcut(command.id) else {
+            continue;
+        };
+        if !shortcut.just_pressed(keys) {
+            continue;
+        }
+        let Some(rank) = command.context.rank(world, focus) else {
+            continue;
+        };
+        match triggered.iter_mut().find(|(s, _, _)| *s == shortcut) {
+            Some(entry) if rank < entry.1 => *entry = (shortcut, rank, command.callback),
+            Some(_) => {}
+            None => triggered.push((shortcut, rank, command.callback)),
+        }
+    }
+    if triggered.is_empty() {
+        return;
+    }
+
+    // Let text fields handle typing and their own editing keys, such as Ctrl+Z.
+    let editing_text = focus.is_some_and(|focus| world.get::<TextBuffer>(focus).is_some());
+    for (shortcut, _, callback) in triggered {
+        let typing = !shortcut.ctrl && !shortcut.alt;
+        if editing_text && (typing || is_edit_key(shortcut.key, shortcut.ctrl, true)) {
+            continue;
+        }
+        world.run_callback(callback, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use bevy_quill_core::*;
+
+    use super::{CommandContext, CommandDef, Shortcut, ShortcutRegistry};
+
+    /// A callback which is never run, for registering commands.
+    fn callback() -> Callback {
+        let mut world = World::new();
+        let owner = world.spawn_empty().id();
+        let mut tracking = TrackingScope::new(world.change_tick());
+        Cx::new(&mut world, owner, &mut tracking).create_callback(|| {})
+    }
+
+    fn command(id: &'static str, shortcut: Shortcut, context: CommandContext) -> CommandDef {
+        CommandDef::new(id, id, callback())
+            .shortcut(shortcut)
+            .context(context)
+    }
+
+    #[test]
+    fn test_conflicts() {
+        let save = Shortcut::new(KeyCode::KeyS).ctrl();
+        let mut registry = ShortcutRegistry::default();
+        assert!(registry.conflicts().is_empty());
+        registry.add(command("a", save, CommandContext::Global));
+        registry.add(command("b", save, CommandContext::Global));
+        registry.add(command("c", save, CommandContext::Global));
+        registry.add(command("d", save.shift(), CommandContext::Global));
+        assert_eq!(registry.conflicts(), [(save, vec!["a", "b", "c"])]);
+
+        // Re-registering a command replaces it rather than conflicting with it.
+        registry.add(command("a", save, CommandContext::Global));
+        assert_eq!(registry.conflicts(), [(save, vec!["b", "c", "a"])]);
+    }
+
+    #[test]
+    fn test_conflicts_by_context() {
+        let key = Shortcut::new(KeyCode::Delete);
+        let panel = Entity::from_raw(1);
+        let other = Entity::from_raw(2);
+        let mut registry = ShortcutRegistry::default();
+        registry.add(command("global", key, CommandContext::Global));
+        registry.add(command("panel", key, CommandContext::FocusWithin(panel)));
+        registry.add(command("other", key, CommandContext::FocusWithin(other)));
+        registry.add(command("when", key, CommandContext::When(|_| true)));
+        assert!(registry.conflicts().is_empty());
+
+        registry.add(command("panel2", key, CommandContext::FocusWithin(panel)));
+        assert_eq!(registry.conflicts(), [(key, vec!["panel", "panel2"])]);
+    }
+
+    #[test]
+    fn test_conflicts_follow_overrides() {
+        let save = Shortcut::new(KeyCode::KeyS).ctrl();
+        let duplicate = Shortcut::new(KeyCode::KeyD).ctrl();
+        let mut registry = ShortcutRegistry::default();
+        registry.add(command("a", save, CommandContext::Global));
+        registry.add(command("b", save, CommandContext::Global));
+        registry.add(command("c", duplicate, CommandContext::Global));
+
+        registry.set_binding("b", None);
+        assert!(registry.conflicts().is_empty());
+
+        registry.set_binding("a", Some(duplicate));
+        assert_eq!(registry.conflicts(), [(duplicate, vec!["a", "c"])]);
+
+        registry.reset_all_bindings();
+        assert_eq!(registry.conflicts(), [(save, vec!["a", "b"])]);
+    }
+
+    #[test]
+    fn test_conflicts_with() {
+        let save = Shortcut::new(KeyCode::KeyS).ctrl();
+        let panel = Entity::from_raw(1);
+        let mut registry = ShortcutRegistry::default();
+        registry.add(command("a", save, CommandContext::Global));
+        registry.add(command("b", save, CommandContext::Global));
+        registry.add(command("c", save, CommandContext::FocusWithin(panel)));
+        assert_eq!(registry.conflicts_with("a", save), ["b"]);
+        assert_eq!(registry.conflicts_with("c", save), Vec::<&str>::new());
+        // Unregistered ids are treated as global commands.
+        assert_eq!(registry.conflicts_with("new", save), ["a", "b"]);
+        assert!(registry
+            .conflicts_with("a", Shortcut::new(KeyCode::KeyS))
+            .is_empty());
+    }
+}
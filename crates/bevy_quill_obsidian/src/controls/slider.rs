@@ -1,16 +1,23 @@
 use std::ops::RangeInclusive;
 
-use bevy::{color::LinearRgba, prelude::*, ui};
+use bevy::{a11y::Focus, color::LinearRgba, prelude::*, ui};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
 use crate::{
-    colors, cursor::StyleBuilderCursor, hooks::UseElementRect, materials::SliderRectMaterial,
-    RoundedCorners,
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseElementRect, UseIsFocus},
+    materials::SliderRectMaterial,
+    theme::Theme,
+    tokens, RoundedCorners,
 };
 
-use super::{IconButton, Spacer};
+use super::{
+    numeric_entry::{NumericEntry, DOUBLE_CLICK_TIME},
+    Disabled, IconButton, IsDisabled, Spacer,
+};
 
 #[derive(Clone, PartialEq, Default, Copy)]
 enum DragType {
@@ -24,6 +31,8 @@ struct DragState {
     dragging: DragType,
     offset: f32,
     was_dragged: bool,
+    /// Time of the last click, used to detect double-clicks.
+    last_click: f64,
 }
 
 fn style_slider(ss: &mut StyleBuilder) {
@@ -52,7 +61,7 @@ fn style_label(ss: &mut StyleBuilder) {
         .font("embedded://bevy_quill_obsidian/assets/fonts/Open_Sans/static/OpenSans-Medium.ttf")
         .font_size(16)
         .padding((6, 0))
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 fn style_slider_button(ss: &mut StyleBuilder) {
@@ -70,7 +79,10 @@ struct SliderState {
     step: f32,
 }
 
-/// Horizontal slider widget
+/// Horizontal slider widget.
+///
+/// Double-clicking the slider, or pressing Enter while focused, switches to a text field where a
+/// number or an expression such as `2*pi` or `+=0.5` can be typed.
 #[derive(Clone, PartialEq)]
 pub struct Slider {
     /// Current slider value.
@@ -204,9 +216,17 @@ impl ViewTemplate for Slider {
         // let hovering = cx.is_hovered(slider_id);
         let rect = cx.use_element_rect(slider_id);
         let show_buttons = rect.width() >= 70.;
+        let focused = cx.is_focus_visible(slider_id);
+        let editing = cx.create_mutable(false);
 
         let on_change = self.on_change;
 
+        let close_entry = cx.create_callback(move |world: &mut World| {
+            editing.set(world, false);
+            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+            focus.0 = Some(slider_id);
+        });
+
         let dec_disabled = self.value <= self.min;
         let dec_click = cx.create_callback(move |world: &mut World| {
             let entt = world.entity(slider_id);
@@ -233,8 +253,8 @@ impl ViewTemplate for Slider {
                     .get_resource_mut::<Assets<SliderRectMaterial>>()
                     .unwrap();
                 ui_materials.add(SliderRectMaterial {
-                    color_lo: LinearRgba::from(colors::U1).to_vec4(),
-                    color_hi: LinearRgba::from(colors::U3).to_vec4(),
+                    color_lo: Vec4::ZERO,
+                    color_hi: Vec4::ZERO,
                     value: Vec4::new(0.5, 0., 0., 0.),
                     radius: RoundedCorners::All.to_vec(4.),
                 })
@@ -242,6 +262,22 @@ impl ViewTemplate for Slider {
             (),
         );
 
+        // Material colors can't be styled with tokens, so follow the theme by hand.
+        let theme = cx.use_resource::<Theme>();
+        let color_lo = theme.color(tokens::U1);
+        let color_hi = theme.color(tokens::U3);
+        cx.create_effect(
+            |world, (material, color_lo, color_hi)| {
+                let mut ui_materials = world
+                    .get_resource_mut::<Assets<SliderRectMaterial>>()
+                    .unwrap();
+                let material = ui_materials.get_mut(material.id()).unwrap();
+                material.color_lo = LinearRgba::from(color_lo).to_vec4();
+                material.color_hi = LinearRgba::from(color_hi).to_vec4();
+            },
+            (material.clone(), color_lo, color_hi),
+        );
+
         // Ensure DragState component exists before rendering.
         let mut entt = cx.world_mut().entity_mut(slider_id);
         if !entt.contains::<DragState>() {
@@ -249,12 +285,27 @@ impl ViewTemplate for Slider {
                 dragging: DragType::None,
                 was_dragged: false,
                 offset: 0.,
+                last_click: f64::NEG_INFINITY,
             });
         }
 
         Element::<MaterialNodeBundle<SliderRectMaterial>>::for_entity(slider_id)
             .style((style_slider, self.style.clone()))
             .insert(material.clone())
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(1.0)
+                            .outline_width(2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
+            .insert(TabIndex(0))
+            .insert_if(self.disabled, || Disabled)
             .insert_dyn(
                 |(value, min, max, precision, step)| SliderState {
                     value,
@@ -269,6 +320,10 @@ impl ViewTemplate for Slider {
                 move |_| {
                     (
                         On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                            // Don't drag while selecting text in the numeric entry.
+                            if editing.get(world) {
+                                return;
+                            }
                             // Save initial value to use as drag offset.
                             let mut event = world
                                 .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
@@ -276,29 +331,50 @@ impl ViewTemplate for Slider {
                             event.stop_propagation();
                             let mut entt = world.entity_mut(slider_id);
                             let value = entt.get::<SliderState>().unwrap().value;
-                            entt.insert(DragState {
-                                dragging: DragType::Dragging,
-                                offset: value,
-                                was_dragged: false,
-                            });
+                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                            ds.dragging = DragType::Dragging;
+                            ds.offset = value;
+                            ds.was_dragged = false;
                         }),
                         On::<Pointer<DragEnd>>::run(move |world: &mut World| {
-                            let entt = world.entity(slider_id);
-                            let ds = entt.get::<DragState>().unwrap();
+                            let mut entt = world.entity_mut(slider_id);
+                            let value = entt.get::<SliderState>().unwrap().value;
+                            let mut ds = entt.get_mut::<DragState>().unwrap();
                             if ds.dragging == DragType::Dragging {
-                                if !ds.was_dragged {
-                                    // We want to know if it was a click or a drag.
-                                    // This will let us display a text input field later.
-                                    // Once we have text input fields.
-                                    println!("was not dragged");
-                                }
-                                let mut entt = world.entity_mut(slider_id);
-                                let state = entt.get::<SliderState>().unwrap();
-                                entt.insert(DragState {
-                                    dragging: DragType::None,
-                                    offset: state.value,
-                                    was_dragged: false,
-                                });
+                                ds.dragging = DragType::None;
+                                ds.offset = value;
+                                ds.was_dragged = false;
+                            }
+                        }),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(slider_id);
+                            if world.is_disabled(slider_id) || editing.get(world) {
+                                return;
+                            }
+                            let now = world.resource::<Time>().elapsed_seconds_f64();
+                            let mut entt = world.entity_mut(slider_id);
+                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                            // Clicks which end a drag don't count.
+                            if ds.was_dragged {
+                                return;
+                            }
+                            let double_click = now - ds.last_click < DOUBLE_CLICK_TIME;
+                            ds.last_click = if double_click { f64::NEG_INFINITY } else { now };
+                            if double_click {
+                                editing.set(world, true);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if world.is_disabled(slider_id) || editing.get(world) {
+                                return;
+                            }
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            if !event.repeat && event.key_code == KeyCode::Enter {
+                                event.stop_propagation();
+                                editing.set(world, true);
                             }
                         }),
                         On::<Pointer<Drag>>::run(move |world: &mut World| {
@@ -365,41 +441,52 @@ impl ViewTemplate for Slider {
             .children((Element::<NodeBundle>::new()
                 .named("Slider")
                 .style(style_overlay)
-                .children((
-                    Cond::new(
-                        show_buttons,
-                        IconButton::new(
-                            "embedded://bevy_quill_obsidian/assets/icons/chevron_left.png",
-                        )
-                        .corners(RoundedCorners::Left)
-                        .style(style_slider_button)
-                        .minimal(true)
-                        .disabled(dec_disabled)
-                        .on_click(dec_click),
-                        (),
-                    ),
-                    Element::<NodeBundle>::new().style(style_label).children((
+                .children(Cond::new(
+                    editing.get(cx),
+                    NumericEntry {
+                        value: self.value,
+                        min: self.min,
+                        max: self.max,
+                        precision: self.precision,
+                        on_change,
+                        on_close: close_entry,
+                    },
+                    (
+                        Cond::new(
+                            show_buttons,
+                            IconButton::new(
+                                "embedded://bevy_quill_obsidian/assets/icons/chevron_left.png",
+                            )
+                            .corners(RoundedCorners::Left)
+                            .style(style_slider_button)
+                            .minimal(true)
+                            .disabled(dec_disabled)
+                            .on_click(dec_click),
+                            (),
+                        ),
+                        Element::<NodeBundle>::new().style(style_label).children((
+                            Cond::new(
+                                self.label.is_some(),
+                                (self.label.clone().unwrap_or_default(), Spacer),
+                                (),
+                            ),
+                            match self.formatted_value {
+                                Some(ref formatted_value) => formatted_value.clone(),
+                                None => format!("{:.*}", self.precision, self.value),
+                            },
+                        )),
                         Cond::new(
-                            self.label.is_some(),
-                            (self.label.clone().unwrap_or_default(), Spacer),
+                            show_buttons,
+                            IconButton::new(
+                                "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
+                            )
+                            .corners(RoundedCorners::Right)
+                            .minimal(true)
+                            .style(style_slider_button)
+                            .disabled(inc_disabled)
+                            .on_click(inc_click),
                             (),
                         ),
-                        match self.formatted_value {
-                            Some(ref formatted_value) => formatted_value.clone(),
-                            None => format!("{:.*}", self.precision, self.value),
-                        },
-                    )),
-                    Cond::new(
-                        show_buttons,
-                        IconButton::new(
-                            "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
-                        )
-                        .corners(RoundedCorners::Right)
-                        .minimal(true)
-                        .style(style_slider_button)
-                        .disabled(inc_disabled)
-                        .on_click(inc_click),
-                        (),
                     ),
                 )),))
     }
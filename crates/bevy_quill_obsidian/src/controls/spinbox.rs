@@ -1,6 +1,14 @@
-use super::IconButton;
-use crate::{colors, cursor::StyleBuilderCursor, hooks::UseElementRect, RoundedCorners};
-use bevy::{prelude::*, ui};
+use super::{
+    numeric_entry::{NumericEntry, DOUBLE_CLICK_TIME},
+    Disabled, IconButton, IsDisabled,
+};
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseElementRect, UseIsFocus},
+    tokens, RoundedCorners,
+};
+use bevy::{a11y::Focus, prelude::*, ui};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
@@ -18,12 +26,14 @@ struct DragState {
     dragging: DragType,
     offset: f32,
     was_dragged: bool,
+    /// Time of the last click, used to detect double-clicks.
+    last_click: f64,
 }
 
 fn style_spinbox(ss: &mut StyleBuilder) {
     ss.min_width(24)
         .height(20)
-        .background_color(colors::U1)
+        .background_color(tokens::U1)
         .border_radius(5);
 }
 
@@ -50,7 +60,7 @@ fn style_spinbox_label(ss: &mut StyleBuilder) {
         .font_size(16)
         .overflow(ui::OverflowAxis::Hidden)
         .padding((3, 0))
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 fn style_spinbox_button(ss: &mut StyleBuilder) {
@@ -73,6 +83,9 @@ struct SpinBoxState {
 /// * The range of values is large or unbounded, making it difficult to select a specific value
 ///   with a slider.
 /// * There is limited horizontal space available.
+///
+/// Double-clicking the value, or pressing Enter while focused, switches to a text field where a
+/// number or an expression such as `2*pi` or `+=0.5` can be typed.
 #[derive(Clone, PartialEq)]
 pub struct SpinBox {
     /// Current slider value.
@@ -195,8 +208,16 @@ impl ViewTemplate for SpinBox {
         let spinbox_id = cx.create_entity();
         let rect = cx.use_element_rect(spinbox_id);
         let show_buttons = rect.width() >= 48.;
+        let focused = cx.is_focus_visible(spinbox_id);
+        let editing = cx.create_mutable(false);
         let on_change = self.on_change;
 
+        let close_entry = cx.create_callback(move |world: &mut World| {
+            editing.set(world, false);
+            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+            focus.0 = Some(spinbox_id);
+        });
+
         let dec_disabled = self.value <= self.min;
         let dec_click = cx.create_callback(move |world: &mut World| {
             let entt = world.entity(spinbox_id);
@@ -223,11 +244,43 @@ impl ViewTemplate for SpinBox {
                 dragging: DragType::None,
                 was_dragged: false,
                 offset: 0.,
+                last_click: f64::NEG_INFINITY,
             });
         }
 
         Element::<NodeBundle>::for_entity(spinbox_id)
             .style((style_spinbox, self.style.clone()))
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(1.0)
+                            .outline_width(2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
+            .insert(TabIndex(0))
+            .insert_if(self.disabled, || Disabled)
+            .insert_dyn(
+                move |_| {
+                    On::<KeyPressEvent>::run(move |world: &mut World| {
+                        if world.is_disabled(spinbox_id) || editing.get(world) {
+                            return;
+                        }
+                        let mut event = world
+                            .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                            .unwrap();
+                        if !event.repeat && event.key_code == KeyCode::Enter {
+                            event.stop_propagation();
+                            editing.set(world, true);
+                        }
+                    })
+                },
+                (),
+            )
             .insert_dyn(
                 |(value, min, max, precision, step)| SpinBoxState {
                     value,
@@ -241,113 +294,139 @@ impl ViewTemplate for SpinBox {
             .children((Element::<NodeBundle>::new()
                 .named("SpinBox")
                 .style(style_overlay)
-                .children((
-                    Cond::new(
-                        show_buttons,
-                        IconButton::new(
-                            "embedded://bevy_quill_obsidian/assets/icons/chevron_left.png",
-                        )
-                        .corners(RoundedCorners::Left)
-                        .style(style_spinbox_button)
-                        .minimal(true)
-                        .disabled(dec_disabled)
-                        .on_click(dec_click),
-                        (),
-                    ),
-                    Element::<NodeBundle>::new()
-                        .style(style_spinbox_label)
-                        .insert_dyn(
-                            move |_| {
-                                (
-                                    On::<Pointer<DragStart>>::run(move |world: &mut World| {
-                                        // Save initial value to use as drag offset.
-                                        let mut event = world
+                .children(Cond::new(
+                    editing.get(cx),
+                    NumericEntry {
+                        value: self.value,
+                        min: self.min,
+                        max: self.max,
+                        precision: self.precision,
+                        on_change,
+                        on_close: close_entry,
+                    },
+                    (
+                        Cond::new(
+                            show_buttons,
+                            IconButton::new(
+                                "embedded://bevy_quill_obsidian/assets/icons/chevron_left.png",
+                            )
+                            .corners(RoundedCorners::Left)
+                            .style(style_spinbox_button)
+                            .minimal(true)
+                            .disabled(dec_disabled)
+                            .on_click(dec_click),
+                            (),
+                        ),
+                        Element::<NodeBundle>::new()
+                            .style(style_spinbox_label)
+                            .insert_dyn(
+                                move |_| {
+                                    (
+                                        On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                                            // Save initial value to use as drag offset.
+                                            let mut event = world
                                             .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
                                             .unwrap();
-                                        event.stop_propagation();
-                                        let mut entt = world.entity_mut(spinbox_id);
-                                        let value = entt.get::<SpinBoxState>().unwrap().value;
-                                        entt.insert(DragState {
-                                            dragging: DragType::Dragging,
-                                            offset: value,
-                                            was_dragged: false,
-                                        });
-                                    }),
-                                    On::<Pointer<DragEnd>>::run(move |world: &mut World| {
-                                        let entt = world.entity(spinbox_id);
-                                        let ds = entt.get::<DragState>().unwrap();
-                                        if ds.dragging == DragType::Dragging {
-                                            if !ds.was_dragged {
-                                                // We want to know if it was a click or a drag.
-                                                // This will let us display a text input field later.
-                                                // Once we have text input fields.
-                                                println!("was not dragged");
-                                            }
+                                            event.stop_propagation();
                                             let mut entt = world.entity_mut(spinbox_id);
-                                            let state = entt.get::<SpinBoxState>().unwrap();
-                                            entt.insert(DragState {
-                                                dragging: DragType::None,
-                                                offset: state.value,
-                                                was_dragged: false,
-                                            });
-                                        }
-                                    }),
-                                    On::<Pointer<Drag>>::run(move |world: &mut World| {
-                                        let entt = world.entity(spinbox_id);
-                                        let ds = *entt.get::<DragState>().unwrap();
-                                        if ds.dragging == DragType::Dragging {
-                                            let event = world
-                                                .get_resource::<ListenerInput<Pointer<Drag>>>()
-                                                .unwrap();
-                                            let delta = (event.distance.x - event.distance.y) * 0.1;
+                                            let value = entt.get::<SpinBoxState>().unwrap().value;
+                                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                                            ds.dragging = DragType::Dragging;
+                                            ds.offset = value;
+                                            ds.was_dragged = false;
+                                        }),
+                                        On::<Pointer<DragEnd>>::run(move |world: &mut World| {
                                             let mut entt = world.entity_mut(spinbox_id);
-                                            let state = entt.get::<SpinBoxState>().unwrap();
-                                            // Rate of change increases with drag distance
-                                            let new_value = ds.offset
-                                                + (delta.abs().powf(1.3)
-                                                    * delta.signum()
-                                                    * state.step);
-                                            let rounding = f32::powi(10., state.precision as i32);
-                                            let value = state.value;
-                                            let min = state.min;
-                                            let max = state.max;
-                                            let new_value =
-                                                (new_value * rounding).round() / rounding;
-                                            if value != new_value {
-                                                if !ds.was_dragged {
-                                                    entt.insert(DragState {
-                                                        was_dragged: true,
-                                                        ..ds
-                                                    });
-                                                }
-                                                if let Some(on_change) = on_change {
-                                                    world.run_callback(
-                                                        on_change,
-                                                        new_value.clamp(min, max),
-                                                    );
+                                            let value = entt.get::<SpinBoxState>().unwrap().value;
+                                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                                            if ds.dragging == DragType::Dragging {
+                                                ds.dragging = DragType::None;
+                                                ds.offset = value;
+                                                ds.was_dragged = false;
+                                            }
+                                        }),
+                                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                                            let mut focus =
+                                                world.get_resource_mut::<Focus>().unwrap();
+                                            focus.0 = Some(spinbox_id);
+                                            if world.is_disabled(spinbox_id) {
+                                                return;
+                                            }
+                                            let now =
+                                                world.resource::<Time>().elapsed_seconds_f64();
+                                            let mut entt = world.entity_mut(spinbox_id);
+                                            let mut ds = entt.get_mut::<DragState>().unwrap();
+                                            // Clicks which end a drag don't count.
+                                            if ds.was_dragged {
+                                                return;
+                                            }
+                                            let double_click =
+                                                now - ds.last_click < DOUBLE_CLICK_TIME;
+                                            ds.last_click =
+                                                if double_click { f64::NEG_INFINITY } else { now };
+                                            if double_click {
+                                                editing.set(world, true);
+                                            }
+                                        }),
+                                        On::<Pointer<Drag>>::run(move |world: &mut World| {
+                                            let entt = world.entity(spinbox_id);
+                                            let ds = *entt.get::<DragState>().unwrap();
+                                            if ds.dragging == DragType::Dragging {
+                                                let event = world
+                                                    .get_resource::<ListenerInput<Pointer<Drag>>>()
+                                                    .unwrap();
+                                                let delta =
+                                                    (event.distance.x - event.distance.y) * 0.1;
+                                                let mut entt = world.entity_mut(spinbox_id);
+                                                let state = entt.get::<SpinBoxState>().unwrap();
+                                                // Rate of change increases with drag distance
+                                                let new_value = ds.offset
+                                                    + (delta.abs().powf(1.3)
+                                                        * delta.signum()
+                                                        * state.step);
+                                                let rounding =
+                                                    f32::powi(10., state.precision as i32);
+                                                let value = state.value;
+                                                let min = state.min;
+                                                let max = state.max;
+                                                let new_value =
+                                                    (new_value * rounding).round() / rounding;
+                                                if value != new_value {
+                                                    if !ds.was_dragged {
+                                                        entt.insert(DragState {
+                                                            was_dragged: true,
+                                                            ..ds
+                                                        });
+                                                    }
+                                                    if let Some(on_change) = on_change {
+                                                        world.run_callback(
+                                                            on_change,
+                                                            new_value.clamp(min, max),
+                                                        );
+                                                    }
                                                 }
                                             }
-                                        }
-                                    }),
-                                )
-                            },
-                            (self.min, self.max),
-                        )
-                        .children(match self.formatted_value {
-                            Some(ref formatted_value) => formatted_value.clone(),
-                            None => format!("{:.*}", self.precision, self.value),
-                        }),
-                    Cond::new(
-                        show_buttons,
-                        IconButton::new(
-                            "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
-                        )
-                        .corners(RoundedCorners::Right)
-                        .minimal(true)
-                        .style(style_spinbox_button)
-                        .disabled(inc_disabled)
-                        .on_click(inc_click),
-                        (),
+                                        }),
+                                    )
+                                },
+                                (self.min, self.max),
+                            )
+                            .children(match self.formatted_value {
+                                Some(ref formatted_value) => formatted_value.clone(),
+                                None => format!("{:.*}", self.precision, self.value),
+                            }),
+                        Cond::new(
+                            show_buttons,
+                            IconButton::new(
+                                "embedded://bevy_quill_obsidian/assets/icons/chevron_right.png",
+                            )
+                            .corners(RoundedCorners::Right)
+                            .minimal(true)
+                            .style(style_spinbox_button)
+                            .disabled(inc_disabled)
+                            .on_click(inc_click),
+                            (),
+                        ),
                     ),
                 )),))
     }
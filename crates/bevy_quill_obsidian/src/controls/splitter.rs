@@ -1,9 +1,9 @@
-use bevy::{color::Luminance, prelude::*, ui};
+use bevy::{prelude::*, ui};
 use bevy_mod_picking::{events::PointerCancel, prelude::*};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::{colors, cursor::StyleBuilderCursor, hooks::UseIsHover};
+use crate::{cursor::StyleBuilderCursor, hooks::UseIsHover, tokens};
 
 /// The direction of the splitter. Represents the direction of the bar, not the items being split.
 #[derive(Clone, Copy, PartialEq, Default)]
@@ -32,7 +32,7 @@ fn style_vsplitter(ss: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Column)
         .gap(8)
         .width(9)
-        .background_color(colors::U1)
+        .background_color(tokens::U1)
         .cursor(CursorIcon::ColResize);
 }
 
@@ -51,7 +51,7 @@ fn style_hsplitter(ss: &mut StyleBuilder) {
         .flex_direction(ui::FlexDirection::Column)
         .gap(8)
         .height(9)
-        .background_color(colors::U2)
+        .background_color(tokens::U2)
         .cursor(CursorIcon::RowResize);
 }
 
@@ -197,9 +197,9 @@ impl ViewTemplate for Splitter {
                         move |(is_hovering, dragging), sb| {
                             // Color change on hover / drag
                             let color = match (dragging, is_hovering) {
-                                (true, _) => colors::U3.lighter(0.05),
-                                (false, true) => colors::U3.lighter(0.02),
-                                (false, false) => colors::U3,
+                                (true, _) => tokens::U3.lighter(0.05),
+                                (false, true) => tokens::U3.lighter(0.02),
+                                (false, false) => tokens::U3,
                             };
                             sb.background_color(color);
                         },
@@ -5,21 +5,25 @@ use bevy_quill_core::*;
 
 use crate::materials::SwatchRectMaterial;
 
-use crate::colors;
+use crate::{
+    focus::{KeyPressEvent, TabIndex},
+    hooks::UseIsFocus,
+    tokens,
+};
 
 fn style_swatch(ss: &mut StyleBuilder) {
     ss.min_width(8)
         .min_height(8)
         .display(ui::Display::Flex)
-        .color(colors::FOREGROUND)
+        .color(tokens::FOREGROUND)
         .border_radius(3)
         .padding(2);
 }
 
 fn style_selection(ss: &mut StyleBuilder) {
     ss.border(1)
-        .border_color(colors::U1)
-        .outline_color(colors::FOREGROUND)
+        .border_color(tokens::U1)
+        .outline_color(tokens::FOREGROUND)
         .outline_width(2)
         .outline_offset(0)
         .align_self(ui::AlignSelf::Stretch)
@@ -42,6 +46,10 @@ pub struct Swatch {
 
     /// Callback called when clicked
     pub on_click: Option<Callback<Srgba>>,
+
+    /// The tab index of the swatch. Swatches are only in the tab order if this is set.
+    /// Pressing Enter or Space while the swatch has focus acts like a click.
+    pub tab_index: Option<i32>,
 }
 
 impl Swatch {
@@ -73,11 +81,19 @@ impl Swatch {
         self.selected = selected;
         self
     }
+
+    /// Make the swatch focusable, with the given tab index.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = Some(tab_index);
+        self
+    }
 }
 
 impl ViewTemplate for Swatch {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focus_visible(id);
         let color = self.color;
         let selected = self.selected;
 
@@ -88,7 +104,7 @@ impl ViewTemplate for Swatch {
                     .get_resource_mut::<Assets<SwatchRectMaterial>>()
                     .unwrap();
                 ui_materials.add(SwatchRectMaterial {
-                    color: LinearRgba::from(colors::U1).to_vec4(),
+                    color: Vec4::ZERO,
                     border_radius: Vec4::splat(0.),
                 })
             },
@@ -107,17 +123,46 @@ impl ViewTemplate for Swatch {
             (material.clone(), self.color),
         );
 
-        Element::<MaterialNodeBundle<SwatchRectMaterial>>::new()
+        Element::<MaterialNodeBundle<SwatchRectMaterial>>::for_entity(id)
             .named("Swatch")
             .style((style_swatch, self.style.clone()))
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_width(2)
+                            .outline_offset(1);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
             .insert_dyn(|m| m, material.clone())
+            .insert_dyn(TabIndex, self.tab_index.unwrap_or(-1))
             .insert_dyn(
                 |(color, on_click)| {
-                    On::<Pointer<Click>>::run(move |world: &mut World| {
-                        if let Some(on_click) = on_click {
-                            world.run_callback(on_click, color);
-                        }
-                    })
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            if let Some(on_click) = on_click {
+                                world.run_callback(on_click, color);
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .unwrap();
+                            if !event.repeat
+                                && (event.key_code == KeyCode::Enter
+                                    || event.key_code == KeyCode::Space)
+                            {
+                                event.stop_propagation();
+                                if let Some(on_click) = on_click {
+                                    world.run_callback(on_click, color);
+                                }
+                            }
+                        }),
+                    )
                 },
                 (color, self.on_click),
             )
@@ -2,7 +2,10 @@ use bevy::{color::Srgba, prelude::*, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use crate::colors;
+use crate::{
+    focus::{nav_group, NavAxis},
+    tokens,
+};
 
 use super::Swatch;
 
@@ -14,7 +17,7 @@ fn style_swatch_grid(ss: &mut StyleBuilder) {
         .display(ui::Display::Grid)
         .grid_auto_rows(vec![ui::GridTrack::default()])
         .border(0)
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 fn style_swatch(ss: &mut StyleBuilder) {
@@ -25,7 +28,7 @@ fn style_empty_slot(ss: &mut StyleBuilder) {
     ss.border(1)
         .min_width(16)
         .min_height(16)
-        .border_color(colors::U2.lighter(0.01));
+        .border_color(tokens::U2.lighter(0.01));
 }
 
 /// Color swatch widget. This displays a solid color, and can also display a checkerboard
@@ -115,6 +118,15 @@ impl ViewTemplate for SwatchGrid {
         let num_colors = self.colors.len().min(num_cells);
         let padding_len = num_cells - num_colors;
 
+        // The grid is a single tab stop, entered at the selected swatch; the arrow keys move
+        // between the swatches.
+        let colors = &self.colors[0..num_colors];
+        let tab_stop = if colors.contains(&selected) {
+            Some(selected)
+        } else {
+            colors.first().copied()
+        };
+
         Element::<NodeBundle>::new()
             .named("SwatchGrid")
             .style((
@@ -128,13 +140,15 @@ impl ViewTemplate for SwatchGrid {
                 },
                 self.style.clone(),
             ))
+            .insert_dyn(move |_| nav_group(NavAxis::Both, false), ())
             .children((
                 // Generate cells for each color
-                For::each(self.colors[0..num_colors].to_owned(), move |color| {
+                For::each(colors.to_owned(), move |color| {
                     Swatch::new(*color)
                         .selected(selected == *color)
                         .style(style_swatch)
                         .on_click(on_click)
+                        .tab_index(if tab_stop == Some(*color) { 0 } else { -1 })
                 }),
                 // Pad with empty cells
                 For::each(0..padding_len, move |_| {
@@ -0,0 +1,766 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    floating::FloatAlign,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseElementRect, UseIsFocus, UseIsHover},
+    scrolling::{ScrollArea, ScrollWheel},
+    size::Size,
+    tokens, typography,
+};
+
+use super::{Disabled, IconButton, IsDisabled, MenuButton, MenuItem, MenuPopup};
+
+fn style_tab_bar(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .height(28)
+        .background_color(tokens::U1);
+}
+
+fn style_tab_strip(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.)
+        .min_width(0)
+        .height(ui::Val::Percent(100.))
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_tab_strip_content(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Stretch)
+        .height(ui::Val::Percent(100.));
+}
+
+fn style_tab(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .flex_shrink(0.)
+        .gap(4)
+        .padding((10, 0))
+        .border_color(tokens::FOCUS)
+        .cursor(CursorIcon::Pointer);
+}
+
+fn style_tab_overflow(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.).margin((2, 0));
+}
+
+fn style_tab_panel(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch);
+}
+
+fn style_tab_page(ss: &mut StyleBuilder) {
+    ss.flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .flex_grow(1.);
+}
+
+/// A single tab displayed in a [`TabBar`].
+#[derive(Default, Clone, PartialEq)]
+pub struct Tab {
+    /// The content to display inside the tab.
+    pub label: ViewChild,
+
+    /// Whether the tab shows a close button.
+    pub closable: bool,
+
+    /// Whether the tab is disabled.
+    pub disabled: bool,
+}
+
+impl Tab {
+    /// Create a new tab with the given label.
+    pub fn new(label: impl IntoViewChild) -> Self {
+        Self {
+            label: label.into_view_child(),
+            ..default()
+        }
+    }
+
+    /// Set whether the tab shows a close button.
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Set whether the tab is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// How a [`TabBar`] handles tabs which don't fit in the available width.
+#[derive(Clone, Copy, PartialEq, Default, Debug)]
+pub enum TabOverflow {
+    /// Tabs can be scrolled horizontally with the mouse wheel.
+    #[default]
+    Scroll,
+
+    /// Tabs can be scrolled, and a drop-down menu listing all tabs is shown when they overflow.
+    Menu,
+}
+
+/// A drag of a tab out of a [`TabBar`], reported to [`TabBar::on_drag`]. Positions are the
+/// window coordinates of the pointer.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TabDrag {
+    /// The tab with the given index started being dragged.
+    Start(usize, Vec2),
+    /// The dragged tab was moved.
+    Move(usize, Vec2),
+    /// The dragged tab was released.
+    End(usize),
+}
+
+/// Component used to hold the tab bar params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct TabBarState {
+    selected: usize,
+    enabled: Vec<bool>,
+    closable: Vec<bool>,
+    on_select: Option<Callback<usize>>,
+    on_close: Option<Callback<usize>>,
+}
+
+/// Identifies a tab entity, so that it can be recognized when dropped onto another tab.
+#[derive(Component, Clone, Copy)]
+struct TabKey {
+    bar: Entity,
+    index: usize,
+}
+
+/// A horizontal strip of tabs. The tab bar is controlled: `on_select` is called with the
+/// index of the tab to select, and the owner is expected to pass it back as `selected`.
+///
+/// When focused, the arrow keys, Home and End select the previous, next, first or last enabled
+/// tab, and Delete closes the selected tab if it is closable. Only the selected tab is reachable
+/// with the Tab key. Closable tabs can also be closed with a middle click.
+#[derive(Default, Clone, PartialEq)]
+pub struct TabBar {
+    /// The tabs to display.
+    pub tabs: Vec<Tab>,
+
+    /// Index of the selected tab.
+    pub selected: usize,
+
+    /// Whether tabs can be reordered by dragging them.
+    pub reorderable: bool,
+
+    /// How to handle tabs which don't fit.
+    pub overflow: TabOverflow,
+
+    /// Additional styles to be applied to the tab bar.
+    pub style: StyleHandle,
+
+    /// Callback called when a tab is selected, with the tab index.
+    pub on_select: Option<Callback<usize>>,
+
+    /// Callback called when a tab's close button is clicked, with the tab index.
+    pub on_close: Option<Callback<usize>>,
+
+    /// Callback called when a tab is dropped onto another tab, with the indices of the
+    /// dragged tab and the tab it was dropped on.
+    pub on_reorder: Option<Callback<(usize, usize)>>,
+
+    /// Callback called while a tab is dragged, for owners which let tabs be dragged elsewhere.
+    /// When set, drag events on tabs don't propagate to the tab bar's ancestors.
+    pub on_drag: Option<Callback<TabDrag>>,
+}
+
+impl TabBar {
+    /// Create a new tab bar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a tab to the tab bar.
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Set the tabs to display.
+    pub fn tabs(mut self, tabs: impl IntoIterator<Item = Tab>) -> Self {
+        self.tabs = tabs.into_iter().collect();
+        self
+    }
+
+    /// Set the index of the selected tab.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set whether tabs can be reordered by dragging them.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Set how to handle tabs which don't fit.
+    pub fn overflow(mut self, overflow: TabOverflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set additional styles to be applied to the tab bar.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when a tab is selected.
+    pub fn on_select(mut self, on_select: Callback<usize>) -> Self {
+        self.on_select = Some(on_select);
+        self
+    }
+
+    /// Set the callback called when a tab is closed.
+    pub fn on_close(mut self, on_close: Callback<usize>) -> Self {
+        self.on_close = Some(on_close);
+        self
+    }
+
+    /// Set the callback called when a tab is dropped onto another tab.
+    pub fn on_reorder(mut self, on_reorder: Callback<(usize, usize)>) -> Self {
+        self.on_reorder = Some(on_reorder);
+        self
+    }
+
+    /// Set the callback called while a tab is dragged.
+    pub fn on_drag(mut self, on_drag: Callback<TabDrag>) -> Self {
+        self.on_drag = Some(on_drag);
+        self
+    }
+}
+
+impl ViewTemplate for TabBar {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let strip_id = cx.create_entity();
+        let content_id = cx.create_entity();
+        let strip_width = cx.use_element_size(strip_id).x;
+        let content_width = cx.use_element_size(content_id).x;
+        let show_menu = self.overflow == TabOverflow::Menu && content_width > strip_width;
+        let strip_focused = cx.is_focus_within(strip_id);
+
+        let selected = self.selected;
+        let reorderable = self.reorderable;
+        let on_select = self.on_select;
+        let on_close = self.on_close;
+        let on_reorder = self.on_reorder;
+        let on_drag = self.on_drag;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TabBar")
+            .style((typography::text_default, style_tab_bar, self.style.clone()))
+            .insert_dyn(
+                |(selected, enabled, closable, on_select, on_close)| TabBarState {
+                    selected,
+                    enabled,
+                    closable,
+                    on_select,
+                    on_close,
+                },
+                (
+                    selected,
+                    self.tabs.iter().map(|t| !t.disabled).collect::<Vec<_>>(),
+                    self.tabs.iter().map(|t| t.closable).collect::<Vec<_>>(),
+                    on_select,
+                    on_close,
+                ),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::TabList)),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            let event = world.resource::<ListenerInput<KeyPressEvent>>();
+                            let key_code = event.key_code;
+                            let state = world.get::<TabBarState>(id).unwrap().clone();
+                            let enabled = |i: &usize| state.enabled[*i];
+                            let count = state.enabled.len();
+                            let next = match key_code {
+                                KeyCode::ArrowLeft => (0..state.selected).rev().find(enabled),
+                                KeyCode::ArrowRight => (state.selected + 1..count).find(enabled),
+                                KeyCode::Home => (0..count).find(enabled),
+                                KeyCode::End => (0..count).rev().find(enabled),
+                                KeyCode::Delete => {
+                                    if let Some(on_close) = state.on_close {
+                                        if state.closable.get(state.selected) == Some(&true) {
+                                            world.run_callback(on_close, state.selected);
+                                        }
+                                    }
+                                    None
+                                }
+                                _ => return,
+                            };
+                            world
+                                .resource_mut::<ListenerInput<KeyPressEvent>>()
+                                .stop_propagation();
+                            if let (Some(next), Some(on_select)) = (next, state.on_select) {
+                                if next != state.selected {
+                                    world.run_callback(on_select, next);
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::for_entity(strip_id)
+                    .named("TabBar::Strip")
+                    .style(style_tab_strip)
+                    .insert_dyn(
+                        move |_| {
+                            (
+                                ScrollArea::default(),
+                                On::<ScrollWheel>::listener_component_mut::<ScrollArea>(
+                                    move |ev, scrolling| {
+                                        ev.stop_propagation();
+                                        scrolling.scroll_by(-ev.delta.x - ev.delta.y, 0.);
+                                    },
+                                ),
+                            )
+                        },
+                        (),
+                    )
+                    .children(
+                        Element::<NodeBundle>::for_entity(content_id)
+                            .named("TabBar::Content")
+                            .style(style_tab_strip_content)
+                            .children(For::index(&self.tabs, move |tab, index| TabButton {
+                                bar: id,
+                                strip: strip_id,
+                                tab: tab.clone(),
+                                index,
+                                selected: index == selected,
+                                strip_focused,
+                                reorderable,
+                                on_select,
+                                on_close,
+                                on_reorder,
+                                on_drag,
+                            })),
+                    ),
+                Cond::new(
+                    show_menu,
+                    MenuButton::new()
+                        .minimal(true)
+                        .tab_index(-1)
+                        .style(style_tab_overflow)
+                        .popup(MenuPopup::new().align(FloatAlign::End).children(For::index(
+                            &self.tabs,
+                            move |tab, index| TabMenuItem {
+                                tab: tab.clone(),
+                                index,
+                                selected: index == selected,
+                                on_select,
+                            },
+                        ))),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// Scroll the tab strip so that the given tab is fully visible.
+fn scroll_tab_into_view(world: &mut World, strip: Entity, tab: Entity) {
+    let rect = |world: &World, e: Entity| match (world.get::<Node>(e), world.get(e)) {
+        (Some(node), Some(transform)) => Some(node.logical_rect(transform)),
+        _ => None,
+    };
+    let (Some(tab_rect), Some(strip_rect)) = (rect(world, tab), rect(world, strip)) else {
+        return;
+    };
+    let Some(mut scrolling) = world.get_mut::<ScrollArea>(strip) else {
+        return;
+    };
+    if tab_rect.min.x < strip_rect.min.x {
+        scrolling.scroll_by(tab_rect.min.x - strip_rect.min.x, 0.);
+    } else if tab_rect.max.x > strip_rect.max.x {
+        scrolling.scroll_by(tab_rect.max.x - strip_rect.max.x, 0.);
+    }
+}
+
+/// An individual tab within a [`TabBar`].
+#[derive(Clone, PartialEq)]
+struct TabButton {
+    bar: Entity,
+    strip: Entity,
+    tab: Tab,
+    index: usize,
+    selected: bool,
+    strip_focused: bool,
+    reorderable: bool,
+    on_select: Option<Callback<usize>>,
+    on_close: Option<Callback<usize>>,
+    on_reorder: Option<Callback<(usize, usize)>>,
+    on_drag: Option<Callback<TabDrag>>,
+}
+
+impl ViewTemplate for TabButton {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let bar = self.bar;
+        let strip = self.strip;
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        // Indicates which side of this tab a dragged tab would be dropped on.
+        let drop_before = cx.create_mutable::<Option<bool>>(None);
+        let index = self.index;
+        let on_select = self.on_select;
+        let on_close = self.on_close;
+        let on_reorder = self.on_reorder;
+        let on_drag = self.on_drag;
+        let reorderable = self.reorderable;
+
+        // Keep the selected tab visible, and move focus along with the selection.
+        cx.create_effect(
+            move |world, (selected, strip_focused)| {
+                if selected {
+                    scroll_tab_into_view(world, strip, id);
+                    if strip_focused {
+                        let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                        if focus.0 != Some(id) {
+                            focus.0 = Some(id);
+                        }
+                    }
+                }
+            },
+            (self.selected, self.strip_focused),
+        );
+
+        let close_click = cx.create_callback(move |world: &mut World| {
+            if let Some(on_close) = on_close {
+                world.run_callback(on_close, index);
+            }
+        });
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Tab")
+            .style(style_tab)
+            .style_dyn(
+                |(selected, disabled, hovering), sb| {
+                    sb.color(match (selected, disabled) {
+                        (_, true) => tokens::FOREGROUND.with_alpha(0.2),
+                        (true, false) => tokens::FOREGROUND,
+                        (false, false) => tokens::DIM,
+                    })
+                    .background_color(match (selected, hovering) {
+                        (true, _) => tokens::U3,
+                        (false, true) => tokens::U2,
+                        (false, false) => tokens::TRANSPARENT,
+                    });
+                },
+                (self.selected, self.tab.disabled, hovering),
+            )
+            .style_dyn(
+                |(focused, drop_before), sb| {
+                    match drop_before {
+                        Some(true) => sb.border(0).border_left(2),
+                        Some(false) => sb.border(0).border_right(2),
+                        None => sb.border(0),
+                    };
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(-2.0)
+                            .outline_width(2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                (focused, drop_before.get(cx)),
+            )
+            .insert_dyn(
+                |selected| TabIndex(if selected { 0 } else { -1 }),
+                self.selected,
+            )
+            .insert_dyn(move |index| TabKey { bar, index }, index)
+            .insert_dyn(
+                |selected| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::Tab);
+                        builder.set_selected(selected);
+                        builder
+                    })
+                },
+                self.selected,
+            )
+            .insert_if(self.tab.disabled, || Disabled)
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            let button = event.button;
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let state = world.get::<TabBarState>(bar).unwrap();
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            if button == PointerButton::Middle {
+                                if state.closable.get(index) == Some(&true) {
+                                    if let Some(on_close) = on_close {
+                                        world.run_callback(on_close, index);
+                                    }
+                                }
+                                return;
+                            }
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                            if let Some(on_select) = on_select {
+                                world.run_callback(on_select, index);
+                            }
+                        }),
+                        On::<Pointer<DragStart>>::run(move |world: &mut World| {
+                            let Some(on_drag) = on_drag else {
+                                return;
+                            };
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            let position = event.pointer_location.position;
+                            if world.is_disabled(id) {
+                                return;
+                            }
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            world.run_callback(on_drag, TabDrag::Start(index, position));
+                        }),
+                        On::<Pointer<Drag>>::run(move |world: &mut World| {
+                            let Some(on_drag) = on_drag else {
+                                return;
+                            };
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Drag>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            let position = event.pointer_location.position;
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            world.run_callback(on_drag, TabDrag::Move(index, position));
+                        }),
+                        On::<Pointer<DragEnd>>::run(move |world: &mut World| {
+                            let Some(on_drag) = on_drag else {
+                                return;
+                            };
+                            world
+                                .resource_mut::<ListenerInput<Pointer<DragEnd>>>()
+                                .stop_propagation();
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            world.run_callback(on_drag, TabDrag::End(index));
+                        }),
+                        On::<Pointer<DragEnter>>::run(move |world: &mut World| {
+                            if !reorderable {
+                                return;
+                            }
+                            let event = world.resource::<ListenerInput<Pointer<DragEnter>>>();
+                            let dragged = world.get::<TabKey>(event.dragged).copied();
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            match dragged {
+                                Some(key) if key.bar == bar && key.index != index => {
+                                    drop_before.set(world, Some(key.index > index));
+                                }
+                                _ => {}
+                            }
+                        }),
+                        On::<Pointer<DragLeave>>::run(move |world: &mut World| {
+                            drop_before.set(world, None);
+                        }),
+                        On::<Pointer<DragDrop>>::run(move |world: &mut World| {
+                            drop_before.set(world, None);
+                            if !reorderable {
+                                return;
+                            }
+                            let event = world.resource::<ListenerInput<Pointer<DragDrop>>>();
+                            let dropped = world.get::<TabKey>(event.dropped).copied();
+                            let index = world.get::<TabKey>(id).unwrap().index;
+                            match (dropped, on_reorder) {
+                                (Some(key), Some(on_reorder))
+                                    if key.bar == bar && key.index != index =>
+                                {
+                                    world.run_callback(on_reorder, (key.index, index));
+                                }
+                                _ => {}
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                self.tab.label.clone(),
+                Cond::new(
+                    self.tab.closable,
+                    IconButton::new("embedded://bevy_quill_obsidian/assets/icons/close.png")
+                        .size(Size::Xxs)
+                        .minimal(true)
+                        .tab_index(-1)
+                        .disabled(self.tab.disabled)
+                        .on_click(close_click),
+                    (),
+                ),
+            ))
+    }
+}
+
+/// Entry in the overflow menu of a [`TabBar`].
+#[derive(Clone, PartialEq)]
+struct TabMenuItem {
+    tab: Tab,
+    index: usize,
+    selected: bool,
+    on_select: Option<Callback<usize>>,
+}
+
+impl ViewTemplate for TabMenuItem {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let index = self.index;
+        let on_select = self.on_select;
+        let on_click = cx.create_callback(move |world: &mut World| {
+            if let Some(on_select) = on_select {
+                world.run_callback(on_select, index);
+            }
+        });
+        MenuItem::new()
+            .label(self.tab.label.clone())
+            .checked(self.selected)
+            .disabled(self.tab.disabled)
+            .on_click(on_click)
+    }
+}
+
+/// Displays one of several pages, typically paired with a [`TabBar`] using the same index.
+///
+/// By default only the selected page exists; switching pages discards the state of the previous
+/// one. With `keep_alive`, all pages are built and the inactive ones are hidden instead, so that
+/// state such as scroll positions and text being edited is preserved.
+#[derive(Default, Clone, PartialEq)]
+pub struct TabPanel {
+    /// The pages, one per tab.
+    pub pages: Vec<ViewChild>,
+
+    /// Index of the visible page.
+    pub selected: usize,
+
+    /// Whether to keep inactive pages alive.
+    pub keep_alive: bool,
+
+    /// Additional styles to be applied to the tab panel.
+    pub style: StyleHandle,
+}
+
+impl TabPanel {
+    /// Create a new tab panel.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a page to the tab panel.
+    pub fn page(mut self, page: impl IntoViewChild) -> Self {
+        self.pages.push(page.into_view_child());
+        self
+    }
+
+    /// Set the pages, one per tab.
+    pub fn pages(mut self, pages: impl IntoIterator<Item = ViewChild>) -> Self {
+        self.pages = pages.into_iter().collect();
+        self
+    }
+
+    /// Set the index of the visible page.
+    pub fn selected(mut self, selected: usize) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set whether to keep inactive pages alive.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
+    /// Set additional styles to be applied to the tab panel.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl ViewTemplate for TabPanel {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let selected = self.selected;
+        let keep_alive = self.keep_alive;
+
+        // Pages are keyed by index, so that a page which is re-created by the owner with the same
+        // view type is updated in place rather than rebuilt.
+        Element::<NodeBundle>::new()
+            .named("TabPanel")
+            .style((style_tab_panel, self.style.clone()))
+            .insert(AccessibilityNode::from(NodeBuilder::new(Role::TabPanel)))
+            .children(For::index(&self.pages, move |page, index| TabPage {
+                page: page.clone(),
+                active: index == selected,
+                keep_alive,
+            }))
+    }
+}
+
+/// A page within a [`TabPanel`], which is hidden when not selected. The content of an inactive
+/// page only exists if the panel keeps pages alive.
+#[derive(Clone, PartialEq)]
+struct TabPage {
+    page: ViewChild,
+    active: bool,
+    keep_alive: bool,
+}
+
+impl ViewTemplate for TabPage {
+    type View = impl View;
+    fn create(&self, _cx: &mut Cx) -> Self::View {
+        Element::<NodeBundle>::new()
+            .named("TabPage")
+            .style(style_tab_page)
+            .style_dyn(
+                |active, sb| {
+                    sb.display(if active {
+                        ui::Display::Flex
+                    } else {
+                        ui::Display::None
+                    });
+                },
+                self.active,
+            )
+            .children(Cond::new(
+                self.active || self.keep_alive,
+                Dynamic::new(self.page.clone()),
+                (),
+            ))
+    }
+}
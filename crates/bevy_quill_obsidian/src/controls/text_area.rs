@@ -0,0 +1,563 @@
+use std::ops::Range;
+
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{AutoFocus, TabIndex},
+    hooks::UseIsFocus,
+    tokens, typography,
+};
+
+use super::{
+    text_buffer::{
+        commit_text, sync_text_buffer, text_edit_handlers, TextBuffer, TextCaret,
+        TextEditCallbacks, TextEditParams, TextPiece,
+    },
+    Disabled, ScrollView,
+};
+
+fn style_text_area(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .min_width(64)
+        .min_height(48)
+        .padding(3)
+        .background_color(tokens::U1)
+        .border_radius(5)
+        .cursor(CursorIcon::Text);
+}
+
+fn style_text_area_scroll(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).min_height(0);
+}
+
+fn style_text_area_content(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .min_width(ui::Val::Percent(100.))
+        .padding((3, 0));
+}
+
+fn style_text_area_line(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::FlexStart);
+}
+
+fn style_text_area_gutter(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::FlexEnd)
+        .flex_shrink(0.)
+        .padding_right(8)
+        .color(tokens::DIM);
+}
+
+fn style_text_area_line_content(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .flex_grow(1.)
+        .min_width(0)
+        .min_height(20);
+}
+
+fn style_text_area_placeholder(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .left(6)
+        .top(3)
+        .color(tokens::DIM);
+}
+
+/// A range of text to be displayed in a given color, used for syntax highlighting.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HighlightSpan {
+    /// Range of bytes within the text.
+    pub range: Range<usize>,
+
+    /// Color of the text within the range.
+    pub color: ColorToken,
+}
+
+impl HighlightSpan {
+    /// Construct a new highlight span.
+    pub fn new(range: Range<usize>, color: ColorToken) -> Self {
+        Self { range, color }
+    }
+}
+
+/// A contiguous run of text within a line which has the same color and selection state, or
+/// the caret.
+#[derive(Clone, PartialEq)]
+struct LinePiece {
+    start: usize,
+    end: usize,
+    text: String,
+    color: Option<ColorToken>,
+    selected: bool,
+    caret: bool,
+}
+
+/// Split the text of the buffer into lines, and each line into pieces at selection,
+/// caret and highlight boundaries.
+fn layout_lines(
+    buffer: &TextBuffer,
+    highlights: &[HighlightSpan],
+    show_caret: bool,
+) -> Vec<Vec<LinePiece>> {
+    // Convert highlight byte ranges to character ranges.
+    let char_index = |byte: usize| {
+        buffer
+            .text
+            .char_indices()
+            .filter(|(i, _)| *i < byte)
+            .count()
+    };
+    let highlights: Vec<(usize, usize, ColorToken)> = highlights
+        .iter()
+        .map(|span| {
+            (
+                char_index(span.range.start),
+                char_index(span.range.end),
+                span.color,
+            )
+        })
+        .collect();
+    let (sel_start, sel_end) = buffer.selection();
+    let chars: Vec<char> = buffer.text.chars().collect();
+
+    let mut lines = Vec::new();
+    let mut line_start = 0;
+    for line in buffer.text.split('\n') {
+        let line_end = line_start + line.chars().count();
+        let mut bounds = vec![line_start, line_end, sel_start, sel_end, buffer.caret];
+        for (start, end, _) in highlights.iter() {
+            bounds.push(*start);
+            bounds.push(*end);
+        }
+        bounds.retain(|b| *b >= line_start && *b <= line_end);
+        bounds.sort_unstable();
+        bounds.dedup();
+
+        let mut pieces = Vec::new();
+        if show_caret && buffer.caret == line_start {
+            pieces.push(LinePiece {
+                start: line_start,
+                end: line_start,
+                text: String::new(),
+                color: None,
+                selected: false,
+                caret: true,
+            });
+        }
+        for pair in bounds.windows(2) {
+            let (start, end) = (pair[0], pair[1]);
+            if show_caret && start == buffer.caret && start != line_start {
+                pieces.push(LinePiece {
+                    start,
+                    end: start,
+                    text: String::new(),
+                    color: None,
+                    selected: false,
+                    caret: true,
+                });
+            }
+            pieces.push(LinePiece {
+                start,
+                end,
+                text: chars[start..end].iter().collect(),
+                color: highlights
+                    .iter()
+                    .find(|(hs, he, _)| *hs <= start && start < *he)
+                    .map(|(_, _, color)| *color),
+                selected: start >= sel_start && end <= sel_end && sel_start != sel_end,
+                caret: false,
+            });
+        }
+        if bounds.len() < 2 {
+            // Empty line: add an empty piece so that the line can be clicked on.
+            pieces.push(LinePiece {
+                start: line_start,
+                end: line_start,
+                text: String::new(),
+                color: None,
+                selected: false,
+                caret: false,
+            });
+        } else if show_caret && buffer.caret == line_end {
+            pieces.push(LinePiece {
+                start: line_end,
+                end: line_end,
+                text: String::new(),
+                color: None,
+                selected: false,
+                caret: true,
+            });
+        }
+        lines.push(pieces);
+        line_start = line_end + 1;
+    }
+    lines
+}
+
+/// A multi-line editable text field, with optional line numbers and syntax highlighting.
+///
+/// Like [`TextInput`](super::TextInput), the text area is controlled: `on_change` is called
+/// after every edit and the owner is expected to pass the new text back as `value`. Enter
+/// inserts a newline; `on_commit` is called on Ctrl+Enter or when focus leaves the field.
+#[derive(Clone, PartialEq)]
+pub struct TextArea {
+    /// Current text.
+    pub value: String,
+
+    /// Text displayed, dimmed, when the value is empty.
+    pub placeholder: String,
+
+    /// Maximum length of the text, in characters.
+    pub max_length: Option<usize>,
+
+    /// Function which validates a proposed edit. Edits for which it returns false are rejected.
+    pub validate: Option<fn(&str) -> bool>,
+
+    /// Function which returns highlight spans for the text.
+    pub highlighter: Option<fn(&str) -> Vec<HighlightSpan>>,
+
+    /// Whether to display line numbers.
+    pub line_numbers: bool,
+
+    /// Whether long lines wrap. If false, the text area scrolls horizontally.
+    pub wrap: bool,
+
+    /// If true, the text can be selected and copied but not edited.
+    pub read_only: bool,
+
+    /// Whether the text area is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the text area.
+    pub style: StyleHandle,
+
+    /// The tab index of the text area (default 0).
+    pub tab_index: i32,
+
+    /// If true, set focus to this text area when it's added to the UI.
+    pub autofocus: bool,
+
+    /// Callback called after each edit, with the new text.
+    pub on_change: Option<Callback<String>>,
+
+    /// Callback called when the user presses Escape, after reverting the text.
+    pub on_cancel: Option<Callback>,
+
+    /// Callback called when the edit is finished by pressing Ctrl+Enter or losing focus.
+    pub on_commit: Option<Callback<String>>,
+}
+
+impl TextArea {
+    /// Create a new text area.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current text.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the text displayed when the value is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the maximum length of the text, in characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the function used to validate edits.
+    pub fn validate(mut self, validate: fn(&str) -> bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Set the function used to compute syntax highlighting.
+    pub fn highlighter(mut self, highlighter: fn(&str) -> Vec<HighlightSpan>) -> Self {
+        self.highlighter = Some(highlighter);
+        self
+    }
+
+    /// Set whether to display line numbers.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Set whether long lines wrap.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set whether the text area is read-only.
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Set whether the text area is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the text area.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the tab index of the text area.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Set whether the text area should be focused when it's added to the UI.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Set the callback called after each edit.
+    pub fn on_change(mut self, on_change: Callback<String>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the callback called when the edit is finished.
+    pub fn on_commit(mut self, on_commit: Callback<String>) -> Self {
+        self.on_commit = Some(on_commit);
+        self
+    }
+
+    /// Set the callback called when the user presses Escape.
+    pub fn on_cancel(mut self, on_cancel: Callback) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+}
+
+impl Default for TextArea {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            max_length: None,
+            validate: None,
+            highlighter: None,
+            line_numbers: false,
+            wrap: true,
+            read_only: false,
+            disabled: false,
+            style: StyleHandle::default(),
+            tab_index: 0,
+            autofocus: false,
+            on_change: None,
+            on_commit: None,
+            on_cancel: None,
+        }
+    }
+}
+
+impl ViewTemplate for TextArea {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focused(id);
+        let on_commit = self.on_commit;
+        let callbacks = TextEditCallbacks {
+            on_change: self.on_change,
+            on_commit,
+            on_cancel: self.on_cancel,
+        };
+        let wrap = self.wrap;
+        let line_numbers = self.line_numbers;
+
+        // Ensure the edit buffer exists, and is up to date with the value, before rendering.
+        sync_text_buffer(cx.world_mut(), id, &self.value);
+
+        // Commit when focus leaves the field.
+        cx.create_effect(
+            move |world, focused| {
+                if !focused {
+                    commit_text(world, id, on_commit, false);
+                }
+            },
+            focused,
+        );
+
+        let buffer = cx.use_component::<TextBuffer>(id).unwrap();
+        let highlights = match self.highlighter {
+            Some(highlighter) => highlighter(&buffer.text),
+            None => Vec::new(),
+        };
+        let lines = layout_lines(buffer, &highlights, focused);
+        let show_placeholder = buffer.text.is_empty() && !self.placeholder.is_empty();
+        let gutter_width = lines.len().to_string().len().max(2) as f32 * 8. + 8.;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TextArea")
+            .style((
+                typography::text_default,
+                style_text_area,
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |disabled, sb| {
+                    sb.color(if disabled {
+                        tokens::FOREGROUND.with_alpha(0.2)
+                    } else {
+                        tokens::FOREGROUND
+                    });
+                },
+                self.disabled,
+            )
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(1.0)
+                            .outline_width(2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_dyn(
+                |(max_length, validate, read_only)| TextEditParams {
+                    max_length,
+                    validate,
+                    multiline: true,
+                    read_only,
+                },
+                (self.max_length, self.validate, self.read_only),
+            )
+            .insert_if(self.disabled, || Disabled)
+            .insert_if(self.autofocus, || AutoFocus)
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::MultilineTextInput)),
+                        text_edit_handlers(id, callbacks),
+                    )
+                },
+                (),
+            )
+            .children((
+                Cond::new(
+                    show_placeholder,
+                    Element::<NodeBundle>::new()
+                        .style(style_text_area_placeholder)
+                        .children(self.placeholder.clone()),
+                    (),
+                ),
+                ScrollView::new()
+                    .style(style_text_area_scroll)
+                    .scroll_enable_x(!wrap)
+                    .scroll_enable_y(true)
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("TextArea::Content")
+                            .style(style_text_area_content)
+                            .children(For::index(&lines, move |pieces, index| {
+                                Element::<NodeBundle>::new()
+                                    .named("TextArea::Line")
+                                    .style(style_text_area_line)
+                                    .children((
+                                        Cond::new(
+                                            line_numbers,
+                                            Element::<NodeBundle>::new()
+                                                .style(style_text_area_gutter)
+                                                .style_dyn(
+                                                    |width, sb| {
+                                                        sb.width(width);
+                                                    },
+                                                    gutter_width,
+                                                )
+                                                .children(format!("{}", index + 1)),
+                                            (),
+                                        ),
+                                        Element::<NodeBundle>::new()
+                                            .style(style_text_area_line_content)
+                                            .style_dyn(
+                                                |wrap, sb| {
+                                                    sb.flex_wrap(if wrap {
+                                                        ui::FlexWrap::Wrap
+                                                    } else {
+                                                        ui::FlexWrap::NoWrap
+                                                    });
+                                                },
+                                                wrap,
+                                            )
+                                            .children(For::index(pieces, move |piece, _| {
+                                                text_area_piece(id, piece)
+                                            })),
+                                    ))
+                            })),
+                    ),
+            ))
+    }
+}
+
+/// Render a single piece of a line.
+fn text_area_piece(owner: Entity, piece: &LinePiece) -> impl View {
+    Element::<NodeBundle>::new()
+        .style_dyn(
+            |(color, selected, caret), sb| {
+                if caret {
+                    sb.flex_shrink(0.)
+                        .width(1)
+                        .height(16)
+                        .margin_right(-1)
+                        .background_color(tokens::FOREGROUND);
+                } else {
+                    sb.flex_shrink(1.)
+                        .width(ui::Val::Auto)
+                        .height(ui::Val::Auto)
+                        .margin_right(0)
+                        .background_color(if selected {
+                            tokens::TEXT_SELECT
+                        } else {
+                            tokens::TRANSPARENT
+                        });
+                }
+                match color {
+                    Some(color) => sb.color(color),
+                    None => sb.color(Option::<Color>::None),
+                };
+            },
+            (piece.color, piece.selected, piece.caret),
+        )
+        .insert_dyn(
+            move |(start, end)| TextPiece { owner, start, end },
+            (piece.start, piece.end),
+        )
+        .insert_if(piece.caret, move || TextCaret(owner))
+        .children(piece.text.clone())
+}
@@ -0,0 +1,892 @@
+use bevy::{a11y::Focus, prelude::*, text::TextLayoutInfo};
+use bevy_mod_picking::prelude::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::{KeyCharEvent, KeyPressEvent},
+    scrolling::{scroll_into_view, ScrollAlignment},
+};
+
+use super::{
+    shortcut::{is_ctrl_pressed, is_shift_pressed},
+    IsDisabled,
+};
+
+/// Clipboard shared by all text inputs, used for copy, cut and paste.
+///
+/// With the `system_clipboard` feature, this reads and writes the system clipboard. Otherwise,
+/// or if the system clipboard can't be opened, the text is only shared within the application.
+#[derive(Resource)]
+pub struct TextClipboard {
+    text: String,
+    #[cfg(feature = "system_clipboard")]
+    system: Option<std::sync::Mutex<arboard::Clipboard>>,
+}
+
+impl Default for TextClipboard {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            #[cfg(feature = "system_clipboard")]
+            system: match arboard::Clipboard::new() {
+                Ok(clipboard) => Some(std::sync::Mutex::new(clipboard)),
+                Err(err) => {
+                    warn!("System clipboard unavailable: {}", err);
+                    None
+                }
+            },
+        }
+    }
+}
+
+impl TextClipboard {
+    /// Return the text on the clipboard.
+    pub fn get(&self) -> String {
+        #[cfg(feature = "system_clipboard")]
+        if let Some(text) = self
+            .system
+            .as_ref()
+            .and_then(|system| system.lock().ok()?.get_text().ok())
+        {
+            return text;
+        }
+        self.text.clone()
+    }
+
+    /// Place text on the clipboard.
+    pub fn set(&mut self, text: String) {
+        #[cfg(feature = "system_clipboard")]
+        if let Some(Ok(mut system)) = self.system.as_ref().map(|system| system.lock()) {
+            if let Err(err) = system.set_text(text.clone()) {
+                warn!("Failed to write to the system clipboard: {}", err);
+            }
+        }
+        self.text = text;
+    }
+}
+
+/// A snapshot of the edit buffer, used for undo and redo.
+#[derive(Clone, Debug)]
+struct TextSnapshot {
+    text: String,
+    caret: usize,
+    anchor: usize,
+}
+
+/// Editing state shared by the text input and text area. Caret and anchor positions are in
+/// characters, not bytes; the selection is the range between them.
+#[derive(Component, Clone, Debug, Default)]
+pub(super) struct TextBuffer {
+    /// Text being edited.
+    pub(super) text: String,
+    /// The most recent value received from the `value` property.
+    source: String,
+    pub(super) caret: usize,
+    pub(super) anchor: usize,
+    undo: Vec<TextSnapshot>,
+    redo: Vec<TextSnapshot>,
+    /// True if the last edit was typing, so that consecutive characters form one undo step.
+    typing: bool,
+    /// True if the caret has moved since it was last scrolled into view.
+    reveal_caret: bool,
+}
+
+impl TextBuffer {
+    fn new(value: &str) -> Self {
+        let len = value.chars().count();
+        Self {
+            text: value.to_string(),
+            source: value.to_string(),
+            caret: len,
+            anchor: len,
+            ..default()
+        }
+    }
+
+    /// Update from the `value` property. The edit buffer is only replaced if the value differs
+    /// from what is being edited, so that echoing `on_change` back as the value keeps the caret
+    /// and undo history intact.
+    fn sync(&mut self, value: &str) {
+        self.source = value.to_string();
+        if self.text != value {
+            self.text = value.to_string();
+            let len = self.len();
+            self.caret = self.caret.min(len);
+            self.anchor = self.anchor.min(len);
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        self.text.chars().count()
+    }
+
+    fn byte_offset(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map_or(self.text.len(), |(offset, _)| offset)
+    }
+
+    pub(super) fn selection(&self) -> (usize, usize) {
+        (self.caret.min(self.anchor), self.caret.max(self.anchor))
+    }
+
+    pub(super) fn has_selection(&self) -> bool {
+        self.caret != self.anchor
+    }
+
+    /// The text between character indices `start` and `end`, clamped to the end of the text.
+    pub(super) fn slice(&self, start: usize, end: usize) -> &str {
+        let start = self.byte_offset(start);
+        let end = self.byte_offset(end).max(start);
+        self.text.get(start..end).unwrap_or_default()
+    }
+
+    fn selected_text(&self) -> &str {
+        let (start, end) = self.selection();
+        self.slice(start, end)
+    }
+
+    fn snapshot(&self) -> TextSnapshot {
+        TextSnapshot {
+            text: self.text.clone(),
+            caret: self.caret,
+            anchor: self.anchor,
+        }
+    }
+
+    fn restore(&mut self, snapshot: TextSnapshot) {
+        self.text = snapshot.text;
+        self.caret = snapshot.caret;
+        self.anchor = snapshot.anchor;
+        self.typing = false;
+    }
+
+    fn move_caret(&mut self, index: usize, extend: bool) {
+        self.caret = index.min(self.len());
+        if !extend {
+            self.anchor = self.caret;
+        }
+        self.typing = false;
+    }
+
+    pub(super) fn select_all(&mut self) {
+        self.anchor = 0;
+        self.caret = self.len();
+        self.typing = false;
+    }
+
+    /// Find the start of the word preceding `index`.
+    fn prev_word(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i > 0 && chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        while i > 0 && !chars[i - 1].is_whitespace() {
+            i -= 1;
+        }
+        i
+    }
+
+    /// Find the end of the word following `index`.
+    fn next_word(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i < chars.len() && chars[i].is_whitespace() {
+            i += 1;
+        }
+        while i < chars.len() && !chars[i].is_whitespace() {
+            i += 1;
+        }
+        i
+    }
+
+    /// Find the start of the line containing `index`.
+    fn line_start(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i > 0 && chars[i - 1] != '\n' {
+            i -= 1;
+        }
+        i
+    }
+
+    /// Find the end of the line containing `index`, not including the newline.
+    fn line_end(&self, index: usize) -> usize {
+        let chars: Vec<char> = self.text.chars().collect();
+        let mut i = index.min(chars.len());
+        while i < chars.len() && chars[i] != '\n' {
+            i += 1;
+        }
+        i
+    }
+
+    /// Find the index in the previous or next line with the same column as `index`. Moving
+    /// past the first or last line goes to the start or end of the text.
+    fn vertical(&self, index: usize, down: bool) -> usize {
+        let start = self.line_start(index);
+        let column = index - start;
+        if down {
+            let end = self.line_end(index);
+            if end >= self.len() {
+                return self.len();
+            }
+            (end + 1 + column).min(self.line_end(end + 1))
+        } else {
+            if start == 0 {
+                return 0;
+            }
+            let prev_start = self.line_start(start - 1);
+            (prev_start + column).min(start - 1)
+        }
+    }
+
+    /// Replace the selection with `insert`, truncating it to fit within the maximum length.
+    /// Returns false if the resulting text is rejected by the validator, or nothing changed.
+    fn replace_selection(&mut self, insert: &str, params: &TextEditParams, typing: bool) -> bool {
+        if params.read_only {
+            return false;
+        }
+        let (start, end) = self.selection();
+        let insert: String = match params.max_length {
+            Some(max) => {
+                let room = max.saturating_sub(self.len() - (end - start));
+                insert.chars().take(room).collect()
+            }
+            None => insert.to_string(),
+        };
+        if insert.is_empty() && start == end {
+            return false;
+        }
+
+        let mut text = self.text.clone();
+        text.replace_range(self.byte_offset(start)..self.byte_offset(end), &insert);
+        if let Some(validate) = params.validate {
+            if !validate(&text) {
+                return false;
+            }
+        }
+
+        // Consecutive typed characters are grouped into a single undo step.
+        if !(typing && self.typing) {
+            let snapshot = self.snapshot();
+            self.undo.push(snapshot);
+        }
+        self.redo.clear();
+        self.text = text;
+        self.caret = start + insert.chars().count();
+        self.anchor = self.caret;
+        self.typing = typing;
+        true
+    }
+
+    /// Delete the selection, or if there is none, the range between the caret and `to`.
+    fn delete_to(&mut self, to: usize, params: &TextEditParams) -> bool {
+        let anchor = self.anchor;
+        if !self.has_selection() {
+            self.anchor = to.min(self.len());
+        }
+        if self.replace_selection("", params, false) {
+            return true;
+        }
+        self.anchor = anchor;
+        false
+    }
+
+    /// Discard edits, returning to the last value received from the owner.
+    fn revert(&mut self) -> bool {
+        if self.text == self.source {
+            return false;
+        }
+        let snapshot = self.snapshot();
+        self.undo.push(snapshot);
+        self.redo.clear();
+        self.text.clone_from(&self.source);
+        self.select_all();
+        true
+    }
+
+    fn undo(&mut self) -> bool {
+        match self.undo.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.redo.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some(snapshot) => {
+                let current = self.snapshot();
+                self.undo.push(current);
+                self.restore(snapshot);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// Component used to hold the text editing params so that they can be accessed by the
+/// callbacks without capturing.
+#[derive(Component, Clone, Copy, PartialEq)]
+pub(super) struct TextEditParams {
+    pub(super) max_length: Option<usize>,
+    pub(super) validate: Option<fn(&str) -> bool>,
+    pub(super) multiline: bool,
+    pub(super) read_only: bool,
+}
+
+/// Marks a node which displays the characters `start..end` of the text buffer on `owner`.
+/// Used to find the character under the pointer.
+#[derive(Component, Clone, Copy, Debug)]
+pub(super) struct TextPiece {
+    pub(super) owner: Entity,
+    pub(super) start: usize,
+    pub(super) end: usize,
+}
+
+/// Marks the caret of the text field `0`.
+#[derive(Component, Clone, Copy, Debug)]
+pub(super) struct TextCaret(pub(super) Entity);
+
+/// Callbacks of a text field.
+#[derive(Clone, Copy)]
+pub(super) struct TextEditCallbacks {
+    pub(super) on_change: Option<Callback<String>>,
+    pub(super) on_commit: Option<Callback<String>>,
+    pub(super) on_cancel: Option<Callback>,
+}
+
+/// Create the edit buffer on entity `id`, or update it with a new value from the owner.
+pub(super) fn sync_text_buffer(world: &mut World, id: Entity, value: &str) {
+    let mut entt = world.entity_mut(id);
+    match entt.get_mut::<TextBuffer>() {
+        Some(mut buffer) => {
+            if buffer.source != value {
+                buffer.sync(value);
+            }
+        }
+        None => {
+            entt.insert(TextBuffer::new(value));
+        }
+    }
+}
+
+/// Mutate the buffer of the text field `id`, and call `on_change` if `edit` returns true.
+fn edit_text(
+    world: &mut World,
+    id: Entity,
+    on_change: Option<Callback<String>>,
+    edit: impl FnOnce(&mut TextBuffer, &TextEditParams) -> bool,
+) {
+    let mut entt = world.entity_mut(id);
+    let params = *entt.get::<TextEditParams>().unwrap();
+    let mut buffer = entt.get_mut::<TextBuffer>().unwrap();
+    buffer.reveal_caret = true;
+    if edit(&mut buffer, &params) {
+        let text = buffer.text.clone();
+        if let Some(on_change) = on_change {
+            world.run_callback(on_change, text);
+        }
+    }
+}
+
+/// Call `on_commit` if the text differs from the last value received from the owner, or if
+/// `force` is true.
+pub(super) fn commit_text(
+    world: &mut World,
+    id: Entity,
+    on_commit: Option<Callback<String>>,
+    force: bool,
+) {
+    let Some(mut buffer) = world.get_mut::<TextBuffer>(id) else {
+        return;
+    };
+    if force || buffer.text != buffer.source {
+        let text = buffer.text.clone();
+        buffer.source = text.clone();
+        if let Some(on_commit) = on_commit {
+            world.run_callback(on_commit, text);
+        }
+    }
+}
+
+fn axis_distance(value: f32, min: f32, max: f32) -> f32 {
+    if value < min {
+        min - value
+    } else if value > max {
+        value - max
+    } else {
+        0.
+    }
+}
+
+/// Return the character index of text field `id` nearest to the window position `pos`.
+fn char_index_at(world: &mut World, id: Entity, pos: Vec2) -> usize {
+    // Find the nearest piece, preferring pieces on the same row.
+    let mut best: Option<(Entity, TextPiece, Rect)> = None;
+    let mut best_distance = (f32::MAX, f32::MAX);
+    let mut query = world.query::<(Entity, &TextPiece, &Node, &GlobalTransform)>();
+    for (entity, piece, node, transform) in query.iter(world) {
+        if piece.owner != id {
+            continue;
+        }
+        let rect = node.logical_rect(transform);
+        let distance = (
+            axis_distance(pos.y, rect.min.y, rect.max.y),
+            axis_distance(pos.x, rect.min.x, rect.max.x),
+        );
+        if distance < best_distance {
+            best_distance = distance;
+            best = Some((entity, *piece, rect));
+        }
+    }
+
+    let buffer = world.get::<TextBuffer>(id).unwrap();
+    let Some((entity, piece, rect)) = best else {
+        return buffer.len();
+    };
+    if pos.x < rect.min.x {
+        return piece.start;
+    }
+
+    // Within the piece, find the first glyph on the nearest row whose center lies to the
+    // right of the pointer. Pieces may wrap, so there can be more than one row.
+    let text = buffer.slice(piece.start, piece.end);
+    let local = pos - rect.min;
+    let layout = world
+        .get::<Children>(entity)
+        .and_then(|children| children.first())
+        .and_then(|text| world.get::<TextLayoutInfo>(*text));
+    let Some(layout) = layout else {
+        return piece.end;
+    };
+    let Some(row) = layout
+        .glyphs
+        .iter()
+        .map(|glyph| glyph.position.y)
+        .min_by(|a, b| (a - local.y).abs().total_cmp(&(b - local.y).abs()))
+    else {
+        return piece.end;
+    };
+    let mut index = piece.start;
+    for glyph in layout
+        .glyphs
+        .iter()
+        .filter(|glyph| (glyph.position.y - row).abs() < 0.5)
+    {
+        // The layout may be stale, so a glyph can lie past the end of the piece.
+        let glyph_index = piece.start
+            + text
+                .get(..glyph.byte_index)
+                .map_or(piece.end - piece.start, |prefix| prefix.chars().count());
+        if local.x < glyph.position.x {
+            return glyph_index;
+        }
+        index = glyph_index + 1;
+    }
+    index.min(piece.end)
+}
+
+/// True if the key is handled by the text field, rather than passed on to the parent.
+pub(super) fn is_edit_key(key_code: KeyCode, ctrl: bool, multiline: bool) -> bool {
+    match key_code {
+        KeyCode::ArrowLeft
+        | KeyCode::ArrowRight
+        | KeyCode::Home
+        | KeyCode::End
+        | KeyCode::Backspace
+        | KeyCode::Delete
+        | KeyCode::Enter
+        | KeyCode::Escape => true,
+        KeyCode::ArrowUp | KeyCode::ArrowDown => multiline,
+        KeyCode::Space => !ctrl,
+        KeyCode::KeyA
+        | KeyCode::KeyC
+        | KeyCode::KeyV
+        | KeyCode::KeyX
+        | KeyCode::KeyY
+        | KeyCode::KeyZ => ctrl,
+        _ => false,
+    }
+}
+
+fn handle_edit_key(
+    world: &mut World,
+    id: Entity,
+    key_code: KeyCode,
+    shift: bool,
+    ctrl: bool,
+    callbacks: TextEditCallbacks,
+) {
+    let on_change = callbacks.on_change;
+    let multiline = world.get::<TextEditParams>(id).unwrap().multiline;
+    match key_code {
+        KeyCode::ArrowLeft => edit_text(world, id, None, |buffer, _| {
+            let to = match (ctrl, buffer.has_selection() && !shift) {
+                (true, _) => buffer.prev_word(buffer.caret),
+                (false, true) => buffer.selection().0,
+                (false, false) => buffer.caret.saturating_sub(1),
+            };
+            buffer.move_caret(to, shift);
+            false
+        }),
+        KeyCode::ArrowRight => edit_text(world, id, None, |buffer, _| {
+            let to = match (ctrl, buffer.has_selection() && !shift) {
+                (true, _) => buffer.next_word(buffer.caret),
+                (false, true) => buffer.selection().1,
+                (false, false) => buffer.caret + 1,
+            };
+            buffer.move_caret(to, shift);
+            false
+        }),
+        KeyCode::ArrowUp | KeyCode::ArrowDown => edit_text(world, id, None, |buffer, _| {
+            let to = buffer.vertical(buffer.caret, key_code == KeyCode::ArrowDown);
+            buffer.move_caret(to, shift);
+            false
+        }),
+        KeyCode::Home => edit_text(world, id, None, |buffer, _| {
+            let to = match multiline && !ctrl {
+                true => buffer.line_start(buffer.caret),
+                false => 0,
+            };
+            buffer.move_caret(to, shift);
+            false
+        }),
+        KeyCode::End => edit_text(world, id, None, |buffer, _| {
+            let to = match multiline && !ctrl {
+                true => buffer.line_end(buffer.caret),
+                false => buffer.len(),
+            };
+            buffer.move_caret(to, shift);
+            false
+        }),
+        KeyCode::Backspace => edit_text(world, id, on_change, |buffer, params| {
+            let to = match ctrl {
+                true => buffer.prev_word(buffer.caret),
+                false => buffer.caret.saturating_sub(1),
+            };
+            buffer.delete_to(to, params)
+        }),
+        KeyCode::Delete => edit_text(world, id, on_change, |buffer, params| {
+            let to = match ctrl {
+                true => buffer.next_word(buffer.caret),
+                false => buffer.caret + 1,
+            };
+            buffer.delete_to(to, params)
+        }),
+        KeyCode::Space => edit_text(world, id, on_change, |buffer, params| {
+            buffer.replace_selection(" ", params, true)
+        }),
+        // In a multi-line field, Enter inserts a newline and Ctrl+Enter commits.
+        KeyCode::Enter if multiline && !ctrl => {
+            edit_text(world, id, on_change, |buffer, params| {
+                buffer.replace_selection("\n", params, false)
+            })
+        }
+        KeyCode::Enter => commit_text(world, id, callbacks.on_commit, true),
+        KeyCode::Escape => {
+            edit_text(world, id, on_change, |buffer, _| buffer.revert());
+            if let Some(on_cancel) = callbacks.on_cancel {
+                world.run_callback(on_cancel, ());
+            }
+        }
+        KeyCode::KeyA => edit_text(world, id, None, |buffer, _| {
+            buffer.select_all();
+            false
+        }),
+        KeyCode::KeyC | KeyCode::KeyX => {
+            let buffer = world.get::<TextBuffer>(id).unwrap();
+            if buffer.has_selection() {
+                let text = buffer.selected_text().to_string();
+                world.resource_mut::<TextClipboard>().set(text);
+                if key_code == KeyCode::KeyX {
+                    edit_text(world, id, on_change, |buffer, params| {
+                        buffer.replace_selection("", params, false)
+                    });
+                }
+            }
+        }
+        KeyCode::KeyV => {
+            let clipboard = world.resource::<TextClipboard>().get();
+            // Single-line fields only paste the first line of the clipboard.
+            let text = match multiline {
+                true => clipboard,
+                false => clipboard.lines().next().unwrap_or_default().to_string(),
+            };
+            edit_text(world, id, on_change, |buffer, params| {
+                buffer.replace_selection(&text, params, false)
+            });
+        }
+        KeyCode::KeyZ if shift => edit_text(world, id, on_change, |buffer, _| buffer.redo()),
+        KeyCode::KeyZ => edit_text(world, id, on_change, |buffer, _| buffer.undo()),
+        KeyCode::KeyY => edit_text(world, id, on_change, |buffer, _| buffer.redo()),
+        _ => {}
+    }
+}
+
+/// Event handlers for pointer selection and keyboard editing of the text field `id`. The
+/// entity must have a [`TextBuffer`] and [`TextEditParams`].
+pub(super) fn text_edit_handlers(id: Entity, callbacks: TextEditCallbacks) -> impl Bundle {
+    (
+        On::<Pointer<Down>>::run(move |world: &mut World| {
+            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+            focus.0 = Some(id);
+            if !world.is_disabled(id) {
+                let mut event = world
+                    .get_resource_mut::<ListenerInput<Pointer<Down>>>()
+                    .unwrap();
+                event.stop_propagation();
+                let pos = event.pointer_location.position;
+                let extend = is_shift_pressed(world);
+                let index = char_index_at(world, id, pos);
+                let mut buffer = world.get_mut::<TextBuffer>(id).unwrap();
+                buffer.move_caret(index, extend);
+            }
+        }),
+        On::<Pointer<Drag>>::run(move |world: &mut World| {
+            if !world.is_disabled(id) {
+                let event = world
+                    .get_resource::<ListenerInput<Pointer<Drag>>>()
+                    .unwrap();
+                let pos = event.pointer_location.position;
+                let index = char_index_at(world, id, pos);
+                let mut buffer = world.get_mut::<TextBuffer>(id).unwrap();
+                if buffer.caret != index {
+                    buffer.move_caret(index, true);
+                }
+            }
+        }),
+        On::<KeyCharEvent>::run(move |world: &mut World| {
+            if world.is_disabled(id) || is_ctrl_pressed(world) {
+                return;
+            }
+            let mut event = world
+                .get_resource_mut::<ListenerInput<KeyCharEvent>>()
+                .unwrap();
+            // Space is handled as a key press.
+            if event.key.is_control() || event.key == ' ' {
+                return;
+            }
+            event.stop_propagation();
+            let key = event.key.to_string();
+            edit_text(world, id, callbacks.on_change, |buffer, params| {
+                buffer.replace_selection(&key, params, true)
+            });
+        }),
+        On::<KeyPressEvent>::run(move |world: &mut World| {
+            if world.is_disabled(id) {
+                return;
+            }
+            let ctrl = is_ctrl_pressed(world);
+            let multiline = world.get::<TextEditParams>(id).unwrap().multiline;
+            let mut event = world
+                .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                .unwrap();
+            let key_code = event.key_code;
+            let shift = event.shift;
+            if is_edit_key(key_code, ctrl, multiline) {
+                event.stop_propagation();
+                handle_edit_key(world, id, key_code, shift, ctrl, callbacks);
+            }
+        }),
+    )
+}
+
+/// Scroll the caret of each text field into view after an edit or caret movement. This runs
+/// after layout, so that the caret's position reflects the change.
+pub(crate) fn reveal_text_caret(world: &mut World) {
+    let mut query = world.query::<(Entity, &TextCaret)>();
+    let carets: Vec<(Entity, Entity)> = query
+        .iter(world)
+        .map(|(caret, owner)| (caret, owner.0))
+        .collect();
+    for (caret, owner) in carets {
+        let Some(mut buffer) = world.get_mut::<TextBuffer>(owner) else {
+            continue;
+        };
+        if buffer.reveal_caret {
+            buffer.reveal_caret = false;
+            scroll_into_view(world, caret, ScrollAlignment::Nearest, false);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PARAMS: TextEditParams = TextEditParams {
+        max_length: None,
+        validate: None,
+        multiline: false,
+        read_only: false,
+    };
+
+    #[test]
+    fn test_caret_movement() {
+        let mut buffer = TextBuffer::new("héllo");
+        assert_eq!(buffer.caret, 5);
+        assert_eq!(buffer.len(), 5);
+
+        buffer.move_caret(1, false);
+        assert_eq!((buffer.caret, buffer.anchor), (1, 1));
+        buffer.move_caret(10, false);
+        assert_eq!(buffer.caret, 5);
+
+        // Multi-byte characters are counted as one.
+        assert!(buffer.replace_selection("!", &PARAMS, true));
+        assert_eq!(buffer.text, "héllo!");
+        buffer.move_caret(2, false);
+        assert!(buffer.replace_selection("x", &PARAMS, true));
+        assert_eq!(buffer.text, "héxllo!");
+        assert_eq!(buffer.caret, 3);
+    }
+
+    #[test]
+    fn test_word_jumps() {
+        let buffer = TextBuffer::new("one  two three");
+        assert_eq!(buffer.next_word(0), 3);
+        assert_eq!(buffer.next_word(3), 8);
+        assert_eq!(buffer.next_word(14), 14);
+        assert_eq!(buffer.prev_word(14), 9);
+        assert_eq!(buffer.prev_word(9), 5);
+        assert_eq!(buffer.prev_word(5), 0);
+        assert_eq!(buffer.prev_word(0), 0);
+    }
+
+    #[test]
+    fn test_lines() {
+        let buffer = TextBuffer::new("ab\ncdef\ng");
+        assert_eq!(buffer.line_start(5), 3);
+        assert_eq!(buffer.line_end(5), 7);
+        assert_eq!(buffer.vertical(1, true), 4);
+        assert_eq!(buffer.vertical(6, true), 9);
+        assert_eq!(buffer.vertical(6, false), 2);
+        assert_eq!(buffer.vertical(1, false), 0);
+        assert_eq!(buffer.vertical(8, true), 9);
+    }
+
+    #[test]
+    fn test_selection() {
+        let mut buffer = TextBuffer::new("hello world");
+        buffer.move_caret(6, false);
+        buffer.move_caret(2, true);
+        assert!(buffer.has_selection());
+        assert_eq!(buffer.selection(), (2, 6));
+        assert_eq!(buffer.selected_text(), "llo ");
+
+        assert!(buffer.replace_selection("y ", &PARAMS, false));
+        assert_eq!(buffer.text, "hey world");
+        assert_eq!(buffer.caret, 4);
+        assert!(!buffer.has_selection());
+
+        buffer.select_all();
+        assert_eq!(buffer.selected_text(), "hey world");
+        assert_eq!(buffer.slice(4, 100), "world");
+        assert_eq!(buffer.slice(20, 30), "");
+    }
+
+    #[test]
+    fn test_delete() {
+        let mut buffer = TextBuffer::new("hello world");
+        assert!(buffer.delete_to(10, &PARAMS));
+        assert_eq!(buffer.text, "hello worl");
+        buffer.move_caret(0, false);
+        assert!(!buffer.delete_to(0, &PARAMS));
+        assert!(buffer.delete_to(buffer.next_word(0), &PARAMS));
+        assert_eq!(buffer.text, " worl");
+    }
+
+    #[test]
+    fn test_undo_redo() {
+        let mut buffer = TextBuffer::new("");
+        for c in ["a", "b", "c"] {
+            buffer.replace_selection(c, &PARAMS, true);
+        }
+        buffer.replace_selection("-", &PARAMS, false);
+        assert_eq!(buffer.text, "abc-");
+
+        // Typed characters are undone as one step.
+        assert!(buffer.undo());
+        assert_eq!(buffer.text, "abc");
+        assert!(buffer.undo());
+        assert_eq!((buffer.text.as_str(), buffer.caret), ("", 0));
+        assert!(!buffer.undo());
+
+        assert!(buffer.redo());
+        assert_eq!((buffer.text.as_str(), buffer.caret), ("abc", 3));
+        assert!(buffer.redo());
+        assert!(!buffer.redo());
+
+        // A new edit discards the redo history.
+        buffer.undo();
+        buffer.replace_selection("!", &PARAMS, false);
+        assert_eq!(buffer.text, "abc!");
+        assert!(!buffer.redo());
+    }
+
+    #[test]
+    fn test_revert() {
+        let mut buffer = TextBuffer::new("abc");
+        assert!(!buffer.revert());
+        buffer.replace_selection("d", &PARAMS, true);
+        assert!(buffer.revert());
+        assert_eq!(buffer.text, "abc");
+        assert_eq!(buffer.selection(), (0, 3));
+        assert!(buffer.undo());
+        assert_eq!(buffer.text, "abcd");
+    }
+
+    #[test]
+    fn test_max_length() {
+        let params = TextEditParams {
+            max_length: Some(5),
+            ..PARAMS
+        };
+        let mut buffer = TextBuffer::new("abc");
+        assert!(buffer.replace_selection("defgh", &params, false));
+        assert_eq!(buffer.text, "abcde");
+        assert!(!buffer.replace_selection("x", &params, true));
+
+        // Replacing a selection makes room for the inserted text.
+        buffer.move_caret(1, false);
+        buffer.move_caret(3, true);
+        assert!(buffer.replace_selection("xyz", &params, false));
+        assert_eq!(buffer.text, "axyde");
+    }
+
+    #[test]
+    fn test_validate() {
+        let params = TextEditParams {
+            validate: Some(|text: &str| text.chars().all(|c| c.is_ascii_digit())),
+            ..PARAMS
+        };
+        let mut buffer = TextBuffer::new("12");
+        assert!(buffer.replace_selection("3", &params, true));
+        assert!(!buffer.replace_selection("x", &params, true));
+        assert_eq!((buffer.text.as_str(), buffer.caret), ("123", 3));
+
+        let read_only = TextEditParams {
+            read_only: true,
+            ..PARAMS
+        };
+        assert!(!buffer.replace_selection("4", &read_only, true));
+    }
+
+    #[test]
+    fn test_sync() {
+        let mut buffer = TextBuffer::new("hello");
+        buffer.move_caret(2, false);
+        buffer.sync("hello");
+        assert_eq!(buffer.caret, 2);
+        buffer.sync("h");
+        assert_eq!((buffer.text.as_str(), buffer.caret), ("h", 1));
+    }
+}
@@ -0,0 +1,381 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{AutoFocus, TabIndex},
+    hooks::UseIsFocus,
+    scrolling::ScrollArea,
+    tokens, typography,
+};
+
+use super::{
+    text_buffer::{
+        commit_text, sync_text_buffer, text_edit_handlers, TextBuffer, TextCaret,
+        TextEditCallbacks, TextEditParams, TextPiece,
+    },
+    Disabled,
+};
+
+fn style_text_input(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .min_width(32)
+        .height(24)
+        .padding((6, 0))
+        .background_color(tokens::U1)
+        .border_radius(5)
+        .overflow(ui::OverflowAxis::Clip)
+        .cursor(CursorIcon::Text);
+}
+
+fn style_text_input_content(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .flex_grow(1.)
+        .height(ui::Val::Percent(100.))
+        .overflow(ui::OverflowAxis::Clip);
+}
+
+fn style_text_input_text(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .height(ui::Val::Percent(100.));
+}
+
+fn style_text_input_span(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.);
+}
+
+fn style_text_input_selection(ss: &mut StyleBuilder) {
+    ss.background_color(tokens::TEXT_SELECT);
+}
+
+fn style_text_input_placeholder(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .left(0)
+        .color(tokens::DIM);
+}
+
+fn style_text_input_caret(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.)
+        .width(1)
+        .height(16)
+        .margin_right(-1)
+        .background_color(tokens::FOREGROUND);
+}
+
+/// A single-line editable text field.
+///
+/// The text input is controlled: `on_change` is called with the new text after every edit, and
+/// the owner is expected to pass it back as `value`. `on_commit` is called when the user presses
+/// Enter or focus leaves the field. Escape reverts to the last value passed in.
+///
+/// Keyboard shortcuts: arrows, Home and End move the caret (with Shift to extend the selection,
+/// and Ctrl to move by words); Ctrl+A selects all; Ctrl+C, Ctrl+X and Ctrl+V copy, cut and paste
+/// using the [`TextClipboard`](super::TextClipboard) resource; Ctrl+Z undoes and Ctrl+Shift+Z or
+/// Ctrl+Y redoes.
+#[derive(Clone, PartialEq)]
+pub struct TextInput {
+    /// Current text.
+    pub value: String,
+
+    /// Text displayed, dimmed, when the value is empty.
+    pub placeholder: String,
+
+    /// Maximum length of the text, in characters.
+    pub max_length: Option<usize>,
+
+    /// Function which validates a proposed edit. Edits for which it returns false are rejected.
+    pub validate: Option<fn(&str) -> bool>,
+
+    /// Whether the text input is disabled.
+    pub disabled: bool,
+
+    /// Additional styles to be applied to the text input.
+    pub style: StyleHandle,
+
+    /// The tab index of the text input (default 0).
+    pub tab_index: i32,
+
+    /// If true, set focus to this text input when it's added to the UI.
+    pub autofocus: bool,
+
+    /// If true, select all of the text when the text input receives focus.
+    pub select_on_focus: bool,
+
+    /// Callback called after each edit, with the new text.
+    pub on_change: Option<Callback<String>>,
+
+    /// Callback called when the user presses Escape, after reverting the text.
+    pub on_cancel: Option<Callback>,
+
+    /// Callback called when the edit is finished by pressing Enter or losing focus.
+    pub on_commit: Option<Callback<String>>,
+}
+
+impl TextInput {
+    /// Create a new text input.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the current text.
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Set the text displayed when the value is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Set the maximum length of the text, in characters.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Set the function used to validate edits.
+    pub fn validate(mut self, validate: fn(&str) -> bool) -> Self {
+        self.validate = Some(validate);
+        self
+    }
+
+    /// Set whether the text input is disabled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set additional styles to be applied to the text input.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the tab index of the text input.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+
+    /// Set whether the text input should be focused when it's added to the UI.
+    pub fn autofocus(mut self, autofocus: bool) -> Self {
+        self.autofocus = autofocus;
+        self
+    }
+
+    /// Set whether to select all of the text when the text input receives focus.
+    pub fn select_on_focus(mut self, select_on_focus: bool) -> Self {
+        self.select_on_focus = select_on_focus;
+        self
+    }
+
+    /// Set the callback called after each edit.
+    pub fn on_change(mut self, on_change: Callback<String>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the callback called when the edit is finished.
+    pub fn on_commit(mut self, on_commit: Callback<String>) -> Self {
+        self.on_commit = Some(on_commit);
+        self
+    }
+
+    /// Set the callback called when the user presses Escape.
+    pub fn on_cancel(mut self, on_cancel: Callback) -> Self {
+        self.on_cancel = Some(on_cancel);
+        self
+    }
+}
+
+impl Default for TextInput {
+    fn default() -> Self {
+        Self {
+            value: String::new(),
+            placeholder: String::new(),
+            max_length: None,
+            validate: None,
+            disabled: false,
+            style: StyleHandle::default(),
+            tab_index: 0,
+            autofocus: false,
+            select_on_focus: false,
+            on_change: None,
+            on_commit: None,
+            on_cancel: None,
+        }
+    }
+}
+
+impl ViewTemplate for TextInput {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let focused = cx.is_focused(id);
+        let on_commit = self.on_commit;
+        let callbacks = TextEditCallbacks {
+            on_change: self.on_change,
+            on_commit,
+            on_cancel: self.on_cancel,
+        };
+
+        // Ensure the edit buffer exists, and is up to date with the value, before rendering.
+        sync_text_buffer(cx.world_mut(), id, &self.value);
+
+        // Commit when focus leaves the field.
+        cx.create_effect(
+            move |world, (focused, select_on_focus)| {
+                if !focused {
+                    commit_text(world, id, on_commit, false);
+                } else if select_on_focus {
+                    world.get_mut::<TextBuffer>(id).unwrap().select_all();
+                }
+            },
+            (focused, self.select_on_focus),
+        );
+
+        let buffer = cx.use_component::<TextBuffer>(id).unwrap();
+        let (sel_start, sel_end) = buffer.selection();
+        let len = buffer.len();
+        let before = buffer.slice(0, sel_start).to_string();
+        let selected = buffer.slice(sel_start, sel_end).to_string();
+        let after = buffer.slice(sel_end, len).to_string();
+        let caret_at_start = buffer.has_selection() && buffer.caret < buffer.anchor;
+        let show_placeholder = buffer.text.is_empty() && !self.placeholder.is_empty();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TextInput")
+            .style((
+                typography::text_default,
+                style_text_input,
+                self.style.clone(),
+            ))
+            .style_dyn(
+                |disabled, sb| {
+                    sb.color(if disabled {
+                        tokens::FOREGROUND.with_alpha(0.2)
+                    } else {
+                        tokens::FOREGROUND
+                    });
+                },
+                self.disabled,
+            )
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(1.0)
+                            .outline_width(2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_dyn(
+                |(max_length, validate)| TextEditParams {
+                    max_length,
+                    validate,
+                    multiline: false,
+                    read_only: false,
+                },
+                (self.max_length, self.validate),
+            )
+            .insert_if(self.disabled, || Disabled)
+            .insert_if(self.autofocus, || AutoFocus)
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::TextInput)),
+                        text_edit_handlers(id, callbacks),
+                    )
+                },
+                (),
+            )
+            .children(
+                Element::<NodeBundle>::new()
+                    .named("TextInput::Content")
+                    .style(style_text_input_content)
+                    // Scrolls horizontally to keep the caret visible.
+                    .insert_dyn(|_| ScrollArea::default(), ())
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("TextInput::Text")
+                            .style(style_text_input_text)
+                            .children((
+                                Cond::new(
+                                    show_placeholder,
+                                    Element::<NodeBundle>::new()
+                                        .style(style_text_input_placeholder)
+                                        .children(self.placeholder.clone()),
+                                    (),
+                                ),
+                                Element::<NodeBundle>::new()
+                                    .style(style_text_input_span)
+                                    .insert_dyn(
+                                        move |end| TextPiece {
+                                            owner: id,
+                                            start: 0,
+                                            end,
+                                        },
+                                        sel_start,
+                                    )
+                                    .children(before),
+                                Cond::new(
+                                    focused && caret_at_start,
+                                    Element::<NodeBundle>::new()
+                                        .style(style_text_input_caret)
+                                        .insert(TextCaret(id)),
+                                    (),
+                                ),
+                                Element::<NodeBundle>::new()
+                                    .style((style_text_input_span, style_text_input_selection))
+                                    .insert_dyn(
+                                        move |(start, end)| TextPiece {
+                                            owner: id,
+                                            start,
+                                            end,
+                                        },
+                                        (sel_start, sel_end),
+                                    )
+                                    .children(selected),
+                                Cond::new(
+                                    focused && !caret_at_start,
+                                    Element::<NodeBundle>::new()
+                                        .style(style_text_input_caret)
+                                        .insert(TextCaret(id)),
+                                    (),
+                                ),
+                                Element::<NodeBundle>::new()
+                                    .style(style_text_input_span)
+                                    .insert_dyn(
+                                        move |(start, end)| TextPiece {
+                                            owner: id,
+                                            start,
+                                            end,
+                                        },
+                                        (sel_end, len),
+                                    )
+                                    .children(after),
+                            )),
+                    ),
+            )
+    }
+}
@@ -0,0 +1,448 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use super::{Button, IconButton};
+use crate::{
+    animation::{AnimatedScale, AnimatedTransition},
+    hooks::{BistableTransitionState, CreateBistableTransition},
+    size::Size,
+    tokens, typography,
+};
+
+const TRANSITION_DURATION: f32 = 0.2;
+
+/// The severity of a toast notification, which determines its accent color.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ToastKind {
+    /// An informational message.
+    #[default]
+    Info,
+
+    /// Something which may need the user's attention.
+    Warning,
+
+    /// Something which has failed.
+    Error,
+}
+
+/// A button displayed within a toast notification.
+#[derive(Clone, PartialEq)]
+pub struct ToastAction {
+    /// The button label.
+    pub label: String,
+
+    /// Callback called when the button is clicked. The toast is dismissed afterwards.
+    pub on_click: Callback,
+}
+
+/// The content of a toast notification.
+#[derive(Clone, PartialEq)]
+pub struct Toast {
+    /// The severity of the message.
+    pub kind: ToastKind,
+
+    /// The message text.
+    pub message: String,
+
+    /// Optional action button.
+    pub action: Option<ToastAction>,
+
+    /// Optional progress value, from 0 to 1, shown as a bar beneath the message.
+    pub progress: Option<f32>,
+
+    /// How long the toast remains visible, in seconds. If `None`, the toast stays open until
+    /// it is dismissed.
+    pub duration: Option<f32>,
+}
+
+impl Toast {
+    /// Create a new toast of the given kind.
+    pub fn new(kind: ToastKind, message: impl Into<String>) -> Self {
+        Self {
+            kind,
+            message: message.into(),
+            action: None,
+            progress: None,
+            duration: Some(match kind {
+                ToastKind::Info | ToastKind::Warning => 4.0,
+                ToastKind::Error => 8.0,
+            }),
+        }
+    }
+
+    /// Create a new informational toast.
+    pub fn info(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Info, message)
+    }
+
+    /// Create a new warning toast.
+    pub fn warning(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Warning, message)
+    }
+
+    /// Create a new error toast.
+    pub fn error(message: impl Into<String>) -> Self {
+        Self::new(ToastKind::Error, message)
+    }
+
+    /// Add an action button to the toast.
+    pub fn action(mut self, label: impl Into<String>, on_click: Callback) -> Self {
+        self.action = Some(ToastAction {
+            label: label.into(),
+            on_click,
+        });
+        self
+    }
+
+    /// Show a progress bar in the toast.
+    pub fn progress(mut self, progress: f32) -> Self {
+        self.progress = Some(progress);
+        self
+    }
+
+    /// Set how long the toast remains visible, or `None` to keep it open until dismissed.
+    pub fn duration(mut self, duration: Option<f32>) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+/// Identifies a toast within [`Notifications`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ToastId(u64);
+
+/// Event which displays a toast notification. This can be sent from any system.
+#[derive(Event, Clone)]
+pub struct Notify(pub Toast);
+
+#[derive(Clone)]
+struct ToastEntry {
+    id: ToastId,
+    toast: Toast,
+    open: bool,
+    expires: Option<f64>,
+}
+
+/// Resource which holds the list of toast notifications currently displayed by
+/// [`NotificationOverlay`].
+#[derive(Resource, Default)]
+pub struct Notifications {
+    next_id: u64,
+    entries: Vec<ToastEntry>,
+}
+
+impl Notifications {
+    /// Display a new toast, returning an id which can be used to update or dismiss it.
+    pub fn push(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.entries.push(ToastEntry {
+            id,
+            toast,
+            open: true,
+            expires: None,
+        });
+        id
+    }
+
+    /// Update the progress bar of a toast.
+    pub fn set_progress(&mut self, id: ToastId, progress: Option<f32>) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            entry.toast.progress = progress;
+        }
+    }
+
+    /// Close a toast. It is removed once its closing animation has finished.
+    pub fn dismiss(&mut self, id: ToastId) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.id == id) {
+            entry.open = false;
+        }
+    }
+
+    /// Close all toasts.
+    pub fn dismiss_all(&mut self) {
+        for entry in self.entries.iter_mut() {
+            entry.open = false;
+        }
+    }
+
+    fn get(&self, id: ToastId) -> Option<&ToastEntry> {
+        self.entries.iter().find(|e| e.id == id)
+    }
+
+    fn remove(&mut self, id: ToastId) {
+        self.entries.retain(|e| e.id != id);
+    }
+}
+
+/// Adds toasts from [`Notify`] events, and closes toasts whose time is up.
+pub(crate) fn update_notifications(
+    mut events: EventReader<Notify>,
+    mut notifications: ResMut<Notifications>,
+    time: Res<Time>,
+) {
+    for event in events.read() {
+        notifications.push(event.0.clone());
+    }
+
+    // Bookkeeping of expiration times shouldn't cause the overlay to re-render.
+    let now = time.elapsed_seconds_f64();
+    let mut expired: Vec<ToastId> = Vec::new();
+    for entry in notifications.bypass_change_detection().entries.iter_mut() {
+        if !entry.open {
+            continue;
+        }
+        match (entry.expires, entry.toast.duration) {
+            (None, Some(duration)) => entry.expires = Some(now + duration as f64),
+            (Some(expires), _) if expires <= now => expired.push(entry.id),
+            _ => {}
+        }
+    }
+
+    for id in expired {
+        notifications.dismiss(id);
+    }
+}
+
+/// Which corner of the window toasts are displayed in.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum ToastCorner {
+    /// Upper-left corner.
+    TopLeft,
+    /// Upper-right corner.
+    TopRight,
+    /// Lower-left corner.
+    BottomLeft,
+    /// Lower-right corner.
+    #[default]
+    BottomRight,
+}
+
+fn style_overlay(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .align_items(ui::AlignItems::Stretch)
+        .width(320)
+        .gap(8)
+        .z_index(150)
+        .pointer_events(false);
+}
+
+fn style_toast(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .gap(4)
+        .padding((8, 6))
+        .background_color(tokens::U2)
+        .border_radius(4.0)
+        .border(1)
+        .border_left(4)
+        .drop_shadow(0, 2, 8, Srgba::new(0., 0., 0., 0.5))
+        .color(tokens::FOREGROUND)
+        .font_size(14)
+        .pointer_events(true);
+}
+
+fn style_toast_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .gap(6);
+}
+
+fn style_toast_message(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.).flex_shrink(1.);
+}
+
+fn style_progress_track(ss: &mut StyleBuilder) {
+    ss.height(4).border_radius(2.0).background_color(tokens::U1);
+}
+
+/// Displays the toasts in the [`Notifications`] resource, stacked in a corner of the window.
+/// Add one of these anywhere in the UI hierarchy; toasts are then shown by sending a [`Notify`]
+/// event, or by calling [`Notifications::push`].
+#[derive(Default, Clone, PartialEq)]
+pub struct NotificationOverlay {
+    /// Which corner of the window the toasts are stacked in.
+    pub corner: ToastCorner,
+}
+
+impl NotificationOverlay {
+    /// Create a new notification overlay.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set which corner of the window the toasts are stacked in.
+    pub fn corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+}
+
+impl ViewTemplate for NotificationOverlay {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let ids: Vec<ToastId> = cx
+            .use_resource::<Notifications>()
+            .entries
+            .iter()
+            .map(|e| e.id)
+            .collect();
+        let corner = self.corner;
+
+        Portal::new(
+            Element::<NodeBundle>::new()
+                .named("NotificationOverlay")
+                .style((style_overlay, move |ss: &mut StyleBuilder| {
+                    // Newest toasts are closest to the edge of the window.
+                    match corner {
+                        ToastCorner::TopLeft | ToastCorner::TopRight => {
+                            ss.top(12).flex_direction(ui::FlexDirection::ColumnReverse);
+                        }
+                        ToastCorner::BottomLeft | ToastCorner::BottomRight => {
+                            ss.bottom(12).flex_direction(ui::FlexDirection::Column);
+                        }
+                    }
+                    match corner {
+                        ToastCorner::TopLeft | ToastCorner::BottomLeft => ss.left(12),
+                        ToastCorner::TopRight | ToastCorner::BottomRight => ss.right(12),
+                    };
+                }))
+                .children(For::each(ids, |id| ToastView { id: *id })),
+        )
+    }
+}
+
+/// A single toast within the overlay. Reads its content from the [`Notifications`] resource
+/// so that it updates in place when the progress changes.
+#[derive(Clone, PartialEq)]
+struct ToastView {
+    id: ToastId,
+}
+
+impl ViewTemplate for ToastView {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = self.id;
+        let entry = cx.use_resource::<Notifications>().get(id).cloned();
+        let open = entry.as_ref().is_some_and(|e| e.open);
+        let state = cx.create_bistable_transition(open, TRANSITION_DURATION);
+
+        // Remove the toast once it has finished closing.
+        cx.create_effect(
+            move |world, (open, state)| {
+                if !open && state == BistableTransitionState::Exited {
+                    world.resource_mut::<Notifications>().remove(id);
+                }
+            },
+            (open, state),
+        );
+
+        let on_close = cx.create_callback(move |mut notifications: ResMut<Notifications>| {
+            notifications.dismiss(id);
+        });
+
+        let on_action = cx.create_callback(move |world: &mut World| {
+            let action = world
+                .resource::<Notifications>()
+                .get(id)
+                .and_then(|e| e.toast.action.as_ref())
+                .map(|a| a.on_click);
+            if let Some(action) = action {
+                world.run_callback(action, ());
+            }
+            world.resource_mut::<Notifications>().dismiss(id);
+        });
+
+        let (kind, message, action, progress) = match entry {
+            Some(entry) => (
+                entry.toast.kind,
+                entry.toast.message,
+                entry.toast.action.map(|a| a.label),
+                entry.toast.progress,
+            ),
+            None => (ToastKind::Info, String::new(), None, None),
+        };
+
+        Element::<NodeBundle>::new()
+            .named("Toast")
+            .style((typography::text_default, style_toast))
+            .style_dyn(
+                |kind, ss| {
+                    let accent = match kind {
+                        ToastKind::Info => tokens::PRIMARY_ACC,
+                        ToastKind::Warning => tokens::LIGHT,
+                        ToastKind::Error => tokens::DESTRUCTIVE_ACC,
+                    };
+                    ss.border_color(accent);
+                },
+                kind,
+            )
+            .insert(Transform {
+                scale: Vec3::new(1.0, 0.0, 1.0),
+                ..default()
+            })
+            .effect(
+                move |cx, ent, state| {
+                    let mut entt = cx.world_mut().entity_mut(ent);
+                    let target = match state {
+                        BistableTransitionState::EnterStart
+                        | BistableTransitionState::Exiting
+                        | BistableTransitionState::Exited => Vec3::new(1.0, 0.0, 1.0),
+                        BistableTransitionState::Entering
+                        | BistableTransitionState::Entered
+                        | BistableTransitionState::ExitStart => Vec3::ONE,
+                    };
+                    AnimatedTransition::<AnimatedScale>::start(
+                        &mut entt,
+                        target,
+                        TRANSITION_DURATION,
+                    );
+                },
+                state,
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(style_toast_row)
+                    .children((
+                        Element::<NodeBundle>::new()
+                            .style(style_toast_message)
+                            .children(message),
+                        Cond::new(
+                            action.is_some(),
+                            Button::new()
+                                .size(Size::Xs)
+                                .children(action.unwrap_or_default())
+                                .on_click(on_action),
+                            (),
+                        ),
+                        IconButton::new("embedded://bevy_quill_obsidian/assets/icons/close.png")
+                            .size(Size::Xxs)
+                            .minimal(true)
+                            .on_click(on_close),
+                    )),
+                Cond::new(
+                    progress.is_some(),
+                    Element::<NodeBundle>::new()
+                        .style(style_progress_track)
+                        .children(Element::<NodeBundle>::new().style_dyn(
+                            |(kind, progress), ss| {
+                                let accent = match kind {
+                                    ToastKind::Error => tokens::DESTRUCTIVE_ACC,
+                                    _ => tokens::PRIMARY_ACC,
+                                };
+                                ss.height(ui::Val::Percent(100.))
+                                    .width(ui::Val::Percent(progress.clamp(0., 1.) * 100.))
+                                    .border_radius(2.0)
+                                    .background_color(accent);
+                            },
+                            (kind, progress.unwrap_or(0.)),
+                        )),
+                    (),
+                ),
+            ))
+    }
+}
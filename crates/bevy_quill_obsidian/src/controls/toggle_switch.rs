@@ -0,0 +1,240 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    cursor::StyleBuilderCursor,
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    tokens, typography,
+};
+
+use super::{checkbox::Checked, Disabled, IsDisabled};
+
+fn style_toggle_switch(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::Center)
+        .align_items(ui::AlignItems::Center)
+        .align_content(ui::AlignContent::Center)
+        .gap(4)
+        .color(tokens::FOREGROUND);
+}
+
+fn style_toggle_track(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .width(28)
+        .height(16)
+        .border_radius(8.0)
+        .cursor(CursorIcon::Pointer);
+}
+
+fn style_toggle_thumb(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .top(2)
+        .width(12)
+        .height(12)
+        .border_radius(6.0);
+}
+
+fn style_toggle_label(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .justify_content(ui::JustifyContent::FlexStart)
+        .align_items(ui::AlignItems::Center);
+}
+
+/// A switch which toggles a setting on or off. Behaves like a [`Checkbox`](super::Checkbox),
+/// but is displayed as a sliding thumb within a track.
+#[derive(Default, Clone, PartialEq)]
+pub struct ToggleSwitch {
+    /// Whether the switch is on.
+    pub checked: bool,
+
+    /// Whether the switch is disabled.
+    pub disabled: bool,
+
+    /// The content to display beside the switch.
+    pub label: ViewChild,
+
+    /// Additional styles to be applied to the switch.
+    pub style: StyleHandle,
+
+    /// Callback called when toggled, with the new state.
+    pub on_change: Option<Callback<bool>>,
+
+    /// The tab index of the switch (default 0).
+    pub tab_index: i32,
+}
+
+impl ToggleSwitch {
+    /// Create a new toggle switch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set whether the switch is on.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = checked;
+        self
+    }
+
+    /// Set the disabled state of the switch.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Set the label of the switch.
+    pub fn label(mut self, label: impl IntoViewChild) -> Self {
+        self.label = label.into_view_child();
+        self
+    }
+
+    /// Set the style of the switch.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the on_change callback of the switch.
+    pub fn on_change(mut self, on_change: Callback<bool>) -> Self {
+        self.on_change = Some(on_change);
+        self
+    }
+
+    /// Set the tab index of the switch.
+    pub fn tab_index(mut self, tab_index: i32) -> Self {
+        self.tab_index = tab_index;
+        self
+    }
+}
+
+impl ViewTemplate for ToggleSwitch {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let checked = self.checked;
+        let on_change = self.on_change;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("ToggleSwitch")
+            .style((
+                typography::text_default,
+                style_toggle_switch,
+                self.style.clone(),
+            ))
+            .insert_dyn(TabIndex, self.tab_index)
+            .insert_if(self.disabled, || Disabled)
+            .insert_if(self.checked, || Checked)
+            .insert_dyn(
+                move |_| {
+                    (
+                        AccessibilityNode::from(NodeBuilder::new(Role::Switch)),
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                            if !world.is_disabled(id) {
+                                let was_checked = world.get::<Checked>(id).is_some();
+                                if let Some(on_change) = on_change {
+                                    world.run_callback(on_change, !was_checked);
+                                }
+                            }
+                        }),
+                        On::<KeyPressEvent>::run(move |world: &mut World| {
+                            if !world.is_disabled(id) {
+                                let mut event = world
+                                    .get_resource_mut::<ListenerInput<KeyPressEvent>>()
+                                    .unwrap();
+                                if !event.repeat
+                                    && (event.key_code == KeyCode::Enter
+                                        || event.key_code == KeyCode::Space)
+                                {
+                                    event.stop_propagation();
+                                    let was_checked = world.get::<Checked>(id).is_some();
+                                    if let Some(on_change) = on_change {
+                                        world.run_callback(on_change, !was_checked);
+                                    }
+                                }
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                Element::<NodeBundle>::new()
+                    .named("ToggleSwitch::Track")
+                    .style(style_toggle_track)
+                    .style_dyn(
+                        |(checked, disabled, hovering), sb| {
+                            let color = match (checked, hovering) {
+                                (true, true) => tokens::ACCENT.darker(0.15),
+                                (true, false) => tokens::ACCENT.darker(0.2),
+                                (false, true) => tokens::U1.lighter(0.002),
+                                (false, false) => tokens::U1,
+                            };
+                            sb.background_color(if disabled {
+                                color.with_alpha(0.3)
+                            } else {
+                                color
+                            });
+                        },
+                        (checked, self.disabled, hovering),
+                    )
+                    .style_dyn(
+                        |focused, sb| {
+                            if focused {
+                                sb.outline_color(tokens::FOCUS)
+                                    .outline_offset(1.0)
+                                    .outline_width(2.0);
+                            } else {
+                                sb.outline_color(Option::<Color>::None);
+                            }
+                        },
+                        focused,
+                    )
+                    .children(
+                        Element::<NodeBundle>::new()
+                            .named("ToggleSwitch::Thumb")
+                            .style(style_toggle_thumb)
+                            .style_dyn(
+                                |(checked, disabled), sb| {
+                                    sb.left(if checked { 14 } else { 2 }).background_color(
+                                        if disabled {
+                                            tokens::FOREGROUND.with_alpha(0.3)
+                                        } else {
+                                            tokens::FOREGROUND
+                                        },
+                                    );
+                                },
+                                (checked, self.disabled),
+                            ),
+                    ),
+                Element::<NodeBundle>::new()
+                    .style(style_toggle_label)
+                    .style_dyn(
+                        |disabled, sb| {
+                            sb.color(if disabled {
+                                tokens::FOREGROUND.with_alpha(0.3)
+                            } else {
+                                tokens::FOREGROUND
+                            });
+                        },
+                        self.disabled,
+                    )
+                    .children(self.label.clone()),
+            ))
+    }
+}
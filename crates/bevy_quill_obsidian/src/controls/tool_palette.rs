@@ -1,4 +1,8 @@
-use crate::{colors, size::Size, RoundedCorners};
+use crate::{
+    focus::{nav_group, NavAxis},
+    size::Size,
+    tokens, RoundedCorners,
+};
 use bevy::{
     a11y::{
         accesskit::{NodeBuilder, Role},
@@ -10,7 +14,7 @@ use bevy::{
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 
-use super::{Button, ButtonVariant, Icon};
+use super::{Button, ButtonVariant, Icon, IconColor};
 
 fn style_tool_palette(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Grid)
@@ -26,7 +30,8 @@ struct ToolPaletteContext {
     size: Size,
 }
 
-/// ToolPalette - a grid of tool buttons
+/// ToolPalette - a grid of tool buttons. The palette is a single tab stop; the arrow keys move
+/// focus between the buttons.
 #[derive(Default, Clone, PartialEq)]
 pub struct ToolPalette {
     /// Button size.
@@ -90,6 +95,7 @@ impl ViewTemplate for ToolPalette {
                 self.style.clone(),
             ))
             .insert(AccessibilityNode::from(NodeBuilder::new(Role::Group)))
+            .insert_dyn(move |_| nav_group(NavAxis::Both, false), ())
             .children(self.children.clone())
     }
 }
@@ -364,11 +370,11 @@ impl ViewTemplate for ToolIconButton {
             .children(
                 Icon::new(&self.icon)
                     .color(if no_tint {
-                        Color::WHITE
+                        IconColor::Color(Srgba::WHITE)
                     } else if disabled {
-                        Color::from(colors::DIM).with_alpha(0.2)
+                        tokens::DIM.with_alpha(0.2).into()
                     } else {
-                        Color::from(colors::DIM)
+                        tokens::DIM.into()
                     })
                     .size(self.size),
             );
@@ -0,0 +1,209 @@
+use bevy::{prelude::*, ui};
+use bevy_mod_picking::{picking_core::Pickable, prelude::*};
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    floating::{FloatAlign, FloatPosition, FloatSide, Floating},
+    hooks::{BistableTransitionState, CreateBistableTransition, UseIsHover},
+    scrolling::ScrollWheel,
+    tokens, typography,
+};
+
+fn style_tooltip_anchor(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch);
+}
+
+fn style_tooltip(ss: &mut StyleBuilder) {
+    ss.position(ui::PositionType::Absolute)
+        .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .max_width(300)
+        .padding((6, 3))
+        .background_color(tokens::U3)
+        .border_radius(4.0)
+        .border(1)
+        .border_color(Srgba::BLACK)
+        .drop_shadow(0, 2, 6, Srgba::new(0., 0., 0., 0.5))
+        .color(tokens::FOREGROUND)
+        .font_size(14);
+}
+
+/// Component which records that the tooltip of an anchor element has been dismissed, so that it
+/// stays hidden until the pointer leaves the anchor.
+#[derive(Component, Default)]
+pub(crate) struct TooltipDismissed(bool);
+
+/// Hide all tooltips when a key is pressed.
+pub(crate) fn dismiss_tooltips(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut query: Query<&mut TooltipDismissed>,
+) {
+    if keys.get_just_pressed().next().is_some() {
+        for mut dismissed in query.iter_mut() {
+            if !dismissed.0 {
+                dismissed.0 = true;
+            }
+        }
+    }
+}
+
+/// Wraps a view, displaying a floating popup next to it after the pointer has hovered over it
+/// for a short time. The popup is hidden when the pointer leaves, or when the user clicks,
+/// scrolls, or presses a key.
+#[derive(Clone, PartialEq)]
+pub struct Tooltip {
+    /// The content of the tooltip popup.
+    pub content: ViewChild,
+
+    /// The view which the tooltip is attached to.
+    pub children: ViewChild,
+
+    /// Which side of the anchor to display the tooltip on, if there's room.
+    pub side: FloatSide,
+
+    /// How long the pointer must hover before the tooltip is shown, in seconds.
+    pub delay: f32,
+
+    /// Additional styles to be applied to the anchor element.
+    pub style: StyleHandle,
+}
+
+impl Tooltip {
+    /// Create a new tooltip.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the content of the tooltip popup.
+    pub fn content(mut self, content: impl IntoViewChild) -> Self {
+        self.content = content.into_view_child();
+        self
+    }
+
+    /// Set the view which the tooltip is attached to.
+    pub fn children(mut self, children: impl IntoViewChild) -> Self {
+        self.children = children.into_view_child();
+        self
+    }
+
+    /// Set which side of the anchor to display the tooltip on.
+    pub fn side(mut self, side: FloatSide) -> Self {
+        self.side = side;
+        self
+    }
+
+    /// Set how long the pointer must hover before the tooltip is shown.
+    pub fn delay(mut self, delay: f32) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Set additional styles to be applied to the anchor element.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            content: ViewChild::default(),
+            children: ViewChild::default(),
+            side: FloatSide::Top,
+            delay: 0.5,
+            style: StyleHandle::default(),
+        }
+    }
+}
+
+impl ViewTemplate for Tooltip {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let hovering = cx.is_hovered(id);
+        let mut entt = cx.world_mut().entity_mut(id);
+        if !entt.contains::<TooltipDismissed>() {
+            entt.insert(TooltipDismissed::default());
+        }
+        let dismissed = cx
+            .use_component::<TooltipDismissed>(id)
+            .is_some_and(|d| d.0);
+
+        // Once the pointer leaves, the tooltip can be shown again.
+        cx.create_effect(
+            move |world, hovering| {
+                if !hovering {
+                    let mut dismissed = world.get_mut::<TooltipDismissed>(id).unwrap();
+                    if dismissed.0 {
+                        dismissed.0 = false;
+                    }
+                }
+            },
+            hovering,
+        );
+
+        let open = hovering && !dismissed;
+        let state = cx.create_bistable_transition(open, self.delay);
+        let visible = open && state == BistableTransitionState::Entered;
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("Tooltip::Anchor")
+            .style((style_tooltip_anchor, self.style.clone()))
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Down>>::run(move |world: &mut World| {
+                            world.get_mut::<TooltipDismissed>(id).unwrap().0 = true;
+                        }),
+                        On::<ScrollWheel>::run(move |world: &mut World| {
+                            world.get_mut::<TooltipDismissed>(id).unwrap().0 = true;
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                self.children.clone(),
+                Cond::new(
+                    visible,
+                    Portal::new(
+                        Element::<NodeBundle>::new()
+                            .named("Tooltip")
+                            .style((typography::text_default, style_tooltip))
+                            .insert_dyn(
+                                |(side, anchor)| {
+                                    (
+                                        Floating {
+                                            anchor,
+                                            position: vec![
+                                                FloatPosition {
+                                                    side,
+                                                    align: FloatAlign::Center,
+                                                    stretch: false,
+                                                    gap: 4.0,
+                                                },
+                                                FloatPosition {
+                                                    side: side.mirror(),
+                                                    align: FloatAlign::Center,
+                                                    stretch: false,
+                                                    gap: 4.0,
+                                                },
+                                            ],
+                                        },
+                                        ZIndex::Global(200),
+                                        Pickable::IGNORE,
+                                    )
+                                },
+                                (self.side, id),
+                            )
+                            .children(self.content.clone()),
+                    ),
+                    (),
+                ),
+            ))
+    }
+}
@@ -0,0 +1,664 @@
+use bevy::{
+    a11y::{
+        accesskit::{NodeBuilder, Role},
+        AccessibilityNode, Focus,
+    },
+    prelude::*,
+    ui,
+};
+use bevy_mod_picking::prelude::*;
+use bevy_mod_stylebuilder::*;
+use bevy_quill_core::*;
+
+use crate::{
+    focus::{KeyPressEvent, TabIndex},
+    hooks::{UseIsFocus, UseIsHover},
+    size::Size,
+    tokens, typography,
+};
+
+use super::{
+    listview::row_bg_color,
+    shortcut::{is_ctrl_pressed, is_shift_pressed},
+    DisclosureToggle, ScrollView,
+};
+
+fn style_tree_view(ss: &mut StyleBuilder) {
+    ss.background_color(tokens::U1)
+        .border_radius(5.0)
+        .padding(3);
+}
+
+fn style_tree_view_inner(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
+        .align_items(ui::AlignItems::Stretch)
+        .align_self(ui::AlignSelf::Stretch)
+        .justify_self(ui::JustifySelf::Stretch)
+        .height(ui::Val::Auto)
+        .min_width(ui::Val::Percent(100.));
+}
+
+fn style_tree_row(ss: &mut StyleBuilder) {
+    ss.display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Row)
+        .align_items(ui::AlignItems::Center)
+        .min_height(20)
+        .padding_right(6);
+}
+
+fn style_tree_indent(ss: &mut StyleBuilder) {
+    ss.flex_shrink(0.)
+        .align_self(ui::AlignSelf::Stretch)
+        .width(16);
+}
+
+fn style_tree_guide(ss: &mut StyleBuilder) {
+    ss.align_self(ui::AlignSelf::Stretch)
+        .margin_left(7)
+        .border_left(1)
+        .border_color(tokens::U3);
+}
+
+/// A node in a [`TreeView`].
+#[derive(Clone, PartialEq)]
+pub struct TreeNode<K: PartialEq + Clone> {
+    /// Unique key for this node.
+    pub key: K,
+
+    /// The content to display for this node.
+    pub label: ViewChild,
+
+    /// Whether the node can be expanded. This can be true even if `children` is empty, which
+    /// allows children to be loaded lazily when the node is first expanded.
+    pub expandable: bool,
+
+    /// Child nodes, displayed when the node is expanded.
+    pub children: Vec<TreeNode<K>>,
+}
+
+impl<K: PartialEq + Clone> TreeNode<K> {
+    /// Create a new tree node.
+    pub fn new(key: K, label: impl IntoViewChild) -> Self {
+        Self {
+            key,
+            label: label.into_view_child(),
+            expandable: false,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set whether the node can be expanded.
+    pub fn expandable(mut self, expandable: bool) -> Self {
+        self.expandable = expandable;
+        self
+    }
+
+    /// Set the child nodes. This also makes the node expandable.
+    pub fn children(mut self, children: Vec<TreeNode<K>>) -> Self {
+        self.children = children;
+        self.expandable = true;
+        self
+    }
+}
+
+/// A visible row of the tree, used for keyboard navigation and range selection.
+#[derive(Clone, PartialEq)]
+struct TreeRowInfo<K> {
+    key: K,
+    parent: Option<usize>,
+    expandable: bool,
+    expanded: bool,
+}
+
+/// Component used to hold the tree view params so that they can be accessed by the callbacks
+/// without capturing.
+#[derive(Component, Clone)]
+struct TreeViewState<K: PartialEq + Clone + Send + Sync + 'static> {
+    rows: Vec<TreeRowInfo<K>>,
+    selection: Vec<K>,
+    multi_select: bool,
+    /// The row which has keyboard focus.
+    cursor: Mutable<Option<K>>,
+    /// The row where a range selection starts.
+    anchor: Mutable<Option<K>>,
+    on_expand: Option<Callback<(K, bool)>>,
+    on_select: Option<Callback<Vec<K>>>,
+}
+
+/// Identifies the key of a tree row entity.
+#[derive(Component, Clone)]
+struct TreeRowKey<K: Send + Sync + 'static> {
+    tree: Entity,
+    key: K,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub(super) enum SelectMode {
+    Replace,
+    Toggle,
+    Range,
+}
+
+/// A scrollable, hierarchical list of items.
+///
+/// The tree view is controlled: the owner passes in the list of `expanded` and `selected` keys,
+/// and updates them in response to `on_expand` and `on_select`. Nodes can be given children
+/// lazily, by marking them as `expandable` and filling in their children in `on_expand`.
+///
+/// Clicking a row selects it; when `multi_select` is enabled, Ctrl-clicking toggles a row and
+/// Shift-clicking selects a range. The up and down arrows, Home and End move the selection,
+/// the left and right arrows collapse and expand nodes or move to the parent or first child, and
+/// Enter toggles the expansion of the focused node.
+#[derive(Clone, PartialEq)]
+pub struct TreeView<K: PartialEq + Clone> {
+    /// The top-level nodes.
+    pub nodes: Vec<TreeNode<K>>,
+
+    /// Keys of the nodes which are expanded.
+    pub expanded: Vec<K>,
+
+    /// Keys of the nodes which are selected.
+    pub selected: Vec<K>,
+
+    /// Whether more than one node can be selected.
+    pub multi_select: bool,
+
+    /// Whether to draw indentation guides.
+    pub guides: bool,
+
+    /// Additional styles to be applied to the tree view.
+    pub style: StyleHandle,
+
+    /// Callback called when a node is expanded or collapsed, with the key and the new state.
+    pub on_expand: Option<Callback<(K, bool)>>,
+
+    /// Callback called when the selection changes, with the new list of selected keys.
+    pub on_select: Option<Callback<Vec<K>>>,
+
+    /// Callback called when a node is dragged onto another node, with the keys of the dragged
+    /// node and the node it was dropped on. Used to implement re-parenting.
+    pub on_drop: Option<Callback<(K, K)>>,
+}
+
+impl<K: PartialEq + Clone> TreeView<K> {
+    /// Create a new tree view.
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            expanded: Vec::new(),
+            selected: Vec::new(),
+            multi_select: false,
+            guides: true,
+            style: StyleHandle::default(),
+            on_expand: None,
+            on_select: None,
+            on_drop: None,
+        }
+    }
+
+    /// Set the top-level nodes.
+    pub fn nodes(mut self, nodes: Vec<TreeNode<K>>) -> Self {
+        self.nodes = nodes;
+        self
+    }
+
+    /// Set the keys of the nodes which are expanded.
+    pub fn expanded(mut self, expanded: Vec<K>) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Set the keys of the nodes which are selected.
+    pub fn selected(mut self, selected: Vec<K>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Set whether more than one node can be selected.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Set whether to draw indentation guides.
+    pub fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Set additional styles to be applied to the tree view.
+    pub fn style<S: StyleTuple + 'static>(mut self, style: S) -> Self {
+        self.style = style.into_handle();
+        self
+    }
+
+    /// Set the callback called when a node is expanded or collapsed.
+    pub fn on_expand(mut self, on_expand: Callback<(K, bool)>) -> Self {
+        self.on_expand = Some(on_expand);
+        self
+    }
+
+    /// Set the callback called when the selection changes.
+    pub fn on_select(mut self, on_select: Callback<Vec<K>>) -> Self {
+        self.on_select = Some(on_select);
+        self
+    }
+
+    /// Set the callback called when a node is dropped onto another node.
+    pub fn on_drop(mut self, on_drop: Callback<(K, K)>) -> Self {
+        self.on_drop = Some(on_drop);
+        self
+    }
+}
+
+impl<K: PartialEq + Clone> Default for TreeView<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Row props produced by flattening the visible part of the tree.
+#[derive(Clone, PartialEq)]
+struct TreeRowProps<K: PartialEq + Clone> {
+    key: K,
+    label: ViewChild,
+    depth: usize,
+    expandable: bool,
+    expanded: bool,
+}
+
+fn flatten_tree<K: PartialEq + Clone>(
+    nodes: &[TreeNode<K>],
+    expanded: &[K],
+    depth: usize,
+    parent: Option<usize>,
+    rows: &mut Vec<TreeRowProps<K>>,
+    infos: &mut Vec<TreeRowInfo<K>>,
+) {
+    for node in nodes {
+        let is_expanded = node.expandable && expanded.contains(&node.key);
+        let index = rows.len();
+        rows.push(TreeRowProps {
+            key: node.key.clone(),
+            label: node.label.clone(),
+            depth,
+            expandable: node.expandable,
+            expanded: is_expanded,
+        });
+        infos.push(TreeRowInfo {
+            key: node.key.clone(),
+            parent,
+            expandable: node.expandable,
+            expanded: is_expanded,
+        });
+        if is_expanded {
+            flatten_tree(
+                &node.children,
+                expanded,
+                depth + 1,
+                Some(index),
+                rows,
+                infos,
+            );
+        }
+    }
+}
+
+/// Update the selection in response to a click or key press on the row with the given key.
+fn select_row<K: PartialEq + Clone + Send + Sync + 'static>(
+    world: &mut World,
+    tree: Entity,
+    key: K,
+    mode: SelectMode,
+) {
+    let state = world.get::<TreeViewState<K>>(tree).unwrap().clone();
+    let selection = match (mode, state.multi_select) {
+        (SelectMode::Toggle, true) => {
+            let mut selection = state.selection.clone();
+            match selection.iter().position(|k| *k == key) {
+                Some(pos) => {
+                    selection.remove(pos);
+                }
+                None => selection.push(key.clone()),
+            }
+            selection
+        }
+        (SelectMode::Range, true) => {
+            let anchor = state.anchor.get_clone(world).unwrap_or(key.clone());
+            let position = |k: &K| state.rows.iter().position(|r| r.key == *k);
+            match (position(&anchor), position(&key)) {
+                (Some(a), Some(b)) => state.rows[a.min(b)..=a.max(b)]
+                    .iter()
+                    .map(|r| r.key.clone())
+                    .collect(),
+                _ => vec![key.clone()],
+            }
+        }
+        _ => vec![key.clone()],
+    };
+    if mode != SelectMode::Range {
+        state.anchor.set_clone(world, Some(key.clone()));
+    }
+    state.cursor.set_clone(world, Some(key));
+    if selection != state.selection {
+        if let Some(on_select) = state.on_select {
+            world.run_callback(on_select, selection);
+        }
+    }
+}
+
+impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for TreeView<K> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let cursor = cx.create_mutable::<Option<K>>(None);
+        let anchor = cx.create_mutable::<Option<K>>(None);
+        let focused = cx.is_focus_within(id);
+        let cursor_key = cursor.get_clone(cx);
+
+        let mut rows = Vec::new();
+        let mut infos = Vec::new();
+        flatten_tree(&self.nodes, &self.expanded, 0, None, &mut rows, &mut infos);
+
+        // The row which can be reached with the Tab key.
+        let tab_stop = cursor_key
+            .as_ref()
+            .filter(|k| rows.iter().any(|r| r.key == **k))
+            .or_else(|| self.selected.first())
+            .or_else(|| rows.first().map(|r| &r.key))
+            .cloned();
+
+        let selected = self.selected.clone();
+        let guides = self.guides;
+        let on_expand = self.on_expand;
+        let on_drop = self.on_drop;
+
+        ScrollView::new()
+            .children(
+                Element::<NodeBundle>::for_entity(id)
+                    .named("TreeView")
+                    .style((typography::text_default, style_tree_view_inner))
+                    .insert_dyn(
+                        move |(rows, selection, multi_select, on_expand, on_select)| {
+                            TreeViewState {
+                                rows,
+                                selection,
+                                multi_select,
+                                cursor,
+                                anchor,
+                                on_expand,
+                                on_select,
+                            }
+                        },
+                        (
+                            infos,
+                            self.selected.clone(),
+                            self.multi_select,
+                            self.on_expand,
+                            self.on_select,
+                        ),
+                    )
+                    .insert_dyn(
+                        move |_| {
+                            (
+                                AccessibilityNode::from(NodeBuilder::new(Role::Tree)),
+                                On::<KeyPressEvent>::run(move |world: &mut World| {
+                                    handle_tree_key::<K>(world, id);
+                                }),
+                            )
+                        },
+                        (),
+                    )
+                    .children(For::index(&rows, move |row, _| TreeRow {
+                        tree: id,
+                        selected: selected.contains(&row.key),
+                        is_cursor: cursor_key.as_ref() == Some(&row.key),
+                        tab_stop: tab_stop.as_ref() == Some(&row.key),
+                        tree_focused: focused,
+                        guides,
+                        row: row.clone(),
+                        on_expand,
+                        on_drop,
+                    })),
+            )
+            .style((style_tree_view, self.style.clone()))
+            .scroll_enable_y(true)
+    }
+}
+
+fn handle_tree_key<K: PartialEq + Clone + Send + Sync + 'static>(world: &mut World, tree: Entity) {
+    let event = world.resource::<ListenerInput<KeyPressEvent>>();
+    let (key_code, shift) = (event.key_code, event.shift);
+    let state = world.get::<TreeViewState<K>>(tree).unwrap().clone();
+    if state.rows.is_empty() {
+        return;
+    }
+    let last = state.rows.len() - 1;
+    let cursor = state.cursor.get_clone(world);
+    let current = cursor.and_then(|k| state.rows.iter().position(|r| r.key == k));
+    let expand = |world: &mut World, index: usize, expanded: bool| {
+        if let Some(on_expand) = state.on_expand {
+            world.run_callback(on_expand, (state.rows[index].key.clone(), expanded));
+        }
+    };
+
+    let target = match (key_code, current) {
+        (KeyCode::ArrowUp, Some(index)) => Some(index.saturating_sub(1)),
+        (KeyCode::ArrowDown, Some(index)) => Some((index + 1).min(last)),
+        (KeyCode::ArrowUp | KeyCode::ArrowDown | KeyCode::Home, _) => Some(0),
+        (KeyCode::End, _) => Some(last),
+        (KeyCode::ArrowLeft, Some(index)) => {
+            let row = &state.rows[index];
+            if row.expanded {
+                expand(world, index, false);
+                None
+            } else {
+                row.parent
+            }
+        }
+        (KeyCode::ArrowRight, Some(index)) => {
+            let row = &state.rows[index];
+            if row.expandable && !row.expanded {
+                expand(world, index, true);
+                None
+            } else if row.expanded && index < last && state.rows[index + 1].parent == Some(index) {
+                Some(index + 1)
+            } else {
+                None
+            }
+        }
+        (KeyCode::Enter, Some(index)) => {
+            let row = &state.rows[index];
+            if row.expandable {
+                expand(world, index, !row.expanded);
+            }
+            None
+        }
+        _ => return,
+    };
+
+    world
+        .resource_mut::<ListenerInput<KeyPressEvent>>()
+        .stop_propagation();
+    if let Some(target) = target {
+        let mode = if shift {
+            SelectMode::Range
+        } else {
+            SelectMode::Replace
+        };
+        select_row(world, tree, state.rows[target].key.clone(), mode);
+    }
+}
+
+/// An individual row within a [`TreeView`].
+#[derive(Clone, PartialEq)]
+struct TreeRow<K: PartialEq + Clone> {
+    tree: Entity,
+    row: TreeRowProps<K>,
+    selected: bool,
+    is_cursor: bool,
+    tab_stop: bool,
+    tree_focused: bool,
+    guides: bool,
+    on_expand: Option<Callback<(K, bool)>>,
+    on_drop: Option<Callback<(K, K)>>,
+}
+
+impl<K: PartialEq + Clone + Send + Sync + 'static> ViewTemplate for TreeRow<K> {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let tree = self.tree;
+        let hovering = cx.is_hovered(id);
+        let focused = cx.is_focus_visible(id);
+        let drop_target = cx.create_mutable(false);
+        let on_expand = self.on_expand;
+        let on_drop = self.on_drop;
+        let guides = self.guides;
+
+        // Move focus along with the cursor while the tree has focus.
+        cx.create_effect(
+            move |world, (is_cursor, tree_focused)| {
+                if is_cursor && tree_focused {
+                    let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                    if focus.0 != Some(id) {
+                        focus.0 = Some(id);
+                    }
+                }
+            },
+            (self.is_cursor, self.tree_focused),
+        );
+
+        let toggle = cx.create_callback(move |expanded: In<bool>, world: &mut World| {
+            let key = world.get::<TreeRowKey<K>>(id).unwrap().key.clone();
+            if let Some(on_expand) = on_expand {
+                world.run_callback(on_expand, (key, *expanded));
+            }
+        });
+
+        let levels: Vec<usize> = (0..self.row.depth).collect();
+
+        Element::<NodeBundle>::for_entity(id)
+            .named("TreeRow")
+            .style(style_tree_row)
+            .style_dyn(
+                |(hovering, selected, drop_target), sb| {
+                    sb.background_color(if drop_target {
+                        tokens::TEXT_SELECT.with_alpha(0.1)
+                    } else {
+                        row_bg_color(false, selected, hovering)
+                    });
+                },
+                (hovering, self.selected, drop_target.get(cx)),
+            )
+            .style_dyn(
+                |focused, sb| {
+                    if focused {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_offset(-1.0)
+                            .outline_width(1.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                focused,
+            )
+            .insert_dyn(
+                |tab_stop| TabIndex(if tab_stop { 0 } else { -1 }),
+                self.tab_stop,
+            )
+            .insert_dyn(move |key| TreeRowKey { tree, key }, self.row.key.clone())
+            .insert_dyn(
+                |(selected, expandable, expanded)| {
+                    AccessibilityNode::from({
+                        let mut builder = NodeBuilder::new(Role::TreeItem);
+                        builder.set_selected(selected);
+                        if expandable {
+                            builder.set_expanded(expanded);
+                        }
+                        builder
+                    })
+                },
+                (self.selected, self.row.expandable, self.row.expanded),
+            )
+            .insert_dyn(
+                move |_| {
+                    (
+                        On::<Pointer<Click>>::run(move |world: &mut World| {
+                            let mut event = world
+                                .get_resource_mut::<ListenerInput<Pointer<Click>>>()
+                                .unwrap();
+                            event.stop_propagation();
+                            let mode = if is_shift_pressed(world) {
+                                SelectMode::Range
+                            } else if is_ctrl_pressed(world) {
+                                SelectMode::Toggle
+                            } else {
+                                SelectMode::Replace
+                            };
+                            let mut focus = world.get_resource_mut::<Focus>().unwrap();
+                            focus.0 = Some(id);
+                            let key = world.get::<TreeRowKey<K>>(id).unwrap().key.clone();
+                            select_row(world, tree, key, mode);
+                        }),
+                        On::<Pointer<DragEnter>>::run(move |world: &mut World| {
+                            let event = world.resource::<ListenerInput<Pointer<DragEnter>>>();
+                            let dragged = event.dragged;
+                            if dragged != id
+                                && world
+                                    .get::<TreeRowKey<K>>(dragged)
+                                    .is_some_and(|k| k.tree == tree)
+                            {
+                                drop_target.set(world, true);
+                            }
+                        }),
+                        On::<Pointer<DragLeave>>::run(move |world: &mut World| {
+                            drop_target.set(world, false);
+                        }),
+                        On::<Pointer<DragDrop>>::run(move |world: &mut World| {
+                            drop_target.set(world, false);
+                            let event = world.resource::<ListenerInput<Pointer<DragDrop>>>();
+                            let dropped = event.dropped;
+                            if dropped == id {
+                                return;
+                            }
+                            let Some(source) = world.get::<TreeRowKey<K>>(dropped) else {
+                                return;
+                            };
+                            if source.tree != tree {
+                                return;
+                            }
+                            let source = source.key.clone();
+                            let target = world.get::<TreeRowKey<K>>(id).unwrap().key.clone();
+                            if let Some(on_drop) = on_drop {
+                                world.run_callback(on_drop, (source, target));
+                            }
+                        }),
+                    )
+                },
+                (),
+            )
+            .children((
+                For::index(&levels, move |_, _| {
+                    Element::<NodeBundle>::new()
+                        .style(style_tree_indent)
+                        .children(Cond::new(
+                            guides,
+                            Element::<NodeBundle>::new().style(style_tree_guide),
+                            (),
+                        ))
+                }),
+                Cond::new(
+                    self.row.expandable,
+                    DisclosureToggle::new()
+                        .size(Size::Xs)
+                        .tab_index(-1)
+                        .expanded(self.row.expanded)
+                        .on_change(toggle),
+                    Element::<NodeBundle>::new().style(style_tree_indent),
+                ),
+                self.row.label.clone(),
+            ))
+    }
+}
@@ -0,0 +1,238 @@
+use std::{any::Any, sync::Arc};
+
+use bevy::prelude::*;
+use bevy_mod_picking::{focus::HoverMap, pointer::PointerId, prelude::*};
+use bevy_quill_core::{Callback, Cx, IntoViewChild, RunCallback, ViewChild};
+
+use crate::{focus::GamepadNavigation, scrolling::AutoScroll};
+
+/// A value carried by a drag operation. Any type can be used as a payload; drop targets only
+/// accept payloads of the type they are expecting.
+#[derive(Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    /// Wrap a value in a payload.
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// Return the payload value, if it is of type `T`.
+    pub fn get<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
+}
+
+/// Resource which holds the state of the drag operation in progress, if any.
+#[derive(Resource, Default)]
+pub struct DragDrop {
+    payload: Option<DragPayload>,
+    preview: Option<ViewChild>,
+    /// The element being dragged.
+    pub source: Option<Entity>,
+    /// The drop target under the pointer, if it accepts the payload.
+    pub target: Option<Entity>,
+}
+
+impl DragDrop {
+    /// Whether a drag operation is in progress.
+    pub fn is_dragging(&self) -> bool {
+        self.payload.is_some()
+    }
+
+    /// Return the payload being dragged, if it is of type `T`.
+    pub fn payload<T: Any>(&self) -> Option<&T> {
+        self.payload.as_ref().and_then(|p| p.get::<T>())
+    }
+
+    /// The view displayed under the pointer while dragging.
+    pub fn preview(&self) -> Option<&ViewChild> {
+        self.preview.as_ref()
+    }
+
+    /// Abandon the drag operation in progress; releasing the pointer won't drop anything.
+    pub fn cancel(&mut self) {
+        self.payload = None;
+        self.preview = None;
+        self.target = None;
+    }
+}
+
+/// Resource which holds the pointer position of the drag operation in progress. This is kept
+/// separate from [`DragDrop`] so that views which depend on the drag state aren't rebuilt every
+/// time the pointer moves.
+#[derive(Resource, Default)]
+pub struct DragPosition(pub Vec2);
+
+/// Component which tracks whether a valid drag payload is over a drop target.
+#[derive(Component, Default)]
+pub(crate) struct DropHovering(pub(crate) bool);
+
+/// Component which holds the payload and preview of a draggable element.
+#[derive(Component, Clone)]
+pub struct Draggable {
+    payload: DragPayload,
+    preview: ViewChild,
+}
+
+/// Component which marks an element as a drop target, and holds its handlers.
+#[derive(Component, Clone)]
+pub struct DropTarget {
+    accepts: Arc<dyn Fn(&DragPayload) -> bool + Send + Sync>,
+    on_drop: Arc<dyn Fn(&mut World, &DragPayload) + Send + Sync>,
+}
+
+/// Make an element draggable. Insert the returned bundle into the element, for example with
+/// `insert_dyn`, so that it is updated when the payload changes. The `preview` view follows the
+/// pointer while dragging, and is displayed by the
+/// [`DragPreviewOverlay`](crate::controls::DragPreviewOverlay).
+pub fn draggable<T: Any + Send + Sync>(payload: T, preview: impl IntoViewChild) -> impl Bundle {
+    (
+        Draggable {
+            payload: DragPayload::new(payload),
+            preview: preview.into_view_child(),
+        },
+        On::<Pointer<DragStart>>::run(|world: &mut World| {
+            let mut event = world
+                .get_resource_mut::<ListenerInput<Pointer<DragStart>>>()
+                .unwrap();
+            if event.button != PointerButton::Primary {
+                return;
+            }
+            event.stop_propagation();
+            let source = event.listener();
+            let position = event.pointer_location.position;
+            let draggable = world.get::<Draggable>(source).unwrap().clone();
+            let mut drag_drop = world.resource_mut::<DragDrop>();
+            drag_drop.payload = Some(draggable.payload);
+            drag_drop.preview = Some(draggable.preview);
+            drag_drop.source = Some(source);
+            drag_drop.target = None;
+            world.resource_mut::<DragPosition>().0 = position;
+        }),
+        On::<Pointer<Drag>>::run(|world: &mut World| {
+            let event = world.resource::<ListenerInput<Pointer<Drag>>>();
+            let position = event.pointer_location.position;
+            let pointer_id = event.pointer_id;
+            if !world.resource::<DragDrop>().is_dragging() {
+                return;
+            }
+            world.resource_mut::<DragPosition>().0 = position;
+            world.resource_mut::<AutoScroll>().0 = Some(position);
+            let target = find_drop_target(world, pointer_id);
+            set_drop_target(world, target);
+        }),
+        On::<Pointer<DragEnd>>::run(|world: &mut World| {
+            let drag_drop = world.resource::<DragDrop>();
+            let drop = drag_drop.payload.clone().zip(drag_drop.target);
+            set_drop_target(world, None);
+            let mut drag_drop = world.resource_mut::<DragDrop>();
+            drag_drop.cancel();
+            drag_drop.source = None;
+            world.resource_mut::<AutoScroll>().0 = None;
+            if let Some((payload, target)) = drop {
+                if let Some(drop_target) = world.get::<DropTarget>(target).cloned() {
+                    (drop_target.on_drop)(world, &payload);
+                }
+            }
+        }),
+    )
+}
+
+/// Make an element a drop target for payloads of type `T`. `accepts` is called to decide
+/// whether a particular payload can be dropped here, and `on_drop` is called with the payload
+/// when it is.
+pub fn drop_target<T: Any + Clone + Send + Sync>(
+    accepts: fn(&T) -> bool,
+    on_drop: Callback<T>,
+) -> impl Bundle {
+    (
+        DropTarget {
+            accepts: Arc::new(move |payload| payload.get::<T>().is_some_and(accepts)),
+            on_drop: Arc::new(move |world, payload| {
+                if let Some(value) = payload.get::<T>() {
+                    world.run_callback(on_drop, value.clone());
+                }
+            }),
+        },
+        DropHovering(false),
+    )
+}
+
+/// Find the innermost drop target under the pointer which accepts the current payload.
+fn find_drop_target(world: &mut World, pointer_id: PointerId) -> Option<Entity> {
+    let mut hits: Vec<(Entity, f32)> = world
+        .resource::<HoverMap>()
+        .get(&pointer_id)?
+        .iter()
+        .map(|(entity, hit)| (*entity, hit.depth))
+        .collect();
+    hits.sort_by(|a, b| a.1.total_cmp(&b.1));
+    let drag_drop = world.resource::<DragDrop>();
+    let payload = drag_drop.payload.as_ref()?;
+    for (hit, _) in hits {
+        let mut entity = Some(hit);
+        while let Some(ent) = entity {
+            if let Some(target) = world.get::<DropTarget>(ent) {
+                if (target.accepts)(payload) {
+                    return Some(ent);
+                }
+            }
+            entity = world.get::<Parent>(ent).map(|p| p.get());
+        }
+    }
+    None
+}
+
+/// Change the current drop target, updating the hover state of the old and new targets.
+fn set_drop_target(world: &mut World, target: Option<Entity>) {
+    let previous = std::mem::replace(&mut world.resource_mut::<DragDrop>().target, target);
+    if previous == target {
+        return;
+    }
+    if let Some(mut hovering) = previous.and_then(|p| world.get_mut::<DropHovering>(p)) {
+        hovering.0 = false;
+    }
+    if let Some(mut hovering) = target.and_then(|t| world.get_mut::<DropHovering>(t)) {
+        hovering.0 = true;
+    }
+}
+
+/// Cancel the drag operation in progress when Escape, or the gamepad cancel button, is
+/// pressed.
+pub(crate) fn cancel_drag_on_escape(
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    mapping: Res<GamepadNavigation>,
+    mut drag_drop: ResMut<DragDrop>,
+    mut auto_scroll: ResMut<AutoScroll>,
+    mut query: Query<&mut DropHovering>,
+) {
+    let cancel = keys.just_pressed(KeyCode::Escape)
+        || (mapping.enabled
+            && buttons
+                .get_just_pressed()
+                .any(|button| button.button_type == mapping.cancel));
+    if cancel && drag_drop.is_dragging() {
+        if let Some(mut hovering) = drag_drop.target.and_then(|t| query.get_mut(t).ok()) {
+            hovering.0 = false;
+        }
+        drag_drop.cancel();
+        auto_scroll.0 = None;
+    }
+}
+
+/// Method which tracks whether an acceptable payload is being dragged over a drop target.
+pub trait UseIsDropHover {
+    /// Hook that returns true when an acceptable payload is being dragged over the given drop
+    /// target.
+    fn is_drop_hovered(&mut self, target: Entity) -> bool;
+}
+
+impl<'p, 'w> UseIsDropHover for Cx<'p, 'w> {
+    fn is_drop_hovered(&mut self, target: Entity) -> bool {
+        self.use_component::<DropHovering>(target)
+            .map(|h| h.0)
+            .unwrap_or(false)
+    }
+}
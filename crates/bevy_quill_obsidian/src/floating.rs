@@ -1,6 +1,6 @@
 use bevy::{
     ecs::{component::Component, entity::Entity, query::Without, system::Query},
-    math::Rect,
+    math::{Rect, Vec2},
     transform::components::GlobalTransform,
     ui::{self, Node, Style},
     window::Window,
@@ -177,12 +177,13 @@ pub(crate) fn position_floating(
 
                 FloatAlign::Center => match position.side {
                     FloatSide::Top | FloatSide::Bottom => {
-                        rect.min.x = (anchor_rect.width() - target_width) * 0.5;
+                        rect.min.x = anchor_rect.min.x + (anchor_rect.width() - target_width) * 0.5;
                         rect.max.x = rect.min.x + target_width;
                     }
 
                     FloatSide::Left | FloatSide::Right => {
-                        rect.min.y = (anchor_rect.width() - target_height) * 0.5;
+                        rect.min.y =
+                            anchor_rect.min.y + (anchor_rect.height() - target_height) * 0.5;
                         rect.max.y = rect.min.y + target_height;
                     }
                 },
@@ -202,21 +203,25 @@ pub(crate) fn position_floating(
         }
 
         if best_occluded < f32::MAX {
-            if best_rect.min.x < window_rect.min.x {
-                best_rect.min.x += window_rect.min.x - best_rect.min.x;
-                best_rect.max.x += window_rect.min.x - best_rect.min.x;
+            // Shift the floating element so that it stays within the window.
+            let shift_x = if best_rect.min.x < window_rect.min.x {
+                window_rect.min.x - best_rect.min.x
             } else if best_rect.max.x > window_rect.max.x {
-                best_rect.min.x += window_rect.max.x - best_rect.max.x;
-                best_rect.max.x += window_rect.max.x - best_rect.max.x;
-            }
+                window_rect.max.x - best_rect.max.x
+            } else {
+                0.
+            };
 
-            if best_rect.min.y < window_rect.min.y {
-                best_rect.min.y += window_rect.min.y - best_rect.min.y;
-                best_rect.max.y += window_rect.min.y - best_rect.min.y;
+            let shift_y = if best_rect.min.y < window_rect.min.y {
+                window_rect.min.y - best_rect.min.y
             } else if best_rect.max.y > window_rect.max.y {
-                best_rect.min.y += window_rect.max.y - best_rect.max.y;
-                best_rect.max.y += window_rect.max.y - best_rect.max.y;
-            }
+                window_rect.max.y - best_rect.max.y
+            } else {
+                0.
+            };
+
+            best_rect.min += Vec2::new(shift_x, shift_y);
+            best_rect.max += Vec2::new(shift_x, shift_y);
 
             style.left = ui::Val::Px(best_rect.min.x);
             style.top = ui::Val::Px(best_rect.min.y);
@@ -2,22 +2,29 @@ use bevy::{
     a11y::Focus,
     app::{App, Plugin, Update},
     ecs::{
+        bundle::Bundle,
         component::Component,
         entity::Entity,
         event::{Event, EventReader, EventWriter},
         query::{Added, With, Without},
-        system::{Query, Res, ResMut, Resource, SystemParam},
+        system::{Query, Res, ResMut, Resource, SystemParam, SystemState},
+        world::World,
     },
     hierarchy::{Children, Parent},
     input::{
+        gamepad::{GamepadButton, GamepadButtonType},
         keyboard::{KeyCode, KeyboardInput},
         ButtonInput, ButtonState,
     },
     log::*,
+    math::Rect,
     prelude::Commands,
+    transform::components::GlobalTransform,
     ui::Node,
 };
-use bevy_mod_picking::prelude::{EntityEvent, EventListenerPlugin};
+use bevy_mod_picking::prelude::{EntityEvent, EventListenerPlugin, ListenerInput, On};
+
+use crate::controls::Disabled;
 
 /// Bubbling event for key character input.
 #[derive(Clone, Event, EntityEvent)]
@@ -91,6 +98,192 @@ pub struct TabGroup {
     pub modal: bool,
 }
 
+/// Which arrow keys move focus within a [`NavGroup`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum NavAxis {
+    /// Left and Right, for toolbars.
+    Horizontal,
+    /// Up and Down, for lists.
+    Vertical,
+    /// All four arrow keys, for grids.
+    #[default]
+    Both,
+    /// All four arrow keys, stepping through the elements in tree order regardless of their
+    /// position on screen, for groups of choices such as radio buttons.
+    Linear,
+}
+
+/// A component which groups focusable elements, such as the buttons of a toolbar or the cells
+/// of a grid, so that they act as a single stop in sequential navigation. The arrow keys move
+/// focus between the elements of the group, based on their position on screen, and Tab leaves
+/// the group. Tabbing back into the group returns to the element which last had focus.
+///
+/// Elements of the group with a negative [`TabIndex`] can be reached with the arrow keys, but
+/// not with Tab; this can be used to choose the element which first receives focus.
+///
+/// Use [`nav_group`] to create the component along with its key handler.
+#[derive(Debug, Default, Component, Copy, Clone)]
+pub struct NavGroup {
+    /// Which arrow keys move focus within the group.
+    pub axis: NavAxis,
+
+    /// Whether moving past the last element returns to the first, and vice versa. Doesn't
+    /// apply to [`NavAxis::Both`].
+    pub wrap: bool,
+
+    /// The element of the group which last had focus.
+    last: Option<Entity>,
+}
+
+impl NavGroup {
+    /// Create a new navigation group.
+    pub fn new(axis: NavAxis) -> Self {
+        Self {
+            axis,
+            wrap: false,
+            last: None,
+        }
+    }
+
+    /// Set whether navigation wraps around at the ends of the group.
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    fn allows(&self, action: &NavAction) -> bool {
+        match action {
+            NavAction::Left | NavAction::Right => self.axis != NavAxis::Vertical,
+            NavAction::Up | NavAction::Down => self.axis != NavAxis::Horizontal,
+            _ => true,
+        }
+    }
+}
+
+/// Make an element a [`NavGroup`]. Arrow keys which aren't handled by the focused element move
+/// focus within the group, as do Home and End. This includes a [`KeyPressEvent`] listener, so
+/// the element can't have another one.
+pub fn nav_group(axis: NavAxis, wrap: bool) -> impl Bundle {
+    nav_group_with(axis, wrap, |_, _| {})
+}
+
+/// Like [`nav_group`], but also calls `on_navigate` with the newly focused element whenever
+/// the arrow keys, Home or End move focus within the group. Choice controls use this to make
+/// the selection follow the focus.
+pub fn nav_group_with(
+    axis: NavAxis,
+    wrap: bool,
+    on_navigate: fn(&mut World, Entity),
+) -> impl Bundle {
+    (
+        NavGroup::new(axis).wrap(wrap),
+        On::<KeyPressEvent>::run(move |world: &mut World| {
+            let event = world.resource::<ListenerInput<KeyPressEvent>>();
+            let group = event.listener();
+            let action = match event.key_code {
+                KeyCode::ArrowUp => NavAction::Up,
+                KeyCode::ArrowDown => NavAction::Down,
+                KeyCode::ArrowLeft => NavAction::Left,
+                KeyCode::ArrowRight => NavAction::Right,
+                KeyCode::Home => NavAction::First,
+                KeyCode::End => NavAction::Last,
+                _ => return,
+            };
+            let mut st: SystemState<(
+                ResMut<ListenerInput<KeyPressEvent>>,
+                ResMut<Focus>,
+                ResMut<FocusVisible>,
+                TabNavigation,
+            )> = SystemState::new(world);
+            let (mut event, mut focus, mut visible, nav) = st.get_mut(world);
+            // At the edge of the group, let the key bubble up, so that an enclosing
+            // group can move focus out of this one.
+            let Some(next) = nav.navigate_in_nav_group(group, focus.0, action) else {
+                return;
+            };
+            event.stop_propagation();
+            focus.0 = Some(next);
+            visible.0 = true;
+            on_navigate(world, next);
+        }),
+    )
+}
+
+/// Make arrow keys which aren't handled by any other element move focus to the nearest
+/// focusable element in that direction, within the current [`TabGroup`]. Insert this into the
+/// same entity as the tab group, or into an ancestor of the focusable elements. This is a
+/// [`KeyPressEvent`] listener, so the entity can't have another one.
+pub fn spatial_navigation() -> impl Bundle {
+    On::<KeyPressEvent>::run(|world: &mut World| {
+        let event = world.resource::<ListenerInput<KeyPressEvent>>();
+        let action = match event.key_code {
+            KeyCode::ArrowUp => NavAction::Up,
+            KeyCode::ArrowDown => NavAction::Down,
+            KeyCode::ArrowLeft => NavAction::Left,
+            KeyCode::ArrowRight => NavAction::Right,
+            _ => return,
+        };
+        let mut st: SystemState<(
+            ResMut<ListenerInput<KeyPressEvent>>,
+            ResMut<Focus>,
+            ResMut<FocusVisible>,
+            TabNavigation,
+        )> = SystemState::new(world);
+        let (mut event, mut focus, mut visible, nav) = st.get_mut(world);
+        if let Some(next) = nav.navigate(focus.0, action) {
+            event.stop_propagation();
+            focus.0 = Some(next);
+            visible.0 = true;
+        }
+    })
+}
+
+/// Resource which maps gamepad buttons to navigation. The directional buttons and the
+/// activate and cancel buttons are delivered to the focused element as the arrow keys, Enter
+/// and Escape, so that every control which can be driven by the keyboard can also be driven
+/// by a gamepad. The next and previous buttons act like Tab and Shift+Tab.
+///
+/// The buttons are only translated into [`KeyPressEvent`]s; systems which read
+/// `ButtonInput<KeyCode>` directly don't see them, and must check the mapping themselves, as
+/// cancelling a drag does.
+#[derive(Debug, Resource, Clone)]
+pub struct GamepadNavigation {
+    /// Whether gamepad input moves focus.
+    pub enabled: bool,
+    /// Button which acts as the Up arrow key.
+    pub up: GamepadButtonType,
+    /// Button which acts as the Down arrow key.
+    pub down: GamepadButtonType,
+    /// Button which acts as the Left arrow key.
+    pub left: GamepadButtonType,
+    /// Button which acts as the Right arrow key.
+    pub right: GamepadButtonType,
+    /// Button which acts as the Enter key.
+    pub activate: GamepadButtonType,
+    /// Button which acts as the Escape key.
+    pub cancel: GamepadButtonType,
+    /// Button which moves focus to the next element in tab order.
+    pub next: GamepadButtonType,
+    /// Button which moves focus to the previous element in tab order.
+    pub previous: GamepadButtonType,
+}
+
+impl Default for GamepadNavigation {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            up: GamepadButtonType::DPadUp,
+            down: GamepadButtonType::DPadDown,
+            left: GamepadButtonType::DPadLeft,
+            right: GamepadButtonType::DPadRight,
+            activate: GamepadButtonType::South,
+            cancel: GamepadButtonType::East,
+            next: GamepadButtonType::RightTrigger,
+            previous: GamepadButtonType::LeftTrigger,
+        }
+    }
+}
+
 /// An injectable object that provides tab navigation functionality.
 #[doc(hidden)]
 #[derive(SystemParam)]
@@ -107,9 +300,16 @@ pub struct TabNavigation<'w, 's> {
     >,
     // Query for parents.
     parent: Query<'w, 's, &'static Parent, With<Node>>,
+    // Query for navigation groups.
+    navgroup: Query<'w, 's, &'static NavGroup>,
+    // Query for element geometry, for directional navigation.
+    geometry: Query<'w, 's, (&'static Node, &'static GlobalTransform)>,
+    // Query for disabled elements, which navigation groups skip.
+    disabled: Query<'w, 's, (), With<Disabled>>,
 }
 
 /// Navigation action for tabbing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum NavAction {
     /// Navigate to the next focusable entity.
     Next,
@@ -119,6 +319,23 @@ pub enum NavAction {
     First,
     /// Navigate to the last focusable entity.
     Last,
+    /// Navigate to the nearest focusable entity above.
+    Up,
+    /// Navigate to the nearest focusable entity below.
+    Down,
+    /// Navigate to the nearest focusable entity to the left.
+    Left,
+    /// Navigate to the nearest focusable entity to the right.
+    Right,
+}
+
+impl NavAction {
+    fn is_directional(&self) -> bool {
+        matches!(
+            self,
+            NavAction::Up | NavAction::Down | NavAction::Left | NavAction::Right
+        )
+    }
 }
 
 impl TabNavigation<'_, '_> {
@@ -128,6 +345,10 @@ impl TabNavigation<'_, '_> {
     /// * `focus`: The current focus entity. If `None`, then the first focusable entity is returned,
     ///    unless `reverse` is true, in which case the last focusable entity is returned.
     /// * `reverse`: Whether to navigate in reverse order.
+    ///
+    /// The directional actions move to the nearest entity in that direction on screen, first
+    /// within the [`NavGroup`] containing the focus entity, if any, and then among all the
+    /// entities which sequential navigation would visit.
     pub fn navigate(&self, focus: Option<Entity>, action: NavAction) -> Option<Entity> {
         // If there are no tab groups, then there are no focusable entities.
         if self.tabgroup.is_empty() {
@@ -160,9 +381,64 @@ impl TabNavigation<'_, '_> {
             return None;
         }
 
+        if action.is_directional() {
+            if let Some(group) = focus.and_then(|f| self.nav_group_of(f)) {
+                if let Some(next) = self.navigate_in_nav_group(group, focus, action) {
+                    return Some(next);
+                }
+            }
+        }
+
         self.navigate_in_group(tabgroup, focus, action)
     }
 
+    /// Navigate within a [`NavGroup`]. Returns `None` if the action doesn't apply to the
+    /// group's axis, or if there is no element in that direction.
+    pub fn navigate_in_nav_group(
+        &self,
+        group: Entity,
+        focus: Option<Entity>,
+        action: NavAction,
+    ) -> Option<Entity> {
+        let nav_group = self.navgroup.get(group).ok()?;
+        if !nav_group.allows(&action) {
+            return None;
+        }
+        let action = match (nav_group.axis, action) {
+            (NavAxis::Linear, NavAction::Up | NavAction::Left) => NavAction::Previous,
+            (NavAxis::Linear, NavAction::Down | NavAction::Right) => NavAction::Next,
+            _ => action,
+        };
+        let mut members: Vec<Entity> = Vec::new();
+        self.gather_members(&mut members, group);
+        let index = members.iter().position(|e| Some(*e) == focus);
+        match (index, action) {
+            (_, NavAction::First) => members.first().copied(),
+            (_, NavAction::Last) => members.last().copied(),
+            (None, _) => members.first().copied(),
+            (Some(idx), NavAction::Next) => members
+                .get(idx + 1)
+                .or(members.first().filter(|_| nav_group.wrap))
+                .copied()
+                .filter(|next| Some(*next) != focus),
+            (Some(idx), NavAction::Previous) => idx
+                .checked_sub(1)
+                .map(|i| members[i])
+                .or(members.last().copied().filter(|_| nav_group.wrap))
+                .filter(|next| Some(*next) != focus),
+            (Some(_), action) => {
+                let focus = focus?;
+                self.nearest_in_direction(focus, &members, &action)
+                    .or_else(|| match action {
+                        _ if !nav_group.wrap || nav_group.axis == NavAxis::Both => None,
+                        NavAction::Right | NavAction::Down => members.first().copied(),
+                        _ => members.last().copied(),
+                    })
+                    .filter(|next| *next != focus)
+            }
+        }
+    }
+
     fn navigate_in_group(
         &self,
         tabgroup: Option<(Entity, &TabGroup)>,
@@ -206,8 +482,21 @@ impl TabNavigation<'_, '_> {
 
         // Stable sort by tabindex
         focusable.sort_by(compare_tab_indices);
-
-        let index = focusable.iter().position(|e| Some(e.0) == focus);
+        self.collapse_nav_groups(&mut focusable, focus);
+
+        // If the focus entity was reached with the arrow keys, navigate from the entry which
+        // represents its group.
+        let focus_group = focus.and_then(|f| self.nav_group_of(f));
+        let index = focusable
+            .iter()
+            .position(|e| Some(e.0) == focus)
+            .or_else(|| {
+                focus_group.and_then(|group| {
+                    focusable
+                        .iter()
+                        .position(|e| self.nav_group_of(e.0) == Some(group))
+                })
+            });
         let count = focusable.len();
         let next = match (index, action) {
             (Some(idx), NavAction::Next) => (idx + 1).rem_euclid(count),
@@ -216,10 +505,116 @@ impl TabNavigation<'_, '_> {
             (None, NavAction::Previous) => count - 1,
             (_, NavAction::First) => 0,
             (_, NavAction::Last) => count - 1,
+            (None, _) => 0,
+            (Some(_), action) => {
+                // Leave the focus entity's group, since that was tried first.
+                let candidates: Vec<Entity> = focusable
+                    .iter()
+                    .map(|(e, _)| *e)
+                    .filter(|e| focus_group.is_none() || self.nav_group_of(*e) != focus_group)
+                    .collect();
+                return self.nearest_in_direction(focus?, &candidates, &action);
+            }
         };
         focusable.get(next).map(|(e, _)| e).copied()
     }
 
+    /// Replace the members of each [`NavGroup`] with a single entry: the focus entity if it's
+    /// in the group, otherwise the member which last had focus, or else the first member.
+    fn collapse_nav_groups(&self, focusable: &mut Vec<(Entity, TabIndex)>, focus: Option<Entity>) {
+        let groups: Vec<Option<Entity>> = focusable
+            .iter()
+            .map(|(e, _)| self.nav_group_of(*e))
+            .collect();
+        let entities: Vec<Entity> = focusable.iter().map(|(e, _)| *e).collect();
+        // If the focus entity isn't in the list, because it has a negative tab index, then its
+        // group is represented like any other.
+        let focus_group = focus
+            .filter(|f| entities.contains(f))
+            .and_then(|f| self.nav_group_of(f));
+        let mut kept: Vec<Entity> = Vec::new();
+        let mut index = 0;
+        focusable.retain(|(entity, _)| {
+            let group = groups[index];
+            index += 1;
+            let Some(group) = group else {
+                return true;
+            };
+            if Some(*entity) == focus {
+                return true;
+            }
+            if Some(group) == focus_group || kept.contains(&group) {
+                return false;
+            }
+            // Keep the member which last had focus, if it's still in the list; otherwise keep
+            // the first member.
+            let last = self.navgroup.get(group).ok().and_then(|g| g.last);
+            let last_is_member = last.is_some_and(|last| {
+                entities
+                    .iter()
+                    .position(|e| *e == last)
+                    .is_some_and(|i| groups[i] == Some(group))
+            });
+            if !last_is_member || last == Some(*entity) {
+                kept.push(group);
+                return true;
+            }
+            false
+        });
+    }
+
+    /// Return the innermost [`NavGroup`] containing an entity, without leaving its tab group.
+    fn nav_group_of(&self, entity: Entity) -> Option<Entity> {
+        let mut current = self.parent.get(entity).ok().map(|p| p.get());
+        while let Some(ent) = current {
+            if self.navgroup.contains(ent) {
+                return Some(ent);
+            }
+            if self.tabgroup.contains(ent) {
+                return None;
+            }
+            current = self.parent.get(ent).ok().map(|p| p.get());
+        }
+        None
+    }
+
+    /// Gather all members of a [`NavGroup`] in tree order, including those with a negative
+    /// tab index, but skipping disabled ones.
+    fn gather_members(&self, out: &mut Vec<Entity>, parent: Entity) {
+        if let Ok((_, _, Some(children))) = self.tabindex.get(parent) {
+            for child in children.iter() {
+                if let Ok((entity, tabindex, _)) = self.tabindex.get(*child) {
+                    if tabindex.is_some() && !self.disabled.contains(entity) {
+                        out.push(entity);
+                    }
+                    self.gather_members(out, entity);
+                }
+            }
+        }
+    }
+
+    /// Find the candidate nearest to the focus entity in the given direction, based on the
+    /// screen rectangles of the elements.
+    fn nearest_in_direction(
+        &self,
+        focus: Entity,
+        candidates: &[Entity],
+        action: &NavAction,
+    ) -> Option<Entity> {
+        let from = self.rect(focus)?;
+        candidates
+            .iter()
+            .filter(|e| **e != focus)
+            .filter_map(|e| Some((*e, direction_score(from, self.rect(*e)?, action)?)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(e, _)| e)
+    }
+
+    fn rect(&self, entity: Entity) -> Option<Rect> {
+        let (node, transform) = self.geometry.get(entity).ok()?;
+        Some(node.logical_rect(transform))
+    }
+
     /// Gather all focusable entities in tree order.
     fn gather_focusable(&self, out: &mut Vec<(Entity, TabIndex)>, parent: Entity) {
         if let Ok((entity, tabindex, children)) = self.tabindex.get(parent) {
@@ -246,6 +641,46 @@ impl TabNavigation<'_, '_> {
     }
 }
 
+/// Score how well `to` lies in the given direction from `from`; lower is better. Returns
+/// `None` if `to` isn't in that direction at all. Distance along the direction of travel counts
+/// less than distance across it, so that elements in the same row or column are preferred.
+fn direction_score(from: Rect, to: Rect, action: &NavAction) -> Option<f32> {
+    let (from_center, to_center) = (from.center(), to.center());
+    let (advance, along, across, overlap) = match action {
+        NavAction::Left => (
+            from_center.x - to_center.x,
+            from.min.x - to.max.x,
+            to_center.y - from_center.y,
+            from.max.y.min(to.max.y) - from.min.y.max(to.min.y),
+        ),
+        NavAction::Right => (
+            to_center.x - from_center.x,
+            to.min.x - from.max.x,
+            to_center.y - from_center.y,
+            from.max.y.min(to.max.y) - from.min.y.max(to.min.y),
+        ),
+        NavAction::Up => (
+            from_center.y - to_center.y,
+            from.min.y - to.max.y,
+            to_center.x - from_center.x,
+            from.max.x.min(to.max.x) - from.min.x.max(to.min.x),
+        ),
+        NavAction::Down => (
+            to_center.y - from_center.y,
+            to.min.y - from.max.y,
+            to_center.x - from_center.x,
+            from.max.x.min(to.max.x) - from.min.x.max(to.min.x),
+        ),
+        _ => return None,
+    };
+    if advance <= 0.5 {
+        return None;
+    }
+    // Gap between the two elements across the direction of travel, if they don't overlap.
+    let gap = (-overlap).max(0.);
+    Some(along.max(0.) + gap * 2. + across.abs() * 0.1)
+}
+
 fn compare_tab_groups(a: &(Entity, TabGroup), b: &(Entity, TabGroup)) -> std::cmp::Ordering {
     a.1.order.cmp(&b.1.order)
 }
@@ -295,6 +730,84 @@ fn handle_tab(
     }
 }
 
+/// Remember which element of each [`NavGroup`] last had focus.
+fn track_nav_group_focus(
+    focus: Res<Focus>,
+    parents: Query<&Parent>,
+    mut groups: Query<&mut NavGroup>,
+) {
+    if !focus.is_changed() {
+        return;
+    }
+    let Some(focus) = focus.0 else {
+        return;
+    };
+    let mut entity = parents.get(focus).ok().map(|p| p.get());
+    while let Some(ent) = entity {
+        if let Ok(mut group) = groups.get_mut(ent) {
+            group.last = Some(focus);
+            return;
+        }
+        entity = parents.get(ent).ok().map(|p| p.get());
+    }
+}
+
+fn handle_gamepad(
+    nav: TabNavigation,
+    buttons: Res<ButtonInput<GamepadButton>>,
+    mapping: Res<GamepadNavigation>,
+    mut focus: ResMut<Focus>,
+    mut visible: ResMut<FocusVisible>,
+    mut press_writer: EventWriter<KeyPressEvent>,
+    default_listener: Query<Entity, With<DefaultKeyListener>>,
+) {
+    if !mapping.enabled {
+        return;
+    }
+    for button in buttons.get_just_pressed() {
+        let button = button.button_type;
+        let key_code = if button == mapping.up {
+            KeyCode::ArrowUp
+        } else if button == mapping.down {
+            KeyCode::ArrowDown
+        } else if button == mapping.left {
+            KeyCode::ArrowLeft
+        } else if button == mapping.right {
+            KeyCode::ArrowRight
+        } else if button == mapping.activate {
+            KeyCode::Enter
+        } else if button == mapping.cancel {
+            KeyCode::Escape
+        } else if button == mapping.next || button == mapping.previous {
+            let next = nav.navigate(
+                focus.0,
+                if button == mapping.next {
+                    NavAction::Next
+                } else {
+                    NavAction::Previous
+                },
+            );
+            if next.is_some() {
+                focus.0 = next;
+                visible.0 = true;
+            }
+            continue;
+        } else {
+            continue;
+        };
+        let Some(target) = focus.0.or_else(|| default_listener.iter().next()) else {
+            continue;
+        };
+        visible.0 = true;
+        press_writer.send(KeyPressEvent {
+            target,
+            key_code,
+            repeat: false,
+            shift: false,
+        });
+    }
+}
+
 fn handle_text_input(
     mut key_events: EventReader<KeyboardInput>,
     key: Res<ButtonInput<KeyCode>>,
@@ -349,11 +862,81 @@ impl Plugin for KeyboardInputPlugin {
             EventListenerPlugin::<KeyPressEvent>::default(),
         ))
         .init_resource::<FocusVisible>()
+        .init_resource::<GamepadNavigation>()
         .add_event::<KeyPressEvent>()
         .add_event::<KeyCharEvent>()
         .add_systems(
             Update,
-            (fix_focus, handle_auto_focus, handle_tab, handle_text_input),
+            (
+                fix_focus,
+                handle_auto_focus,
+                handle_tab,
+                handle_gamepad,
+                handle_text_input,
+                track_nav_group_focus,
+            ),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+
+    use super::{direction_score, NavAction};
+
+    fn assert_score(from: Rect, to: Rect, action: NavAction, expected: f32) {
+        let score = direction_score(from, to, &action);
+        assert!(
+            score.is_some_and(|score| (score - expected).abs() < 1e-4),
+            "expected {}, got {:?}",
+            expected,
+            score
         );
     }
+
+    #[test]
+    fn test_direction_score() {
+        let from = Rect::new(0., 0., 10., 10.);
+        assert_score(from, Rect::new(-20., 0., -10., 10.), NavAction::Left, 10.);
+        assert_score(from, Rect::new(20., 0., 30., 10.), NavAction::Right, 10.);
+        assert_score(from, Rect::new(0., -30., 10., -20.), NavAction::Up, 20.);
+        assert_score(from, Rect::new(0., 20., 10., 30.), NavAction::Down, 10.);
+    }
+
+    #[test]
+    fn test_direction_score_off_axis() {
+        let from = Rect::new(0., 0., 10., 10.);
+        // Distance along the direction, plus twice the gap across it, plus a tenth of the
+        // offset between centers.
+        assert_score(from, Rect::new(20., 20., 30., 30.), NavAction::Right, 32.);
+        // Overlapping elements have no distance along the direction.
+        assert_score(from, Rect::new(5., 0., 15., 10.), NavAction::Right, 0.);
+
+        // An element further away in the same row is preferred to one in the next row.
+        let same_row = direction_score(from, Rect::new(40., 0., 50., 10.), &NavAction::Right);
+        let next_row = direction_score(from, Rect::new(20., 30., 30., 40.), &NavAction::Right);
+        assert!(same_row.unwrap() < next_row.unwrap());
+    }
+
+    #[test]
+    fn test_direction_score_rejects() {
+        let from = Rect::new(0., 0., 10., 10.);
+        let right = Rect::new(20., 0., 30., 10.);
+        assert_eq!(direction_score(from, right, &NavAction::Left), None);
+        assert_eq!(direction_score(from, right, &NavAction::Up), None);
+        assert_eq!(direction_score(from, right, &NavAction::Down), None);
+        assert_eq!(direction_score(from, from, &NavAction::Right), None);
+        // Elements whose centers are less than half a pixel apart aren't in any direction.
+        let nudged = Rect::new(0.5, 0., 10.5, 10.);
+        assert_eq!(direction_score(from, nudged, &NavAction::Right), None);
+        for action in [
+            NavAction::Next,
+            NavAction::Previous,
+            NavAction::First,
+            NavAction::Last,
+        ] {
+            assert_eq!(direction_score(from, right, &action), None);
+        }
+    }
 }
@@ -1,5 +1,5 @@
 #![feature(impl_trait_in_assoc_type, associated_type_defaults)]
-use bevy::{asset::embedded_asset, prelude::*};
+use bevy::{asset::embedded_asset, prelude::*, transform::TransformSystem};
 
 mod rounded_corners;
 
@@ -16,6 +16,9 @@ pub mod controls;
 /// Module containing utilities for creating custom window cursors.
 pub mod cursor;
 
+/// Generic drag-and-drop between widgets.
+pub mod drag_drop;
+
 /// Utilities for tabbing between widgets.
 pub mod focus;
 
@@ -26,7 +29,7 @@ pub mod floating;
 pub mod hooks;
 
 /// Module containing custom materials.
-mod materials;
+pub mod materials;
 
 /// Utilities for managing scrolling views.
 pub mod scrolling;
@@ -34,6 +37,13 @@ pub mod scrolling;
 /// Module containing standard sizes.
 pub mod size;
 
+/// Runtime-switchable color themes.
+pub mod theme;
+
+/// Module containing named color tokens, which are resolved against the current theme.
+#[allow(missing_docs)]
+pub mod tokens;
+
 /// Module of utilities for embedding a 3D viewport in the 2D UI.
 pub mod viewport;
 
@@ -47,8 +57,12 @@ pub mod prelude {
 }
 
 use bevy_mod_picking::prelude::EventListenerPlugin;
-use controls::{MenuCloseEvent, RecentColors};
-use materials::{GradientRectMaterial, SliderRectMaterial, SwatchRectMaterial};
+use bevy_mod_stylebuilder::ColorTokens;
+use controls::{MenuCloseEvent, RecentColors, SavedColors, TextClipboard};
+use materials::{
+    DrawPathMaterial, GradientRampMaterial, GradientRectMaterial, HsvRectMaterial,
+    SliderRectMaterial, SwatchRectMaterial,
+};
 pub use rounded_corners::RoundedCorners;
 
 pub use hooks::is_hover::UseIsHover as _;
@@ -84,11 +98,18 @@ impl Plugin for ObsidianUiPlugin {
         embedded_asset!(app, "assets/icons/undo.png");
         embedded_asset!(app, "assets/icons/zoom_in.png");
         embedded_asset!(app, "assets/icons/zoom_out.png");
+        embedded_asset!(app, "assets/shaders/draw_path.wgsl");
+        embedded_asset!(app, "assets/shaders/gradient_ramp.wgsl");
         embedded_asset!(app, "assets/shaders/gradient_rect.wgsl");
+        embedded_asset!(app, "assets/shaders/hsv_rect.wgsl");
         embedded_asset!(app, "assets/shaders/swatch_rect.wgsl");
         embedded_asset!(app, "assets/shaders/slider_rect.wgsl");
+        embedded_asset!(app, "assets/themes/high_contrast.theme.ron");
         app.add_plugins((
+            UiMaterialPlugin::<DrawPathMaterial>::default(),
+            UiMaterialPlugin::<GradientRampMaterial>::default(),
             UiMaterialPlugin::<GradientRectMaterial>::default(),
+            UiMaterialPlugin::<HsvRectMaterial>::default(),
             UiMaterialPlugin::<SliderRectMaterial>::default(),
             UiMaterialPlugin::<SwatchRectMaterial>::default(),
             hooks::BistableTransitionPlugin,
@@ -100,16 +121,55 @@ impl Plugin for ObsidianUiPlugin {
             EventListenerPlugin::<MenuCloseEvent>::default(),
         ))
         .add_event::<scrolling::ScrollWheel>()
+        .add_event::<controls::Notify>()
         .add_systems(
             Update,
             (
                 scrolling::handle_scroll_events,
+                scrolling::update_scroll_motion,
+                scrolling::update_auto_scroll,
                 scrolling::update_scroll_positions,
+                controls::sync_table_headers.after(scrolling::update_scroll_positions),
+                scrolling::scroll_focus_into_view,
                 hooks::is_hover::update_hover_states,
                 cursor::update_cursor,
+                controls::dismiss_tooltips,
+                controls::update_notifications,
+                controls::trigger_shortcuts,
+                controls::activate_menu_bar,
+                controls::update_eyedropper,
+                drag_drop::cancel_drag_on_escape,
             ),
         )
+        .init_asset::<theme::Theme>()
+        .init_asset_loader::<theme::ThemeLoader>()
+        .init_resource::<theme::Theme>()
+        .init_resource::<ColorTokens>()
+        .add_systems(
+            PreUpdate,
+            (theme::load_active_theme, theme::update_theme_tokens).chain(),
+        )
         .init_resource::<RecentColors>()
-        .add_systems(PostUpdate, floating::position_floating);
+        .init_resource::<SavedColors>()
+        .init_resource::<controls::Eyedropper>()
+        .init_resource::<TextClipboard>()
+        .init_resource::<scrolling::AutoScroll>()
+        .init_resource::<drag_drop::DragDrop>()
+        .init_resource::<drag_drop::DragPosition>()
+        .init_resource::<controls::Notifications>()
+        .init_resource::<controls::ShortcutRegistry>()
+        .register_type::<controls::Gradient>()
+        .register_type::<controls::GradientStop>()
+        .register_type::<controls::GradientInterpolation>()
+        .register_type::<animation::ScalarCurve>()
+        .register_type::<animation::CurveKey>()
+        .register_type::<animation::CurveInterpolation>()
+        .add_systems(
+            PostUpdate,
+            (
+                floating::position_floating,
+                controls::reveal_text_caret.after(TransformSystem::TransformPropagate),
+            ),
+        );
     }
 }
@@ -21,6 +21,7 @@ pub struct DrawablePath {
 }
 
 impl DrawablePath {
+    /// Create a new, empty path with the given stroke width.
     pub fn new(width: f32) -> Self {
         Self {
             width,
@@ -28,19 +29,63 @@ impl DrawablePath {
         }
     }
 
+    /// Start a new sub-path at the given position.
     pub fn move_to(&mut self, point: Vec2) {
         self.commands.push(DrawablePathSegment::Move(point));
     }
 
+    /// Draw a straight line from the current position.
     pub fn line_to(&mut self, point: Vec2) {
         self.commands.push(DrawablePathSegment::Line(point));
     }
 
+    /// Draw a quadratic curve from the current position.
     pub fn quadratic_to(&mut self, control: Vec2, point: Vec2) {
         self.commands
             .push(DrawablePathSegment::Quadratic((control, point)));
     }
 
+    /// Draw a cubic Bézier curve from the current position. Since the shader only knows how
+    /// to draw quadratic curves, the cubic is split into several pieces, and each piece is
+    /// approximated by a quadratic.
+    pub fn cubic_to(&mut self, control1: Vec2, control2: Vec2, point: Vec2) {
+        const PIECES: usize = 4;
+        let start = self.current_point();
+        let position = |t: f32| {
+            let u = 1. - t;
+            start * (u * u * u)
+                + control1 * (3. * u * u * t)
+                + control2 * (3. * u * t * t)
+                + point * (t * t * t)
+        };
+        let derivative = |t: f32| {
+            let u = 1. - t;
+            (control1 - start) * (3. * u * u)
+                + (control2 - control1) * (6. * u * t)
+                + (point - control2) * (3. * t * t)
+        };
+        for i in 0..PIECES {
+            let t0 = i as f32 / PIECES as f32;
+            let t1 = (i + 1) as f32 / PIECES as f32;
+            let p0 = position(t0);
+            let p1 = position(t1);
+            let d0 = derivative(t0) * (t1 - t0);
+            let d1 = derivative(t1) * (t1 - t0);
+            self.quadratic_to((2. * (p0 + p1) + d0 - d1) * 0.25, p1);
+        }
+    }
+
+    /// Return the end point of the last segment, or the origin if the path is empty.
+    pub fn current_point(&self) -> Vec2 {
+        match self.commands.last() {
+            Some(DrawablePathSegment::Move(point))
+            | Some(DrawablePathSegment::Line(point))
+            | Some(DrawablePathSegment::Quadratic((_, point))) => *point,
+            None => Vec2::ZERO,
+        }
+    }
+
+    /// Return the bounding rectangle of the path, including the stroke width.
     pub fn bounds(&self) -> Rect {
         if self.commands.is_empty() {
             return Rect::default();
@@ -110,6 +155,7 @@ pub struct DrawPathMaterial {
 }
 
 impl DrawPathMaterial {
+    /// Set the stroke color, as a linear gradient between two points.
     pub fn update_color(
         &mut self,
         from_color: Srgba,
@@ -125,6 +171,8 @@ impl DrawPathMaterial {
         self.to_offset = to_pos.dot(norm);
     }
 
+    /// Set the path to draw. Coordinates are relative to the top-left corner of the path bounds,
+    /// so the node displaying the material should be positioned at `path.bounds().min`.
     pub fn update_path(&mut self, path: &DrawablePath) {
         let bounds = path.bounds();
         self.width = path.width;
@@ -161,6 +209,6 @@ impl DrawPathMaterial {
 
 impl UiMaterial for DrawPathMaterial {
     fn fragment_shader() -> ShaderRef {
-        "embedded://bevy_quill_obsidian_graph/assets/draw_path.wgsl".into()
+        "embedded://bevy_quill_obsidian/assets/shaders/draw_path.wgsl".into()
     }
 }
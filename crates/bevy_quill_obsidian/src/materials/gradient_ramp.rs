@@ -0,0 +1,20 @@
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use bevy::render::render_resource::*;
+
+/// Number of samples in a [`GradientRampMaterial`]. Must match the shader.
+pub(crate) const GRADIENT_RAMP_SAMPLES: usize = 64;
+
+/// Material which draws a color ramp from a table of evenly-spaced sRGB samples, so that
+/// gradients with any number of stops and interpolation modes can be displayed.
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
+pub(crate) struct GradientRampMaterial {
+    #[uniform(0)]
+    pub(crate) samples: [Vec4; GRADIENT_RAMP_SAMPLES],
+}
+
+impl UiMaterial for GradientRampMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "embedded://bevy_quill_obsidian/assets/shaders/gradient_ramp.wgsl".into()
+    }
+}
@@ -0,0 +1,18 @@
+use bevy::prelude::*;
+use bevy::reflect::TypePath;
+use bevy::render::render_resource::*;
+
+/// Material which draws a hue ring, with a saturation / value square for the current hue
+/// inscribed inside it.
+#[derive(AsBindGroup, Asset, TypePath, Debug, Clone)]
+pub(crate) struct HsvRectMaterial {
+    /// x: hue in degrees, y: width of the hue ring, z: gap between the ring and the square.
+    #[uniform(0)]
+    pub(crate) params: Vec4,
+}
+
+impl UiMaterial for HsvRectMaterial {
+    fn fragment_shader() -> ShaderRef {
+        "embedded://bevy_quill_obsidian/assets/shaders/hsv_rect.wgsl".into()
+    }
+}
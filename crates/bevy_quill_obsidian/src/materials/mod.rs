@@ -1,11 +1,15 @@
 // mod dot_grid;
-// mod draw_path;
+mod draw_path;
+mod gradient_ramp;
 mod gradient_rect;
+mod hsv_rect;
 mod slider_rect;
 mod swatch_rect;
 
 // pub(crate) use dot_grid::DotGridMaterial;
-// pub(crate) use draw_path::*;
+pub use draw_path::{DrawPathMaterial, DrawablePath, DrawablePathSegment};
+pub(crate) use gradient_ramp::{GradientRampMaterial, GRADIENT_RAMP_SAMPLES};
 pub(crate) use gradient_rect::GradientRectMaterial;
+pub(crate) use hsv_rect::HsvRectMaterial;
 pub(crate) use slider_rect::SliderRectMaterial;
 pub(crate) use swatch_rect::SwatchRectMaterial;
@@ -1,6 +1,26 @@
-use bevy::{input::mouse::MouseWheel, prelude::*, render::view::visibility, ui};
+use bevy::{a11y::Focus, input::mouse::MouseWheel, prelude::*, render::view::visibility, ui};
 use bevy_mod_picking::{focus::HoverMap, pointer::PointerId, prelude::EntityEvent};
 
+use crate::focus::FocusVisible;
+
+/// Rate at which an animated scroll approaches its destination; higher is faster.
+const SMOOTH_SCROLL_RATE: f32 = 15.;
+
+/// Rate at which kinetic scrolling slows down; higher stops sooner.
+const KINETIC_FRICTION: f32 = 4.;
+
+/// How long wheel input must stop, in seconds, before kinetic scrolling begins to coast.
+const KINETIC_DELAY: f32 = 0.05;
+
+/// Speed, in pixels per second, below which kinetic scrolling stops.
+const KINETIC_MIN_SPEED: f32 = 10.;
+
+/// Distance from the edge of a scroll area within which dragging auto-scrolls.
+const AUTO_SCROLL_MARGIN: f32 = 24.;
+
+/// Maximum speed, in pixels per second, of auto-scrolling while dragging.
+const AUTO_SCROLL_SPEED: f32 = 600.;
+
 /// Mouse wheel entity event
 #[derive(Clone, Event, EntityEvent, Debug)]
 #[can_bubble]
@@ -33,25 +53,98 @@ pub struct ScrollArea {
 
     /// Entity id of the Y scrollbar
     pub id_scrollbar_y: Option<Entity>,
+
+    /// Whether wheel scrolling coasts to a stop after the input ends, as is customary for
+    /// touchpads.
+    pub kinetic: bool,
+
+    /// Current kinetic scrolling velocity, in pixels per second.
+    pub velocity: Vec2,
+
+    /// Seconds since the last wheel input.
+    pub idle: f32,
+
+    /// Destination of an animated scroll in progress.
+    pub target: Option<Vec2>,
+}
+
+/// How to position an element when scrolling it into view.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ScrollAlignment {
+    /// Scroll as little as possible: don't scroll at all if the element is already visible,
+    /// otherwise align whichever edge is nearest.
+    #[default]
+    Nearest,
+
+    /// Align the element with the top or left edge of the scroll area.
+    Start,
+
+    /// Center the element within the scroll area.
+    Center,
+
+    /// Align the element with the bottom or right edge of the scroll area.
+    End,
+}
+
+impl ScrollAlignment {
+    /// Compute the scroll offset along one axis which brings the span `min..max` into view,
+    /// where both the span and `visible` are relative to the current scroll position.
+    fn offset(self, min: f32, max: f32, visible: f32) -> f32 {
+        match self {
+            ScrollAlignment::Nearest if min < 0. || max - min > visible => min,
+            ScrollAlignment::Nearest if max > visible => max - visible,
+            ScrollAlignment::Nearest => 0.,
+            ScrollAlignment::Start => min,
+            ScrollAlignment::Center => (min + max - visible) * 0.5,
+            ScrollAlignment::End => max - visible,
+        }
+    }
 }
 
 impl ScrollArea {
     /// Offset the current scroll position by the given values.
     pub fn scroll_by(&mut self, dx: f32, dy: f32) {
-        // Apply max constraint first, then min - don't use clamp() here.
-        self.scroll_left = (self.scroll_left + dx)
-            .min(self.content_size.x - self.visible_size.x)
-            .max(0.);
-        self.scroll_top = (self.scroll_top + dy)
-            .min(self.content_size.y - self.visible_size.y)
-            .max(0.);
+        self.scroll_to(self.scroll_left + dx, self.scroll_top + dy);
     }
 
     /// Scroll to the given scroll position (values clamped).
     pub fn scroll_to(&mut self, x: f32, y: f32) {
+        self.target = None;
+        self.velocity = Vec2::ZERO;
+        let position = self.clamp_position(Vec2::new(x, y));
+        self.scroll_left = position.x;
+        self.scroll_top = position.y;
+    }
+
+    /// Animate the scroll position towards the given position (values clamped).
+    pub fn scroll_to_smooth(&mut self, x: f32, y: f32) {
+        self.velocity = Vec2::ZERO;
+        self.target = Some(self.clamp_position(Vec2::new(x, y)));
+    }
+
+    /// Apply scrolling from the mouse wheel or touchpad. Unlike [`ScrollArea::scroll_by`],
+    /// this tracks the scrolling speed, so that kinetic scroll areas can continue to coast
+    /// after the input ends.
+    pub fn scroll_wheel(&mut self, delta: Vec2, dt: f32) {
+        let velocity = self.velocity;
+        self.scroll_by(-delta.x, -delta.y);
+        if self.kinetic && dt > 0. {
+            self.velocity = velocity.lerp(-delta / dt, 0.5);
+        }
+        self.idle = 0.;
+    }
+
+    /// The scroll position where an animated scroll will end up; this is the current
+    /// position if there is no animation in progress.
+    pub fn target_position(&self) -> Vec2 {
+        self.target.unwrap_or(self.scroll_position())
+    }
+
+    fn clamp_position(&self, position: Vec2) -> Vec2 {
         // Apply max constraint first, then min - don't use clamp() here.
-        self.scroll_left = x.min(self.content_size.x - self.visible_size.x).max(0.);
-        self.scroll_top = y.min(self.content_size.y - self.visible_size.y).max(0.);
+        position
+            .min(self.content_size - self.visible_size)
+            .max(Vec2::ZERO)
     }
 
     /// Current scroll position
@@ -214,3 +307,149 @@ pub(crate) fn handle_scroll_events(
         }
     }
 }
+
+/// Scroll all of the scroll areas which contain the given entity, innermost first, so that the
+/// entity becomes visible.
+pub fn scroll_into_view(
+    world: &mut World,
+    entity: Entity,
+    alignment: ScrollAlignment,
+    smooth: bool,
+) {
+    let Some(mut rect) = node_rect(world, entity) else {
+        return;
+    };
+    let mut ancestor = world.get::<Parent>(entity).map(|p| p.get());
+    while let Some(parent) = ancestor {
+        ancestor = world.get::<Parent>(parent).map(|p| p.get());
+        let Some(visible) = node_rect(world, parent) else {
+            continue;
+        };
+        let Some(mut scroll_area) = world.get_mut::<ScrollArea>(parent) else {
+            continue;
+        };
+        // Measure relative to where the area is headed, so that repeated requests during an
+        // animation don't overshoot.
+        let current = scroll_area.scroll_position();
+        let start = scroll_area.target_position();
+        let min = rect.min - visible.min - (start - current);
+        let max = rect.max - visible.min - (start - current);
+        let offset = Vec2::new(
+            alignment.offset(min.x, max.x, visible.width()),
+            alignment.offset(min.y, max.y, visible.height()),
+        );
+        if offset == Vec2::ZERO {
+            continue;
+        }
+        let destination = start + offset;
+        if smooth {
+            scroll_area.scroll_to_smooth(destination.x, destination.y);
+        } else {
+            scroll_area.scroll_to(destination.x, destination.y);
+        }
+        // The outer scroll areas see the entity where it will be once this one has scrolled.
+        let moved = scroll_area.target_position() - current;
+        rect.min -= moved;
+        rect.max -= moved;
+    }
+}
+
+fn node_rect(world: &World, entity: Entity) -> Option<Rect> {
+    let node = world.get::<Node>(entity)?;
+    let transform = world.get::<GlobalTransform>(entity)?;
+    Some(node.logical_rect(transform))
+}
+
+/// Keep the focused element visible when focus moves via the keyboard.
+pub(crate) fn scroll_focus_into_view(world: &mut World, mut previous: Local<Option<Entity>>) {
+    let focus = world.resource::<Focus>().0;
+    if focus == *previous {
+        return;
+    }
+    *previous = focus;
+    if let Some(focus) = focus {
+        if world.resource::<FocusVisible>().0 {
+            scroll_into_view(world, focus, ScrollAlignment::Nearest, true);
+        }
+    }
+}
+
+/// Advance animated and kinetic scrolling.
+pub(crate) fn update_scroll_motion(time: Res<Time>, mut query: Query<&mut ScrollArea>) {
+    let dt = time.delta_seconds();
+    for mut scroll_area in query.iter_mut() {
+        if let Some(target) = scroll_area.target {
+            let current = scroll_area.scroll_position();
+            let next = if current.distance(target) < 0.5 {
+                scroll_area.target = None;
+                target
+            } else {
+                current.lerp(target, 1. - (-SMOOTH_SCROLL_RATE * dt).exp())
+            };
+            scroll_area.scroll_left = next.x;
+            scroll_area.scroll_top = next.y;
+            continue;
+        }
+
+        if scroll_area.velocity == Vec2::ZERO {
+            continue;
+        }
+        scroll_area.idle += dt;
+        if scroll_area.idle < KINETIC_DELAY {
+            continue;
+        }
+        let velocity = scroll_area.velocity * (-KINETIC_FRICTION * dt).exp();
+        let start = scroll_area.scroll_position();
+        let next = scroll_area.clamp_position(start + velocity * dt);
+        scroll_area.scroll_left = next.x;
+        scroll_area.scroll_top = next.y;
+        // Stop when the motion is too slow to notice, or has hit the end of the content.
+        scroll_area.velocity = if velocity.length() < KINETIC_MIN_SPEED || next == start {
+            Vec2::ZERO
+        } else {
+            velocity
+        };
+    }
+}
+
+/// Resource which requests automatic scrolling while the pointer is dragged near the edge of
+/// a scroll area, such as when dragging an item towards a part of a list which is out of view.
+///
+/// Drag handlers set the pointer position on each drag event, and clear it when the drag ends.
+#[derive(Resource, Default)]
+pub struct AutoScroll(pub Option<Vec2>);
+
+/// Scroll areas near the dragged pointer, at a speed which increases closer to the edge.
+pub(crate) fn update_auto_scroll(
+    auto_scroll: Res<AutoScroll>,
+    time: Res<Time>,
+    mut query: Query<(&Node, &GlobalTransform, &mut ScrollArea)>,
+) {
+    let Some(position) = auto_scroll.0 else {
+        return;
+    };
+    let dt = time.delta_seconds();
+    for (node, transform, mut scroll_area) in query.iter_mut() {
+        let rect = node.logical_rect(transform);
+        if !rect.contains(position) {
+            continue;
+        }
+        let speed = |near: f32, far: f32| {
+            if near < AUTO_SCROLL_MARGIN {
+                -(1. - near / AUTO_SCROLL_MARGIN)
+            } else if far < AUTO_SCROLL_MARGIN {
+                1. - far / AUTO_SCROLL_MARGIN
+            } else {
+                0.
+            }
+        };
+        let direction = Vec2::new(
+            speed(position.x - rect.min.x, rect.max.x - position.x),
+            speed(position.y - rect.min.y, rect.max.y - position.y),
+        );
+        if direction != Vec2::ZERO {
+            let delta = direction * AUTO_SCROLL_SPEED * dt;
+            scroll_area.scroll_by(delta.x, delta.y);
+        }
+    }
+}
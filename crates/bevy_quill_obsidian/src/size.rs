@@ -1,3 +1,5 @@
+use bevy_mod_stylebuilder::{Length, DEFAULT_ROOT_FONT_SIZE};
+
 /// Standard sizes for buttons and other widgets that have size variants.
 #[derive(Clone, Copy, PartialEq, Debug, Default)]
 #[allow(missing_docs)]
@@ -26,6 +28,18 @@ impl Size {
         }
     }
 
+    /// Returns the height of the widget relative to the root font size, so that it scales with
+    /// the [`RootFontSize`](bevy_mod_stylebuilder::RootFontSize) resource.
+    pub fn height_rem(&self) -> Length {
+        Length::Rem(self.height() / DEFAULT_ROOT_FONT_SIZE)
+    }
+
+    /// Returns the horizontal padding around the label of the widget, relative to the root font
+    /// size.
+    pub fn padding_rem(&self) -> Length {
+        Length::Rem(self.font_size() * 0.75 / DEFAULT_ROOT_FONT_SIZE)
+    }
+
     /// Returns the height of the widget in pixels.
     pub fn border_radius(&self) -> f32 {
         match self {
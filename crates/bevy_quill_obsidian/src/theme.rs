@@ -0,0 +1,202 @@
+use bevy::{
+    asset::{io::Reader, AssetLoader, AsyncReadExt, LoadContext},
+    color::Srgba,
+    platform::collections::HashMap,
+    prelude::*,
+    reflect::TypePath,
+};
+use bevy_mod_stylebuilder::{ColorToken, ColorTokens};
+use serde::{Deserialize, Serialize};
+
+use crate::colors;
+
+const DARK: &[(&str, Srgba)] = &[
+    ("u1", colors::U1),
+    ("u2", colors::U2),
+    ("u3", colors::U3),
+    ("u4", colors::U4),
+    ("u5", colors::U5),
+    ("background", colors::BACKGROUND),
+    ("foreground", colors::FOREGROUND),
+    ("dim", colors::DIM),
+    ("accent", colors::ACCENT),
+    ("animation", colors::ANIMATION),
+    ("asset", colors::ASSET),
+    ("code", colors::CODE),
+    ("light", colors::LIGHT),
+    ("resource", colors::RESOURCE),
+    ("x_red", colors::X_RED),
+    ("y_green", colors::Y_GREEN),
+    ("z_blue", colors::Z_BLUE),
+    ("primary", colors::PRIMARY),
+    ("primary_acc", colors::PRIMARY_ACC),
+    ("destructive", colors::DESTRUCTIVE),
+    ("destructive_acc", colors::DESTRUCTIVE_ACC),
+    ("transparent", colors::TRANSPARENT),
+    ("focus", colors::FOCUS),
+    ("text_select", colors::TEXT_SELECT),
+];
+
+const LIGHT: &[(&str, Srgba)] = &[
+    ("u1", Srgba::new(0.980, 0.980, 0.984, 1.0)),
+    ("u2", Srgba::new(0.929, 0.929, 0.937, 1.0)),
+    ("u3", Srgba::new(0.855, 0.855, 0.871, 1.0)),
+    ("u4", Srgba::new(0.600, 0.600, 0.635, 1.0)),
+    ("u5", Srgba::new(0.0, 0.0, 0.0, 1.0)),
+    ("background", Srgba::new(0.906, 0.906, 0.918, 1.0)),
+    ("foreground", Srgba::new(0.102, 0.102, 0.110, 1.0)),
+    ("dim", Srgba::new(0.376, 0.376, 0.400, 1.0)),
+    ("accent", Srgba::new(0.000, 0.459, 0.757, 1.0)),
+    ("primary", Srgba::new(0.604, 0.722, 0.835, 1.0)),
+    ("primary_acc", Srgba::new(0.420, 0.565, 0.710, 1.0)),
+    ("destructive", Srgba::new(0.855, 0.620, 0.698, 1.0)),
+    ("destructive_acc", Srgba::new(0.710, 0.420, 0.565, 1.0)),
+    ("focus", Srgba::new(0.000, 0.459, 0.757, 0.3)),
+    ("text_select", Srgba::new(0.000, 0.459, 0.757, 0.35)),
+];
+
+/// A named set of colors which are used to resolve the color tokens in the
+/// [`tokens`](crate::tokens) module. The current theme is stored as a resource; replacing or
+/// modifying it re-colors every element that was styled with tokens.
+///
+/// Themes can also be loaded from `.theme.ron` asset files, see [`ActiveTheme`]. Any tokens
+/// not defined by a theme file take their value from the default dark theme. A high-contrast
+/// theme is included as `embedded://bevy_quill_obsidian/assets/themes/high_contrast.theme.ron`.
+#[derive(Resource, Asset, TypePath, Clone, Debug)]
+pub struct Theme {
+    /// Name of the theme, for display in theme pickers.
+    pub name: String,
+
+    /// Table of colors, indexed by token name.
+    pub colors: HashMap<String, Srgba>,
+}
+
+impl Theme {
+    /// The default dark theme.
+    pub fn dark() -> Self {
+        Self::from_table("Dark", DARK)
+    }
+
+    /// A light theme. Category colors (asset, code, etc.) are shared with the dark theme.
+    pub fn light() -> Self {
+        let mut theme = Self::dark();
+        theme.name = "Light".to_string();
+        for (name, color) in LIGHT {
+            theme.colors.insert(name.to_string(), *color);
+        }
+        theme
+    }
+
+    fn from_table(name: &str, table: &[(&str, Srgba)]) -> Self {
+        Self {
+            name: name.to_string(),
+            colors: table
+                .iter()
+                .map(|(name, color)| (name.to_string(), *color))
+                .collect(),
+        }
+    }
+
+    /// Resolve a color token against this theme. This is useful for computing colors in code,
+    /// such as animation targets; use `cx.use_resource::<Theme>()` to react to theme changes.
+    pub fn color(&self, token: ColorToken) -> Srgba {
+        let mut tokens = ColorTokens::default();
+        if let Some(color) = self.colors.get(token.name()) {
+            tokens.insert(token.name(), *color);
+        }
+        token
+            .resolve(&tokens)
+            .map(Srgba::from)
+            .unwrap_or(colors::TRANSPARENT)
+    }
+
+    /// Set the color for a token.
+    pub fn set(&mut self, token: ColorToken, color: impl Into<Srgba>) {
+        self.colors.insert(token.name().to_string(), color.into());
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Resource which, if present, selects a theme asset to load into the [`Theme`] resource. The
+/// theme is re-applied whenever the asset is reloaded.
+#[derive(Resource, Clone, Debug)]
+pub struct ActiveTheme(pub Handle<Theme>);
+
+/// Serialized form of a theme file.
+#[derive(Debug, Deserialize, Serialize)]
+struct ThemeSer {
+    name: String,
+    colors: HashMap<String, String>,
+}
+
+/// Loader for `.theme.ron` files, which contain a theme name and a map of token names to hex
+/// color strings.
+#[derive(Default)]
+pub struct ThemeLoader;
+
+impl AssetLoader for ThemeLoader {
+    type Asset = Theme;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load<'a>(
+        &'a self,
+        reader: &'a mut Reader<'_>,
+        _settings: &'a Self::Settings,
+        _load_context: &'a mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let theme_ser: ThemeSer = ron::de::from_str(&String::from_utf8(bytes)?)?;
+        let mut theme = Theme::dark();
+        theme.name = theme_ser.name;
+        for (name, hex) in theme_ser.colors.iter() {
+            theme.colors.insert(name.clone(), Srgba::hex(hex)?);
+        }
+        Ok(theme)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["theme.ron"]
+    }
+}
+
+/// Copies the active theme asset into the [`Theme`] resource when it is selected or reloaded.
+pub(crate) fn load_active_theme(
+    active: Option<Res<ActiveTheme>>,
+    themes: Res<Assets<Theme>>,
+    mut events: EventReader<AssetEvent<Theme>>,
+    mut theme: ResMut<Theme>,
+) {
+    let Some(active) = active else {
+        events.clear();
+        return;
+    };
+    let mut reloaded = false;
+    for ev in events.read() {
+        if let AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } = ev {
+            reloaded |= *id == active.0.id();
+        }
+    }
+    if active.is_changed() || reloaded {
+        if let Some(loaded) = themes.get(&active.0) {
+            *theme = loaded.clone();
+        }
+    }
+}
+
+/// Replaces the contents of the style builder's token table with the colors of the [`Theme`]
+/// resource, so that tokens which the new theme doesn't define are no longer resolved.
+pub(crate) fn update_theme_tokens(theme: Res<Theme>, mut tokens: ResMut<ColorTokens>) {
+    if theme.is_changed() {
+        tokens.0.clear();
+        for (name, color) in theme.colors.iter() {
+            tokens.insert(name.clone(), *color);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use bevy_mod_stylebuilder::ColorToken;
+
+pub const U1: ColorToken = ColorToken::new("u1");
+pub const U2: ColorToken = ColorToken::new("u2");
+pub const U3: ColorToken = ColorToken::new("u3");
+pub const U4: ColorToken = ColorToken::new("u4");
+pub const U5: ColorToken = ColorToken::new("u5");
+pub const BACKGROUND: ColorToken = ColorToken::new("background");
+pub const FOREGROUND: ColorToken = ColorToken::new("foreground");
+pub const DIM: ColorToken = ColorToken::new("dim");
+pub const ACCENT: ColorToken = ColorToken::new("accent");
+pub const ANIMATION: ColorToken = ColorToken::new("animation");
+pub const ASSET: ColorToken = ColorToken::new("asset");
+pub const CODE: ColorToken = ColorToken::new("code");
+pub const LIGHT: ColorToken = ColorToken::new("light");
+pub const RESOURCE: ColorToken = ColorToken::new("resource");
+pub const X_RED: ColorToken = ColorToken::new("x_red");
+pub const Y_GREEN: ColorToken = ColorToken::new("y_green");
+pub const Z_BLUE: ColorToken = ColorToken::new("z_blue");
+pub const PRIMARY: ColorToken = ColorToken::new("primary");
+pub const PRIMARY_ACC: ColorToken = ColorToken::new("primary_acc");
+pub const DESTRUCTIVE: ColorToken = ColorToken::new("destructive");
+pub const DESTRUCTIVE_ACC: ColorToken = ColorToken::new("destructive_acc");
+pub const TRANSPARENT: ColorToken = ColorToken::new("transparent");
+pub const FOCUS: ColorToken = ColorToken::new("focus");
+pub const TEXT_SELECT: ColorToken = ColorToken::new("text_select");
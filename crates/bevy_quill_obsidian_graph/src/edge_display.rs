@@ -6,12 +6,14 @@ use bevy_mod_stylebuilder::{
     StyleBuilder, StyleBuilderLayout, StyleBuilderPointerEvents, StyleBuilderVisibility,
 };
 use bevy_quill_core::prelude::*;
-use bevy_quill_obsidian::cursor::StyleBuilderCursor;
+use bevy_quill_obsidian::{
+    cursor::StyleBuilderCursor,
+    materials::{DrawPathMaterial, DrawablePath},
+};
 
 use crate::{
-    materials::{DrawPathMaterial, DrawablePath},
-    relative_pos::RelativeWorldPositions,
-    ConnectionAnchor, ConnectionTarget, DragAction, DragMode, Gesture, GestureState, GraphEvent,
+    relative_pos::RelativeWorldPositions, ConnectionAnchor, ConnectionTarget, DragAction, DragMode,
+    Gesture, GestureState, GraphEvent,
 };
 
 fn style_edge(ss: &mut StyleBuilder) {
@@ -24,10 +26,16 @@ fn style_edge_hitbox(ss: &mut StyleBuilder) {
         .height(12)
         .pointer_events(true)
         // .border(2)
-        // .border_color(colors::Y_GREEN)
+        // .border_color(tokens::Y_GREEN)
         .cursor(CursorIcon::Grab);
 }
 
+/// Component on the pickable parts of an edge display, which identifies the edge it represents.
+/// This can be used to map the target of a pointer event back to the edge, for example when
+/// deciding what to show in a context menu.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct EdgeRef(pub Entity);
+
 /// Displays a stroked path between two nodes.
 #[derive(Clone, PartialEq)]
 pub struct EdgeDisplay {
@@ -55,6 +63,7 @@ impl ViewTemplate for EdgeDisplay {
 
     fn create(&self, cx: &mut Cx) -> Self::View {
         let display_id = cx.create_entity();
+        let edge_id = self.edge_id;
         let material = cx.create_memo(
             |world, _| {
                 let mut ui_materials = world
@@ -132,6 +141,7 @@ impl ViewTemplate for EdgeDisplay {
                 (
                     Element::<NodeBundle>::new()
                         .insert_dyn(edge_event_handlers, (self.edge_id, display_id, false))
+                        .insert_if(edge_id.is_some(), move || EdgeRef(edge_id.unwrap()))
                         .style(style_edge_hitbox)
                         .style_dyn(
                             |pos, sb| {
@@ -141,6 +151,7 @@ impl ViewTemplate for EdgeDisplay {
                         ),
                     Element::<NodeBundle>::new()
                         .insert_dyn(edge_event_handlers, (self.edge_id, display_id, true))
+                        .insert_if(edge_id.is_some(), move || EdgeRef(edge_id.unwrap()))
                         .style(style_edge_hitbox)
                         .style_dyn(
                             |pos, sb| {
@@ -6,7 +6,7 @@ use bevy::{
     sprite::{MaterialMesh2dBundle, Mesh2dHandle},
 };
 use bevy_quill_core::prelude::*;
-use bevy_quill_obsidian::colors;
+use bevy_quill_obsidian::{theme::Theme, tokens};
 
 use crate::materials::LineMaterial;
 
@@ -28,11 +28,19 @@ impl ViewTemplate for EdgeDisplay {
             |world, _| {
                 let mut line_materials = world.get_resource_mut::<Assets<LineMaterial>>().unwrap();
                 line_materials.add(LineMaterial {
-                    color: colors::U4.into(),
+                    color: LinearRgba::NONE,
                 })
             },
             (),
         );
+        let color = cx.use_resource::<Theme>().color(tokens::U4);
+        cx.create_effect(
+            |world, (material, color)| {
+                let mut line_materials = world.get_resource_mut::<Assets<LineMaterial>>().unwrap();
+                line_materials.get_mut(material.id()).unwrap().color = color.into();
+            },
+            (line_material.clone(), color),
+        );
         // let line_material_id = line_material.id();
 
         // let mut ui_materials = cx
@@ -51,7 +59,7 @@ impl ViewTemplate for EdgeDisplay {
             .effect(
                 move |cx, ent, (src, dst)| {
                     let mut position = Vec::with_capacity(20);
-                    // let mut path = DrawablePath::new(colors::U4, 1.5);
+                    // let mut path = DrawablePath::new(tokens::U4, 1.5);
                     let dx = (dst.x - src.x).abs().mul(0.3).min(20.);
                     let src1 = src + Vec2::new(dx, 0.);
                     let dst1 = dst - Vec2::new(dx, 0.);
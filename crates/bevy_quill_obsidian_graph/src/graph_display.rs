@@ -2,12 +2,12 @@ use bevy::{prelude::*, ui};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
-use bevy_quill_obsidian::{colors, controls::ScrollView};
+use bevy_quill_obsidian::{controls::ScrollView, theme::Theme, tokens};
 
 use crate::{materials::DotGridMaterial, DragAction, DragMode, Gesture, GestureState, GraphEvent};
 
 fn style_node_graph(ss: &mut StyleBuilder) {
-    ss.background_color(colors::U1);
+    ss.background_color(tokens::U1);
 }
 
 fn style_node_graph_scroll(ss: &mut StyleBuilder) {
@@ -62,13 +62,27 @@ impl ViewTemplate for GraphDisplay {
             |world, _| {
                 let mut ui_materials = world.get_resource_mut::<Assets<DotGridMaterial>>().unwrap();
                 ui_materials.add(DotGridMaterial {
-                    color_bg: LinearRgba::from(colors::U1).to_vec4(),
-                    color_fg: LinearRgba::from(colors::U3).to_vec4(),
+                    color_bg: Vec4::ZERO,
+                    color_fg: Vec4::ZERO,
                 })
             },
             (),
         );
 
+        // Material colors can't be styled with tokens, so follow the theme by hand.
+        let theme = cx.use_resource::<Theme>();
+        let color_bg = theme.color(tokens::U1);
+        let color_fg = theme.color(tokens::U3);
+        cx.create_effect(
+            |world, (material, color_bg, color_fg)| {
+                let mut ui_materials = world.get_resource_mut::<Assets<DotGridMaterial>>().unwrap();
+                let material = ui_materials.get_mut(material.id()).unwrap();
+                material.color_bg = LinearRgba::from(color_bg).to_vec4();
+                material.color_fg = LinearRgba::from(color_fg).to_vec4();
+            },
+            (material.clone(), color_bg, color_fg),
+        );
+
         ScrollView::new()
             .entity(self.entity)
             .children(
@@ -17,11 +17,11 @@ use bevy::{
 };
 
 use bevy_mod_picking::prelude::EventListenerPlugin;
-pub use edge_display::EdgeDisplay;
+pub use edge_display::{EdgeDisplay, EdgeRef};
 pub use events::*;
 pub use graph_display::GraphDisplay;
-use materials::{DotGridMaterial, DrawPathMaterial, LineMaterial};
-pub use node_display::NodeDisplay;
+use materials::{DotGridMaterial, LineMaterial};
+pub use node_display::{NodeDisplay, NodeRef};
 pub use terminal_display::{InputTerminalDisplay, NoTerminalDisplay, OutputTerminalDisplay};
 
 /// Plugin for the Obsidian UI library.
@@ -30,12 +30,10 @@ pub struct ObsidianGraphPlugin;
 impl Plugin for ObsidianGraphPlugin {
     fn build(&self, app: &mut App) {
         embedded_asset!(app, "assets/dot_grid.wgsl");
-        embedded_asset!(app, "assets/draw_path.wgsl");
         embedded_asset!(app, "assets/line_material.wgsl");
         app.init_resource::<GestureState>()
             .add_plugins((
                 UiMaterialPlugin::<DotGridMaterial>::default(),
-                UiMaterialPlugin::<DrawPathMaterial>::default(),
                 Material2dPlugin::<LineMaterial>::default(),
                 EventListenerPlugin::<GraphEvent>::default(),
             ))
@@ -1,7 +1,5 @@
 mod dot_grid;
-mod draw_path;
 mod line_material;
 
 pub(crate) use dot_grid::DotGridMaterial;
-pub(crate) use draw_path::*;
 pub(crate) use line_material::LineMaterial;
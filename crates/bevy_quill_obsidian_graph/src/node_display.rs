@@ -5,9 +5,9 @@ use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::{prelude::*, IntoViewChild, ViewChild};
 use bevy_quill_obsidian::{
-    colors,
     cursor::StyleBuilderCursor,
     hooks::{UseElementRect, UseIsHover},
+    tokens,
 };
 
 use crate::{DragAction, DragMode, Gesture, GestureState, GraphEvent};
@@ -20,12 +20,18 @@ fn style_node_graph_node(ss: &mut StyleBuilder) {
         .visible(false);
 }
 
+/// Component on the display element of a node, which identifies the graph node it represents.
+/// This can be used to map the target of a pointer event back to the node, for example when
+/// deciding what to show in a context menu.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+pub struct NodeRef(pub Entity);
+
 const NODE_BORDER_RADIUS: f32 = 5.;
 const NODE_BORDER_WIDTH: f32 = 1.;
 
 fn style_node_graph_node_title(ss: &mut StyleBuilder) {
     ss.border(1)
-        .border_color(colors::U4)
+        .border_color(tokens::U4)
         .border(ui::UiRect {
             left: ui::Val::Px(NODE_BORDER_WIDTH),
             right: ui::Val::Px(NODE_BORDER_WIDTH),
@@ -38,7 +44,7 @@ fn style_node_graph_node_title(ss: &mut StyleBuilder) {
             bottom_left: ui::Val::Px(0.),
             bottom_right: ui::Val::Px(0.),
         })
-        .background_color(colors::Y_GREEN.darker(0.05))
+        .background_color(tokens::Y_GREEN.darker(0.05))
         .padding((6, 2))
         .cursor(CursorIcon::Grab);
 }
@@ -49,7 +55,7 @@ fn style_node_graph_node_content(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Stretch)
         .gap(4)
         .border(1)
-        .border_color(colors::U4)
+        .border_color(tokens::U4)
         .border(ui::UiRect {
             left: ui::Val::Px(NODE_BORDER_WIDTH),
             right: ui::Val::Px(NODE_BORDER_WIDTH),
@@ -62,7 +68,7 @@ fn style_node_graph_node_content(ss: &mut StyleBuilder) {
             bottom_left: ui::Val::Px(NODE_BORDER_RADIUS),
             bottom_right: ui::Val::Px(NODE_BORDER_RADIUS),
         })
-        .background_color(colors::U2)
+        .background_color(tokens::U2)
         .padding((0, 6));
 }
 
@@ -84,7 +90,7 @@ fn style_node_graph_node_outline(ss: &mut StyleBuilder) {
         .right(-3)
         .bottom(-3)
         .border(2)
-        .border_color(colors::FOCUS)
+        .border_color(tokens::FOCUS)
         .border_radius(NODE_BORDER_RADIUS + 3.)
         .pointer_events(false);
 }
@@ -166,6 +172,7 @@ impl ViewTemplate for NodeDisplay {
             .named("NodeGraph::Node")
             .style(style_node_graph_node)
             .insert_dyn(move |_| node_event_handlers(display_id, node_id), ())
+            .insert_dyn(NodeRef, node_id)
             .effect(
                 move |cx, ent, (position, size)| {
                     if size.x > 0 && size.y > 0 {
@@ -188,9 +195,9 @@ impl ViewTemplate for NodeDisplay {
                     .style_dyn(
                         |selected, sb| {
                             sb.border_color(if selected {
-                                colors::FOREGROUND
+                                tokens::FOREGROUND
                             } else {
-                                colors::U4
+                                tokens::U4
                             });
                         },
                         self.selected,
@@ -202,9 +209,9 @@ impl ViewTemplate for NodeDisplay {
                     .style_dyn(
                         |selected, sb| {
                             sb.border_color(if selected {
-                                colors::FOREGROUND
+                                tokens::FOREGROUND
                             } else {
-                                colors::U4
+                                tokens::U4
                             });
                         },
                         self.selected,
@@ -2,7 +2,7 @@ use bevy::{prelude::*, ui};
 use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::{prelude::*, ViewChild};
-use bevy_quill_obsidian::{colors, cursor::StyleBuilderCursor, hooks::UseIsHover};
+use bevy_quill_obsidian::{cursor::StyleBuilderCursor, hooks::UseIsHover, tokens};
 
 use crate::{
     ConnectionAnchor, ConnectionTarget, DragAction, DragMode, Gesture, GestureState, GraphEvent,
@@ -15,7 +15,7 @@ fn style_terminal_outline(ss: &mut StyleBuilder) {
         .right(-3)
         .bottom(-3)
         .border(2)
-        .border_color(colors::FOCUS)
+        .border_color(tokens::FOCUS)
         .border_radius(8)
         .pointer_events(false);
 }
@@ -4,7 +4,8 @@ use crate::{
     inspectors::{
         bool::BooleanFieldInspector, color::SrgbaInspector, f32::F32FieldInspector,
         fallback::FallbackInspector, list::ListInspector, r#enum::EnumInspector,
-        r#struct::NestedStruct, tuple_struct::NestedTupleStruct, vec3::Vec3FieldInspector,
+        r#struct::NestedStruct, string::StringFieldInspector, tuple_struct::NestedTupleStruct,
+        vec3::Vec3FieldInspector,
     },
     templates::{field_label::FieldLabel, field_readonly_value::FieldReadonlyValue},
     Inspectable, InspectorFactory,
@@ -57,6 +58,9 @@ impl InspectorFactory for DefaultInspectorFactory {
             ReflectRef::Value(v) => match v.reflect_type_path() {
                 "bool" => Some(BooleanFieldInspector(field.clone()).into_view_child()),
                 "f32" => Some(F32FieldInspector(field.clone()).into_view_child()),
+                "alloc::string::String" => {
+                    Some(StringFieldInspector(field.clone()).into_view_child())
+                }
                 _ => Some(FallbackInspector(field.clone()).into_view_child()),
             },
         }
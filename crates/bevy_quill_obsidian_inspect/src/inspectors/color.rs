@@ -4,17 +4,17 @@ use bevy::{prelude::*, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
-    colors,
     controls::{Icon, MenuButton, MenuPopup, Spacer, Swatch},
     floating::{FloatAlign, FloatSide},
     size::Size,
+    tokens,
 };
 
 fn style_field(ss: &mut StyleBuilder) {
     ss.flex_direction(ui::FlexDirection::Row)
         .align_items(ui::AlignItems::Center)
         .justify_content(ui::JustifyContent::FlexStart)
-        .color(colors::FOREGROUND);
+        .color(tokens::FOREGROUND);
 }
 
 fn style_swatch(ss: &mut StyleBuilder) {
@@ -51,6 +51,8 @@ impl ViewTemplate for SrgbaInspector {
             mode: ColorMode::Rgb,
             rgb: Srgba::default(),
             hsl: Hsla::default(),
+            hsv: Hsva::default(),
+            oklch: Oklcha::default(),
         });
 
         let field = self.0.clone();
@@ -80,7 +82,7 @@ impl ViewTemplate for SrgbaInspector {
                         Icon::new("embedded://bevy_quill_obsidian/assets/icons/tune.png")
                             .size(Vec2::splat(16.0))
                             .style(style_menu_icon)
-                            .color(Color::from(colors::DIM)),
+                            .color(tokens::DIM),
                     )
                     .popup(
                         MenuPopup::new()
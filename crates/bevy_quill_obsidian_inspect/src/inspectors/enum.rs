@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use bevy::{
     ecs::reflect::AppTypeRegistry,
-    prelude::World,
+    log::warn,
+    prelude::{In, World},
     reflect::{
         std_traits::ReflectDefault, DynamicEnum, DynamicStruct, DynamicTuple, DynamicVariant,
         OffsetAccess, ReflectRef, TypeInfo, TypeRegistry, VariantInfo, VariantType,
@@ -10,8 +11,7 @@ use bevy::{
 };
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
-    controls::{MenuButton, MenuItem, MenuPopup},
-    floating::{FloatAlign, FloatSide},
+    controls::{Select, SelectOption},
     size::Size,
 };
 
@@ -56,115 +56,64 @@ impl ViewTemplate for VariantSelector {
     type View = impl View;
     fn create(&self, cx: &mut Cx) -> Self::View {
         let target = self.target.clone();
-        let variant_name = match target.reflect(cx) {
-            Some(reflect) => {
-                if let ReflectRef::Enum(en) = reflect.reflect_ref() {
-                    en.variant_name().to_string()
-                } else {
-                    "".to_string()
-                }
-            }
-            None => "".to_string(),
-        };
+        let on_change = cx.create_callback(move |variant_index: In<usize>, world: &mut World| {
+            set_variant(world, &target, *variant_index);
+        });
 
         let target = self.target.clone();
-        Dynamic::new(
-            match target
-                .reflect(cx)
-                .unwrap()
-                .get_represented_type_info()
-                .unwrap()
-            {
-                TypeInfo::Enum(en) => {
-                    let num_variants = en.variant_len();
-                    let mut items: Vec<ViewChild> = Vec::new();
-                    let registry = cx.world().resource::<AppTypeRegistry>().0.clone();
-                    let registry_lock = registry.read();
-                    for findex in 0..num_variants {
-                        let variant = en.variant_at(findex).unwrap();
-                        let variant_default = variant_default_value(variant, &registry_lock);
-                        if variant_default.is_none() {
-                            continue;
-                        }
-                        items.push(
-                            SetVariantItem {
-                                field: target.clone(),
-                                variant_name: variant.name().to_string(),
-                                variant_index: findex,
-                            }
-                            .into_view_child(),
-                        );
-                    }
+        let reflect = target.reflect(cx).unwrap();
+        let (variant_name, variant_index) = match reflect.reflect_ref() {
+            ReflectRef::Enum(en) => (en.variant_name().to_string(), Some(en.variant_index())),
+            _ => ("".to_string(), None),
+        };
 
-                    if !items.is_empty() {
-                        let variant_name = variant_name.clone();
-                        MenuButton::new()
-                            .children(variant_name)
-                            .popup(
-                                MenuPopup::new()
-                                    .side(FloatSide::Bottom)
-                                    .align(FloatAlign::End)
-                                    .children(items),
-                            )
-                            .size(Size::Sm)
-                            .into_view_child()
-                    } else {
-                        ().into_view_child()
+        // Only variants which can be default-constructed can be chosen.
+        let mut options: Vec<SelectOption<usize>> = Vec::new();
+        match reflect.get_represented_type_info().unwrap() {
+            TypeInfo::Enum(en) => {
+                let registry = cx.world().resource::<AppTypeRegistry>().0.clone();
+                let registry_lock = registry.read();
+                for findex in 0..en.variant_len() {
+                    let variant = en.variant_at(findex).unwrap();
+                    if variant_default_value(variant, &registry_lock).is_some() {
+                        options.push(SelectOption::new(findex, variant.name()));
                     }
                 }
-                _ => {
-                    println!(
-                        "Fallback: {}",
-                        target.reflect(cx).unwrap().reflect_type_path()
-                    );
-                    ().into_view_child()
-                }
-            },
-        )
-    }
-}
-
-#[derive(Clone)]
-struct SetVariantItem {
-    field: Arc<Inspectable>,
-    variant_name: String,
-    variant_index: usize,
-}
+            }
+            _ => {
+                warn!("Not an enum: {}", reflect.reflect_type_path());
+            }
+        }
 
-impl PartialEq for SetVariantItem {
-    fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.field, &other.field)
-            && self.variant_name == other.variant_name
-            && self.variant_index == other.variant_index
+        Cond::new(
+            !options.is_empty(),
+            Select::new()
+                .value(variant_index)
+                .options(options)
+                .placeholder(variant_name)
+                .size(Size::Sm)
+                .on_change(on_change),
+            (),
+        )
     }
 }
 
-impl ViewTemplate for SetVariantItem {
-    type View = impl View;
-    fn create(&self, cx: &mut Cx) -> Self::View {
-        let field = self.field.clone();
-        let variant_index = self.variant_index;
-        let callback = cx.create_callback(move |world: &mut World| {
-            let Some(field_reflect) = field.reflect_untracked(world) else {
-                return;
-            };
-            let Some(TypeInfo::Enum(enum_info)) = field_reflect.get_represented_type_info() else {
-                panic!("Expected TypeInfo::Enum");
-            };
-
-            let registry = world.resource::<AppTypeRegistry>().0.clone();
-            let variant = enum_info.variant_at(variant_index).unwrap();
-            let registry_lock = registry.read();
-            let variant_default = variant_default_value(variant, &registry_lock);
-            if let Some(def) = variant_default {
-                field.set_value(world, &def);
-            } else {
-                println!("Can't find ReflectDefault for: {:?}", variant.name());
-            }
-        });
-        MenuItem::new()
-            .label(self.variant_name.clone())
-            .on_click(callback)
+fn set_variant(world: &mut World, field: &Inspectable, variant_index: usize) {
+    let Some(field_reflect) = field.reflect_untracked(world) else {
+        return;
+    };
+    let Some(TypeInfo::Enum(enum_info)) = field_reflect.get_represented_type_info() else {
+        panic!("Expected TypeInfo::Enum");
+    };
+
+    let registry = world.resource::<AppTypeRegistry>().0.clone();
+    let variant = enum_info.variant_at(variant_index).unwrap();
+    let registry_lock = registry.read();
+    let variant_default = variant_default_value(variant, &registry_lock);
+    if let Some(def) = variant_default {
+        field.set_value(world, &def);
+    } else {
+        warn!("Can't find ReflectDefault for: {:?}", variant.name());
     }
 }
 
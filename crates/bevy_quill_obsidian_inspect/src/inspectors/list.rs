@@ -8,9 +8,9 @@ use bevy::{
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
-    colors,
     controls::{DisclosureToggle, IconButton},
     size::Size,
+    tokens,
 };
 
 use crate::{templates::field_label::FieldLabelWide, Inspectable, InspectorFactoryRegistry};
@@ -225,10 +225,10 @@ fn style_list_items(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Stretch)
         .grid_column_span(2)
         .min_width(64)
-        .color(colors::DIM)
+        .color(tokens::DIM)
         .margin_left(16);
 }
 
 fn style_empty_list(ss: &mut StyleBuilder) {
-    ss.color(colors::DIM);
+    ss.color(tokens::DIM);
 }
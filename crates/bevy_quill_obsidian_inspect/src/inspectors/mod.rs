@@ -4,6 +4,7 @@ pub mod r#enum;
 pub mod r#f32;
 pub mod fallback;
 pub mod list;
+pub mod string;
 pub mod r#struct;
 pub mod tuple_struct;
 pub mod vec3;
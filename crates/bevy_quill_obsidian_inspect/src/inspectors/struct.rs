@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
 use bevy::{
-    color::Color,
     ecs::reflect::AppTypeRegistry,
+    log::warn,
     prelude::{In, World},
     reflect::{
         std_traits::ReflectDefault, DynamicEnum, DynamicTuple, OffsetAccess, ReflectKind,
@@ -13,10 +13,10 @@ use bevy::{
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
-    colors,
     controls::{DisclosureToggle, Icon, MenuButton, MenuItem, MenuPopup},
     floating::FloatAlign,
     size::Size,
+    tokens,
 };
 
 use crate::{templates::field_label::FieldLabelWide, Inspectable, InspectorFactoryRegistry};
@@ -42,7 +42,7 @@ fn style_field_list(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Stretch)
         .grid_column_span(2)
         .min_width(64)
-        .color(colors::DIM)
+        .color(tokens::DIM)
         .margin_left(16)
         .margin_top(4)
         .margin_bottom(4);
@@ -240,7 +240,7 @@ impl ViewTemplate for StructInspectorHeaderControls {
             MenuButton::new()
                 .children(
                     Icon::new("embedded://bevy_quill_obsidian/assets/icons/add_box.png")
-                        .color(Color::from(colors::DIM))
+                        .color(tokens::DIM)
                         .style(style_menu_icon),
                 )
                 .popup(MenuPopup::new().align(FloatAlign::End).children(For::each(
@@ -366,7 +366,7 @@ impl ViewTemplate for AddStructFieldItem {
                     let dynamic_enum = DynamicEnum::new("Some", data);
                     field.set_value(world, &dynamic_enum);
                 } else {
-                    println!("Can't find ReflectDefault for: {:?}", some_type.type_path());
+                    warn!("Can't find ReflectDefault for: {:?}", some_type.type_path());
                 }
             }
         });
@@ -7,7 +7,7 @@ use bevy::{
 };
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
-use bevy_quill_obsidian::{colors, controls::DisclosureToggle, size::Size};
+use bevy_quill_obsidian::{controls::DisclosureToggle, size::Size, tokens};
 
 use crate::{templates::field_label::FieldLabelWide, Inspectable, InspectorFactoryRegistry};
 
@@ -32,7 +32,7 @@ fn style_field_list(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Stretch)
         .grid_column_span(2)
         .min_width(64)
-        .color(colors::DIM)
+        .color(tokens::DIM)
         .margin_left(16)
         .margin_top(4)
         .margin_bottom(4);
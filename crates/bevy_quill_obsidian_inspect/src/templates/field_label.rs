@@ -4,10 +4,9 @@ use bevy::{prelude::*, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
 use bevy_quill_obsidian::{
-    colors,
     controls::{IconButton, Spacer},
     size::Size,
-    typography,
+    tokens, typography,
 };
 
 use crate::Inspectable;
@@ -18,7 +17,7 @@ fn style_field_label(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Center)
         .justify_content(ui::JustifyContent::FlexStart)
         .font_size(16)
-        .color(colors::DIM)
+        .color(tokens::DIM)
         .padding_left(16);
 }
 
@@ -69,7 +68,7 @@ fn style_field_label_wide(ss: &mut StyleBuilder) {
         .grid_column_span(2)
         .font_size(16)
         .min_width(64)
-        .color(colors::DIM);
+        .color(tokens::DIM);
 }
 
 /// Label for editable struct field in an inspector.
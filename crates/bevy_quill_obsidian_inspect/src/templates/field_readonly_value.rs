@@ -1,7 +1,7 @@
 use bevy::{prelude::*, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
-use bevy_quill_obsidian::{colors, typography};
+use bevy_quill_obsidian::{tokens, typography};
 
 fn style_field_readonly_value(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
@@ -9,9 +9,9 @@ fn style_field_readonly_value(ss: &mut StyleBuilder) {
         .align_items(ui::AlignItems::Center)
         .justify_content(ui::JustifyContent::FlexStart)
         .border(1)
-        .border_color(colors::U3)
+        .border_color(tokens::U3)
         .font_size(16)
-        .color(colors::DIM)
+        .color(tokens::DIM)
         .padding((4, 1));
 }
 
@@ -1,7 +1,7 @@
 use bevy::{prelude::*, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill_core::*;
-use bevy_quill_obsidian::{colors, typography};
+use bevy_quill_obsidian::{tokens, typography};
 
 fn style_inspector_panel(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
@@ -16,14 +16,14 @@ fn style_inspector_panel_header(ss: &mut StyleBuilder) {
         .justify_content(ui::JustifyContent::SpaceBetween)
         .height(24)
         .font_size(16)
-        .background_color(colors::U3)
+        .background_color(tokens::U3)
         .border_radius(ui::BorderRadius {
             top_left: ui::Val::Px(4.0),
             top_right: ui::Val::Px(4.0),
             bottom_left: ui::Val::Px(0.0),
             bottom_right: ui::Val::Px(0.0),
         })
-        .color(colors::FOREGROUND)
+        .color(tokens::FOREGROUND)
         .padding_left(8)
         .padding_right(3);
 }
@@ -41,7 +41,7 @@ fn style_inspector_panel_body(ss: &mut StyleBuilder) {
         ])
         .column_gap(4)
         .row_gap(2)
-        .border_color(colors::U3)
+        .border_color(tokens::U3)
         .border(ui::UiRect {
             left: ui::Val::Px(1.0),
             right: ui::Val::Px(1.0),
@@ -60,7 +60,11 @@ fn style_inspector_panel_body(ss: &mut StyleBuilder) {
         .padding_left(6)
         .padding_right(4)
         .padding_top(4)
-        .padding_bottom(4);
+        .padding_bottom(4)
+        .when_width_below(480., |ss| {
+            // Stack labels above their editors on narrow windows.
+            ss.grid_template_columns(vec![ui::RepeatedGridTrack::flex(1, 1.)]);
+        });
 }
 
 /// Displays a inspector panel card with a title and a body.
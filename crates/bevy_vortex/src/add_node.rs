@@ -14,6 +14,35 @@ use crate::{
     operator::ReflectOperator,
 };
 
+/// Drag payload for an operator dragged from the catalog onto the graph. The argument is the
+/// type path of the operator.
+#[derive(Clone, PartialEq)]
+pub struct OperatorDrag(pub &'static str);
+
+/// Create a new graph node for the operator with the given type path.
+pub fn create_operator_node(
+    registry: &AppTypeRegistry,
+    graph: &mut GraphResource,
+    commands: &mut Commands,
+    operator_path: &str,
+    position: IVec2,
+) {
+    let registry_lock = registry.read();
+    if let Some(operator_type) = registry_lock.get_with_type_path(operator_path) {
+        let rd = operator_type.data::<ReflectDefault>().unwrap();
+        let value = rd.default();
+        let reflect_operator = registry_lock
+            .get_type_data::<ReflectOperator>(operator_type.type_id())
+            .unwrap();
+        let operator = reflect_operator.get_boxed(value).unwrap();
+        let mut action = UndoAction::new("Add Node");
+        graph
+            .0
+            .create_node(commands, operator, position, &mut action);
+        graph.0.add_undo_action(action);
+    }
+}
+
 /// Displays the list of available operators, by category.
 #[derive(Clone, PartialEq)]
 pub struct AddNodeButton;
@@ -30,34 +59,25 @@ impl ViewTemplate for AddNodeButton {
                   scrollarea_query: Query<&ScrollArea>,
                   mut graph: ResMut<GraphResource>,
                   mut commands: Commands| {
-                let registry_lock = registry.read();
                 let Some(selected_operator_path) = selection.0 else {
                     warn!("No selection");
                     return;
                 };
-                if let Some(operator_type) =
-                    registry_lock.get_with_type_path(selected_operator_path)
-                {
-                    let rd = operator_type.data::<ReflectDefault>().unwrap();
-                    let value = rd.default();
-                    let reflect_operator = registry_lock
-                        .get_type_data::<ReflectOperator>(operator_type.type_id())
-                        .unwrap();
-                    let operator = reflect_operator.get_boxed(value).unwrap();
-                    let mut action = UndoAction::new("Add Node");
-                    // Find the center of the current graph view, based on scroll position and size.
-                    let position = if let Ok(scrollarea) = scrollarea_query.get(graph_view_id) {
-                        let pos = scrollarea.scroll_position();
-                        let size = scrollarea.visible_size();
-                        Vec2::new(pos.x + size.x * 0.5, pos.y + size.y * 0.5).as_ivec2()
-                    } else {
-                        IVec2::default()
-                    };
-                    graph
-                        .0
-                        .create_node(&mut commands, operator, position, &mut action);
-                    graph.0.add_undo_action(action);
-                }
+                // Find the center of the current graph view, based on scroll position and size.
+                let position = if let Ok(scrollarea) = scrollarea_query.get(graph_view_id) {
+                    let pos = scrollarea.scroll_position();
+                    let size = scrollarea.visible_size();
+                    Vec2::new(pos.x + size.x * 0.5, pos.y + size.y * 0.5).as_ivec2()
+                } else {
+                    IVec2::default()
+                };
+                create_operator_node(
+                    &registry,
+                    &mut graph,
+                    &mut commands,
+                    selected_operator_path,
+                    position,
+                );
             },
         );
         let selected = cx.use_resource::<SelectedCatalogEntry>();
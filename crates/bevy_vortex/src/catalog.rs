@@ -3,12 +3,17 @@ use bevy::{
     reflect::TypeInfo,
     ui::{self, node_bundles::NodeBundle},
 };
-use bevy_mod_picking::prelude::*;
 use bevy_mod_stylebuilder::*;
 use bevy_quill::*;
-use bevy_quill_obsidian::{colors, controls::ListView, typography::text_strong};
+use bevy_quill_obsidian::{
+    controls::{TreeNode, TreeView},
+    drag_drop::draggable,
+    tokens,
+    typography::text_strong,
+};
 
 use crate::{
+    add_node::OperatorDrag,
     graph::NodeSelected,
     operator::{DisplayName, OperatorCategory, OperatorClass, ReflectOperator},
 };
@@ -41,6 +46,13 @@ impl Ord for CatalogEntry {
 #[derive(Resource, Default)]
 pub struct OperatorCatalog(pub Vec<CatalogEntry>);
 
+/// Key identifying a node in the catalog tree: either a category or an operator.
+#[derive(Clone, PartialEq)]
+enum CatalogKey {
+    Category(OperatorCategory),
+    Operator(&'static str),
+}
+
 /// Displays the list of available operators, by category.
 #[derive(Clone, PartialEq)]
 pub struct CatalogView;
@@ -49,86 +61,98 @@ fn style_catalog(ss: &mut StyleBuilder) {
     ss.flex_grow(1.).min_height(100);
 }
 
-impl ViewTemplate for CatalogView {
-    type View = impl View;
-
-    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
-        let catalog = cx.use_resource::<OperatorCatalog>();
-        ListView::new()
-            .style(style_catalog)
-            .children(For::each(catalog.0.clone(), |entry| {
-                CatalogRow(entry.clone())
-            }))
-    }
-}
-
-fn style_catalog_row(ss: &mut StyleBuilder) {
-    ss.align_self(ui::AlignSelf::Stretch)
-        .display(ui::Display::Flex)
-        .flex_direction(ui::FlexDirection::Row);
-}
-
-fn style_catalog_operator_class(ss: &mut StyleBuilder) {
-    ss.width(ui::Val::Percent(30.))
-        .padding(2)
-        .display(ui::Display::Flex)
-        .justify_content(ui::JustifyContent::FlexEnd)
-        .color(colors::DIM)
-        .overflow(ui::OverflowAxis::Clip);
+fn style_catalog_category(ss: &mut StyleBuilder) {
+    ss.padding(2).color(tokens::DIM);
 }
 
 fn style_catalog_operator_name(ss: &mut StyleBuilder) {
     ss.flex_grow(1.)
         .padding(2)
-        .color(colors::FOREGROUND)
+        .color(tokens::FOREGROUND)
         .overflow(ui::OverflowAxis::Clip);
 }
 
-#[derive(Clone, PartialEq)]
-struct CatalogRow(CatalogEntry);
-
-impl ViewTemplate for CatalogRow {
+impl ViewTemplate for CatalogView {
     type View = impl View;
-    fn create(&self, cx: &mut Cx) -> Self::View {
-        let selected = cx.use_resource::<SelectedCatalogEntry>();
-        let is_selected = Some(self.0.path) == selected.0;
-        let path = self.0.path;
-        Element::<NodeBundle>::new()
-            .style(style_catalog_row)
-            .style_dyn(
-                |selected, sb| {
-                    sb.background_color(if selected {
-                        colors::TEXT_SELECT
-                    } else {
-                        colors::TRANSPARENT
+
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let catalog = cx.use_resource::<OperatorCatalog>().0.clone();
+        let selected = cx.use_resource::<SelectedCatalogEntry>().0;
+        // Categories which have been collapsed by the user.
+        let collapsed = cx.create_mutable::<Vec<OperatorCategory>>(Vec::new());
+
+        let on_expand =
+            cx.create_callback(move |params: In<(CatalogKey, bool)>, world: &mut World| {
+                if let (CatalogKey::Category(category), expanded) = params.0.clone() {
+                    collapsed.update(world, |mut collapsed| {
+                        collapsed.retain(|c| *c != category);
+                        if !expanded {
+                            collapsed.push(category);
+                        }
                     });
-                },
-                is_selected,
-            )
-            .insert_dyn(
-                move |_| {
-                    On::<Pointer<Click>>::run(
-                        move |mut selected: ResMut<SelectedCatalogEntry>,
-                              graph_nodes: Query<Entity, With<NodeSelected>>,
-                              mut commands: Commands| {
-                            // Clear node selection
-                            for selected in graph_nodes.iter() {
-                                commands.entity(selected).remove::<NodeSelected>();
-                            }
-                            selected.0 = Some(path);
-                        },
+                }
+            });
+
+        let on_select = cx.create_callback(
+            |keys: In<Vec<CatalogKey>>,
+             mut selected: ResMut<SelectedCatalogEntry>,
+             graph_nodes: Query<Entity, With<NodeSelected>>,
+             mut commands: Commands| {
+                if let Some(CatalogKey::Operator(path)) = keys.first() {
+                    // Clear node selection
+                    for selected in graph_nodes.iter() {
+                        commands.entity(selected).remove::<NodeSelected>();
+                    }
+                    selected.0 = Some(*path);
+                }
+            },
+        );
+
+        // Group operators by category; the catalog is already sorted by category.
+        let mut nodes: Vec<TreeNode<CatalogKey>> = Vec::new();
+        for entry in catalog.iter() {
+            let key = CatalogKey::Category(entry.category.clone());
+            if nodes.last().map(|n| &n.key) != Some(&key) {
+                nodes.push(
+                    TreeNode::new(
+                        key,
+                        Element::<NodeBundle>::new()
+                            .style(style_catalog_category)
+                            .children(entry.category.to_local_string()),
                     )
-                },
-                (),
-            )
-            .children((
-                Element::<NodeBundle>::new()
-                    .style(style_catalog_operator_class)
-                    .children(self.0.category.to_local_string()),
+                    .expandable(true),
+                );
+            }
+            nodes.last_mut().unwrap().children.push(TreeNode::new(
+                CatalogKey::Operator(entry.path),
                 Element::<NodeBundle>::new()
                     .style((text_strong, style_catalog_operator_name))
-                    .children(self.0.display_name),
-            ))
+                    // Operators can be dragged onto the graph to create them.
+                    .insert_dyn(
+                        |(path, name)| draggable(OperatorDrag(path), name),
+                        (entry.path, entry.display_name),
+                    )
+                    .children(entry.display_name),
+            ));
+        }
+
+        let collapsed = collapsed.get_clone(cx);
+        let expanded = nodes
+            .iter()
+            .filter(|node| match &node.key {
+                CatalogKey::Category(category) => !collapsed.contains(category),
+                CatalogKey::Operator(_) => false,
+            })
+            .map(|node| node.key.clone())
+            .collect();
+
+        TreeView::new()
+            .style(style_catalog)
+            .nodes(nodes)
+            .expanded(expanded)
+            .selected(selected.map(CatalogKey::Operator).into_iter().collect())
+            .on_expand(on_expand)
+            .on_select(on_select)
     }
 }
 
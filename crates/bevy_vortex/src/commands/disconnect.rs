@@ -0,0 +1,64 @@
+use bevy::{
+    ecs::{system::SystemState, world::Command},
+    prelude::*,
+};
+
+use crate::{commands::mark_modified::MarkModifiedCmd, graph::*};
+
+/// Remove connections from the graph.
+pub(crate) enum DisconnectCmd {
+    /// Remove a single connection.
+    Connection(Entity),
+    /// Remove all connections to or from the selected nodes.
+    Selected,
+}
+
+impl Command for DisconnectCmd {
+    fn apply(self, world: &mut World) {
+        let connections: Vec<Entity> = match self {
+            DisconnectCmd::Connection(conn_id) => vec![conn_id],
+            DisconnectCmd::Selected => {
+                let mut st: SystemState<(
+                    Query<(Entity, &Connection)>,
+                    Query<(), (With<GraphNode>, With<NodeSelected>)>,
+                )> = SystemState::new(world);
+                let (connections, selected) = st.get(world);
+                connections
+                    .iter()
+                    .filter(|(_, conn)| {
+                        selected.contains(conn.input.node_id)
+                            || selected.contains(conn.output.node_id)
+                    })
+                    .map(|(ent, _)| ent)
+                    .collect()
+            }
+        };
+
+        let mut action = UndoAction::new("Disconnect");
+        for conn_id in connections {
+            let Some(conn) = world.get::<Connection>(conn_id).copied() else {
+                continue;
+            };
+            for terminal_id in [conn.input.terminal_id, conn.output.terminal_id] {
+                if let Some(mut terminal) = world.get_mut::<Terminal>(terminal_id) {
+                    terminal.connections.remove(&conn_id);
+                }
+            }
+            world.resource_scope(|world, mut graph: Mut<GraphResource>| {
+                graph.0.remove_connection(world, conn_id, &mut action);
+            });
+
+            // Mark input node as modified.
+            world.commands().add(MarkModifiedCmd {
+                start: conn.input.node_id,
+            });
+        }
+
+        if !action.mutations.is_empty() {
+            world
+                .resource_mut::<GraphResource>()
+                .0
+                .add_undo_action(action);
+        }
+    }
+}
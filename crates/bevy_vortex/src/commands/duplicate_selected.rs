@@ -0,0 +1,39 @@
+use bevy::{
+    ecs::{system::SystemState, world::Command},
+    prelude::*,
+};
+
+use crate::graph::*;
+
+/// Offset of duplicated nodes relative to the originals.
+const DUPLICATE_OFFSET: IVec2 = IVec2::new(24, 24);
+
+/// Create a copy of each selected node, and select the copies instead of the originals.
+pub(crate) struct DuplicateSelectedCmd;
+
+impl Command for DuplicateSelectedCmd {
+    fn apply(self, world: &mut World) {
+        let mut st: SystemState<(
+            Commands,
+            ResMut<GraphResource>,
+            Query<(Entity, &GraphNode), With<NodeSelected>>,
+        )> = SystemState::new(world);
+        let (mut commands, mut graph, nodes) = st.get_mut(world);
+
+        let mut action = UndoAction::new("Duplicate");
+        for (entity, node) in nodes.iter() {
+            commands.entity(entity).remove::<NodeSelected>();
+            graph.0.create_node(
+                &mut commands,
+                node.clone_operator(),
+                node.position + DUPLICATE_OFFSET,
+                &mut action,
+            );
+        }
+
+        if !action.mutations.is_empty() {
+            graph.0.add_undo_action(action);
+        }
+        st.apply(world);
+    }
+}
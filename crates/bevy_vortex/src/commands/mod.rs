@@ -1,7 +1,11 @@
 mod add_connection;
 mod delete_selected;
+mod disconnect;
+mod duplicate_selected;
 mod mark_modified;
 
 pub(crate) use add_connection::AddConnectionCmd;
 pub(crate) use delete_selected::DeleteSelectedCmd;
+pub(crate) use disconnect::DisconnectCmd;
+pub(crate) use duplicate_selected::DuplicateSelectedCmd;
 pub(crate) use mark_modified::MarkModifiedCmd;
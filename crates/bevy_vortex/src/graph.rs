@@ -169,6 +169,11 @@ impl GraphNode {
         self.operator.reflect_short_type_path()
     }
 
+    /// Return a copy of this node's operator, including its property values.
+    pub fn clone_operator(&self) -> Box<dyn Operator> {
+        self.operator.to_boxed_clone()
+    }
+
     pub fn operator_reflect(&self) -> &dyn Reflect {
         self.operator.as_reflect()
     }
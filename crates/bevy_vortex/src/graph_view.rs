@@ -1,4 +1,5 @@
 use crate::{
+    commands::{DeleteSelectedCmd, DisconnectCmd, DuplicateSelectedCmd},
     graph::{Connection, GraphNode, GraphResource, NodeSelected, Terminal},
     operator::{DisplayName, DisplayWidth, OperatorInput, OperatorInputOnly, OperatorOutput},
     propedit::GraphNodePropertyEdit,
@@ -6,10 +7,15 @@ use crate::{
 use bevy::{color::Color, prelude::*, reflect::TypeInfo, ui};
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{prelude::*, Dynamic, IntoViewChild};
-use bevy_quill_obsidian::{colors, hooks::UseElementRect};
+use bevy_quill_obsidian::{
+    controls::{MenuDivider, MenuItem, MenuPopup},
+    hooks::UseElementRect,
+    theme::Theme,
+    tokens,
+};
 use bevy_quill_obsidian_graph::{
-    ConnectionAnchor, ConnectionTarget, EdgeDisplay, GraphDisplay, InputTerminalDisplay,
-    NoTerminalDisplay, NodeDisplay, OutputTerminalDisplay,
+    ConnectionAnchor, ConnectionTarget, EdgeDisplay, EdgeRef, GraphDisplay, InputTerminalDisplay,
+    NoTerminalDisplay, NodeDisplay, NodeRef, OutputTerminalDisplay,
 };
 
 fn style_node_graph(ss: &mut StyleBuilder) {
@@ -60,6 +66,104 @@ impl ViewTemplate for GraphView {
     }
 }
 
+/// What a context menu in the graph view applies to.
+#[derive(Clone, Copy, PartialEq, Default)]
+pub enum GraphMenuTarget {
+    /// Nothing is selected.
+    #[default]
+    None,
+    /// The currently selected nodes.
+    Selection,
+    /// A single edge.
+    Edge(Entity),
+}
+
+impl GraphMenuTarget {
+    /// Work out what a context menu applies to, given the entity that was clicked. Clicking on
+    /// a node which isn't selected selects it instead of the current selection, so that node
+    /// menus always apply to the selection.
+    pub fn from_clicked(world: &mut World, clicked: Entity) -> Self {
+        let mut entity = Some(clicked);
+        while let Some(ent) = entity {
+            if let Some(edge) = world.get::<EdgeRef>(ent) {
+                return GraphMenuTarget::Edge(edge.0);
+            }
+            if let Some(node) = world.get::<NodeRef>(ent) {
+                let node = node.0;
+                if !world.entity(node).contains::<NodeSelected>() {
+                    let selected: Vec<Entity> = world
+                        .query_filtered::<Entity, With<NodeSelected>>()
+                        .iter(world)
+                        .collect();
+                    for ent in selected {
+                        world.entity_mut(ent).remove::<NodeSelected>();
+                    }
+                    world.entity_mut(node).insert(NodeSelected);
+                }
+                return GraphMenuTarget::Selection;
+            }
+            entity = world.get::<Parent>(ent).map(|parent| parent.get());
+        }
+
+        let mut selected = world
+            .iter_entities()
+            .filter(|e| e.contains::<NodeSelected>());
+        if selected.next().is_some() {
+            GraphMenuTarget::Selection
+        } else {
+            GraphMenuTarget::None
+        }
+    }
+}
+
+/// Context menu popup for the graph view. The argument holds what the menu applies to, which is
+/// set when the menu is opened.
+#[derive(Clone, PartialEq)]
+pub struct GraphContextMenu(pub Mutable<GraphMenuTarget>);
+
+impl ViewTemplate for GraphContextMenu {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let target = self.0;
+        let on_delete = cx.create_callback(|mut commands: Commands| {
+            commands.add(DeleteSelectedCmd);
+        });
+        let on_duplicate = cx.create_callback(|mut commands: Commands| {
+            commands.add(DuplicateSelectedCmd);
+        });
+        let on_disconnect = cx.create_callback(move |world: &mut World| {
+            let cmd = match target.get(world) {
+                GraphMenuTarget::Edge(edge) => DisconnectCmd::Connection(edge),
+                _ => DisconnectCmd::Selected,
+            };
+            world.commands().add(cmd);
+        });
+        let target = target.get(cx);
+        let no_selection = target == GraphMenuTarget::None;
+
+        MenuPopup::new().children(Cond::new(
+            matches!(target, GraphMenuTarget::Edge(_)),
+            MenuItem::new().label("Disconnect").on_click(on_disconnect),
+            (
+                MenuItem::new()
+                    .label("Duplicate")
+                    .disabled(no_selection)
+                    .shortcut("graph.duplicate")
+                    .on_click(on_duplicate),
+                MenuItem::new()
+                    .label("Disconnect")
+                    .disabled(no_selection)
+                    .on_click(on_disconnect),
+                MenuDivider,
+                MenuItem::new()
+                    .label("Delete")
+                    .disabled(no_selection)
+                    .on_click(on_delete),
+            ),
+        ))
+    }
+}
+
 #[derive(Clone, PartialEq)]
 pub struct GraphNodeView(Entity);
 
@@ -196,8 +300,8 @@ impl ViewTemplate for ConnectionView {
                 edge_id: None,
                 src_pos: IVec2::default(),
                 dst_pos: IVec2::default(),
-                src_color: colors::U3,
-                dst_color: colors::U3,
+                src_color: theme_color(cx, tokens::U3),
+                dst_color: theme_color(cx, tokens::U3),
                 hidden: true,
             };
         };
@@ -274,7 +378,10 @@ impl ViewTemplate for ConnectionProxyView {
                     get_terminal_edge_color(cx, input),
                 )
             }
-            None => (IVec2::default(), IVec2::default(), colors::U3, colors::U3),
+            None => {
+                let color = theme_color(cx, tokens::U3);
+                (IVec2::default(), IVec2::default(), color, color)
+            }
         };
         Cond::new(
             drag_state.connect_from.is_some(),
@@ -296,16 +403,22 @@ fn get_terminal_position(cx: &Cx, terminal_id: Entity) -> IVec2 {
     rect.map_or(IVec2::default(), |f| f.center().as_ivec2())
 }
 
+/// Edges are drawn with a material, so their colors are resolved from the theme.
+fn theme_color(cx: &Cx, token: ColorToken) -> Srgba {
+    cx.use_resource::<Theme>().color(token)
+}
+
 fn get_terminal_color(cx: &Cx, terminal_id: Entity) -> Srgba {
-    if let Some(terminal) = cx.use_component::<Terminal>(terminal_id) {
+    let token = if let Some(terminal) = cx.use_component::<Terminal>(terminal_id) {
         match terminal.data_type {
-            crate::graph::ConnectionDataType::Scalar => colors::U4,
-            crate::graph::ConnectionDataType::Vector => colors::LIGHT,
-            crate::graph::ConnectionDataType::Color => colors::RESOURCE,
+            crate::graph::ConnectionDataType::Scalar => tokens::U4,
+            crate::graph::ConnectionDataType::Vector => tokens::LIGHT,
+            crate::graph::ConnectionDataType::Color => tokens::RESOURCE,
         }
     } else {
-        colors::U3
-    }
+        tokens::U3
+    };
+    theme_color(cx, token)
 }
 
 fn get_terminal_edge_color(cx: &Cx, terminal_id: Entity) -> Srgba {
@@ -327,7 +440,7 @@ fn get_target_color(cx: &Cx, target: Option<ConnectionTarget>) -> Srgba {
         Some(ConnectionTarget::InputTerminal(term) | ConnectionTarget::OutputTerminal(term)) => {
             get_terminal_edge_color(cx, term)
         }
-        _ => colors::U3,
+        _ => theme_color(cx, tokens::U3),
     }
 }
 
@@ -353,8 +466,8 @@ fn get_relative_rect(cx: &Cx, id: Entity, levels: usize) -> Option<Rect> {
 }
 
 fn style_selection_rect(ss: &mut StyleBuilder) {
-    ss.background_color(colors::TEXT_SELECT.with_alpha(0.02))
-        .border_color(colors::TEXT_SELECT.with_alpha(0.1))
+    ss.background_color(tokens::TEXT_SELECT.with_alpha(0.02))
+        .border_color(tokens::TEXT_SELECT.with_alpha(0.1))
         .border(2);
 }
 
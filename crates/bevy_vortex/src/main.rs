@@ -11,8 +11,9 @@ mod ops;
 mod pipeline;
 mod preview;
 mod propedit;
+mod shader_source;
 
-use add_node::AddNodeButton;
+use add_node::{create_operator_node, AddNodeButton, OperatorDrag};
 use bevy_mod_picking::{
     debug::DebugPickingMode,
     picking_core::Pickable,
@@ -21,22 +22,28 @@ use bevy_mod_picking::{
 };
 use bevy_mod_stylebuilder::*;
 use bevy_quill_obsidian::{
-    colors,
-    controls::{Splitter, SplitterDirection},
+    controls::{
+        CommandDef, CommandPalette, ContextMenu, DockDirection, DockLayout, DockNode, DockPanel,
+        DockSpace, DragPreviewOverlay, MenuBar, MenuButton, MenuDivider, MenuItem, MenuPopup,
+        NotificationOverlay, Shortcut, ShortcutRegistry, SubMenu,
+    },
+    drag_drop::{drop_target, DragPosition, UseIsDropHover},
     focus::{DefaultKeyListener, KeyPressEvent, TabGroup},
-    typography, viewport, ObsidianUiPlugin,
+    scrolling::ScrollArea,
+    theme::Theme,
+    tokens, typography, viewport, ObsidianUiPlugin,
 };
 use bevy_quill_obsidian_graph::{
     ConnectionAnchor, ConnectionTarget, DragAction, Gesture, GraphEvent, ObsidianGraphPlugin,
 };
 use catalog::{build_operator_catalog, CatalogView, OperatorCatalog, SelectedCatalogEntry};
-use commands::{AddConnectionCmd, DeleteSelectedCmd};
+use commands::{AddConnectionCmd, DeleteSelectedCmd, DuplicateSelectedCmd};
 use gen::{begin_build_shaders, finish_build_shaders};
 use graph::{
     sync_connection_refs, Connection, GraphNode, GraphResource, NodeBasePosition, NodeSelected,
     ValidateConnectionCmd,
 };
-use graph_view::{DragState, GraphView, GraphViewId};
+use graph_view::{DragState, GraphContextMenu, GraphMenuTarget, GraphView, GraphViewId};
 use ops::OperatorsPlugin;
 use pipeline::NodeShaderMeshPlugin;
 use preview::{PreviewControls, PreviewPlugin};
@@ -51,19 +58,23 @@ fn style_main(ss: &mut StyleBuilder) {
         .bottom(0)
         .right(0)
         .border(1)
-        .border_color(colors::U2)
+        .border_color(tokens::U2)
         .display(ui::Display::Flex)
+        .flex_direction(ui::FlexDirection::Column)
         .pointer_events(false);
 }
 
-fn style_aside(ss: &mut StyleBuilder) {
+fn style_menu_bar(ss: &mut StyleBuilder) {
+    ss.background_color(tokens::U1).pointer_events(true);
+}
+
+fn style_panel(ss: &mut StyleBuilder) {
     ss.display(ui::Display::Flex)
-        .background_color(colors::U2)
-        .z_index(-1)
+        .flex_direction(ui::FlexDirection::Column)
+        .flex_grow(1.)
+        .min_height(0)
         .padding(8)
         .gap(8)
-        .flex_direction(ui::FlexDirection::Column)
-        .width(200)
         .pointer_events(true);
 }
 
@@ -78,15 +89,58 @@ fn style_viewport(ss: &mut StyleBuilder) {
         .aspect_ratio(1.);
 }
 
+/// Below this window width, the workspace uses the narrow layout, which stacks the panels above
+/// the graph instead of beside it.
+const NARROW_LAYOUT_WIDTH: f32 = 800.;
+
+/// The arrangement of the workspace panels, which the user can rearrange by dragging their
+/// tabs. Wide and narrow windows each have their own arrangement.
 #[derive(Resource)]
-pub struct PanelWidth(f32);
+pub struct WorkspaceLayout {
+    wide: DockLayout,
+    narrow: DockLayout,
+}
+
+impl Default for WorkspaceLayout {
+    fn default() -> Self {
+        Self {
+            wide: DockLayout::new(DockNode::split(
+                DockDirection::Row,
+                0.25,
+                DockNode::split(
+                    DockDirection::Column,
+                    0.6,
+                    DockNode::group(["catalog"]),
+                    DockNode::group(["preview"]),
+                ),
+                DockNode::group(["graph"]),
+            )),
+            narrow: DockLayout::new(DockNode::split(
+                DockDirection::Column,
+                0.35,
+                DockNode::split(
+                    DockDirection::Row,
+                    0.5,
+                    DockNode::group(["catalog"]),
+                    DockNode::group(["preview"]),
+                ),
+                DockNode::group(["graph"]),
+            )),
+        }
+    }
+}
+
+/// True if the UI node `id` is displayed in a window narrower than [`NARROW_LAYOUT_WIDTH`].
+fn is_narrow(world: &World, id: Entity) -> bool {
+    ViewportMetrics::for_entity(world, id).logical_size.x < NARROW_LAYOUT_WIDTH
+}
 
 fn main() {
     App::new()
         .init_resource::<OperatorCatalog>()
         .init_resource::<GraphResource>()
         .init_resource::<SelectedCatalogEntry>()
-        .insert_resource(PanelWidth(300.))
+        .init_resource::<WorkspaceLayout>()
         .init_resource::<viewport::ViewportInset>()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
         .add_plugins((DefaultPickingPlugins,))
@@ -107,7 +161,6 @@ fn main() {
         .add_systems(
             Update,
             (
-                close_on_esc,
                 build_operator_catalog,
                 viewport::update_viewport_inset,
                 viewport::update_camera_viewport,
@@ -143,10 +196,20 @@ impl ViewTemplate for VortexUi {
     type View = impl View;
 
     fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
         let graph_view_id = cx.create_entity();
-        let panel_width = cx.use_resource::<PanelWidth>().0;
         let camera = self.0;
 
+        // Re-render when the window is resized, to switch between the wide and narrow layouts.
+        cx.use_component::<ui::ComputedNodeTarget>(id);
+        let narrow = is_narrow(cx.world(), id);
+        let workspace = cx.use_resource::<WorkspaceLayout>();
+        let layout = if narrow {
+            workspace.narrow.clone()
+        } else {
+            workspace.wide.clone()
+        };
+
         // Needed to ensure popup menus and dialogs render on the correct camera.
         cx.insert(TargetCamera(camera));
 
@@ -154,7 +217,54 @@ impl ViewTemplate for VortexUi {
         cx.insert(GraphViewId(graph_view_id));
         cx.insert(DragState::default());
 
-        Element::<NodeBundle>::new()
+        // Application commands. The graph editing commands are also displayed in the graph
+        // context menu.
+        let palette_open = cx.create_mutable(false);
+        let on_duplicate = cx.create_callback(|mut commands: Commands| {
+            commands.add(DuplicateSelectedCmd);
+        });
+        let on_delete = cx.create_callback(|mut commands: Commands| {
+            commands.add(DeleteSelectedCmd);
+        });
+        let on_show_palette = cx.create_callback(move |world: &mut World| {
+            let open = palette_open.get(world);
+            palette_open.set(world, !open);
+        });
+        let on_quit = cx.create_callback(|mut exit: EventWriter<AppExit>| {
+            exit.send(AppExit::Success);
+        });
+        cx.create_effect(
+            move |world, _| {
+                let mut registry = world.resource_mut::<ShortcutRegistry>();
+                registry.add(
+                    CommandDef::new("graph.duplicate", "Duplicate Selected Nodes", on_duplicate)
+                        .shortcut(Shortcut::new(KeyCode::KeyD).ctrl()),
+                );
+                registry.add(
+                    CommandDef::new("graph.delete", "Delete Selected Nodes", on_delete)
+                        .shortcut(Shortcut::new(KeyCode::Delete)),
+                );
+                registry.add(
+                    CommandDef::new("view.command_palette", "Show All Commands", on_show_palette)
+                        .shortcut(Shortcut::new(KeyCode::KeyP).ctrl().shift()),
+                );
+                registry.add(
+                    CommandDef::new("app.quit", "Quit", on_quit)
+                        .shortcut(Shortcut::new(KeyCode::KeyQ).ctrl()),
+                );
+            },
+            (),
+        );
+
+        let theme_name = cx.use_resource::<Theme>().name.clone();
+        let on_dark_theme = cx.create_callback(|mut theme: ResMut<Theme>| {
+            *theme = Theme::dark();
+        });
+        let on_light_theme = cx.create_callback(|mut theme: ResMut<Theme>| {
+            *theme = Theme::light();
+        });
+
+        Element::<NodeBundle>::for_entity(id)
             .named("Main")
             .style((typography::text_default, style_main))
             .insert_dyn(
@@ -163,11 +273,10 @@ impl ViewTemplate for VortexUi {
                         TabGroup::default(),
                         TargetCamera(camera),
                         DefaultKeyListener,
+                        // Backspace also deletes, for keyboards without a Delete key.
                         On::<KeyPressEvent>::run(
                             |event: Listener<KeyPressEvent>, mut commands: Commands| {
-                                if event.key_code == KeyCode::Delete
-                                    || event.key_code == KeyCode::Backspace
-                                {
+                                if event.key_code == KeyCode::Backspace {
                                     commands.add(DeleteSelectedCmd);
                                 }
                             },
@@ -177,38 +286,95 @@ impl ViewTemplate for VortexUi {
                 (),
             )
             .children((
-                Element::<NodeBundle>::new()
-                    .named("ControlPalette")
-                    .style(style_aside)
-                    .style_dyn(
-                        move |width, sb| {
-                            sb.width(ui::Val::Px(width));
+                MenuBar::new().style(style_menu_bar).children((
+                    MenuButton::new()
+                        .minimal(true)
+                        .no_caret(true)
+                        .children("File")
+                        .popup(
+                            MenuPopup::new()
+                                .children(MenuItem::new().label("Quit").shortcut("app.quit")),
+                        ),
+                    MenuButton::new()
+                        .minimal(true)
+                        .no_caret(true)
+                        .children("Edit")
+                        .popup(
+                            MenuPopup::new().children((
+                                MenuItem::new()
+                                    .label("Duplicate")
+                                    .shortcut("graph.duplicate"),
+                                MenuItem::new().label("Delete").shortcut("graph.delete"),
+                            )),
+                        ),
+                    MenuButton::new()
+                        .minimal(true)
+                        .no_caret(true)
+                        .children("View")
+                        .popup(
+                            MenuPopup::new().children((
+                                MenuItem::new()
+                                    .label("Show All Commands")
+                                    .shortcut("view.command_palette"),
+                                MenuDivider,
+                                SubMenu::new().label("Theme").children((
+                                    MenuItem::new()
+                                        .label("Dark")
+                                        .checked(theme_name == "Dark")
+                                        .on_click(on_dark_theme),
+                                    MenuItem::new()
+                                        .label("Light")
+                                        .checked(theme_name == "Light")
+                                        .on_click(on_light_theme),
+                                )),
+                            )),
+                        ),
+                )),
+                DockSpace::new()
+                    .panels([
+                        DockPanel::new(
+                            "catalog",
+                            "Operators",
+                            Element::<NodeBundle>::new()
+                                .named("CatalogPanel")
+                                .style(style_panel)
+                                .children((AddNodeButton, CatalogView)),
+                        ),
+                        DockPanel::new(
+                            "preview",
+                            "Preview",
+                            Element::<NodeBundle>::new()
+                                .named("PreviewPanel")
+                                .style(style_panel)
+                                .children((
+                                    PreviewControls,
+                                    Element::<NodeBundle>::new()
+                                        .named("Preview")
+                                        .style(style_viewport)
+                                        .insert((viewport::ViewportInsetElement, Pickable::IGNORE)),
+                                )),
+                        ),
+                        DockPanel::new("graph", "Graph", CenterPanel),
+                    ])
+                    .layout(layout)
+                    .on_change(cx.create_callback(
+                        move |layout: In<DockLayout>, world: &mut World| {
+                            let narrow = is_narrow(world, id);
+                            let mut workspace = world.resource_mut::<WorkspaceLayout>();
+                            if narrow {
+                                workspace.narrow = layout.0;
+                            } else {
+                                workspace.wide = layout.0;
+                            }
                         },
-                        panel_width,
-                    )
-                    .children((
-                        AddNodeButton,
-                        CatalogView,
-                        PreviewControls,
-                        Element::<NodeBundle>::new()
-                            .named("Preview")
-                            .style(style_viewport)
-                            .style_dyn(
-                                move |width, sb: &mut StyleBuilder| {
-                                    sb.width(width - 16.).max_width(width - 16.);
-                                },
-                                panel_width,
-                            )
-                            .insert((viewport::ViewportInsetElement, Pickable::IGNORE)),
                     )),
-                Splitter::new()
-                    .direction(SplitterDirection::Vertical)
-                    .value(panel_width)
-                    .on_change(cx.create_callback(|value: In<f32>, world: &mut World| {
-                        let mut panel_width = world.get_resource_mut::<PanelWidth>().unwrap();
-                        panel_width.0 = value.clamp(200., 800.);
+                NotificationOverlay::new(),
+                DragPreviewOverlay,
+                CommandPalette::new()
+                    .open(palette_open.get(cx))
+                    .on_close(cx.create_callback(move |world: &mut World| {
+                        palette_open.set(world, false);
                     })),
-                CenterPanel,
             ))
     }
 }
@@ -220,13 +386,66 @@ fn wrapper_style(ss: &mut StyleBuilder) {
         .flex_direction(FlexDirection::Column);
 }
 
+fn style_graph_context_menu(ss: &mut StyleBuilder) {
+    ss.flex_grow(1.);
+}
+
 #[derive(Clone, PartialEq)]
 struct CenterPanel;
 
 impl ViewTemplate for CenterPanel {
     type View = impl View;
-    fn create(&self, _cx: &mut Cx) -> Self::View {
-        Element::<NodeBundle>::new()
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = cx.create_entity();
+        let drop_hovered = cx.is_drop_hovered(id);
+        let menu_target = cx.create_mutable(GraphMenuTarget::None);
+        let on_open_menu = cx.create_callback(move |clicked: In<Entity>, world: &mut World| {
+            let target = GraphMenuTarget::from_clicked(world, *clicked);
+            menu_target.set(world, target);
+        });
+
+        // Create a node where an operator from the catalog is dropped.
+        let graph_view_id = cx.use_inherited_component::<GraphViewId>().unwrap().0;
+        let on_drop_operator = cx.create_callback(
+            move |operator: In<OperatorDrag>,
+                  registry: Res<AppTypeRegistry>,
+                  drag_position: Res<DragPosition>,
+                  scrollarea_query: Query<(&Node, &GlobalTransform, &ScrollArea)>,
+                  mut graph: ResMut<GraphResource>,
+                  mut commands: Commands| {
+                let Ok((node, transform, scrollarea)) = scrollarea_query.get(graph_view_id) else {
+                    return;
+                };
+                let origin = node.logical_rect(transform).min;
+                let position = drag_position.0 - origin + scrollarea.scroll_position();
+                create_operator_node(
+                    &registry,
+                    &mut graph,
+                    &mut commands,
+                    operator.0,
+                    position.as_ivec2(),
+                );
+            },
+        );
+
+        Element::<NodeBundle>::for_entity(id)
+            .insert_dyn(
+                move |_| drop_target::<OperatorDrag>(|_| true, on_drop_operator),
+                (),
+            )
+            // Highlight the graph while an operator is dragged over it.
+            .style_dyn(
+                |drop_hovered, sb| {
+                    if drop_hovered {
+                        sb.outline_color(tokens::FOCUS)
+                            .outline_width(2)
+                            .outline_offset(-2.0);
+                    } else {
+                        sb.outline_color(Option::<Color>::None);
+                    }
+                },
+                drop_hovered,
+            )
             .insert_dyn(
                 |_| {
                     On::<GraphEvent>::run(
@@ -465,7 +684,13 @@ impl ViewTemplate for CenterPanel {
                 },
                 (),
             )
-            .children(GraphView)
+            .children(
+                ContextMenu::new()
+                    .style(style_graph_context_menu)
+                    .on_open(on_open_menu)
+                    .popup(GraphContextMenu(menu_target))
+                    .children(GraphView),
+            )
             .style(wrapper_style)
     }
 }
@@ -483,11 +708,3 @@ fn setup_ui(mut commands: Commands) -> Entity {
         },))
         .id()
 }
-
-pub fn close_on_esc(input: Res<ButtonInput<KeyCode>>, mut exit: EventWriter<AppExit>) {
-    if input.just_pressed(KeyCode::Escape) {
-        exit.send(AppExit::Success);
-    }
-}
-
-pub fn handle_key_events() {}
@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use bevy::{
     core_pipeline::core_3d::{Opaque3d, Opaque3dBinKey, CORE_3D_DEPTH_FORMAT},
     pbr::{
@@ -14,17 +16,31 @@ use bevy::{
             ViewBinnedRenderPhases,
         },
         render_resource::{
-            BlendState, ColorTargetState, ColorWrites, CompareFunction, DepthBiasState,
-            DepthStencilState, Face, FragmentState, FrontFace, MultisampleState, PipelineCache,
-            PolygonMode, PrimitiveState, RenderPipelineDescriptor, SpecializedMeshPipeline,
-            SpecializedMeshPipelineError, SpecializedMeshPipelines, StencilState, TextureFormat,
-            VertexState,
+            BlendState, CachedPipelineState, CachedRenderPipelineId, ColorTargetState, ColorWrites,
+            CompareFunction, DepthBiasState, DepthStencilState, Face, FragmentState, FrontFace,
+            MultisampleState, PipelineCache, PipelineCacheError, PolygonMode, PrimitiveState,
+            RenderPipelineDescriptor, SpecializedMeshPipeline, SpecializedMeshPipelineError,
+            SpecializedMeshPipelines, StencilState, TextureFormat, VertexState,
         },
         texture::BevyDefault,
         view::{self, ExtractedView, ViewTarget, VisibilitySystems, VisibleEntities},
         Render, RenderApp, RenderSet,
     },
+    utils::HashSet,
 };
+use bevy_quill_obsidian::controls::{Notify, Toast};
+
+/// Error messages from generated shaders which failed to compile. This is shared between the
+/// main world and the render world, so that failures can be reported to the user.
+#[derive(Resource, Clone, Default)]
+pub struct ShaderErrors(Arc<Mutex<Vec<String>>>);
+
+impl ShaderErrors {
+    /// Take all of the errors reported since the last call.
+    pub fn take(&self) -> Vec<String> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
 
 /// Component that associates a generated shader to a mesh.
 #[derive(Component, Default, Clone, ExtractComponent)]
@@ -173,6 +189,8 @@ fn queue_node_shader_item(
     render_meshes: Res<RenderAssets<GpuMesh>>,
     render_mesh_instances: Res<RenderMeshInstances>,
     shader_handle: Query<&NodeShader3dHandle>,
+    shader_errors: Res<ShaderErrors>,
+    mut failed: Local<HashSet<CachedRenderPipelineId>>,
 ) {
     let draw_custom_phase_item = opaque_draw_functions
         .read()
@@ -216,6 +234,22 @@ fn queue_node_shader_item(
                 )
                 .expect("Failed to specialize mesh pipeline");
 
+            // Report compilation failures once, rather than every frame.
+            match pipeline_cache.get_render_pipeline_state(pipeline_id) {
+                CachedPipelineState::Err(
+                    err @ (PipelineCacheError::ProcessShaderError(_)
+                    | PipelineCacheError::CreateShaderModule(_)),
+                ) => {
+                    if failed.insert(pipeline_id) {
+                        shader_errors.0.lock().unwrap().push(err.to_string());
+                    }
+                }
+                CachedPipelineState::Err(_) => {}
+                _ => {
+                    failed.remove(&pipeline_id);
+                }
+            }
+
             // Add the custom render item. We use the
             // [`BinnedRenderPhaseType::NonMesh`] type to skip the special
             // handling that Bevy has for meshes (preprocessing, indirect
@@ -239,25 +273,40 @@ fn queue_node_shader_item(
     }
 }
 
+/// Display a notification for each generated shader which failed to compile.
+fn report_shader_errors(shader_errors: Res<ShaderErrors>, mut notify: EventWriter<Notify>) {
+    for error in shader_errors.take() {
+        let message = error.lines().next().unwrap_or_default();
+        notify.send(Notify(Toast::error(format!(
+            "Shader compilation failed: {}",
+            message
+        ))));
+    }
+}
+
 /// Plugin that renders [`NodeShaderMesh3d`]s
 pub struct NodeShaderMeshPlugin;
 
 impl Plugin for NodeShaderMeshPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins(ExtractComponentPlugin::<NodeShader3dHandle>::default())
+        let shader_errors = ShaderErrors::default();
+        app.insert_resource(shader_errors.clone())
+            .add_plugins(ExtractComponentPlugin::<NodeShader3dHandle>::default())
             .add_systems(
                 PostUpdate,
                 // Make sure to tell Bevy to check our entity for visibility. Bevy won't
                 // do this by default, for efficiency reasons.
                 view::check_visibility::<WithNodeShader3dHandle>
                     .in_set(VisibilitySystems::CheckVisibility),
-            );
+            )
+            .add_systems(Update, report_shader_errors);
 
         // We make sure to add these to the render app, not the main app.
         let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
             return;
         };
         render_app
+            .insert_resource(shader_errors)
             .init_resource::<SpecializedMeshPipelines<NodeShaderMesh3dPipeline>>()
             .add_render_command::<Opaque3d, DrawNodeShaderMeshCommands>()
             .add_systems(Render, queue_node_shader_item.in_set(RenderSet::Queue));
@@ -4,11 +4,14 @@ use bevy::{color::palettes, prelude::*, ui};
 use bevy_mod_stylebuilder::{StyleBuilder, StyleBuilderLayout};
 use bevy_quill::*;
 use bevy_quill_obsidian::{
-    controls::{Button, IconButton, ToolIconButton, ToolPalette},
-    viewport, RoundedCorners,
+    controls::{Button, Icon, IconButton, IconColor, RadioOption, SegmentedControl, Tooltip},
+    tokens, viewport,
 };
 
-use crate::{gen::NodeOutput, graph::NodeSelected, pipeline::NodeShader3dHandle};
+use crate::{
+    gen::NodeOutput, graph::NodeSelected, pipeline::NodeShader3dHandle,
+    shader_source::ShaderSourceDialog,
+};
 
 #[derive(States, Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 enum PreviewMode {
@@ -56,14 +59,30 @@ fn style_preview_controls(ss: &mut StyleBuilder) {
 impl ViewTemplate for PreviewControls {
     type View = impl View;
 
-    fn create(&self, _cx: &mut bevy_quill::Cx) -> Self::View {
+    fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
+        let source_open = cx.create_mutable(false);
+        let on_show_source = cx.create_callback(move |world: &mut World| {
+            source_open.set(world, true);
+        });
+        let on_close_source = cx.create_callback(move |world: &mut World| {
+            source_open.set(world, false);
+        });
+
         Element::<NodeBundle>::new()
             .style(style_preview_controls)
             .children((
                 PreviewModeButtons,
-                IconButton::new("embedded://bevy_quill_obsidian/assets/icons/lock.png"),
-                Button::new().children("Source..."),
+                Tooltip::new()
+                    .content("Lock preview")
+                    .children(IconButton::new(
+                        "embedded://bevy_quill_obsidian/assets/icons/lock.png",
+                    )),
+                Button::new().on_click(on_show_source).children("Source..."),
                 Button::new().children("Export..."),
+                ShaderSourceDialog {
+                    open: source_open.get(cx),
+                    on_close: on_close_source,
+                },
             ))
     }
 }
@@ -77,78 +96,47 @@ impl ViewTemplate for PreviewModeButtons {
     fn create(&self, cx: &mut bevy_quill::Cx) -> Self::View {
         let mode = *cx.use_resource::<State<PreviewMode>>().get();
 
-        ToolPalette::new().columns(7).children((
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/square.png")
-                .size(Vec2::splat(20.))
-                .corners(RoundedCorners::Left)
-                .selected(mode == PreviewMode::Square)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Square);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/square2x2.png")
-                .size(Vec2::splat(20.))
-                .corners(RoundedCorners::None)
-                .selected(mode == PreviewMode::Square2X2)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Square2X2);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/square3x3.png")
-                .size(Vec2::splat(20.))
-                .corners(RoundedCorners::None)
-                .selected(mode == PreviewMode::Square3X3)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Square3X3);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/sphere.png")
-                .size(Vec2::splat(20.))
-                .tint(false)
-                .corners(RoundedCorners::None)
-                .selected(mode == PreviewMode::Sphere)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Sphere);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/cuboid.png")
-                .size(Vec2::splat(20.))
-                .tint(false)
-                .corners(RoundedCorners::None)
-                .selected(mode == PreviewMode::Cuboid)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Cuboid);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/tetra.png")
-                .size(Vec2::splat(20.))
-                .tint(false)
-                .corners(RoundedCorners::None)
-                .selected(mode == PreviewMode::Tetra)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Tetra);
-                    }),
-                ),
-            ToolIconButton::new("embedded://bevy_vortex/assets/icons/torus.png")
-                .size(Vec2::splat(20.))
-                .tint(false)
-                .corners(RoundedCorners::Right)
-                .selected(mode == PreviewMode::Torus)
-                .on_click(
-                    cx.create_callback(|mut mode: ResMut<NextState<PreviewMode>>| {
-                        mode.set(PreviewMode::Torus);
-                    }),
-                ),
-        ))
+        SegmentedControl::new()
+            .options([
+                preview_mode_option(PreviewMode::Square, "Square", "square.png", true),
+                preview_mode_option(PreviewMode::Square2X2, "Square 2x2", "square2x2.png", true),
+                preview_mode_option(PreviewMode::Square3X3, "Square 3x3", "square3x3.png", true),
+                preview_mode_option(PreviewMode::Sphere, "Sphere", "sphere.png", false),
+                preview_mode_option(PreviewMode::Cuboid, "Cuboid", "cuboid.png", false),
+                preview_mode_option(PreviewMode::Tetra, "Tetrahedron", "tetra.png", false),
+                preview_mode_option(PreviewMode::Torus, "Torus", "torus.png", false),
+            ])
+            .value(mode)
+            .on_change(cx.create_callback(
+                |value: In<PreviewMode>, mut mode: ResMut<NextState<PreviewMode>>| {
+                    mode.set(*value);
+                },
+            ))
     }
 }
 
+/// One segment of the preview mode selector: an icon with a tooltip. Shape icons are
+/// full-color images, so they are drawn untinted.
+fn preview_mode_option(
+    mode: PreviewMode,
+    name: &'static str,
+    icon: &'static str,
+    tint: bool,
+) -> RadioOption<PreviewMode> {
+    RadioOption::new(
+        mode,
+        Tooltip::new().content(name).children(
+            Icon::new(format!("embedded://bevy_vortex/assets/icons/{}", icon))
+                .size(Vec2::splat(20.))
+                .color(if tint {
+                    IconColor::Token(tokens::DIM)
+                } else {
+                    IconColor::Color(Srgba::WHITE)
+                }),
+        ),
+    )
+}
+
 /// A marker component for our shapes
 #[derive(Component)]
 pub(crate) struct PreviewShape;
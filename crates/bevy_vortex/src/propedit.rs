@@ -3,12 +3,17 @@ use crate::{
     graph::GraphNode,
     operator::{OpValuePrecision, OpValueRange, OpValueStep},
 };
-use bevy::{prelude::*, reflect::TypeInfo, ui};
+use bevy::{
+    prelude::*,
+    reflect::{DynamicEnum, DynamicVariant, ReflectRef, TypeInfo, VariantInfo},
+    ui,
+};
 use bevy_mod_stylebuilder::*;
 use bevy_quill::{prelude::*, Dynamic, IntoViewChild};
 use bevy_quill_obsidian::{
     controls::{
-        ColorEdit, ColorEditState, ColorMode, MenuButton, MenuPopup, Slider, SpinBox, Swatch,
+        ColorEdit, ColorEditState, ColorMode, MenuButton, MenuPopup, Select, SelectOption, Slider,
+        SpinBox, Swatch,
     },
     floating::{FloatAlign, FloatSide},
     size::Size,
@@ -33,6 +38,10 @@ impl ViewTemplate for GraphNodePropertyEdit {
             panic!("Expected StructInfo");
         };
         let field = st_info.field(self.field).unwrap();
+        let is_enum = matches!(
+            reflect.reflect_path(self.field).map(|f| f.reflect_ref()),
+            Ok(ReflectRef::Enum(_))
+        );
 
         Dynamic::new(match field.type_path() {
             "f32" | "i32" | "glam::Vec2" | "glam::Vec3" | "bevy_color::linear_rgba::LinearRgba"
@@ -63,6 +72,12 @@ impl ViewTemplate for GraphNodePropertyEdit {
                 field: self.field,
             }
             .into_view_child(),
+            _ if is_enum => GraphNodePropertyEditEnum {
+                node: self.node,
+                display_name: self.display_name,
+                field: self.field,
+            }
+            .into_view_child(),
 
             _ => {
                 warn!("Unsupported type: {}", field.type_path());
@@ -257,6 +272,8 @@ impl ViewTemplate for GraphNodePropertyEditLinearRgba {
             mode: ColorMode::Rgb,
             rgb: Srgba::default(),
             hsl: Hsla::default(),
+            hsv: Hsva::default(),
+            oklch: Oklcha::default(),
         });
 
         Element::<NodeBundle>::new()
@@ -305,3 +322,66 @@ impl ViewTemplate for GraphNodePropertyEditLinearRgba {
             ))
     }
 }
+
+#[derive(Clone, PartialEq)]
+pub struct GraphNodePropertyEditEnum {
+    node: Entity,
+    display_name: &'static str,
+    field: &'static str,
+}
+
+impl ViewTemplate for GraphNodePropertyEditEnum {
+    type View = impl View;
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let id = self.node;
+        let path = self.field;
+        let on_change = cx.create_callback(
+            move |variant: In<&'static str>,
+                  mut nodes: Query<&mut GraphNode>,
+                  mut commands: Commands| {
+                let mut node = nodes.get_mut(id).unwrap();
+                let reflect = node.operator_reflect_mut();
+                let field_reflect = reflect.reflect_path_mut(path).unwrap();
+                field_reflect.apply(&DynamicEnum::new(*variant, DynamicVariant::Unit));
+                commands.add(MarkModifiedCmd { start: id });
+            },
+        );
+
+        let node = cx.use_component::<GraphNode>(id).unwrap();
+        let field_reflect = node.operator_reflect().reflect_path(path).unwrap();
+        let ReflectRef::Enum(en) = field_reflect.reflect_ref() else {
+            panic!("Expected enum");
+        };
+        let Some(TypeInfo::Enum(enum_info)) = field_reflect.get_represented_type_info() else {
+            panic!("Expected EnumInfo");
+        };
+        let value = enum_info.variant(en.variant_name()).map(|v| v.name());
+
+        // Only unit variants can be chosen, since other variants would need field values.
+        let options: Vec<SelectOption<&'static str>> = enum_info
+            .iter()
+            .filter(|variant| matches!(variant, VariantInfo::Unit(_)))
+            .map(|variant| SelectOption::new(variant.name(), variant.name()))
+            .collect();
+
+        Element::<NodeBundle>::new()
+            .style(|sb: &mut StyleBuilder| {
+                sb.gap(4).justify_items(ui::JustifyItems::End);
+            })
+            .children((
+                Element::<NodeBundle>::new()
+                    .style(|sb: &mut StyleBuilder| {
+                        sb.flex_grow(1.0).flex_basis(0);
+                    })
+                    .children(self.display_name),
+                Select::new()
+                    .value(value)
+                    .options(options)
+                    .size(Size::Xxs)
+                    .style(|sb: &mut StyleBuilder| {
+                        sb.min_width(64).height(NODE_PROP_HEIGHT);
+                    })
+                    .on_change(on_change),
+            ))
+    }
+}
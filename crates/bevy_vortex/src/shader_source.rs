@@ -0,0 +1,157 @@
+use bevy::{prelude::*, render::render_resource::Source, ui};
+use bevy_mod_stylebuilder::*;
+use bevy_quill::*;
+use bevy_quill_obsidian::{
+    controls::{
+        Button, ButtonVariant, Dialog, DialogBody, DialogFooter, DialogHeader, HighlightSpan,
+        TextArea,
+    },
+    tokens,
+};
+
+use crate::preview::PreviewShaderHandle;
+
+/// WGSL keywords, highlighted in the shader source.
+const KEYWORDS: &[&str] = &[
+    "alias",
+    "break",
+    "case",
+    "const",
+    "const_assert",
+    "continue",
+    "continuing",
+    "default",
+    "diagnostic",
+    "discard",
+    "else",
+    "enable",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "let",
+    "loop",
+    "override",
+    "requires",
+    "return",
+    "struct",
+    "switch",
+    "true",
+    "var",
+    "while",
+];
+
+fn is_type_name(word: &str) -> bool {
+    matches!(
+        word,
+        "array" | "atomic" | "bool" | "f16" | "f32" | "i32" | "ptr" | "sampler" | "u32"
+    ) || ["mat", "texture", "vec"]
+        .iter()
+        .any(|prefix| word.starts_with(prefix))
+}
+
+/// Highlight comments, keywords, types, numbers and attributes in WGSL source. Only ASCII
+/// characters start or end a span, so the spans always fall on character boundaries.
+fn highlight_wgsl(text: &str) -> Vec<HighlightSpan> {
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let start = i;
+        let c = bytes[i];
+        if bytes[i..].starts_with(b"//") {
+            i += bytes[i..]
+                .iter()
+                .position(|b| *b == b'\n')
+                .unwrap_or(bytes.len() - i);
+            spans.push(HighlightSpan::new(start..i, tokens::DIM));
+        } else if bytes[i..].starts_with(b"/*") {
+            i += bytes[i + 2..]
+                .windows(2)
+                .position(|w| w == b"*/")
+                .map_or(bytes.len() - i, |end| end + 4);
+            spans.push(HighlightSpan::new(start..i, tokens::DIM));
+        } else if c == b'@' || c == b'_' || c.is_ascii_alphabetic() {
+            i += 1;
+            while i < bytes.len() && (bytes[i] == b'_' || bytes[i].is_ascii_alphanumeric()) {
+                i += 1;
+            }
+            let word = &text[start..i];
+            let color = if c == b'@' {
+                Some(tokens::ACCENT)
+            } else if KEYWORDS.contains(&word) {
+                Some(tokens::CODE)
+            } else if is_type_name(word) {
+                Some(tokens::RESOURCE)
+            } else {
+                None
+            };
+            if let Some(color) = color {
+                spans.push(HighlightSpan::new(start..i, color));
+            }
+        } else if c.is_ascii_digit() {
+            i += 1;
+            while i < bytes.len() && (bytes[i] == b'.' || bytes[i].is_ascii_alphanumeric()) {
+                i += 1;
+            }
+            spans.push(HighlightSpan::new(start..i, tokens::ASSET));
+        } else {
+            i += 1;
+        }
+    }
+    spans
+}
+
+fn style_source(ss: &mut StyleBuilder) {
+    ss.height(400);
+}
+
+/// Dialog which displays the generated WGSL source of the shader being previewed.
+#[derive(Clone, PartialEq)]
+pub struct ShaderSourceDialog {
+    /// Whether the dialog is open.
+    pub open: bool,
+
+    /// Callback called when the dialog is closed.
+    pub on_close: Callback,
+}
+
+impl ViewTemplate for ShaderSourceDialog {
+    type View = impl View;
+
+    fn create(&self, cx: &mut Cx) -> Self::View {
+        let handle = cx.use_resource::<PreviewShaderHandle>().0.clone();
+        let source = match cx.use_resource::<Assets<Shader>>().get(&handle) {
+            Some(Shader {
+                source: Source::Wgsl(source),
+                ..
+            }) => source.to_string(),
+            _ => String::new(),
+        };
+
+        Dialog::new()
+            .width(ui::Val::Px(640.))
+            .open(self.open)
+            .on_close(self.on_close)
+            .children((
+                DialogHeader::new().children("Shader Source"),
+                DialogBody::new().children(
+                    TextArea::new()
+                        .value(source)
+                        .placeholder("No shader has been generated.")
+                        .highlighter(highlight_wgsl)
+                        .line_numbers(true)
+                        .wrap(false)
+                        .read_only(true)
+                        .style(style_source),
+                ),
+                DialogFooter::new().children(
+                    Button::new()
+                        .variant(ButtonVariant::Primary)
+                        .autofocus(true)
+                        .on_click(self.on_close)
+                        .children("Close"),
+                ),
+            ))
+    }
+}